@@ -0,0 +1,227 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use paddle_rust_sdk::entities::{
+    Event, SubscriptionChargeItem, SubscriptionWithInclude, Transaction,
+};
+use paddle_rust_sdk::response::{self, SuccessResponse};
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+
+fn fixture(json: &str) -> Value {
+    serde_json::from_str(json).unwrap()
+}
+
+fn envelope(data: Value) -> Vec<u8> {
+    serde_json::to_vec(&json!({
+        "data": data,
+        "meta": { "request_id": "19ee6a0a-3e85-4b4c-b3b9-f4d0e3f6c1d7" }
+    }))
+    .unwrap()
+}
+
+fn product(i: usize) -> Value {
+    let mut product = fixture(include_str!("../tests/fixtures/product.json"));
+    product["id"] = format!("pro_01gsz4t5hdjse780zja8vvr{i:04}").into();
+    product["name"] = format!("Product {i}").into();
+    product["custom_data"] = json!({ "features": { "sso": true, "seats": i } });
+    product
+}
+
+fn price(i: usize) -> Value {
+    let mut price = fixture(include_str!("../tests/fixtures/price.json"));
+    price["id"] = format!("pri_01gsz8x8sawmvhz1pv30nge{i:04}").into();
+    price["product_id"] = format!("pro_01gsz4t5hdjse780zja8vvr{i:04}").into();
+    price["unit_price_overrides"] = json!([
+        { "country_codes": ["GB", "IE"], "unit_price": { "amount": "2500", "currency_code": "GBP" } }
+    ]);
+    price
+}
+
+/// The transaction fixture with its single item and line item repeated `items` times.
+fn transaction(items: usize) -> Value {
+    let mut transaction = fixture(include_str!("../tests/fixtures/transaction.json"));
+    let item = transaction["items"][0].clone();
+    let line_item = transaction["details"]["line_items"][0].clone();
+
+    transaction["items"] = (0..items)
+        .map(|i| {
+            let mut item = item.clone();
+            item["price"] = price(i);
+            item
+        })
+        .collect();
+    transaction["details"]["line_items"] = (0..items)
+        .map(|i| {
+            let mut line_item = line_item.clone();
+            line_item["id"] = format!("txnitm_01hv8wt98jahpbm1t1tzr0{i:04}").into();
+            line_item["price_id"] = format!("pri_01gsz8x8sawmvhz1pv30nge{i:04}").into();
+            line_item["product"] = product(i);
+            line_item
+        })
+        .collect();
+
+    transaction
+}
+
+/// The subscription fixture with its single item repeated `items` times, plus the next transaction preview that the `next_transaction` and `recurring_transaction_details` includes add.
+fn subscription_with_include(items: usize) -> Value {
+    let mut subscription = fixture(include_str!("../tests/fixtures/subscription.json"));
+    let item = subscription["items"][0].clone();
+
+    subscription["items"] = (0..items)
+        .map(|i| {
+            let mut item = item.clone();
+            item["price"] = price(i);
+            item["product"] = product(i);
+            item
+        })
+        .collect();
+
+    // Preview line items are transaction line items without an ID, for products that may not exist yet
+    let transaction = transaction(items);
+    let line_items = transaction["details"]["line_items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|line_item| {
+            let mut line_item = line_item.clone();
+            line_item.as_object_mut().unwrap().remove("id");
+            line_item["product"]["id"] = Value::Null;
+            line_item
+        })
+        .collect::<Vec<_>>();
+    let details = json!({
+        "tax_rates_used": transaction["details"]["tax_rates_used"],
+        "totals": transaction["details"]["totals"],
+        "line_items": line_items
+    });
+
+    subscription["next_transaction"] = json!({
+        "billing_period": {
+            "starts_at": "2024-05-12T10:18:47.635628Z",
+            "ends_at": "2024-06-12T10:18:47.635628Z"
+        },
+        "details": details,
+        "adjustments": []
+    });
+    subscription["recurring_transaction_details"] = details;
+
+    subscription
+}
+
+fn customer(i: usize) -> Value {
+    let mut customer = fixture(include_str!("../tests/fixtures/customer.json"));
+    customer["id"] = format!("ctm_01hv6y1jedq4p1n0yqn5ba3ky{i:04}").into();
+    customer["name"] = format!("Customer {i}").into();
+    customer["email"] = format!("customer-{i}@example.com").into();
+    customer
+}
+
+fn events(count: usize) -> Value {
+    let events = (0..count)
+        .map(|i| {
+            let (event_type, data) = match i % 3 {
+                0 => ("transaction.completed", transaction(5)),
+                1 => ("subscription.updated", subscription_with_include(5)),
+                _ => ("customer.updated", customer(i)),
+            };
+
+            json!({
+                "event_id": format!("evt_01hv8x2acma3zqs7qwvbcwbm{i:04}"),
+                "event_type": event_type,
+                "occurred_at": "2024-04-12T10:18:49.621022Z",
+                "data": data
+            })
+        })
+        .collect::<Vec<_>>();
+
+    Value::Array(events)
+}
+
+fn charge_items(count: usize) -> Vec<u8> {
+    let items = (0..count)
+        .map(|i| match i % 2 {
+            0 => json!({
+                "quantity": 1,
+                "price_id": format!("pri_01gsz98e27ak2tyhexptwc5{i:04}")
+            }),
+            _ => json!({
+                "quantity": 1,
+                "price": {
+                    "product_id": format!("pro_01gsz97mq9pa4fkyy0wqenepk{i:04}"),
+                    "description": "One-time onboarding session",
+                    "name": null,
+                    "tax_mode": "account_setting",
+                    "unit_price": { "amount": "19900", "currency_code": "USD" },
+                    "unit_price_overrides": [],
+                    "quantity": { "minimum": 1, "maximum": 1 },
+                    "custom_data": null
+                }
+            }),
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::to_vec(&items).unwrap()
+}
+
+fn decode_transaction(c: &mut Criterion) {
+    let body = envelope(transaction(50));
+
+    c.bench_function("decode transaction with 50 line items", |b| {
+        b.iter(|| {
+            let res: SuccessResponse<Transaction> =
+                response::from_slice(StatusCode::OK, std::hint::black_box(&body)).unwrap();
+            res
+        })
+    });
+}
+
+fn decode_subscription(c: &mut Criterion) {
+    let body = envelope(subscription_with_include(10));
+
+    c.bench_function("decode subscription with includes", |b| {
+        b.iter(|| {
+            let res: SuccessResponse<SubscriptionWithInclude> =
+                response::from_slice(StatusCode::OK, std::hint::black_box(&body)).unwrap();
+            res
+        })
+    });
+}
+
+fn decode_events(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode events");
+
+    for count in [10, 50] {
+        let body = envelope(events(count));
+
+        group.bench_with_input(BenchmarkId::from_parameter(count), &body, |b, body| {
+            b.iter(|| {
+                let res: SuccessResponse<Vec<Event>> =
+                    response::from_slice(StatusCode::OK, std::hint::black_box(body)).unwrap();
+                res
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn decode_charge_items(c: &mut Criterion) {
+    let body = charge_items(50);
+
+    c.bench_function("decode 50 subscription charge items", |b| {
+        b.iter(|| {
+            let res: Vec<SubscriptionChargeItem> =
+                serde_json::from_slice(std::hint::black_box(&body)).unwrap();
+            res
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    decode_transaction,
+    decode_subscription,
+    decode_events,
+    decode_charge_items
+);
+criterion_main!(benches);
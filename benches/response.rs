@@ -0,0 +1,50 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use paddle_rust_sdk::entities::Customer;
+use paddle_rust_sdk::response::{self, SuccessResponse};
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+
+fn customers_page(per_page: usize) -> Vec<u8> {
+    let customer: Value =
+        serde_json::from_str(include_str!("../tests/fixtures/customer.json")).unwrap();
+
+    let customers = (0..per_page)
+        .map(|i| {
+            let mut customer = customer.clone();
+            customer["id"] = format!("ctm_01hv6y1jedq4p1n0yqn5ba3ky{i:04}").into();
+            customer["name"] = format!("Customer {i}").into();
+            customer["email"] = format!("customer-{i}@example.com").into();
+            customer["custom_data"] = json!({ "internal_id": i });
+            customer
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::to_vec(&json!({
+        "data": customers,
+        "meta": {
+            "request_id": "19ee6a0a-3e85-4b4c-b3b9-f4d0e3f6c1d7",
+            "pagination": {
+                "per_page": per_page,
+                "next": "https://api.paddle.com/customers?after=ctm_01hv6y1jedq4p1n0yqn5ba3ky0199",
+                "has_more": true,
+                "estimated_total": 1000
+            }
+        }
+    }))
+    .unwrap()
+}
+
+fn decode_list(c: &mut Criterion) {
+    let body = customers_page(200);
+
+    c.bench_function("decode 200 customers", |b| {
+        b.iter(|| {
+            let res: SuccessResponse<Vec<Customer>> =
+                response::from_slice(StatusCode::OK, std::hint::black_box(&body)).unwrap();
+            res
+        })
+    });
+}
+
+criterion_group!(benches, decode_list);
+criterion_main!(benches);
@@ -8,10 +8,7 @@ async fn main() {
         .business_create("ctm_01jqztc78e1xfdgwhcgjzdrvgd", "Company Inc.")
         .company_number("202835086")
         .tax_identifier("BG202835086")
-        .contacts([Contact {
-            name: "Primary Contact".into(),
-            email: "test@example.com".into(),
-        }])
+        .contacts([Contact::new("Primary Contact", "test@example.com")])
         .send()
         .await
         .unwrap();
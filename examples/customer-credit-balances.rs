@@ -1,3 +1,4 @@
+use paddle_rust_sdk::enums::CurrencyCode;
 use paddle_rust_sdk::Paddle;
 
 #[tokio::main]
@@ -10,5 +11,6 @@ async fn main() {
         .await
         .unwrap();
 
+    dbg!(balances.data.available_minor(CurrencyCode::USD));
     dbg!(balances);
 }
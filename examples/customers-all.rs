@@ -4,8 +4,8 @@ use paddle_rust_sdk::Paddle;
 async fn main() {
     let client = Paddle::new(std::env::var("PADDLE_API_KEY").unwrap(), Paddle::SANDBOX).unwrap();
 
-    let mut list = client.customers_list();
-    let mut paginated = list.per_page(1).send();
+    let list = client.customers_list().per_page(1);
+    let mut paginated = list.send().prefetch(true);
     let customers = paginated.all().await.unwrap();
 
     for customer in customers {
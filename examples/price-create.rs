@@ -1,5 +1,6 @@
 use paddle_rust_sdk::{
-    enums::{CountryCodeSupported, CurrencyCode, Interval},
+    entities::Duration,
+    enums::{CountryCodeSupported, CurrencyCode},
     Paddle,
 };
 
@@ -15,8 +16,8 @@ async fn main() {
             CurrencyCode::USD,
         )
         .name("This is a test price")
-        .billing_cycle(20, Interval::Day)
-        .trial_period(10, Interval::Day)
+        .billing_cycle(Duration::months(1))
+        .trial_period(Duration::days(14))
         .add_unit_price_override([CountryCodeSupported::BG], 555, CurrencyCode::USD)
         .quantity(1..200)
         .custom_data([("grant_tokens".to_string(), "123".to_string())].into())
@@ -1,4 +1,4 @@
-use paddle_rust_sdk::Paddle;
+use paddle_rust_sdk::{enums::PriceInclude, Paddle};
 
 #[tokio::main]
 async fn main() {
@@ -6,7 +6,7 @@ async fn main() {
 
     let price = client
         .price_get("pri_01jqxvdyjkp961jzv4me7ezg4d")
-        .include(["product"])
+        .include([PriceInclude::Product])
         .send()
         .await
         .unwrap();
@@ -5,10 +5,7 @@ async fn main() {
     let client = Paddle::new(std::env::var("PADDLE_API_KEY").unwrap(), Paddle::SANDBOX).unwrap();
 
     let res = client
-        .pricing_preview([PricePreviewItem {
-            price_id: "pri_01jqxvdyjkp961jzv4me7ezg4d".into(),
-            quantity: 1,
-        }])
+        .pricing_preview([PricePreviewItem::new("pri_01jqxvdyjkp961jzv4me7ezg4d", 1)])
         .send()
         .await
         .unwrap();
@@ -1,4 +1,4 @@
-use paddle_rust_sdk::Paddle;
+use paddle_rust_sdk::{enums::ProductInclude, Paddle};
 
 #[tokio::main]
 async fn main() {
@@ -6,7 +6,7 @@ async fn main() {
 
     let product = client
         .product_get("pro_01jqx9rdbdhs1zb1sj5v475fdz")
-        .include(["prices"])
+        .include([ProductInclude::Prices])
         .send()
         .await
         .unwrap();
@@ -1,15 +1,15 @@
-use paddle_rust_sdk::Paddle;
+use paddle_rust_sdk::{enums::ProductInclude, Paddle};
 
 #[tokio::main]
 async fn main() {
     let client = Paddle::new(std::env::var("PADDLE_API_KEY").unwrap(), Paddle::SANDBOX).unwrap();
 
-    let mut products_list = client.products_list();
-    let mut products = products_list
-        .include(["prices"])
+    let products_list = client
+        .products_list()
+        .include([ProductInclude::Prices])
         .order_by_asc("id")
-        .per_page(20)
-        .send();
+        .per_page(20);
+    let mut products = products_list.send();
 
     while let Some(products) = products.next().await.unwrap() {
         dbg!(products);
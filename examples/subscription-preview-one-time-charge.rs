@@ -1,4 +1,4 @@
-use paddle_rust_sdk::{enums::EffectiveFrom, transactions::TransactionItem, Paddle};
+use paddle_rust_sdk::{enums::EffectiveFrom, Paddle};
 
 #[tokio::main]
 async fn main() {
@@ -6,14 +6,12 @@ async fn main() {
 
     let response = client
         .subscription_preview_one_time_charge("sub_01jt0rbstf4v79k955pa7jhmjy")
-        .items([TransactionItem::CatalogItem {
-            price_id: "pri_01jk83yyzeb91c32t3tktt8697".into(),
-            quantity: 1,
-        }])
+        .append_catalog_item("pri_01jk83yyzeb91c32t3tktt8697", 1)
         .effective_from(EffectiveFrom::Immediately)
         .send()
         .await
         .unwrap();
 
-    dbg!(response.data);
+    dbg!(response.data.immediate_total());
+    dbg!(response.data.next_billing_total());
 }
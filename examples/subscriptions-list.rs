@@ -8,12 +8,12 @@ use paddle_rust_sdk::{
 async fn main() {
     let client = Paddle::new(std::env::var("PADDLE_API_KEY").unwrap(), Paddle::SANDBOX).unwrap();
 
-    let mut list = client.subscriptions_list();
-    let mut paginated = list
+    let list = client
+        .subscriptions_list()
         // .status([SubscriptionStatus::Trialing])
         .per_page(25)
-        .collection_mode(CollectionMode::Automatic)
-        .send();
+        .collection_mode(CollectionMode::Automatic);
+    let mut paginated = list.send();
 
     while let Some(page) = paginated.next().await.unwrap() {
         dbg!(page.data);
@@ -1,6 +1,6 @@
 use paddle_rust_sdk::{
     entities::{TransactionItemNonCatalogPrice, TransactionSubscriptionProductCreate},
-    enums::{CurrencyCode, TaxCategory},
+    enums::{CurrencyCode, TaxCategory, TransactionInclude},
     Paddle,
 };
 
@@ -10,7 +10,7 @@ async fn main() {
 
     let transaction = client
         .transaction_create()
-        .include(["address"])
+        .include([TransactionInclude::Address])
         .append_catalog_item("pri_01jqxvdyjkp961jzv4me7ezg4d", 1)
         .append_non_catalog_item(
             TransactionItemNonCatalogPrice::new(
@@ -19,13 +19,10 @@ async fn main() {
                 CurrencyCode::USD,
             )
             .name("This is a test")
-            .product(TransactionSubscriptionProductCreate {
-                name: "Test".into(),
-                description: Some("Test".into()),
-                tax_category: TaxCategory::Standard,
-                image_url: None,
-                custom_data: None,
-            }),
+            .product(
+                TransactionSubscriptionProductCreate::new("Test", TaxCategory::Standard)
+                    .description("Test"),
+            ),
             1,
         )
         .append_non_catalog_item(
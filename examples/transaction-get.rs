@@ -1,3 +1,4 @@
+use paddle_rust_sdk::enums::TransactionInclude;
 use paddle_rust_sdk::Paddle;
 
 #[tokio::main]
@@ -6,7 +7,7 @@ async fn main() {
 
     let response = client
         .transaction_get("txn_01jkfx8v9z4pee0p5bd35x95bp")
-        .include(["address"])
+        .include([TransactionInclude::Address, TransactionInclude::Customer])
         .send()
         .await
         .unwrap();
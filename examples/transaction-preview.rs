@@ -18,13 +18,10 @@ async fn main() {
                 CurrencyCode::USD,
             )
             .name("This is a test")
-            .product(TransactionSubscriptionProductCreate {
-                name: "Test".into(),
-                description: Some("Test".into()),
-                tax_category: TaxCategory::Standard,
-                image_url: None,
-                custom_data: None,
-            }),
+            .product(
+                TransactionSubscriptionProductCreate::new("Test", TaxCategory::Standard)
+                    .description("Test"),
+            ),
             1,
         )
         .append_non_catalog_item(
@@ -1,4 +1,7 @@
-use paddle_rust_sdk::{enums::TransactionStatus, Paddle};
+use paddle_rust_sdk::{
+    enums::{TransactionInclude, TransactionStatus},
+    Paddle,
+};
 
 #[tokio::main]
 async fn main() {
@@ -6,7 +9,7 @@ async fn main() {
 
     let response = client
         .transaction_update("txn_01jkfx8v9z4pee0p5bd35x95bp")
-        .include(["address"])
+        .include([TransactionInclude::Address])
         .status(TransactionStatus::Billed)
         .send()
         .await
@@ -5,13 +5,13 @@ use paddle_rust_sdk::{enums::CollectionMode, Paddle};
 async fn main() {
     let client = Paddle::new(std::env::var("PADDLE_API_KEY").unwrap(), Paddle::SANDBOX).unwrap();
 
-    let mut list = client.transactions_list();
-    let mut paginated = list
+    let list = client
+        .transactions_list()
         .collection_mode(CollectionMode::Automatic)
         .billed_at_lt(Utc::now())
         // .customer_id(["ctm_01jk84f1s981kf2a4fqmv968ba"])
-        .per_page(1)
-        .send();
+        .per_page(1);
+    let mut paginated = list.send();
 
     while let Some(transactions) = paginated.next().await.unwrap() {
         dbg!(transactions);
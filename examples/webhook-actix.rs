@@ -1,4 +1,4 @@
-use actix_web::{post, App, HttpRequest, HttpResponse, HttpServer, Responder};
+use actix_web::{post, web, App, HttpRequest, HttpResponse, HttpServer, Responder};
 use paddle_rust_sdk::{webhooks::MaximumVariance, Paddle};
 
 #[tokio::main]
@@ -13,7 +13,7 @@ async fn main() -> std::io::Result<()> {
 
 /// http://127.0.0.1:8080/paddle-callback
 #[post("/paddle-callback")]
-async fn paddle_callback(request_body: String, req: HttpRequest) -> impl Responder {
+async fn paddle_callback(request_body: web::Bytes, req: HttpRequest) -> impl Responder {
     let maybe_signature = req
         .headers()
         .get("paddle-signature")
@@ -1,18 +1,76 @@
 //! Contains all Paddle entity types.
+//!
+//! Entity structs are `#[non_exhaustive]` so fields can be added as Paddle adds them. Structs that are sent to Paddle,
+//! such as [Contact], [Money] or [PricePreviewItem], are created with their `new` constructors.
 
-use std::collections::HashMap;
+use std::fmt;
 
 use chrono::DateTime;
-use chrono::FixedOffset;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::enums::*;
 use crate::ids::*;
+use crate::permissions::Permission;
+
+/// Implement [Debug](fmt::Debug) for an entity that holds personal data. Fields marked `redact` are printed as `[REDACTED]`.
+///
+/// Only used with the `redact-pii` feature. Every field has to be listed, so adding a field to the entity without deciding whether it's personal data fails to compile.
+#[cfg(feature = "redact-pii")]
+macro_rules! redacted_debug {
+    ($name:ident { $($field:ident: $kind:ident),* $(,)? }) => {
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let Self { $($field),* } = self;
+
+                f.debug_struct(stringify!($name))
+                    $(.field(stringify!($field), redacted_debug!(@$kind $field)))*
+                    .finish()
+            }
+        }
+    };
+    (@show $field:ident) => { $field };
+    (@redact $field:ident) => {{
+        let _ = $field;
+        &Redacted
+    }};
+}
+
+/// Placeholder printed instead of personal data.
+#[cfg(feature = "redact-pii")]
+struct Redacted;
+
+#[cfg(feature = "redact-pii")]
+impl fmt::Debug for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+/// Write an amount in the lowest denomination formatted for its currency. Amounts that aren't integers are written as-is, followed by the currency code.
+fn write_amount(
+    f: &mut fmt::Formatter<'_>,
+    amount: &str,
+    currency_code: &CurrencyCode,
+) -> fmt::Result {
+    match amount.parse() {
+        Ok(amount) => f.write_str(&currency_code.format_minor(amount)),
+        Err(_) => write!(f, "{} {}", amount, currency_code.as_ref()),
+    }
+}
+
+/// Serialized name of an enum value, e.g. `past_due` for [SubscriptionStatus::PastDue].
+fn variant_name(value: &impl Serialize) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => String::from("unknown"),
+    }
+}
 
 /// Import information for this entity. `null` if this entity is not imported.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct ImportMeta {
     /// Reference or identifier for this entity from the solution where it was imported from.
     pub external_id: Option<String>,
@@ -21,7 +79,9 @@ pub struct ImportMeta {
 }
 
 /// Represents an address entity.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(not(feature = "redact-pii"), derive(Debug))]
+#[non_exhaustive]
 pub struct Address {
     /// Unique Paddle ID for this address entity, prefixed with `add_`.
     pub id: AddressID,
@@ -53,8 +113,34 @@ pub struct Address {
     pub import_meta: Option<ImportMeta>,
 }
 
+#[cfg(feature = "redact-pii")]
+redacted_debug!(Address {
+    id: show,
+    customer_id: show,
+    description: redact,
+    first_line: redact,
+    second_line: redact,
+    city: redact,
+    postal_code: redact,
+    region: redact,
+    country_code: show,
+    custom_data: show,
+    status: show,
+    created_at: show,
+    updated_at: show,
+    import_meta: show,
+});
+
+/// Prints the ID and status, e.g. `add_01gm302t81w94gyjpjpqypkzkf (active)`. The address itself is left out, as it's personal data.
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.id, variant_name(&self.status))
+    }
+}
+
 /// Represents an address entity when previewing addresses.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct AddressPreview {
     /// ZIP or postal code of this address. Include for more accurate tax calculations.
     pub postal_code: Option<String>,
@@ -64,6 +150,7 @@ pub struct AddressPreview {
 
 /// Breakdown of the total for an adjustment.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct AdjustmentTotals {
     /// Total before tax. For tax adjustments, the value is 0.
     pub subtotal: String,
@@ -84,6 +171,7 @@ pub struct AdjustmentTotals {
 
 /// Chargeback fee before conversion to the payout currency. `null` when the chargeback fee is the same as the payout currency.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Original {
     /// Fee amount for this chargeback in the original currency.
     pub amount: String,
@@ -93,6 +181,7 @@ pub struct Original {
 
 /// Chargeback fees incurred for this adjustment. Only returned when the adjustment `action` is `chargeback` or `chargeback_warning`.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct ChargebackFee {
     /// Chargeback fee converted into the payout currency.
     pub amount: String,
@@ -102,6 +191,7 @@ pub struct ChargebackFee {
 
 /// Breakdown of how this adjustment affects your payout balance.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct AdjustmentPayoutTotals {
     /// Adjustment total before tax and fees.
     pub subtotal: String,
@@ -121,6 +211,7 @@ pub struct AdjustmentPayoutTotals {
 
 /// Calculated totals for the tax applied to this adjustment.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct AdjustmentTaxRateUsedTotals {
     /// Total before tax. For tax adjustments, the value is 0.
     pub subtotal: String,
@@ -131,6 +222,7 @@ pub struct AdjustmentTaxRateUsedTotals {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct AdjustmentTaxRateUsed {
     /// Rate used to calculate tax for this adjustment.
     pub tax_rate: String,
@@ -140,6 +232,7 @@ pub struct AdjustmentTaxRateUsed {
 
 /// Represents an adjustment entity.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Adjustment {
     /// Unique Paddle ID for this adjustment entity, prefixed with `adj_`.
     pub id: AdjustmentID,
@@ -183,6 +276,7 @@ pub struct Adjustment {
 
 /// Represents an adjustment entity when creating adjustments.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct AdjustmentCreate {
     /// Unique Paddle ID for this adjustment entity, prefixed with `adj_`.
     pub id: AdjustmentID,
@@ -216,12 +310,13 @@ pub struct AdjustmentCreate {
     /// Breakdown of how this adjustment affects your payout balance.
     pub payout_totals: Option<AdjustmentPayoutTotals>,
     /// RFC 3339 datetime string of when this entity was created. Set automatically by Paddle.
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// RFC 3339 datetime string of when this entity was updated. Set automatically by Paddle.
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TimePeriod {
     /// RFC 3339 datetime string.
     pub starts_at: DateTime<Utc>,
@@ -229,8 +324,15 @@ pub struct TimePeriod {
     pub ends_at: DateTime<Utc>,
 }
 
+impl TimePeriod {
+    pub fn new(starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> Self {
+        Self { starts_at, ends_at }
+    }
+}
+
 /// How proration was calculated for this item. Populated when a transaction is created from a subscription change, where `proration_billing_mode` was `prorated_immediately` or `prorated_next_billing_period`. Set automatically by Paddle.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Proration {
     /// Rate used to calculate proration.
     pub rate: String,
@@ -239,6 +341,7 @@ pub struct Proration {
 
 /// Breakdown of the total for an adjustment item.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct AdjustmentItemTotals {
     /// Amount multiplied by quantity.
     pub subtotal: String,
@@ -249,6 +352,7 @@ pub struct AdjustmentItemTotals {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct AdjustmentItem {
     /// Unique Paddle ID for this transaction item, prefixed with `txnitm_`. Used when working with [adjustments](https://developer.paddle.com/build/transactions/create-transaction-adjustments).
     pub item_id: TransactionItemID,
@@ -264,6 +368,7 @@ pub struct AdjustmentItem {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct AdjustmentItemInput {
     /// Unique Paddle ID for this transaction item, prefixed with `txnitm_`. Used when working with [adjustments](https://developer.paddle.com/build/transactions/create-transaction-adjustments).
     pub item_id: TransactionItemID,
@@ -274,8 +379,29 @@ pub struct AdjustmentItemInput {
     pub amount: Option<String>,
 }
 
+impl AdjustmentItemInput {
+    /// Adjust the full total of a transaction item.
+    pub fn full(item_id: impl Into<TransactionItemID>) -> Self {
+        Self {
+            item_id: item_id.into(),
+            r#type: AdjustmentItemType::Full,
+            amount: None,
+        }
+    }
+
+    /// Adjust part of a transaction item. `amount` is in the lowest denomination for the currency of the transaction.
+    pub fn partial(item_id: impl Into<TransactionItemID>, amount: u64) -> Self {
+        Self {
+            item_id: item_id.into(),
+            r#type: AdjustmentItemType::Partial,
+            amount: Some(amount.to_string()),
+        }
+    }
+}
+
 /// Represents an adjustment entity when previewing adjustments.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct AdjustmentPreview {
     /// Unique Paddle ID for this transaction entity, prefixed with `txn_`.
     pub transaction_id: TransactionID,
@@ -286,6 +412,7 @@ pub struct AdjustmentPreview {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Duration {
     /// Unit of time.
     pub interval: Interval,
@@ -293,31 +420,109 @@ pub struct Duration {
     pub frequency: u64,
 }
 
+impl Duration {
+    /// Duration of `frequency` units of `interval`, e.g. `Duration::new(3, Interval::Month)` for a quarter.
+    pub fn new(frequency: u64, interval: Interval) -> Self {
+        Self {
+            interval,
+            frequency,
+        }
+    }
+
+    /// Duration of a number of days.
+    pub fn days(frequency: u64) -> Self {
+        Self::new(frequency, Interval::Day)
+    }
+
+    /// Duration of a number of weeks.
+    pub fn weeks(frequency: u64) -> Self {
+        Self::new(frequency, Interval::Week)
+    }
+
+    /// Duration of a number of months.
+    pub fn months(frequency: u64) -> Self {
+        Self::new(frequency, Interval::Month)
+    }
+
+    /// Duration of a number of years.
+    pub fn years(frequency: u64) -> Self {
+        Self::new(frequency, Interval::Year)
+    }
+}
+
 /// Details for invoicing. Required if `collection_mode` is `manual`.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct BillingDetails {
     /// Whether the related transaction may be paid using Paddle Checkout. If omitted when creating a transaction, defaults to `false`.
+    #[serde(default)]
     pub enable_checkout: bool,
     /// Customer purchase order number. Appears on invoice documents.
-    pub purchase_order_number: String,
+    #[serde(default)]
+    pub purchase_order_number: Option<String>,
     /// Notes or other information to include on this invoice. Appears on invoice documents.
     pub additional_information: Option<String>,
     pub payment_terms: Duration,
 }
 
+impl BillingDetails {
+    /// Billing details with payment due `days` after an invoice is issued, e.g. `30` for net 30 terms.
+    ///
+    /// Paying with Paddle Checkout is disabled and no purchase order number is set. Chain the other methods to change that:
+    ///
+    /// ```rust
+    /// use paddle_rust_sdk_types::entities::BillingDetails;
+    ///
+    /// let billing_details = BillingDetails::payment_terms_days(30)
+    ///     .purchase_order_number("PO-123")
+    ///     .enable_checkout(true);
+    /// ```
+    pub fn payment_terms_days(days: u64) -> Self {
+        Self {
+            enable_checkout: false,
+            purchase_order_number: None,
+            additional_information: None,
+            payment_terms: Duration::days(days),
+        }
+    }
+
+    /// Customer purchase order number. Appears on invoice documents.
+    pub fn purchase_order_number(mut self, purchase_order_number: impl Into<String>) -> Self {
+        self.purchase_order_number = Some(purchase_order_number.into());
+        self
+    }
+
+    /// Whether the related transaction may be paid using Paddle Checkout.
+    pub fn enable_checkout(mut self, enable_checkout: bool) -> Self {
+        self.enable_checkout = enable_checkout;
+        self
+    }
+
+    /// Notes or other information to include on invoices. Appears on invoice documents.
+    pub fn additional_information(mut self, additional_information: impl Into<String>) -> Self {
+        self.additional_information = Some(additional_information.into());
+        self
+    }
+}
+
 /// Details for invoicing. Required if `collection_mode` is `manual`.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct BillingDetailsUpdate {
     /// Whether the related transaction may be paid using Paddle Checkout.
+    #[serde(default)]
     pub enable_checkout: bool,
     /// Customer purchase order number. Appears on invoice documents.
-    pub purchase_order_number: String,
+    #[serde(default)]
+    pub purchase_order_number: Option<String>,
     /// Notes or other information to include on this invoice. Appears on invoice documents.
     pub additional_information: Option<String>,
     pub payment_terms: Duration,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(not(feature = "redact-pii"), derive(Debug))]
+#[non_exhaustive]
 pub struct Contact {
     /// Full name.
     pub name: String,
@@ -325,8 +530,25 @@ pub struct Contact {
     pub email: String,
 }
 
+impl Contact {
+    pub fn new(name: impl Into<String>, email: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            email: email.into(),
+        }
+    }
+}
+
+#[cfg(feature = "redact-pii")]
+redacted_debug!(Contact {
+    name: redact,
+    email: redact,
+});
+
 /// Represents a business entity.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(not(feature = "redact-pii"), derive(Debug))]
+#[non_exhaustive]
 pub struct Business {
     /// Unique Paddle ID for this business entity, prefixed with `biz_`.
     pub id: BusinessID,
@@ -352,8 +574,32 @@ pub struct Business {
     pub import_meta: Option<ImportMeta>,
 }
 
+#[cfg(feature = "redact-pii")]
+redacted_debug!(Business {
+    id: show,
+    customer_id: show,
+    name: redact,
+    company_number: redact,
+    tax_identifier: redact,
+    status: show,
+    contacts: redact,
+    created_at: show,
+    updated_at: show,
+    custom_data: show,
+    import_meta: show,
+});
+
+/// Prints the ID and status, e.g. `biz_01jqztc78e1xfdgwhcgjzdrvgd (active)`. The name and contacts are left out, as they're personal data.
+impl fmt::Display for Business {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.id, variant_name(&self.status))
+    }
+}
+
 /// Card metadata
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(not(feature = "redact-pii"), derive(Debug))]
+#[non_exhaustive]
 pub struct Card {
     /// Type of credit or debit card used to pay.
     pub r#type: CardType,
@@ -367,7 +613,17 @@ pub struct Card {
     pub cardholder_name: String,
 }
 
+#[cfg(feature = "redact-pii")]
+redacted_debug!(Card {
+    r#type: show,
+    last4: show,
+    expiry_month: show,
+    expiry_year: show,
+    cardholder_name: redact,
+});
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct CustomerBalance {
     /// Total amount of credit available to use.
     pub available: String,
@@ -377,8 +633,26 @@ pub struct CustomerBalance {
     pub used: String,
 }
 
+impl CustomerBalance {
+    /// [available](Self::available) in the lowest denomination of the currency. `None` if the amount is not a valid integer.
+    pub fn available_minor(&self) -> Option<i64> {
+        self.available.parse().ok()
+    }
+
+    /// [reserved](Self::reserved) in the lowest denomination of the currency. `None` if the amount is not a valid integer.
+    pub fn reserved_minor(&self) -> Option<i64> {
+        self.reserved.parse().ok()
+    }
+
+    /// [used](Self::used) in the lowest denomination of the currency. `None` if the amount is not a valid integer.
+    pub fn used_minor(&self) -> Option<i64> {
+        self.used.parse().ok()
+    }
+}
+
 /// Represents a credit balance for a customer.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct CreditBalance {
     /// Unique Paddle ID for this customer entity, prefixed with `ctm_`.
     pub customer_id: CustomerID,
@@ -387,8 +661,49 @@ pub struct CreditBalance {
     pub balance: CustomerBalance,
 }
 
+/// Credit balances of a customer, one for each currency the customer has credit activity in.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct CreditBalances(pub Vec<CreditBalance>);
+
+impl CreditBalances {
+    /// Balance in a currency. `None` if the customer has no credit activity in it.
+    pub fn for_currency(&self, currency_code: CurrencyCode) -> Option<&CustomerBalance> {
+        self.0
+            .iter()
+            .find(|balance| balance.currency_code == currency_code)
+            .map(|balance| &balance.balance)
+    }
+
+    /// Credit available to use in a currency, in its lowest denomination. `Some(0)` if the customer has no credit activity in it, `None` if the amount is not a valid integer.
+    pub fn available_minor(&self, currency_code: CurrencyCode) -> Option<i64> {
+        self.for_currency(currency_code)
+            .map_or(Some(0), CustomerBalance::available_minor)
+    }
+}
+
+impl std::ops::Deref for CreditBalances {
+    type Target = [CreditBalance];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl IntoIterator for CreditBalances {
+    type Item = CreditBalance;
+    type IntoIter = std::vec::IntoIter<CreditBalance>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
 /// Represents a customer entity.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(not(feature = "redact-pii"), derive(Debug))]
+#[non_exhaustive]
 pub struct Customer {
     /// Unique Paddle ID for this customer entity, prefixed with `ctm_`.
     pub id: CustomerID,
@@ -413,8 +728,31 @@ pub struct Customer {
     pub import_meta: Option<ImportMeta>,
 }
 
+#[cfg(feature = "redact-pii")]
+redacted_debug!(Customer {
+    id: show,
+    name: redact,
+    email: redact,
+    marketing_consent: show,
+    status: show,
+    custom_data: show,
+    locale: show,
+    created_at: show,
+    updated_at: show,
+    import_meta: show,
+});
+
+/// Prints the ID and status, e.g. `ctm_01hv6y1jedq4p1n0yqn5ba3ky4 (active)`. The name and email are left out, as they're personal data.
+impl fmt::Display for Customer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.id, variant_name(&self.status))
+    }
+}
+
 /// PayPal metadata
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
+#[cfg_attr(not(feature = "redact-pii"), derive(Debug))]
+#[non_exhaustive]
 pub struct PayPal {
     /// Email address associated with the PayPal account.
     pub email: String,
@@ -422,8 +760,15 @@ pub struct PayPal {
     pub reference: String,
 }
 
+#[cfg(feature = "redact-pii")]
+redacted_debug!(PayPal {
+    email: redact,
+    reference: show,
+});
+
 /// Korean local credit or debit card metadata
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SouthKoreaLocalCard {
     /// Type of Korean payment method used to pay.
     pub r#type: SouthKoreaLocalCardType,
@@ -432,7 +777,10 @@ pub struct SouthKoreaLocalCard {
 }
 
 /// Represents a customer payment method entity.
+///
+/// `payment_method.saved` and `payment_method.deleted` webhooks deliver a slimmer payload without the `card`, `paypal` and `south_korea_local_card` details, so these fields are `None` for payment methods received from events.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct PaymentMethod {
     /// Unique Paddle ID for this payment method entity, prefixed with `paymtd_`.
     pub id: PaymentMethodID,
@@ -458,12 +806,14 @@ pub struct PaymentMethod {
 
 /// Authenticated customer portal deep links that aren't associated with a specific entity.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct CustomerPortalSessionGeneralUrls {
     /// Link to the overview page in the customer portal.
     pub overview: String,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct CustomerPortalSessionSubscriptionUrls {
     /// Unique Paddle ID for this subscription entity, prefixed with `sub_`.
     pub id: SubscriptionID,
@@ -477,6 +827,7 @@ pub struct CustomerPortalSessionSubscriptionUrls {
 
 /// Authenticated customer portal deep links. For security, the `token` appended to each link is temporary. You shouldn't store these links.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct CustomerPortalSessionUrls {
     /// Authenticated customer portal deep links that aren't associated with a specific entity.
     pub general: CustomerPortalSessionGeneralUrls,
@@ -490,6 +841,7 @@ pub struct CustomerPortalSessionUrls {
 
 /// Represents a customer portal session.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct CustomerPortalSession {
     /// Unique Paddle ID for this customer portal session entity, prefixed with `cpls_`.
     pub id: CustomerPortalSessionID,
@@ -501,8 +853,21 @@ pub struct CustomerPortalSession {
     pub created_at: DateTime<Utc>,
 }
 
+impl CustomerPortalSession {
+    /// How long the links of a session are assumed to work after it's created.
+    ///
+    /// Paddle doesn't document how long the token in the links stays valid. This is a conservative estimate, meant to keep links from being stored and handed out later.
+    pub const LINK_LIFETIME_HINT: chrono::Duration = chrono::Duration::minutes(10);
+
+    /// When the links of this session should no longer be used. Create a new session instead of reusing links after this time.
+    pub fn expires_hint(&self) -> DateTime<Utc> {
+        self.created_at + Self::LINK_LIFETIME_HINT
+    }
+}
+
 /// Represents a customer authentication token.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct CustomerAuthenticationToken {
     /// Authentication token generated by Paddle for this customer. Pass to Paddle.js when opening a checkout to let customers work with saved payment methods.
     pub customer_auth_token: String,
@@ -512,6 +877,7 @@ pub struct CustomerAuthenticationToken {
 
 /// Represents a discount entity.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Discount {
     /// Unique Paddle ID for this discount, prefixed with `dsc_`.
     pub id: DiscountID,
@@ -560,18 +926,29 @@ pub struct Discount {
     pub import_meta: Option<ImportMeta>,
 }
 
+impl Discount {
+    /// Products and prices this discount is restricted to, see [restrict_to](Self::restrict_to) for the raw IDs. `None` if this discount applies to all products and prices.
+    pub fn restrictions(&self) -> Option<Vec<RestrictTo>> {
+        self.restrict_to
+            .as_ref()
+            .map(|ids| ids.iter().map(|id| RestrictTo::parse(id)).collect())
+    }
+}
+
 /// Details of the discount applied to this subscription.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionDiscountTimePeriod {
     /// Unique Paddle ID for this discount, prefixed with `dsc_`.
     pub id: DiscountID,
     /// RFC 3339 datetime string of when this discount was first applied. `null` for canceled subscriptions where a discount was redeemed but never applied to a transaction.
-    pub starts_at: Option<DateTime<FixedOffset>>,
+    pub starts_at: Option<DateTime<Utc>>,
     /// RFC 3339 datetime string of when this discount no longer applies. Where a discount has `maximum_recurring_intervals`, this is the date of the last billing period where this discount applies. `null` where a discount recurs forever.
-    pub ends_at: Option<DateTime<FixedOffset>>,
+    pub ends_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Errors {
     /// Field where validation error occurred.
     pub field: String,
@@ -581,6 +958,7 @@ pub struct Errors {
 
 /// Represents an error.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Error {
     /// Type of error encountered.
     pub r#type: Type,
@@ -596,12 +974,14 @@ pub struct Error {
 
 /// Information about this response.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Meta {
     /// Unique ID for the request relating to this response. Provide this when contacting Paddle support about a specific request.
     pub request_id: String,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct ValidationError {
     /// Represents an error.
     pub error: Error,
@@ -611,6 +991,7 @@ pub struct ValidationError {
 
 /// Represents an event entity.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Event {
     /// Unique Paddle ID for this event, prefixed with `evt_`.
     pub event_id: EventID,
@@ -621,11 +1002,79 @@ pub struct Event {
     pub data: EventData,
 }
 
+/// Implements `TryFrom<Event>` for an entity type. The conversion succeeds when the event data wraps that entity and returns the unchanged event otherwise.
+macro_rules! entity_from_event {
+    ($entity:ty, $($variant:ident),+ $(,)?) => {
+        impl TryFrom<Event> for $entity {
+            type Error = Event;
+
+            fn try_from(event: Event) -> Result<Self, Self::Error> {
+                match event.data {
+                    $(EventData::$variant(entity) => Ok(entity),)+
+                    _ => Err(event),
+                }
+            }
+        }
+    };
+}
+
+entity_from_event!(Address, AddressCreated, AddressImported, AddressUpdated);
+entity_from_event!(Adjustment, AdjustmentCreated, AdjustmentUpdated);
+entity_from_event!(
+    ApiKey,
+    ApiKeyCreated,
+    ApiKeyUpdated,
+    ApiKeyExpiring,
+    ApiKeyExpired,
+    ApiKeyRevoked
+);
+entity_from_event!(Business, BusinessCreated, BusinessImported, BusinessUpdated);
+entity_from_event!(Customer, CustomerCreated, CustomerImported, CustomerUpdated);
+entity_from_event!(Discount, DiscountCreated, DiscountImported, DiscountUpdated);
+entity_from_event!(PaymentMethod, PaymentMethodSaved, PaymentMethodDeleted);
+entity_from_event!(Payout, PayoutCreated, PayoutPaid);
+entity_from_event!(Price, PriceCreated, PriceImported, PriceUpdated);
+entity_from_event!(Product, ProductCreated, ProductImported, ProductUpdated);
+entity_from_event!(ReportBase, ReportCreated, ReportUpdated);
+entity_from_event!(
+    Transaction,
+    TransactionBilled,
+    TransactionCanceled,
+    TransactionCompleted,
+    TransactionCreated,
+    TransactionPaid,
+    TransactionPastDue,
+    TransactionPaymentFailed,
+    TransactionReady,
+    TransactionRevised,
+    TransactionUpdated
+);
+
+impl TryFrom<Event> for Subscription {
+    type Error = Event;
+
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event.data {
+            EventData::SubscriptionCreated(created) => Ok(created.subscription),
+            EventData::SubscriptionActivated(subscription)
+            | EventData::SubscriptionCanceled(subscription)
+            | EventData::SubscriptionImported(subscription)
+            | EventData::SubscriptionPastDue(subscription)
+            | EventData::SubscriptionPaused(subscription)
+            | EventData::SubscriptionResumed(subscription)
+            | EventData::SubscriptionTrialing(subscription)
+            | EventData::SubscriptionUpdated(subscription) => Ok(subscription),
+            _ => Err(event),
+        }
+    }
+}
+
 /// Represents an event type.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct EventType {
     /// Type of event sent by Paddle, in the format `entity.event_type`.
-    pub name: String, // EventTypeName,
+    pub name: EventTypeName,
     /// Short description of this event type.
     pub description: String,
     /// Group for this event type. Typically the entity that this event relates to.
@@ -634,8 +1083,17 @@ pub struct EventType {
     pub available_versions: Vec<u64>,
 }
 
+/// IP addresses Paddle uses to send webhooks from.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct IpAddresses {
+    /// List of IPv4 CIDR blocks, e.g. `34.194.127.46/32`.
+    pub ipv4_cidrs: Vec<String>,
+}
+
 /// A base representation of monetary value unformatted in the lowest denomination with currency code.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Money {
     /// Amount in the lowest denomination for the currency, e.g. 10 USD = 1000 (cents). Although represented as a string, this value must be a valid integer.
     pub amount: String,
@@ -643,7 +1101,25 @@ pub struct Money {
     pub currency_code: CurrencyCode,
 }
 
+impl Money {
+    /// - `amount` - Amount in the lowest denomination for the currency, e.g. 10 USD = 1000 (cents).
+    pub fn new(amount: u64, currency_code: CurrencyCode) -> Self {
+        Self {
+            amount: amount.to_string(),
+            currency_code,
+        }
+    }
+}
+
+/// Prints the amount formatted for its currency, e.g. `$10.50`. See [CurrencyCode::format_minor].
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_amount(f, &self.amount, &self.currency_code)
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct UnitPriceOverride {
     /// Supported two-letter ISO 3166-1 alpha-2 country code. Customers located in the listed countries are charged the override price.
     pub country_codes: Vec<CountryCodeSupported>,
@@ -651,7 +1127,25 @@ pub struct UnitPriceOverride {
     pub unit_price: Money,
 }
 
+impl UnitPriceOverride {
+    pub fn new(
+        country_codes: impl IntoIterator<Item = CountryCodeSupported>,
+        amount: u64,
+        currency_code: CurrencyCode,
+    ) -> Self {
+        Self {
+            country_codes: country_codes.into_iter().collect(),
+            unit_price: Money::new(amount, currency_code),
+        }
+    }
+}
+
+/// Limits on how many times the related product can be purchased at this price.
+///
+/// Missing fields are deserialized with the defaults Paddle applies, see [PriceQuantity::default].
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+#[non_exhaustive]
 pub struct PriceQuantity {
     /// Minimum quantity of the product related to this price that can be bought. Required if `maximum` set.
     pub minimum: u64,
@@ -659,8 +1153,25 @@ pub struct PriceQuantity {
     pub maximum: u64,
 }
 
+impl PriceQuantity {
+    pub fn new(minimum: u64, maximum: u64) -> Self {
+        Self { minimum, maximum }
+    }
+}
+
+impl Default for PriceQuantity {
+    /// Paddle's defaults for prices created without a quantity: a minimum of `1` and a maximum of `100`.
+    fn default() -> Self {
+        Self {
+            minimum: 1,
+            maximum: 100,
+        }
+    }
+}
+
 /// Represents a price entity.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Price {
     /// Unique Paddle ID for this price, prefixed with `pri_`.
     pub id: PriceID,
@@ -683,6 +1194,8 @@ pub struct Price {
     /// List of unit price overrides. Use to override the base price with a custom price and currency for a country or group of countries.
     #[serde(default)]
     pub unit_price_overrides: Vec<UnitPriceOverride>,
+    /// Limits on how many times the related product can be purchased at this price. Paddle's defaults are used if it's missing from the response.
+    #[serde(default)]
     pub quantity: PriceQuantity,
     /// Whether this entity can be used in Paddle.
     pub status: Status,
@@ -696,8 +1209,16 @@ pub struct Price {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Prints the ID and unit price, e.g. `pri_01gsz8x8sawmvhz1pv30nge1ke ($10.00)`.
+impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.id, self.unit_price)
+    }
+}
+
 /// Represents a product entity.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Product {
     /// Unique Paddle ID for this product, prefixed with `pro_`.
     pub id: ProductID,
@@ -725,8 +1246,16 @@ pub struct Product {
     pub prices: Option<Vec<Price>>,
 }
 
+/// Prints the name and ID, e.g. `AeroEdit Student (pro_01htz88xpr0mm7b3ta2pjkr7w2)`.
+impl fmt::Display for Product {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.name, self.id)
+    }
+}
+
 /// Represents a subscription item.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionItem {
     /// Status of this subscription item. Set automatically by Paddle.
     pub status: SubscriptionItemStatus,
@@ -739,9 +1268,9 @@ pub struct SubscriptionItem {
     /// RFC 3339 datetime string of when this entity was updated. Set automatically by Paddle.
     pub updated_at: DateTime<Utc>,
     /// RFC 3339 datetime string of when this item was last billed.
-    pub previously_billed_at: Option<DateTime<FixedOffset>>,
+    pub previously_billed_at: Option<DateTime<Utc>>,
     /// RFC 3339 datetime string of when this item is next scheduled to be billed.
-    pub next_billed_at: Option<DateTime<FixedOffset>>,
+    pub next_billed_at: Option<DateTime<Utc>>,
     /// Trial dates for this item.
     pub trial_dates: Option<TimePeriod>,
     /// Represents a price entity.
@@ -752,6 +1281,7 @@ pub struct SubscriptionItem {
 
 /// Keys used for working with paginated results.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Pagination {
     /// Number of entities per page for this response. May differ from the number requested if the requested number is greater than the maximum.
     pub per_page: i64,
@@ -765,6 +1295,7 @@ pub struct Pagination {
 
 /// Information about this response.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct MetaPaginated {
     /// Unique ID for the request relating to this response. Provide this when contacting Paddle support about a specific request.
     pub request_id: String,
@@ -774,6 +1305,7 @@ pub struct MetaPaginated {
 
 /// Information about the payment method used for a payment attempt.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct MethodDetails {
     /// Type of payment method used for this payment attempt.
     pub r#type: PaymentMethodType,
@@ -787,6 +1319,7 @@ pub struct MethodDetails {
 
 /// Notification payload. Includes the new or changed event.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct NotificationPayload {
     /// Unique Paddle ID for this notification, prefixed with `ntf_`.
     pub notification_id: NotificationID,
@@ -795,13 +1328,14 @@ pub struct NotificationPayload {
     /// Type of event sent by Paddle, in the format `entity.event_type`.
     pub event_type: EventTypeName,
     /// RFC 3339 datetime string.
-    pub occurred_at: String,
+    pub occurred_at: DateTime<Utc>,
     /// New or changed entity.
-    pub data: HashMap<String, String>,
+    pub data: serde_json::Value,
 }
 
 /// Represents a notification entity.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Notification {
     /// Unique Paddle ID for this notification, prefixed with `ntf_`.
     pub id: NotificationID,
@@ -811,25 +1345,34 @@ pub struct Notification {
     pub status: NotificationStatus,
     pub payload: NotificationPayload,
     /// RFC 3339 datetime string.
-    pub occurred_at: DateTime<FixedOffset>,
+    pub occurred_at: DateTime<Utc>,
     /// RFC 3339 datetime string of when this notification was delivered. `null` if not yet delivered successfully.
-    pub delivered_at: Option<DateTime<FixedOffset>>,
+    pub delivered_at: Option<DateTime<Utc>>,
     /// RFC 3339 datetime string of when this notification was replayed. `null` if not replayed.
-    pub replayed_at: Option<DateTime<FixedOffset>>,
+    pub replayed_at: Option<DateTime<Utc>>,
     /// Describes how this notification was created.
     pub origin: NotificationOrigin,
     /// RFC 3339 datetime string of when this notification was last attempted.
-    pub last_attempt_at: Option<DateTime<FixedOffset>>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
     /// RFC 3339 datetime string of when this notification is scheduled to be retried.
-    pub retry_at: Option<DateTime<FixedOffset>>,
+    pub retry_at: Option<DateTime<Utc>>,
     /// How many times delivery of this notification has been attempted. Automatically incremented by Paddle after an attempt.
     pub times_attempted: i64,
     /// Unique Paddle ID for this notification setting, prefixed with `ntfset_`.
     pub notification_setting_id: NotificationSettingID,
 }
 
+/// Notification created by replaying another notification.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct NotificationReplay {
+    /// Unique Paddle ID for the new notification, prefixed with `ntf_`.
+    pub notification_id: NotificationID,
+}
+
 /// Represents a notification log entity.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct NotificationLog {
     /// Unique Paddle ID for this notification log, prefixed with `ntflog_`.
     pub id: NotificationLogID,
@@ -840,11 +1383,12 @@ pub struct NotificationLog {
     /// Response body sent by the responding server. Typically empty for success responses.
     pub response_body: String,
     /// RFC 3339 datetime string.
-    pub attempted_at: String,
+    pub attempted_at: DateTime<Utc>,
 }
 
 /// Represents a notification destination.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct NotificationSetting {
     /// Unique Paddle ID for this notification setting, prefixed with `ntfset_`.
     pub id: NotificationSettingID,
@@ -870,6 +1414,7 @@ pub struct NotificationSetting {
 
 /// Represents a notification destination when creating notification destinations.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct NotificationSettingCreate {
     /// Unique Paddle ID for this notification setting, prefixed with `ntfset_`.
     pub id: NotificationSettingID,
@@ -886,7 +1431,7 @@ pub struct NotificationSettingCreate {
     /// Whether potentially sensitive fields should be sent to this notification destination. If omitted, defaults to `false`.
     pub include_sensitive_fields: bool,
     /// Subscribed events for this notification destination. When creating or updating a notification destination, pass an array of event type names only. Paddle returns the complete event type object.
-    pub subscribed_events: Vec<String>,
+    pub subscribed_events: Vec<EventTypeName>,
     /// Webhook destination secret key, prefixed with `pdl_ntfset_`. Used for signature verification.
     pub endpoint_secret_key: EndpointSecretKey,
     /// Whether Paddle should deliver real platform events, simulation events or both to this notification destination. If omitted, defaults to `platform`.
@@ -895,6 +1440,7 @@ pub struct NotificationSettingCreate {
 
 /// Represents a notification destination when updating notification destinations.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct NotificationSettingUpdate {
     /// Short description for this notification destination. Shown in the Paddle Dashboard.
     pub description: String,
@@ -907,13 +1453,14 @@ pub struct NotificationSettingUpdate {
     /// Whether potentially sensitive fields should be sent to this notification destination.
     pub include_sensitive_fields: bool,
     /// Subscribed events for this notification destination. When creating or updating a notification destination, pass an array of event type names only. Paddle returns the complete event type object.
-    pub subscribed_events: Option<Vec<String>>,
+    pub subscribed_events: Option<Vec<EventTypeName>>,
     /// Whether Paddle should deliver real platform events, simulation events or both to this notification destination.
     pub traffic_source: TrafficSource,
 }
 
 /// Represents a price preview entity.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct PricePreview {
     /// Unique Paddle ID for this price, prefixed with `pri_`.
     /// The value is null for custom prices being previewed.
@@ -937,6 +1484,8 @@ pub struct PricePreview {
     pub unit_price: Money,
     /// List of unit price overrides. Use to override the base price with a custom price and currency for a country or group of countries.
     pub unit_price_overrides: Vec<UnitPriceOverride>,
+    /// Limits on how many times the related product can be purchased at this price. Paddle's defaults are used if it's missing from the response.
+    #[serde(default)]
     pub quantity: PriceQuantity,
     /// Whether this entity can be used in Paddle.
     pub status: Status,
@@ -945,13 +1494,14 @@ pub struct PricePreview {
     /// Import information for this entity. `null` if this entity is not imported.
     pub import_meta: ImportMeta,
     /// RFC 3339 datetime string of when this entity was created. Set automatically by Paddle.
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// RFC 3339 datetime string of when this entity was updated. Set automatically by Paddle.
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
 }
 
 /// Represents a product (preview) entity.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct ProductPreview {
     /// Unique Paddle ID for this product, prefixed with `pro_`.
     /// The value is null for custom products being previewed.
@@ -978,14 +1528,50 @@ pub struct ProductPreview {
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize)]
 #[serde(untagged)]
 pub enum ReportFilterValue {
     String(String),
     Array(Vec<String>),
 }
 
+impl<'de> Deserialize<'de> for ReportFilterValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ReportFilterValueVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ReportFilterValueVisitor {
+            type Value = ReportFilterValue;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a string or an array of strings")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(ReportFilterValue::String(value.to_string()))
+            }
+
+            fn visit_string<E: serde::de::Error>(self, value: String) -> Result<Self::Value, E> {
+                Ok(ReportFilterValue::String(value))
+            }
+
+            fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                Vec::deserialize(serde::de::value::SeqAccessDeserializer::new(seq))
+                    .map(ReportFilterValue::Array)
+            }
+        }
+
+        deserializer.deserialize_any(ReportFilterValueVisitor)
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[non_exhaustive]
 pub struct ReportFilter<T: Serialize> {
     /// Field name to filter by.
     pub name: T,
@@ -995,8 +1581,23 @@ pub struct ReportFilter<T: Serialize> {
     pub value: ReportFilterValue,
 }
 
+impl<T: Serialize> ReportFilter<T> {
+    pub fn new(
+        name: T,
+        operator: Option<FilterOperator>,
+        value: impl Into<ReportFilterValue>,
+    ) -> Self {
+        Self {
+            name,
+            operator,
+            value: value.into(),
+        }
+    }
+}
+
 /// Represents a report entity.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct ReportBase {
     /// Unique Paddle ID for this entity.
     pub id: PaddleID,
@@ -1018,6 +1619,7 @@ pub struct ReportBase {
 
 /// Information about the request. Sent by Paddle as part of the simulation.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SimulationEventRequest {
     /// Request body sent by Paddle.
     pub body: String,
@@ -1025,6 +1627,7 @@ pub struct SimulationEventRequest {
 
 /// Information about the response. Sent by the responding server for the notification setting.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SimulationEventResponse {
     /// Response body sent by the responding server. May be empty for success responses.
     pub body: String,
@@ -1034,6 +1637,7 @@ pub struct SimulationEventResponse {
 
 /// Represents a simulation event.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SimulationEvent {
     /// Unique Paddle ID for this simulation event, prefixed with `ntfsimevt_`.
     pub id: SimulationEventID,
@@ -1043,48 +1647,77 @@ pub struct SimulationEvent {
     pub event_type: EventTypeName,
     /// Simulation payload. Pass a JSON object that matches the schema for an event type to simulate a custom payload. If omitted, Paddle populates with a demo example.
     pub payload: serde_json::Value,
-    /// Information about the request. Sent by Paddle as part of the simulation.
-    pub request: SimulationEventRequest,
-    /// Information about the response. Sent by the responding server for the notification setting.
-    pub response: SimulationEventResponse,
+    /// Information about the request. Sent by Paddle as part of the simulation. `null` until Paddle attempts delivery.
+    pub request: Option<SimulationEventRequest>,
+    /// Information about the response. Sent by the responding server for the notification setting. `null` if no response was received.
+    pub response: Option<SimulationEventResponse>,
     /// RFC 3339 datetime string of when this entity was created. Set automatically by Paddle.
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// RFC 3339 datetime string of when this entity was updated. Set automatically by Paddle.
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
 }
 
 /// Represents a simulation run entity for a scenario.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SimulationRunScenario {
     /// Unique Paddle ID for this simulation run, prefixed with `ntfsimrun_`.
     pub id: SimulationRunID,
     /// Status of this simulation run.
     pub status: SimulationRunStatus,
     /// RFC 3339 datetime string of when this entity was created. Set automatically by Paddle.
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// RFC 3339 datetime string of when this entity was updated. Set automatically by Paddle.
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
     /// Scenario for a simulation.
     pub r#type: SimulationScenarioType,
 }
 
 /// Represents a simulation run entity for a single event.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SimulationRunSingleEvent {
     /// Unique Paddle ID for this simulation run, prefixed with `ntfsimrun_`.
     pub id: SimulationRunID,
     /// Status of this simulation run.
     pub status: SimulationRunStatus,
     /// RFC 3339 datetime string of when this entity was created. Set automatically by Paddle.
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// RFC 3339 datetime string of when this entity was updated. Set automatically by Paddle.
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
+    /// Type of event sent by Paddle, in the format `entity.event_type`.
+    pub r#type: EventTypeName,
+    /// Events sent for this run. Only returned when requested with `include=events`.
+    pub events: Option<Vec<SimulationEvent>>,
+}
+
+/// Represents a simulation entity for a single event.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SimulationSingleEvent {
+    /// Unique Paddle ID for this simulation, prefixed with `ntfsim_`.
+    pub id: SimulationID,
+    /// Whether this entity can be used in Paddle.
+    pub status: Status,
+    /// Unique Paddle ID for this notification setting, prefixed with `ntfset_`.
+    pub notification_setting_id: NotificationSettingID,
+    /// Name of this simulation.
+    pub name: String,
     /// Type of event sent by Paddle, in the format `entity.event_type`.
     pub r#type: EventTypeName,
+    /// Simulation payload. `null` when Paddle populates it with a demo example.
+    pub payload: Option<serde_json::Value>,
+    /// RFC 3339 datetime string of when this simulation was last run. `null` until run. Set automatically by Paddle.
+    pub last_run_at: Option<DateTime<Utc>>,
+    /// RFC 3339 datetime string of when this entity was created. Set automatically by Paddle.
+    pub created_at: DateTime<Utc>,
+    /// RFC 3339 datetime string of when this entity was updated. Set automatically by Paddle.
+    pub updated_at: DateTime<Utc>,
 }
 
 /// Represents a simulation entity for a scenario.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SimulationScenario {
     /// Unique Paddle ID for this simulation, prefixed with `ntfsim_`.
     pub id: SimulationID,
@@ -1099,15 +1732,16 @@ pub struct SimulationScenario {
     /// Simulation payload. `null` for scenarios.
     pub payload: Option<serde_json::Value>,
     /// RFC 3339 datetime string of when this simulation was last run. `null` until run. Set automatically by Paddle.
-    pub last_run_at: Option<DateTime<FixedOffset>>,
+    pub last_run_at: Option<DateTime<Utc>>,
     /// RFC 3339 datetime string of when this entity was created. Set automatically by Paddle.
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// RFC 3339 datetime string of when this entity was updated. Set automatically by Paddle.
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
 }
 
 /// Represents a simulation entity for a scenario when creating.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SimulationScenarioCreate {
     /// Unique Paddle ID for this notification setting, prefixed with `ntfset_`.
     pub notification_setting_id: NotificationSettingID,
@@ -1119,6 +1753,7 @@ pub struct SimulationScenarioCreate {
 
 /// Represents a simulation entity for a scenario when updating.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SimulationScenarioUpdate {
     /// Unique Paddle ID for this notification setting, prefixed with `ntfset_`.
     pub notification_setting_id: NotificationSettingID,
@@ -1131,7 +1766,9 @@ pub struct SimulationScenarioUpdate {
 }
 
 /// Represents a simulation entity for a single event when creating.
+#[skip_serializing_none]
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SimulationSingleEventCreate {
     /// Unique Paddle ID for this notification setting, prefixed with `ntfset_`.
     pub notification_setting_id: NotificationSettingID,
@@ -1143,8 +1780,35 @@ pub struct SimulationSingleEventCreate {
     pub payload: Option<serde_json::Value>,
 }
 
+impl SimulationSingleEventCreate {
+    pub fn new(
+        notification_setting_id: impl Into<NotificationSettingID>,
+        r#type: EventTypeName,
+    ) -> Self {
+        Self {
+            notification_setting_id: notification_setting_id.into(),
+            name: None,
+            r#type,
+            payload: None,
+        }
+    }
+
+    /// Name of this simulation.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Simulation payload. If omitted, Paddle populates with a demo example.
+    pub fn payload(mut self, payload: serde_json::Value) -> Self {
+        self.payload = Some(payload);
+        self
+    }
+}
+
 /// Represents a simulation entity for a single event when updating.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SimulationSingleEventUpdate {
     /// Unique Paddle ID for this notification setting, prefixed with `ntfset_`.
     pub notification_setting_id: NotificationSettingID,
@@ -1160,6 +1824,7 @@ pub struct SimulationSingleEventUpdate {
 
 /// Represents a simulation type.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SimulationType {
     /// Type of simulation sent by Paddle. Single event simulations are in the format `entity.event_type`; scenario simulations are in `snake_case`.
     pub name: String,
@@ -1177,17 +1842,35 @@ pub struct SimulationType {
 
 /// Change that's scheduled to be applied to a subscription. Use the pause subscription, cancel subscription, and resume subscription operations to create scheduled changes. `null` if no scheduled changes.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionScheduledChange {
     /// Kind of change that's scheduled to be applied to this subscription.
     pub action: ScheduledChangeAction,
     /// RFC 3339 datetime string.
-    pub effective_at: DateTime<FixedOffset>,
+    pub effective_at: DateTime<Utc>,
     /// RFC 3339 datetime string of when a paused subscription should resume. Only used for `pause` scheduled changes.
-    pub resume_at: Option<DateTime<FixedOffset>>,
+    pub resume_at: Option<DateTime<Utc>>,
+}
+
+/// Change scheduled for a subscription, decoded from [SubscriptionScheduledChange]. Returned by [Subscription::scheduled_summary].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScheduledSummary {
+    /// No change is scheduled.
+    None,
+    /// The subscription ends on this date.
+    CancelsAt(DateTime<Utc>),
+    /// The subscription is paused on `at`. `resumes_at` is `None` when the pause has no end date.
+    PausesAt {
+        at: DateTime<Utc>,
+        resumes_at: Option<DateTime<Utc>>,
+    },
+    /// The paused subscription becomes active again on this date.
+    ResumesAt(DateTime<Utc>),
 }
 
 /// Authenticated customer portal deep links for this subscription. For security, the `token` appended to each link is temporary. You shouldn't store these links.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionManagementUrls {
     /// Link to the page for this subscription in the customer portal with the payment method update form pre-opened. Use as part of workflows to let customers update their payment details. `null` for manually-collected subscriptions.
     pub update_payment_method: Option<String>,
@@ -1197,6 +1880,7 @@ pub struct SubscriptionManagementUrls {
 
 /// Represents a subscription entity.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Subscription {
     /// Unique Paddle ID for this subscription entity, prefixed with `sub_`.
     pub id: SubscriptionID,
@@ -1245,8 +1929,94 @@ pub struct Subscription {
     pub import_meta: Option<ImportMeta>,
 }
 
+/// Prints the ID and status, e.g. `sub_01hv8x29kz0t586xy6zn1a62ny (past_due)`.
+impl fmt::Display for Subscription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.id, variant_name(&self.status))
+    }
+}
+
+impl Subscription {
+    /// Whether this subscription is currently in trial.
+    pub fn is_in_trial(&self) -> bool {
+        self.status == SubscriptionStatus::Trialing
+    }
+
+    /// Whether this subscription has a scheduled change to cancel it.
+    pub fn will_cancel(&self) -> bool {
+        self.scheduled_change
+            .as_ref()
+            .is_some_and(|change| change.action == ScheduledChangeAction::Cancel)
+    }
+
+    /// Whether this subscription has a scheduled change to pause it.
+    pub fn will_pause(&self) -> bool {
+        self.scheduled_change
+            .as_ref()
+            .is_some_and(|change| change.action == ScheduledChangeAction::Pause)
+    }
+
+    /// Change scheduled for this subscription, e.g. for UI copy such as "Your plan ends on …".
+    pub fn scheduled_summary(&self) -> ScheduledSummary {
+        let Some(change) = &self.scheduled_change else {
+            return ScheduledSummary::None;
+        };
+
+        match change.action {
+            ScheduledChangeAction::Cancel => ScheduledSummary::CancelsAt(change.effective_at),
+            ScheduledChangeAction::Pause => ScheduledSummary::PausesAt {
+                at: change.effective_at,
+                resumes_at: change.resume_at,
+            },
+            ScheduledChangeAction::Resume => {
+                ScheduledSummary::ResumesAt(change.resume_at.unwrap_or(change.effective_at))
+            }
+        }
+    }
+
+    /// Reference to this subscription in the platform it was imported from, e.g. a Stripe subscription ID. `None` for subscriptions created in Paddle.
+    pub fn external_id(&self) -> Option<&str> {
+        self.import_meta.as_ref()?.external_id.as_deref()
+    }
+
+    /// Items on this subscription that Paddle is currently billing for.
+    pub fn active_items(&self) -> impl Iterator<Item = &SubscriptionItem> {
+        self.items
+            .iter()
+            .filter(|item| item.status == SubscriptionItemStatus::Active)
+    }
+
+    /// Sum of the unit price multiplied by quantity for every recurring item on this subscription, in the lowest denomination of the subscription currency.
+    ///
+    /// Unit price overrides are not taken into account. Returns `None` if an item amount is not a valid integer or the total overflows.
+    pub fn total_recurring_amount(&self) -> Option<Money> {
+        let total =
+            self.items
+                .iter()
+                .filter(|item| item.recurring)
+                .try_fold(0u64, |total, item| {
+                    let amount = item.price.unit_price.amount.parse::<u64>().ok()?;
+                    let quantity = u64::try_from(item.quantity).ok()?;
+
+                    total.checked_add(amount.checked_mul(quantity)?)
+                })?;
+
+        Some(Money {
+            amount: total.to_string(),
+            currency_code: self.currency_code.clone(),
+        })
+    }
+
+    /// Number of whole days from `now` until this subscription is next billed. `None` if the subscription has no next billing date.
+    pub fn days_until_next_billing(&self, now: DateTime<Utc>) -> Option<i64> {
+        self.next_billed_at
+            .map(|next_billed_at| (next_billed_at - now).num_days())
+    }
+}
+
 /// Represents a subscription entity when sent as a subscription.created event.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionCreatedEvent {
     /// Unique Paddle ID for this transaction entity, prefixed with `txn_`.
     pub transaction_id: TransactionID,
@@ -1254,7 +2024,9 @@ pub struct SubscriptionCreatedEvent {
     pub subscription: Subscription,
 }
 
+/// Item to bill for. Serialized as the inner object, the variant is picked by which of `price_id`, `price` or `product` is present.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
 pub enum SubscriptionChargeItem {
     /// Add a catalog item to a subscription. In this case, the product and price that you're billing for exist in your product catalog in Paddle.
     CatalogItem(SubscriptionItemCreateWithPriceId),
@@ -1266,6 +2038,7 @@ pub enum SubscriptionChargeItem {
 
 /// Represents a one-time charge for a subscription.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionCharge {
     /// When this subscription change should take effect from. Defaults to `next_billing_period`, which creates a
     /// `scheduled_change` to apply the subscription change at the end of the billing period.
@@ -1282,6 +2055,7 @@ pub struct SubscriptionCharge {
 
 /// Breakdown of a charge in the lowest denomination of a currency (e.g. cents for USD).
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Totals {
     /// Subtotal before discount, tax, and deductions. If an item, unit price multiplied by quantity.
     pub subtotal: String,
@@ -1296,6 +2070,7 @@ pub struct Totals {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TaxRatesUsed {
     /// Rate used to calculate tax for this transaction preview.
     pub tax_rate: String,
@@ -1305,6 +2080,7 @@ pub struct TaxRatesUsed {
 
 /// Breakdown of the total for a transaction. These numbers can be negative when dealing with subscription updates that result in credit.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionTotals {
     /// Subtotal before discount, tax, and deductions. If an item, unit price multiplied by quantity.
     pub subtotal: String,
@@ -1332,8 +2108,19 @@ pub struct TransactionTotals {
     pub currency_code: CurrencyCode,
 }
 
+impl TransactionTotals {
+    /// Total after discount and tax, together with its currency.
+    pub fn total_money(&self) -> Money {
+        Money {
+            amount: self.total.clone(),
+            currency_code: self.currency_code.clone(),
+        }
+    }
+}
+
 /// SubscriptionTransactionDetailsPreview requires same fields as TransactionLineItemPreview but proration is optional
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionTransactionDetailsPreviewItem {
     /// Paddle ID for the price related to this transaction line item, prefixed with `pri_`.
     /// The value is null for custom prices being previewed.
@@ -1354,6 +2141,7 @@ pub struct SubscriptionTransactionDetailsPreviewItem {
 
 /// Calculated totals for a transaction preview, including discounts, tax, and currency conversion. Considered the source of truth for totals on a transaction preview.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionTransactionDetailsPreview {
     /// List of tax rates applied to this transaction preview.
     pub tax_rates_used: Vec<TaxRatesUsed>,
@@ -1365,6 +2153,7 @@ pub struct SubscriptionTransactionDetailsPreview {
 
 /// Preview of the next transaction for this subscription. May include prorated charges that aren't yet billed and one-time charges. `null` if the subscription is scheduled to cancel or pause.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct NextTransaction {
     pub billing_period: TimePeriod,
     /// Calculated totals for a transaction preview, including discounts, tax, and currency conversion. Considered the source of truth for totals on a transaction preview.
@@ -1375,6 +2164,7 @@ pub struct NextTransaction {
 
 /// Represents a subscription entity with related entities included.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionWithInclude {
     /// The subscription entity.
     #[serde(flatten)]
@@ -1387,6 +2177,7 @@ pub struct SubscriptionWithInclude {
 
 /// Details of the result of credits and charges. Where the total of any credit adjustments is greater than the total charge, the result is a prorated credit; otherwise, the result is a prorated charge.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct UpdateSummaryResult {
     /// Whether the subscription change results in a prorated credit or a charge.
     pub action: UpdateSummaryResultAction,
@@ -1398,6 +2189,7 @@ pub struct UpdateSummaryResult {
 
 /// Impact of this subscription change. Includes whether the change results in a charge or credit, and totals for prorated amounts.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionPreviewUpdateSummary {
     /// A base representation of monetary value unformatted in the lowest denomination with currency code.
     pub credit: Money,
@@ -1409,6 +2201,7 @@ pub struct SubscriptionPreviewUpdateSummary {
 
 /// Represents a subscription preview when previewing a subscription.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionPreview {
     /// Status of this subscription. Set automatically by Paddle. Use the pause subscription or cancel subscription operations to change.
     pub status: SubscriptionStatus,
@@ -1462,8 +2255,23 @@ pub struct SubscriptionPreview {
     pub import_meta: Option<ImportMeta>,
 }
 
+impl SubscriptionPreview {
+    /// Total that will be charged immediately as a result of the previewed changes. `None` if nothing is billed immediately.
+    pub fn immediate_total(&self) -> Option<Money> {
+        self.immediate_transaction
+            .as_ref()
+            .map(|transaction| transaction.details.totals.total_money())
+    }
+
+    /// Total that will be charged on the next billing date, including one-time charges and changes billed on the next billing period.
+    pub fn next_billing_total(&self) -> Money {
+        self.next_transaction.details.totals.total_money()
+    }
+}
+
 /// Details of the discount applied to this subscription. Include to add a discount to a subscription. `null` to remove a discount.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionDiscountEffectiveFrom {
     /// Unique Paddle ID for this discount, prefixed with `dsc_`.
     pub id: DiscountID,
@@ -1472,8 +2280,18 @@ pub struct SubscriptionDiscountEffectiveFrom {
     pub effective_from: EffectiveFrom,
 }
 
+impl SubscriptionDiscountEffectiveFrom {
+    pub fn new(id: impl Into<DiscountID>, effective_from: EffectiveFrom) -> Self {
+        Self {
+            id: id.into(),
+            effective_from,
+        }
+    }
+}
+
 /// Represents a subscription entity when updating subscriptions.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionUpdate {
     /// Unique Paddle ID for this customer entity, prefixed with `ctm_`.
     pub customer_id: CustomerID,
@@ -1509,6 +2327,7 @@ pub struct SubscriptionUpdate {
 
 /// Price object for a non-catalog item to bill for. Include a `product_id` to relate this non-catalog price to an existing catalog price.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionChargeCreateWithPricePrice {
     /// Unique Paddle ID for this product, prefixed with `pro_`.
     pub product_id: ProductID,
@@ -1529,6 +2348,7 @@ pub struct SubscriptionChargeCreateWithPricePrice {
 
 /// Price object for a non-catalog item to charge for. Include a `product` object to create a non-catalog product for this non-catalog price.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionChargeCreateWithPriceAndProduct {
     /// Internal description for this price, not shown to customers. Typically notes for your team.
     pub description: String,
@@ -1548,6 +2368,7 @@ pub struct SubscriptionChargeCreateWithPriceAndProduct {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionChargeCreateWithPrice {
     /// Quantity to bill for.
     pub quantity: i64,
@@ -1556,6 +2377,7 @@ pub struct SubscriptionChargeCreateWithPrice {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionPriceCreateWithProductId {
     /// Internal description for this price, not shown to customers. Typically notes for your team.
     pub description: String,
@@ -1579,6 +2401,7 @@ pub struct TransactionPriceCreateWithProductId {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionItemCreateWithPrice {
     /// Quantity to bill for.
     pub quantity: i64,
@@ -1586,6 +2409,7 @@ pub struct SubscriptionItemCreateWithPrice {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionItemCreateWithPriceId {
     /// Quantity to bill for.
     pub quantity: i64,
@@ -1594,6 +2418,7 @@ pub struct SubscriptionItemCreateWithPriceId {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SubscriptionUpdateItem {
     /// Unique Paddle ID for this price, prefixed with `pri_`.
     pub price_id: PriceID,
@@ -1603,6 +2428,7 @@ pub struct SubscriptionUpdateItem {
 
 /// Breakdown of a charge in the lowest denomination of a currency (e.g. cents for USD).
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TotalsWithoutDiscount {
     /// Subtotal before tax, and deductions. If an item, unit price multiplied by quantity.
     pub subtotal: String,
@@ -1613,6 +2439,7 @@ pub struct TotalsWithoutDiscount {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionItem {
     /// Represents a price entity.
     pub price: Price,
@@ -1624,6 +2451,7 @@ pub struct TransactionItem {
 
 /// Breakdown of the totals for a transaction after adjustments.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionTotalsAdjusted {
     /// Subtotal before discount, tax, and deductions. If an item, unit price multiplied by quantity.
     pub subtotal: String,
@@ -1644,6 +2472,7 @@ pub struct TransactionTotalsAdjusted {
 
 /// Breakdown of the payout total for a transaction after adjustments. `null` until the transaction is `completed`.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionPayoutTotalsAdjusted {
     /// Total before tax and fees.
     pub subtotal: String,
@@ -1663,6 +2492,7 @@ pub struct TransactionPayoutTotalsAdjusted {
 
 /// Information about line items for this transaction. Different from transaction `items` as they include totals calculated by Paddle. Considered the source of truth for line item totals.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionLineItemWithId {
     /// Unique Paddle ID for this transaction item, prefixed with `txnitm_`.
     pub id: TransactionItemID,
@@ -1684,6 +2514,7 @@ pub struct TransactionLineItemWithId {
 
 /// Calculated totals for a transaction, including proration, discounts, tax, and currency conversion. Considered the source of truth for totals on a transaction.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionDetails {
     /// List of tax rates applied for this transaction.
     pub tax_rates_used: Vec<TaxRatesUsed>,
@@ -1700,6 +2531,7 @@ pub struct TransactionDetails {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionPaymentAttempt {
     /// UUID for this payment attempt.
     pub payment_attempt_id: String,
@@ -1716,20 +2548,30 @@ pub struct TransactionPaymentAttempt {
     /// Information about the payment method used for a payment attempt.
     pub method_details: MethodDetails,
     /// RFC 3339 datetime string of when this entity was created. Set automatically by Paddle.
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     /// RFC 3339 datetime string of when this payment was captured. `null` if `status` is not `captured`.
-    pub captured_at: Option<DateTime<FixedOffset>>,
+    pub captured_at: Option<DateTime<Utc>>,
 }
 
 /// Paddle Checkout details for this transaction. Returned for automatically-collected transactions and where `billing_details.enable_checkout` is `true` for manually-collected transactions; `null` otherwise.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionCheckout {
     /// Paddle Checkout URL for this transaction, composed of the URL passed in the request or your default payment URL + `?_ptxn=` and the Paddle ID for this transaction.
     pub url: Option<String>,
 }
 
+impl TransactionCheckout {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: Some(url.into()),
+        }
+    }
+}
+
 /// Contains an invoice PDF url for a transaction.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionInvoice {
     /// URL of the requested resource.
     pub url: Option<String>,
@@ -1737,6 +2579,7 @@ pub struct TransactionInvoice {
 
 /// Represents a transaction entity.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Transaction {
     /// Unique Paddle ID for this transaction entity, prefixed with `txn_`.
     pub id: TransactionID,
@@ -1758,6 +2601,10 @@ pub struct Transaction {
     /// Paddle ID of the subscription that this transaction is for, prefixed with `sub_`.
     pub subscription_id: Option<SubscriptionID>,
     /// Paddle ID of the invoice that this transaction is related to, prefixed with `inv_`. Used for compatibility with the Paddle Invoice API, which is now deprecated. This field is scheduled to be removed in the next version of the Paddle API.
+    ///
+    /// Only available with the `invoice-id` feature, enabled by default. Missing from the response once Paddle removes it. Use `invoice_number` to refer to invoices instead.
+    #[cfg(feature = "invoice-id")]
+    #[serde(default)]
     pub invoice_id: Option<InvoiceId>,
     /// Invoice number for this transaction. Automatically generated by Paddle when you mark a transaction as `billed` where `collection_mode` is `manual`.
     pub invoice_number: Option<String>,
@@ -1787,8 +2634,89 @@ pub struct Transaction {
     pub revised_at: Option<DateTime<Utc>>,
 }
 
+/// Prints the ID, status and grand total, e.g. `txn_01hv8wptq8987qeep44cyrewp9 (completed, $10.50)`.
+impl fmt::Display for Transaction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}, ", self.id, variant_name(&self.status))?;
+        write_amount(f, &self.details.totals.grand_total, &self.currency_code)?;
+        f.write_str(")")
+    }
+}
+
+impl Transaction {
+    /// Paddle ID of the invoice that this transaction is related to, prefixed with `inv_`.
+    ///
+    /// Always `None` when the `invoice-id` feature is disabled or once Paddle removes the field from the API.
+    #[deprecated(
+        note = "`invoice_id` is scheduled to be removed from the Paddle API, use `invoice_number` instead"
+    )]
+    pub fn invoice_id(&self) -> Option<&InvoiceId> {
+        #[cfg(feature = "invoice-id")]
+        return self.invoice_id.as_ref();
+
+        #[cfg(not(feature = "invoice-id"))]
+        return None;
+    }
+}
+
+/// Totals for all adjustments on a transaction.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TransactionAdjustmentsTotals {
+    /// Total before tax.
+    pub subtotal: String,
+    /// Total tax on the subtotal.
+    pub tax: String,
+    /// Total after tax.
+    pub total: String,
+    /// Total fee taken by Paddle for the adjustments.
+    pub fee: String,
+    /// Total earnings. This is the subtotal minus the Paddle fee.
+    pub earnings: String,
+    /// Breakdown of the total adjustments by adjustment action.
+    pub breakdown: TransactionAdjustmentsBreakdown,
+    /// Supported three-letter ISO 4217 currency code.
+    pub currency_code: CurrencyCode,
+}
+
+/// Breakdown of the total adjustments on a transaction by adjustment action.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TransactionAdjustmentsBreakdown {
+    /// Total amount of credit adjustments.
+    pub credit: String,
+    /// Total amount of refund adjustments.
+    pub refund: String,
+    /// Total amount of chargeback adjustments.
+    pub chargeback: String,
+}
+
+/// Represents a transaction entity with related entities included.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TransactionWithIncludes {
+    /// The transaction entity.
+    #[serde(flatten)]
+    pub transaction: Transaction,
+    /// Address for this transaction. Returned when the `include` parameter is used with the `address` value and the transaction has an `address_id`.
+    pub address: Option<Address>,
+    /// Adjustments for this transaction. Returned when the `include` parameter is used with the `adjustments` value.
+    pub adjustments: Option<Vec<Adjustment>>,
+    /// Totals for all adjustments on this transaction. Returned when the `include` parameter is used with the `adjustments_totals` value.
+    pub adjustments_totals: Option<TransactionAdjustmentsTotals>,
+    /// Payment methods that can be used to pay for this transaction. Returned when the `include` parameter is used with the `available_payment_methods` value.
+    pub available_payment_methods: Option<Vec<PaymentMethodType>>,
+    /// Business for this transaction. Returned when the `include` parameter is used with the `business` value and the transaction has a `business_id`.
+    pub business: Option<Business>,
+    /// Customer for this transaction. Returned when the `include` parameter is used with the `customer` value and the transaction has a `customer_id`.
+    pub customer: Option<Customer>,
+    /// Discount for this transaction. Returned when the `include` parameter is used with the `discount` value and the transaction has a `discount_id`.
+    pub discount: Option<Discount>,
+}
+
 /// Represents a transaction entity when creating transactions.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionCreate {
     /// Unique Paddle ID for this transaction entity, prefixed with `txn_`.
     pub id: TransactionID,
@@ -1809,7 +2737,11 @@ pub struct TransactionCreate {
     /// Paddle ID of the subscription that this transaction is for, prefixed with `sub_`.
     pub subscription_id: SubscriptionID,
     /// Paddle ID of the invoice that this transaction is related to, prefixed with `inv_`. Used for compatibility with the Paddle Invoice API, which is now deprecated. This field is scheduled to be removed in the next version of the Paddle API.
-    pub invoice_id: InvoiceId,
+    ///
+    /// Only available with the `invoice-id` feature, enabled by default. Missing from the response once Paddle removes it. Use `invoice_number` to refer to invoices instead.
+    #[cfg(feature = "invoice-id")]
+    #[serde(default)]
+    pub invoice_id: Option<InvoiceId>,
     /// Invoice number for this transaction. Automatically generated by Paddle when you mark a transaction as `billed` where `collection_mode` is `manual`.
     pub invoice_number: Option<String>,
     /// How payment is collected. `automatic` for checkout, `manual` for invoices.
@@ -1831,15 +2763,16 @@ pub struct TransactionCreate {
     /// Paddle Checkout details for this transaction. You may pass a URL when creating or updating an automatically-collected transaction, or when creating or updating a manually-collected transaction where `billing_details.enable_checkout` is `true`.
     pub checkout: TransactionCheckout,
     /// RFC 3339 datetime string of when this entity was created. Set automatically by Paddle.
-    pub created_at: DateTime<FixedOffset>,
+    pub created_at: DateTime<Utc>,
     /// RFC 3339 datetime string of when this entity was updated. Set automatically by Paddle.
-    pub updated_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<Utc>,
     /// RFC 3339 datetime string of when this transaction was marked as `billed`. `null` for transactions that aren't `billed` or `completed`. Set automatically by Paddle.
-    pub billed_at: Option<DateTime<FixedOffset>>,
+    pub billed_at: Option<DateTime<Utc>>,
 }
 
 /// Information about line items for this transaction preview. Different from transaction preview `items` as they include totals calculated by Paddle. Considered the source of truth for line item totals.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionLineItemPreview {
     /// Paddle ID for the price related to this transaction line item, prefixed with `pri_`.
     /// The value is null for custom prices being previewed.
@@ -1860,6 +2793,7 @@ pub struct TransactionLineItemPreview {
 
 /// Calculated totals for a transaction preview, including discounts, tax, and currency conversion. Considered the source of truth for totals on a transaction preview.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionDetailsPreview {
     /// List of tax rates applied to this transaction preview.
     pub tax_rates_used: Vec<TaxRatesUsed>,
@@ -1871,6 +2805,7 @@ pub struct TransactionDetailsPreview {
 
 /// Represents a transaction entity when previewing transactions.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionPreview {
     /// Paddle ID of the customer that this transaction preview is for, prefixed with `ctm_`.
     pub customer_id: Option<CustomerID>,
@@ -1899,6 +2834,7 @@ pub struct TransactionPreview {
 
 /// Represents an entity for previewing prices.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct PricingPreview {
     /// Paddle ID of the customer that this transaction preview is for, prefixed with `ctm_`.
     pub customer_id: Option<CustomerID>,
@@ -1921,6 +2857,7 @@ pub struct PricingPreview {
 
 /// Represents a transaction entity when previewing.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionPreviewCreate {
     /// Paddle ID of the customer that this transaction preview is for, prefixed with `ctm_`.
     pub customer_id: CustomerID,
@@ -1940,6 +2877,7 @@ pub struct TransactionPreviewCreate {
 
 /// Represents a price entity.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionPriceCreateBase {
     /// Internal description for this price, not shown to customers. Typically notes for your team.
     pub description: String,
@@ -1962,6 +2900,7 @@ pub struct TransactionPriceCreateBase {
 
 /// Represents a customer information revision for a transaction.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionRevise {
     /// Revised customer information for this transaction.
     pub customer: Customer,
@@ -1972,6 +2911,7 @@ pub struct TransactionRevise {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionSubscriptionProductCreate {
     /// Name of this product.
     pub name: String,
@@ -1985,8 +2925,39 @@ pub struct TransactionSubscriptionProductCreate {
     pub custom_data: Option<serde_json::Value>,
 }
 
+impl TransactionSubscriptionProductCreate {
+    pub fn new(name: impl Into<String>, tax_category: TaxCategory) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            tax_category,
+            image_url: None,
+            custom_data: None,
+        }
+    }
+
+    /// Short description for this product.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Image for this product. Included in the checkout and on some customer documents.
+    pub fn image_url(mut self, image_url: impl Into<String>) -> Self {
+        self.image_url = Some(image_url.into());
+        self
+    }
+
+    /// Your own structured key-value data.
+    pub fn custom_data(mut self, custom_data: serde_json::Value) -> Self {
+        self.custom_data = Some(custom_data);
+        self
+    }
+}
+
 /// Represents a transaction entity when updating transactions.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionUpdate {
     /// Unique Paddle ID for this transaction entity, prefixed with `txn_`.
     pub id: TransactionID,
@@ -2007,7 +2978,11 @@ pub struct TransactionUpdate {
     /// Paddle ID of the subscription that this transaction is for, prefixed with `sub_`.
     pub subscription_id: SubscriptionID,
     /// Paddle ID of the invoice that this transaction is related to, prefixed with `inv_`. Used for compatibility with the Paddle Invoice API, which is now deprecated. This field is scheduled to be removed in the next version of the Paddle API.
-    pub invoice_id: InvoiceId,
+    ///
+    /// Only available with the `invoice-id` feature, enabled by default. Missing from the response once Paddle removes it. Use `invoice_number` to refer to invoices instead.
+    #[cfg(feature = "invoice-id")]
+    #[serde(default)]
+    pub invoice_id: Option<InvoiceId>,
     /// Invoice number for this transaction. Automatically generated by Paddle when you mark a transaction as `billed` where `collection_mode` is `manual`.
     pub invoice_number: Option<String>,
     /// How payment is collected. `automatic` for checkout, `manual` for invoices.
@@ -2031,14 +3006,15 @@ pub struct TransactionUpdate {
     /// Paddle Checkout details for this transaction. You may pass a URL when creating or updating an automatically-collected transaction, or when creating or updating a manually-collected transaction where `billing_details.enable_checkout` is `true`.
     pub checkout: TransactionCheckout,
     /// RFC 3339 datetime string of when this entity was created. Set automatically by Paddle.
-    pub created_at: DateTime<FixedOffset>,
+    pub created_at: DateTime<Utc>,
     /// RFC 3339 datetime string of when this entity was updated. Set automatically by Paddle.
-    pub updated_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<Utc>,
     /// RFC 3339 datetime string of when this transaction was marked as `billed`. `null` for transactions that aren't `billed` or `completed`. Set automatically by Paddle.
-    pub billed_at: Option<DateTime<FixedOffset>>,
+    pub billed_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionItemCreateBase {
     /// Quantity of this item on the transaction.
     pub quantity: i64,
@@ -2048,6 +3024,7 @@ pub struct TransactionItemCreateBase {
 
 /// Information about line items for this transaction. Different from transaction `items` as they include totals calculated by Paddle. Considered the source of truth for line item totals.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionLineItem {
     /// Unique Paddle ID for this price, prefixed with `pri_`.
     pub price_id: PriceID,
@@ -2067,6 +3044,7 @@ pub struct TransactionLineItem {
 
 /// Breakdown of the payout total for a transaction. `null` until the transaction is `completed`. Returned in your payout currency.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionPayoutTotals {
     /// Total before tax and fees.
     pub subtotal: String,
@@ -2094,6 +3072,7 @@ pub struct TransactionPayoutTotals {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct TransactionItemPreviewBase {
     /// Quantity of this item on the transaction.
     pub quantity: i64,
@@ -2104,6 +3083,7 @@ pub struct TransactionItemPreviewBase {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct PricePreviewBase {
     /// Paddle ID of the customer that this preview is for, prefixed with `ctm_`.
     pub customer_id: CustomerID,
@@ -2123,6 +3103,7 @@ pub struct PricePreviewBase {
 
 /// Array of discounts applied to this preview line item. Empty if no discounts applied.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct PricePreviewDiscounts {
     /// Represents a discount entity.
     pub discount: Discount,
@@ -2134,6 +3115,7 @@ pub struct PricePreviewDiscounts {
 
 /// Information about line items for this preview. Includes totals calculated by Paddle. Considered the source of truth for line item totals.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct PricePreviewLineItem {
     /// Represents a price entity.
     pub price: Price,
@@ -2154,8 +3136,46 @@ pub struct PricePreviewLineItem {
     pub discounts: Vec<PricePreviewDiscounts>,
 }
 
+/// Original and discounted price of a line item, formatted for display.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct PromoPrice<'a> {
+    /// Price before discounts, e.g. `$100.00`. Formatted subtotal of the line item.
+    pub was: &'a str,
+    /// Price after discounts, e.g. `$80.00`. Formatted total of the line item.
+    pub now: &'a str,
+    /// Percentage taken off the price, from 0 to 100.
+    pub percent_off: f64,
+}
+
+impl PricePreviewLineItem {
+    /// Percentage taken off the subtotal of this line item by discounts, from 0 to 100. `None` if the line item isn't discounted.
+    pub fn discount_percentage(&self) -> Option<f64> {
+        let subtotal: f64 = self.totals.subtotal.parse().ok()?;
+        let discount: f64 = self.totals.discount.parse().ok()?;
+
+        if subtotal <= 0.0 || discount <= 0.0 {
+            return None;
+        }
+
+        Some(discount / subtotal * 100.0)
+    }
+
+    /// Original and discounted price of this line item, for showing promo pricing. `None` if the line item isn't discounted.
+    ///
+    /// Uses the formatted totals returned by Paddle. The `now` price includes tax. For prices with a `tax_mode` of `external`, the `was` price doesn't.
+    pub fn promo_price(&self) -> Option<PromoPrice<'_>> {
+        Some(PromoPrice {
+            was: &self.formatted_totals.subtotal,
+            now: &self.formatted_totals.total,
+            percent_off: self.discount_percentage()?,
+        })
+    }
+}
+
 /// Payout entity received from a payout event
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Payout {
     /// ID for this payout.
     pub id: PayoutID,
@@ -2169,6 +3189,7 @@ pub struct Payout {
 
 /// ApiKey entity
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct ApiKey {
     /// Unique Paddle ID for this API key entity, prefixed with apikey_.
     pub id: ApiKeyID,
@@ -2192,13 +3213,31 @@ pub struct ApiKey {
     pub updated_at: DateTime<Utc>,
 }
 
+impl ApiKey {
+    /// Whether this API key has been granted the given permission.
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.permissions.iter().any(|p| p == permission.as_str())
+    }
+
+    /// Permissions from `required` that are not granted to this API key.
+    pub fn missing_permissions(&self, required: &[Permission]) -> Vec<Permission> {
+        required
+            .iter()
+            .filter(|permission| !self.has_permission(**permission))
+            .copied()
+            .collect()
+    }
+}
+
 /// Calculated totals for a price preview, including discounts, tax, and currency conversion.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct PricePreviewDetails {
     pub line_items: Vec<PricePreviewLineItem>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct PricePreviewItem {
     /// Unique Paddle ID for this price, prefixed with `pri_`.
     pub price_id: PriceID,
@@ -2206,11 +3245,20 @@ pub struct PricePreviewItem {
     pub quantity: i64,
 }
 
+impl PricePreviewItem {
+    pub fn new(price_id: impl Into<PriceID>, quantity: i64) -> Self {
+        Self {
+            price_id: price_id.into(),
+            quantity,
+        }
+    }
+}
+
 /// Price object for a non-catalog item to charge for.
 ///
 /// Can be for existing products, or you can pass a product object as part of your price to charge for a non-catalog product.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
 pub struct TransactionItemNonCatalogPrice {
     description: String,
     name: Option<String>,
@@ -2238,10 +3286,7 @@ impl TransactionItemNonCatalogPrice {
             billing_cycle: None,
             trial_period: None,
             tax_mode: None,
-            unit_price: Money {
-                amount: amount.to_string(),
-                currency_code: currency,
-            },
+            unit_price: Money::new(amount, currency),
             unit_price_overrides: None,
             quantity: None,
             custom_data: None,
@@ -2292,13 +3337,7 @@ impl TransactionItemNonCatalogPrice {
         self.unit_price_overrides
             .as_mut()
             .unwrap()
-            .push(UnitPriceOverride {
-                country_codes: country_codes.into_iter().collect(),
-                unit_price: Money {
-                    amount: amount.to_string(),
-                    currency_code: currency,
-                },
-            });
+            .push(UnitPriceOverride::new(country_codes, amount, currency));
 
         self
     }
@@ -2343,3 +3382,200 @@ impl TransactionItemNonCatalogPrice {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn customer() -> Customer {
+        serde_json::from_value(json!({
+            "id": "ctm_01hv6y1jedq4p1n0yqn5ba3ky4",
+            "name": "Sam Miller",
+            "email": "sam@example.com",
+            "marketing_consent": false,
+            "status": "active",
+            "custom_data": null,
+            "locale": "en",
+            "created_at": "2024-04-11T15:57:24.813Z",
+            "updated_at": "2024-04-11T15:59:56.658719Z",
+            "import_meta": null
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn display_leaves_out_personal_data() {
+        assert_eq!(
+            customer().to_string(),
+            "ctm_01hv6y1jedq4p1n0yqn5ba3ky4 (active)"
+        );
+
+        let money = |amount: &str, currency_code| Money {
+            amount: amount.to_string(),
+            currency_code,
+        };
+        assert_eq!(money("1050", CurrencyCode::USD).to_string(), "$10.50");
+        assert_eq!(money("1050", CurrencyCode::JPY).to_string(), "¥1050");
+        assert_eq!(money("10.5", CurrencyCode::EUR).to_string(), "10.5 EUR");
+    }
+
+    #[test]
+    fn constructors_serialize_like_the_api_expects() {
+        let item = serde_json::to_value(AdjustmentItemInput::partial("txnitm_01", 250)).unwrap();
+        assert_eq!(
+            item,
+            json!({ "item_id": "txnitm_01", "type": "partial", "amount": "250" })
+        );
+
+        let money = serde_json::to_value(Money::new(1050, CurrencyCode::USD)).unwrap();
+        assert_eq!(money, json!({ "amount": "1050", "currency_code": "USD" }));
+    }
+
+    #[test]
+    fn duration_constructors_pick_the_interval() {
+        let duration = |duration: Duration| serde_json::to_value(duration).unwrap();
+
+        assert_eq!(
+            duration(Duration::new(3, Interval::Month)),
+            json!({ "interval": "month", "frequency": 3 })
+        );
+        assert_eq!(
+            duration(Duration::days(14)),
+            json!({ "interval": "day", "frequency": 14 })
+        );
+        assert_eq!(
+            duration(Duration::weeks(2)),
+            json!({ "interval": "week", "frequency": 2 })
+        );
+        assert_eq!(
+            duration(Duration::months(1)),
+            json!({ "interval": "month", "frequency": 1 })
+        );
+        assert_eq!(
+            duration(Duration::years(1)),
+            json!({ "interval": "year", "frequency": 1 })
+        );
+    }
+
+    #[test]
+    fn notification_setting_events_serialize_as_dotted_names() {
+        let events = json!(["transaction.completed", "subscription.canceled"]);
+
+        let create: NotificationSettingCreate = serde_json::from_value(json!({
+            "id": "ntfset_01gkpjp8bkm3tm53kdgkx6sms7",
+            "description": "Orders",
+            "type": "Url",
+            "destination": "https://example.com/webhooks",
+            "active": true,
+            "api_version": 1,
+            "include_sensitive_fields": false,
+            "subscribed_events": events,
+            "endpoint_secret_key": "pdl_ntfset_01gkpjp8bkm3tm53kdgkx6sms7_6h3qd3uFSi9YCD3OLYAShQI90XTI5vEI",
+            "traffic_source": "Platform"
+        }))
+        .unwrap();
+        assert_eq!(
+            create.subscribed_events,
+            [
+                EventTypeName::TransactionCompleted,
+                EventTypeName::SubscriptionCanceled
+            ]
+        );
+        assert_eq!(
+            serde_json::to_value(&create).unwrap()["subscribed_events"],
+            events
+        );
+
+        let update: NotificationSettingUpdate = serde_json::from_value(json!({
+            "description": "Orders",
+            "destination": "https://example.com/webhooks",
+            "active": true,
+            "api_version": 1,
+            "include_sensitive_fields": false,
+            "subscribed_events": events,
+            "traffic_source": "All"
+        }))
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(&update).unwrap()["subscribed_events"],
+            events
+        );
+    }
+
+    #[test]
+    fn billing_details_allow_missing_checkout_and_purchase_order() {
+        let billing_details: BillingDetails = serde_json::from_value(json!({
+            "additional_information": null,
+            "payment_terms": { "interval": "day", "frequency": 30 }
+        }))
+        .unwrap();
+
+        assert!(!billing_details.enable_checkout);
+        assert_eq!(billing_details.purchase_order_number, None);
+        assert_eq!(billing_details.payment_terms.frequency, 30);
+    }
+
+    #[test]
+    fn subscription_charge_items_round_trip() {
+        let price = json!({
+            "description": "Setup fee",
+            "name": null,
+            "tax_mode": "account_setting",
+            "unit_price": { "amount": "5000", "currency_code": "USD" },
+            "unit_price_overrides": [],
+            "quantity": { "minimum": 1, "maximum": 1 },
+            "custom_data": null
+        });
+
+        let catalog_item = json!({ "quantity": 1, "price_id": "pri_01gsz8x8sawmvhz1pv30nge1ke" });
+
+        let mut existing_product = json!({ "quantity": 1, "price": price.clone() });
+        existing_product["price"]["product_id"] = "pro_01gsz4t5hdjse780zja8vvr7jg".into();
+
+        let mut price_and_product = price;
+        price_and_product["product"] = json!({
+            "name": "Onboarding",
+            "description": null,
+            "tax_category": "standard",
+            "image_url": null,
+            "custom_data": null
+        });
+
+        let items = [catalog_item, existing_product, price_and_product];
+        let parsed: Vec<SubscriptionChargeItem> = items
+            .iter()
+            .map(|item| serde_json::from_value(item.clone()).unwrap())
+            .collect();
+
+        assert!(matches!(parsed[0], SubscriptionChargeItem::CatalogItem(_)));
+        assert!(matches!(
+            parsed[1],
+            SubscriptionChargeItem::NonCatalogExistingProduct(_)
+        ));
+        assert!(matches!(
+            parsed[2],
+            SubscriptionChargeItem::NonCatalogPriceAndProduct(_)
+        ));
+
+        for (item, parsed) in items.iter().zip(&parsed) {
+            assert_eq!(&serde_json::to_value(parsed).unwrap(), item);
+        }
+
+        assert!(
+            serde_json::from_value::<SubscriptionChargeItem>(json!({ "quantity": 1 })).is_err()
+        );
+    }
+
+    #[cfg(feature = "redact-pii")]
+    #[test]
+    fn debug_redacts_personal_data() {
+        let debug = format!("{:?}", customer());
+
+        assert!(debug.contains("ctm_01hv6y1jedq4p1n0yqn5ba3ky4"));
+        assert!(debug.contains("[REDACTED]"));
+        assert!(!debug.contains("sam@example.com"));
+        assert!(!debug.contains("Sam Miller"));
+    }
+}
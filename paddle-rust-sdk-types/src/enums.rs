@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "strum")]
 use strum::{Display, EnumString};
 
+use crate::ids::{PriceID, ProductID};
 use crate::reports::ReportType;
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -475,6 +476,777 @@ pub enum CountryCodeSupported {
     Other(String),
 }
 
+impl CountryCodeSupported {
+    /// Parse an ISO 3166-1 alpha-2 country code, ignoring case. Codes without a dedicated variant are returned as [CountryCodeSupported::Other].
+    pub fn from_alpha2(code: &str) -> Self {
+        match code.to_ascii_uppercase().as_str() {
+            "AD" => Self::AD,
+            "AE" => Self::AE,
+            "AG" => Self::AG,
+            "AI" => Self::AI,
+            "AL" => Self::AL,
+            "AM" => Self::AM,
+            "AO" => Self::AO,
+            "AR" => Self::AR,
+            "AS" => Self::AS,
+            "AT" => Self::AT,
+            "AU" => Self::AU,
+            "AW" => Self::AW,
+            "AX" => Self::AX,
+            "AZ" => Self::AZ,
+            "BA" => Self::BA,
+            "BB" => Self::BB,
+            "BD" => Self::BD,
+            "BE" => Self::BE,
+            "BF" => Self::BF,
+            "BG" => Self::BG,
+            "BH" => Self::BH,
+            "BI" => Self::BI,
+            "BJ" => Self::BJ,
+            "BL" => Self::BL,
+            "BM" => Self::BM,
+            "BN" => Self::BN,
+            "BO" => Self::BO,
+            "BQ" => Self::BQ,
+            "BR" => Self::BR,
+            "BS" => Self::BS,
+            "BT" => Self::BT,
+            "BV" => Self::BV,
+            "BW" => Self::BW,
+            "BZ" => Self::BZ,
+            "CA" => Self::CA,
+            "CC" => Self::CC,
+            "CG" => Self::CG,
+            "CH" => Self::CH,
+            "CI" => Self::CI,
+            "CK" => Self::CK,
+            "CL" => Self::CL,
+            "CM" => Self::CM,
+            "CN" => Self::CN,
+            "CO" => Self::CO,
+            "CR" => Self::CR,
+            "CV" => Self::CV,
+            "CW" => Self::CW,
+            "CX" => Self::CX,
+            "CY" => Self::CY,
+            "CZ" => Self::CZ,
+            "DE" => Self::DE,
+            "DJ" => Self::DJ,
+            "DK" => Self::DK,
+            "DM" => Self::DM,
+            "DO" => Self::DO,
+            "DZ" => Self::DZ,
+            "EC" => Self::EC,
+            "EE" => Self::EE,
+            "EG" => Self::EG,
+            "EH" => Self::EH,
+            "ER" => Self::ER,
+            "ES" => Self::ES,
+            "ET" => Self::ET,
+            "FI" => Self::FI,
+            "FJ" => Self::FJ,
+            "FK" => Self::FK,
+            "FM" => Self::FM,
+            "FO" => Self::FO,
+            "FR" => Self::FR,
+            "GA" => Self::GA,
+            "GB" => Self::GB,
+            "GD" => Self::GD,
+            "GE" => Self::GE,
+            "GF" => Self::GF,
+            "GG" => Self::GG,
+            "GH" => Self::GH,
+            "GI" => Self::GI,
+            "GL" => Self::GL,
+            "GM" => Self::GM,
+            "GN" => Self::GN,
+            "GP" => Self::GP,
+            "GQ" => Self::GQ,
+            "GR" => Self::GR,
+            "GS" => Self::GS,
+            "GT" => Self::GT,
+            "GU" => Self::GU,
+            "GW" => Self::GW,
+            "GY" => Self::GY,
+            "HK" => Self::HK,
+            "HM" => Self::HM,
+            "HN" => Self::HN,
+            "HR" => Self::HR,
+            "HU" => Self::HU,
+            "ID" => Self::ID,
+            "IE" => Self::IE,
+            "IL" => Self::IL,
+            "IM" => Self::IM,
+            "IN" => Self::IN,
+            "IO" => Self::IO,
+            "IQ" => Self::IQ,
+            "IS" => Self::IS,
+            "IT" => Self::IT,
+            "JE" => Self::JE,
+            "JM" => Self::JM,
+            "JO" => Self::JO,
+            "JP" => Self::JP,
+            "KE" => Self::KE,
+            "KG" => Self::KG,
+            "KH" => Self::KH,
+            "KI" => Self::KI,
+            "KM" => Self::KM,
+            "KN" => Self::KN,
+            "KR" => Self::KR,
+            "KW" => Self::KW,
+            "KY" => Self::KY,
+            "KZ" => Self::KZ,
+            "LA" => Self::LA,
+            "LB" => Self::LB,
+            "LC" => Self::LC,
+            "LI" => Self::LI,
+            "LK" => Self::LK,
+            "LR" => Self::LR,
+            "LS" => Self::LS,
+            "LT" => Self::LT,
+            "LU" => Self::LU,
+            "LV" => Self::LV,
+            "MA" => Self::MA,
+            "MC" => Self::MC,
+            "MD" => Self::MD,
+            "ME" => Self::ME,
+            "MF" => Self::MF,
+            "MG" => Self::MG,
+            "MH" => Self::MH,
+            "MK" => Self::MK,
+            "MN" => Self::MN,
+            "MO" => Self::MO,
+            "MP" => Self::MP,
+            "MQ" => Self::MQ,
+            "MR" => Self::MR,
+            "MS" => Self::MS,
+            "MT" => Self::MT,
+            "MU" => Self::MU,
+            "MV" => Self::MV,
+            "MW" => Self::MW,
+            "MX" => Self::MX,
+            "MY" => Self::MY,
+            "MZ" => Self::MZ,
+            "NA" => Self::NA,
+            "NC" => Self::NC,
+            "NE" => Self::NE,
+            "NF" => Self::NF,
+            "NG" => Self::NG,
+            "NL" => Self::NL,
+            "NO" => Self::NO,
+            "NP" => Self::NP,
+            "NR" => Self::NR,
+            "NU" => Self::NU,
+            "NZ" => Self::NZ,
+            "OM" => Self::OM,
+            "PA" => Self::PA,
+            "PE" => Self::PE,
+            "PF" => Self::PF,
+            "PG" => Self::PG,
+            "PH" => Self::PH,
+            "PK" => Self::PK,
+            "PL" => Self::PL,
+            "PM" => Self::PM,
+            "PN" => Self::PN,
+            "PR" => Self::PR,
+            "PS" => Self::PS,
+            "PT" => Self::PT,
+            "PW" => Self::PW,
+            "PY" => Self::PY,
+            "QA" => Self::QA,
+            "RE" => Self::RE,
+            "RO" => Self::RO,
+            "RS" => Self::RS,
+            "RW" => Self::RW,
+            "SA" => Self::SA,
+            "SB" => Self::SB,
+            "SC" => Self::SC,
+            "SE" => Self::SE,
+            "SG" => Self::SG,
+            "SH" => Self::SH,
+            "SI" => Self::SI,
+            "SJ" => Self::SJ,
+            "SK" => Self::SK,
+            "SL" => Self::SL,
+            "SM" => Self::SM,
+            "SN" => Self::SN,
+            "SR" => Self::SR,
+            "ST" => Self::ST,
+            "SV" => Self::SV,
+            "SX" => Self::SX,
+            "SZ" => Self::SZ,
+            "TC" => Self::TC,
+            "TD" => Self::TD,
+            "TF" => Self::TF,
+            "TG" => Self::TG,
+            "TH" => Self::TH,
+            "TJ" => Self::TJ,
+            "TK" => Self::TK,
+            "TL" => Self::TL,
+            "TM" => Self::TM,
+            "TN" => Self::TN,
+            "TO" => Self::TO,
+            "TR" => Self::TR,
+            "TT" => Self::TT,
+            "TV" => Self::TV,
+            "TW" => Self::TW,
+            "TZ" => Self::TZ,
+            "UA" => Self::UA,
+            "UG" => Self::UG,
+            "UM" => Self::UM,
+            "US" => Self::US,
+            "UY" => Self::UY,
+            "UZ" => Self::UZ,
+            "VA" => Self::VA,
+            "VC" => Self::VC,
+            "VG" => Self::VG,
+            "VI" => Self::VI,
+            "VN" => Self::VN,
+            "VU" => Self::VU,
+            "WF" => Self::WF,
+            "WS" => Self::WS,
+            "XK" => Self::XK,
+            "YT" => Self::YT,
+            "ZA" => Self::ZA,
+            "ZM" => Self::ZM,
+            _ => Self::Other(code.to_string()),
+        }
+    }
+
+    /// ISO 3166-1 alpha-2 code of this country.
+    pub fn as_alpha2(&self) -> &str {
+        match self {
+            Self::AD => "AD",
+            Self::AE => "AE",
+            Self::AG => "AG",
+            Self::AI => "AI",
+            Self::AL => "AL",
+            Self::AM => "AM",
+            Self::AO => "AO",
+            Self::AR => "AR",
+            Self::AS => "AS",
+            Self::AT => "AT",
+            Self::AU => "AU",
+            Self::AW => "AW",
+            Self::AX => "AX",
+            Self::AZ => "AZ",
+            Self::BA => "BA",
+            Self::BB => "BB",
+            Self::BD => "BD",
+            Self::BE => "BE",
+            Self::BF => "BF",
+            Self::BG => "BG",
+            Self::BH => "BH",
+            Self::BI => "BI",
+            Self::BJ => "BJ",
+            Self::BL => "BL",
+            Self::BM => "BM",
+            Self::BN => "BN",
+            Self::BO => "BO",
+            Self::BQ => "BQ",
+            Self::BR => "BR",
+            Self::BS => "BS",
+            Self::BT => "BT",
+            Self::BV => "BV",
+            Self::BW => "BW",
+            Self::BZ => "BZ",
+            Self::CA => "CA",
+            Self::CC => "CC",
+            Self::CG => "CG",
+            Self::CH => "CH",
+            Self::CI => "CI",
+            Self::CK => "CK",
+            Self::CL => "CL",
+            Self::CM => "CM",
+            Self::CN => "CN",
+            Self::CO => "CO",
+            Self::CR => "CR",
+            Self::CV => "CV",
+            Self::CW => "CW",
+            Self::CX => "CX",
+            Self::CY => "CY",
+            Self::CZ => "CZ",
+            Self::DE => "DE",
+            Self::DJ => "DJ",
+            Self::DK => "DK",
+            Self::DM => "DM",
+            Self::DO => "DO",
+            Self::DZ => "DZ",
+            Self::EC => "EC",
+            Self::EE => "EE",
+            Self::EG => "EG",
+            Self::EH => "EH",
+            Self::ER => "ER",
+            Self::ES => "ES",
+            Self::ET => "ET",
+            Self::FI => "FI",
+            Self::FJ => "FJ",
+            Self::FK => "FK",
+            Self::FM => "FM",
+            Self::FO => "FO",
+            Self::FR => "FR",
+            Self::GA => "GA",
+            Self::GB => "GB",
+            Self::GD => "GD",
+            Self::GE => "GE",
+            Self::GF => "GF",
+            Self::GG => "GG",
+            Self::GH => "GH",
+            Self::GI => "GI",
+            Self::GL => "GL",
+            Self::GM => "GM",
+            Self::GN => "GN",
+            Self::GP => "GP",
+            Self::GQ => "GQ",
+            Self::GR => "GR",
+            Self::GS => "GS",
+            Self::GT => "GT",
+            Self::GU => "GU",
+            Self::GW => "GW",
+            Self::GY => "GY",
+            Self::HK => "HK",
+            Self::HM => "HM",
+            Self::HN => "HN",
+            Self::HR => "HR",
+            Self::HU => "HU",
+            Self::ID => "ID",
+            Self::IE => "IE",
+            Self::IL => "IL",
+            Self::IM => "IM",
+            Self::IN => "IN",
+            Self::IO => "IO",
+            Self::IQ => "IQ",
+            Self::IS => "IS",
+            Self::IT => "IT",
+            Self::JE => "JE",
+            Self::JM => "JM",
+            Self::JO => "JO",
+            Self::JP => "JP",
+            Self::KE => "KE",
+            Self::KG => "KG",
+            Self::KH => "KH",
+            Self::KI => "KI",
+            Self::KM => "KM",
+            Self::KN => "KN",
+            Self::KR => "KR",
+            Self::KW => "KW",
+            Self::KY => "KY",
+            Self::KZ => "KZ",
+            Self::LA => "LA",
+            Self::LB => "LB",
+            Self::LC => "LC",
+            Self::LI => "LI",
+            Self::LK => "LK",
+            Self::LR => "LR",
+            Self::LS => "LS",
+            Self::LT => "LT",
+            Self::LU => "LU",
+            Self::LV => "LV",
+            Self::MA => "MA",
+            Self::MC => "MC",
+            Self::MD => "MD",
+            Self::ME => "ME",
+            Self::MF => "MF",
+            Self::MG => "MG",
+            Self::MH => "MH",
+            Self::MK => "MK",
+            Self::MN => "MN",
+            Self::MO => "MO",
+            Self::MP => "MP",
+            Self::MQ => "MQ",
+            Self::MR => "MR",
+            Self::MS => "MS",
+            Self::MT => "MT",
+            Self::MU => "MU",
+            Self::MV => "MV",
+            Self::MW => "MW",
+            Self::MX => "MX",
+            Self::MY => "MY",
+            Self::MZ => "MZ",
+            Self::NA => "NA",
+            Self::NC => "NC",
+            Self::NE => "NE",
+            Self::NF => "NF",
+            Self::NG => "NG",
+            Self::NL => "NL",
+            Self::NO => "NO",
+            Self::NP => "NP",
+            Self::NR => "NR",
+            Self::NU => "NU",
+            Self::NZ => "NZ",
+            Self::OM => "OM",
+            Self::PA => "PA",
+            Self::PE => "PE",
+            Self::PF => "PF",
+            Self::PG => "PG",
+            Self::PH => "PH",
+            Self::PK => "PK",
+            Self::PL => "PL",
+            Self::PM => "PM",
+            Self::PN => "PN",
+            Self::PR => "PR",
+            Self::PS => "PS",
+            Self::PT => "PT",
+            Self::PW => "PW",
+            Self::PY => "PY",
+            Self::QA => "QA",
+            Self::RE => "RE",
+            Self::RO => "RO",
+            Self::RS => "RS",
+            Self::RW => "RW",
+            Self::SA => "SA",
+            Self::SB => "SB",
+            Self::SC => "SC",
+            Self::SE => "SE",
+            Self::SG => "SG",
+            Self::SH => "SH",
+            Self::SI => "SI",
+            Self::SJ => "SJ",
+            Self::SK => "SK",
+            Self::SL => "SL",
+            Self::SM => "SM",
+            Self::SN => "SN",
+            Self::SR => "SR",
+            Self::ST => "ST",
+            Self::SV => "SV",
+            Self::SX => "SX",
+            Self::SZ => "SZ",
+            Self::TC => "TC",
+            Self::TD => "TD",
+            Self::TF => "TF",
+            Self::TG => "TG",
+            Self::TH => "TH",
+            Self::TJ => "TJ",
+            Self::TK => "TK",
+            Self::TL => "TL",
+            Self::TM => "TM",
+            Self::TN => "TN",
+            Self::TO => "TO",
+            Self::TR => "TR",
+            Self::TT => "TT",
+            Self::TV => "TV",
+            Self::TW => "TW",
+            Self::TZ => "TZ",
+            Self::UA => "UA",
+            Self::UG => "UG",
+            Self::UM => "UM",
+            Self::US => "US",
+            Self::UY => "UY",
+            Self::UZ => "UZ",
+            Self::VA => "VA",
+            Self::VC => "VC",
+            Self::VG => "VG",
+            Self::VI => "VI",
+            Self::VN => "VN",
+            Self::VU => "VU",
+            Self::WF => "WF",
+            Self::WS => "WS",
+            Self::XK => "XK",
+            Self::YT => "YT",
+            Self::ZA => "ZA",
+            Self::ZM => "ZM",
+            Self::Other(code) => code,
+        }
+    }
+
+    /// English name of this country. `None` for [CountryCodeSupported::Other].
+    pub fn name(&self) -> Option<&'static str> {
+        let name = match self {
+            Self::AD => "Andorra",
+            Self::AE => "United Arab Emirates",
+            Self::AG => "Antigua and Barbuda",
+            Self::AI => "Anguilla",
+            Self::AL => "Albania",
+            Self::AM => "Armenia",
+            Self::AO => "Angola",
+            Self::AR => "Argentina",
+            Self::AS => "American Samoa",
+            Self::AT => "Austria",
+            Self::AU => "Australia",
+            Self::AW => "Aruba",
+            Self::AX => "Åland Islands",
+            Self::AZ => "Azerbaijan",
+            Self::BA => "Bosnia and Herzegovina",
+            Self::BB => "Barbados",
+            Self::BD => "Bangladesh",
+            Self::BE => "Belgium",
+            Self::BF => "Burkina Faso",
+            Self::BG => "Bulgaria",
+            Self::BH => "Bahrain",
+            Self::BI => "Burundi",
+            Self::BJ => "Benin",
+            Self::BL => "Saint Barthélemy",
+            Self::BM => "Bermuda",
+            Self::BN => "Brunei",
+            Self::BO => "Bolivia",
+            Self::BQ => "Caribbean Netherlands (Bonaire, Sint Eustatius, and Saba)",
+            Self::BR => "Brazil",
+            Self::BS => "Bahamas",
+            Self::BT => "Bhutan",
+            Self::BV => "Bouvet Island",
+            Self::BW => "Botswana",
+            Self::BZ => "Belize",
+            Self::CA => "Canada",
+            Self::CC => "Cocos Islands",
+            Self::CG => "Republic of Congo",
+            Self::CH => "Switzerland",
+            Self::CI => "Côte d'Ivoire (Ivory Coast)",
+            Self::CK => "Cook Islands",
+            Self::CL => "Chile",
+            Self::CM => "Cameroon",
+            Self::CN => "China",
+            Self::CO => "Colombia",
+            Self::CR => "Costa Rica",
+            Self::CV => "Cape Verde",
+            Self::CW => "Curaçao",
+            Self::CX => "Christmas Island",
+            Self::CY => "Cyprus",
+            Self::CZ => "Czechia (Czech Republic)",
+            Self::DE => "Germany",
+            Self::DJ => "Djibouti",
+            Self::DK => "Denmark",
+            Self::DM => "Dominica",
+            Self::DO => "Dominican Republic",
+            Self::DZ => "Algeria",
+            Self::EC => "Ecuador",
+            Self::EE => "Estonia",
+            Self::EG => "Egypt",
+            Self::EH => "Western Sahara",
+            Self::ER => "Eritrea",
+            Self::ES => "Spain",
+            Self::ET => "Ethiopia",
+            Self::FI => "Finland",
+            Self::FJ => "Fiji",
+            Self::FK => "Falkland Islands",
+            Self::FM => "Micronesia",
+            Self::FO => "Faroe Islands",
+            Self::FR => "France",
+            Self::GA => "Gabon",
+            Self::GB => "United Kingdom",
+            Self::GD => "Grenada",
+            Self::GE => "Georgia",
+            Self::GF => "French Guiana",
+            Self::GG => "Guernsey",
+            Self::GH => "Ghana",
+            Self::GI => "Gibraltar",
+            Self::GL => "Greenland",
+            Self::GM => "Gambia",
+            Self::GN => "Guinea",
+            Self::GP => "Guadeloupe",
+            Self::GQ => "Equatorial Guinea",
+            Self::GR => "Greece",
+            Self::GS => "South Georgia and the South Sandwich Islands",
+            Self::GT => "Guatemala",
+            Self::GU => "Guam",
+            Self::GW => "Guinea-Bissau",
+            Self::GY => "Guyana",
+            Self::HK => "Hong Kong",
+            Self::HM => "Heard Island and McDonald Islands",
+            Self::HN => "Honduras",
+            Self::HR => "Croatia",
+            Self::HU => "Hungary",
+            Self::ID => "Indonesia",
+            Self::IE => "Ireland",
+            Self::IL => "Israel",
+            Self::IM => "Isle of Man",
+            Self::IN => "India",
+            Self::IO => "British Indian Ocean Territory",
+            Self::IQ => "Iraq",
+            Self::IS => "Iceland",
+            Self::IT => "Italy",
+            Self::JE => "Jersey",
+            Self::JM => "Jamaica",
+            Self::JO => "Jordan",
+            Self::JP => "Japan",
+            Self::KE => "Kenya",
+            Self::KG => "Kyrgyzstan",
+            Self::KH => "Cambodia",
+            Self::KI => "Kiribati",
+            Self::KM => "Comoros",
+            Self::KN => "Saint Kitts and Nevis",
+            Self::KR => "South Korea",
+            Self::KW => "Kuwait",
+            Self::KY => "Cayman Islands",
+            Self::KZ => "Kazakhstan",
+            Self::LA => "Lao People's Democratic Republic (Laos)",
+            Self::LB => "Lebanon",
+            Self::LC => "Saint Lucia",
+            Self::LI => "Liechtenstein",
+            Self::LK => "Sri Lanka",
+            Self::LR => "Liberia",
+            Self::LS => "Lesotho",
+            Self::LT => "Lithuania",
+            Self::LU => "Luxembourg",
+            Self::LV => "Latvia",
+            Self::MA => "Morocco",
+            Self::MC => "Monaco",
+            Self::MD => "Moldova",
+            Self::ME => "Montenegro",
+            Self::MF => "Saint Martin",
+            Self::MG => "Madagascar",
+            Self::MH => "Marshall Islands",
+            Self::MK => "Macedonia",
+            Self::MN => "Mongolia",
+            Self::MO => "Macao",
+            Self::MP => "Northern Mariana Islands",
+            Self::MQ => "Martinique",
+            Self::MR => "Mauritania",
+            Self::MS => "Montserrat",
+            Self::MT => "Malta",
+            Self::MU => "Mauritius",
+            Self::MV => "Maldives",
+            Self::MW => "Malawi",
+            Self::MX => "Mexico",
+            Self::MY => "Malaysia",
+            Self::MZ => "Mozambique",
+            Self::NA => "Namibia",
+            Self::NC => "New Caledonia",
+            Self::NE => "Niger",
+            Self::NF => "Norfolk Island",
+            Self::NG => "Nigeria",
+            Self::NL => "Netherlands",
+            Self::NO => "Norway",
+            Self::NP => "Nepal",
+            Self::NR => "Nauru",
+            Self::NU => "Niue",
+            Self::NZ => "New Zealand",
+            Self::OM => "Oman",
+            Self::PA => "Panama",
+            Self::PE => "Peru",
+            Self::PF => "French Polynesia",
+            Self::PG => "Papua New Guinea",
+            Self::PH => "Philippines",
+            Self::PK => "Pakistan",
+            Self::PL => "Poland",
+            Self::PM => "Saint Pierre and Miquelon",
+            Self::PN => "Pitcairn",
+            Self::PR => "Puerto Rico",
+            Self::PS => "Palestinian territories",
+            Self::PT => "Portugal",
+            Self::PW => "Palau",
+            Self::PY => "Paraguay",
+            Self::QA => "Qatar",
+            Self::RE => "Reunion",
+            Self::RO => "Romania",
+            Self::RS => "Republic of Serbia",
+            Self::RW => "Rwanda",
+            Self::SA => "Saudi Arabia",
+            Self::SB => "Solomon Islands",
+            Self::SC => "Seychelles",
+            Self::SE => "Sweden",
+            Self::SG => "Singapore",
+            Self::SH => "Saint Helena",
+            Self::SI => "Slovenia",
+            Self::SJ => "Svalbard and Jan Mayen",
+            Self::SK => "Slovakia",
+            Self::SL => "Sierra Leone",
+            Self::SM => "San Marino",
+            Self::SN => "Senegal",
+            Self::SR => "Suriname",
+            Self::ST => "São Tomé and Príncipe",
+            Self::SV => "El Salvador",
+            Self::SX => "Sint Maarten",
+            Self::SZ => "Swaziland",
+            Self::TC => "Turks and Caicos Islands",
+            Self::TD => "Chad",
+            Self::TF => "French Southern and Antarctic Lands",
+            Self::TG => "Togo",
+            Self::TH => "Thailand",
+            Self::TJ => "Tajikistan",
+            Self::TK => "Tokelau",
+            Self::TL => "Timor-Leste",
+            Self::TM => "Turkmenistan",
+            Self::TN => "Tunisia",
+            Self::TO => "Tonga",
+            Self::TR => "Turkey",
+            Self::TT => "Trinidad and Tobago",
+            Self::TV => "Tuvalu",
+            Self::TW => "Taiwan",
+            Self::TZ => "Tanzania",
+            Self::UA => "Ukraine",
+            Self::UG => "Uganda",
+            Self::UM => "United States Minor Outlying Islands",
+            Self::US => "United States",
+            Self::UY => "Uruguay",
+            Self::UZ => "Uzbekistan",
+            Self::VA => "Holy See (Vatican City)",
+            Self::VC => "Saint Vincent and the Grenadines",
+            Self::VG => "British Virgin Islands",
+            Self::VI => "U.S. Virgin Islands",
+            Self::VN => "Vietnam",
+            Self::VU => "Vanuatu",
+            Self::WF => "Wallis and Futuna",
+            Self::WS => "Samoa",
+            Self::XK => "Kosovo",
+            Self::YT => "Mayotte",
+            Self::ZA => "South Africa",
+            Self::ZM => "Zambia",
+            Self::Other(_) => return None,
+        };
+
+        Some(name)
+    }
+
+    /// Whether this country is a member state of the European Union.
+    pub fn is_eu(&self) -> bool {
+        matches!(
+            self,
+            Self::AT
+                | Self::BE
+                | Self::BG
+                | Self::CY
+                | Self::CZ
+                | Self::DE
+                | Self::DK
+                | Self::EE
+                | Self::ES
+                | Self::FI
+                | Self::FR
+                | Self::GR
+                | Self::HR
+                | Self::HU
+                | Self::IE
+                | Self::IT
+                | Self::LT
+                | Self::LU
+                | Self::LV
+                | Self::MT
+                | Self::NL
+                | Self::PL
+                | Self::PT
+                | Self::RO
+                | Self::SE
+                | Self::SI
+                | Self::SK
+        )
+    }
+
+    /// Whether Paddle requires a postal code for addresses in this country.
+    ///
+    /// See the [Paddle documentation](https://developer.paddle.com/concepts/sell/supported-countries-locales) for more information.
+    pub fn requires_postal_code(&self) -> bool {
+        matches!(
+            self,
+            Self::AU
+                | Self::CA
+                | Self::DE
+                | Self::ES
+                | Self::FR
+                | Self::GB
+                | Self::IN
+                | Self::IT
+                | Self::NL
+                | Self::US
+        )
+    }
+}
+
+impl AsRef<str> for CountryCodeSupported {
+    fn as_ref(&self) -> &str {
+        self.as_alpha2()
+    }
+}
+
 /// Whether this entity can be used in Paddle.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "strum", derive(EnumString, Display))]
@@ -520,7 +1292,7 @@ pub enum AdjustmentType {
 }
 
 /// Supported three-letter ISO 4217 currency code.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "strum", derive(EnumString, Display))]
 pub enum CurrencyCode {
     /// United States Dollar
@@ -585,6 +1357,160 @@ pub enum CurrencyCode {
     VND,
     /// South African Rand
     ZAR,
+    /// Currency code that this crate doesn't know about yet, e.g. a currency Paddle started supporting after this version was released.
+    #[serde(untagged)]
+    #[cfg_attr(feature = "strum", strum(default))]
+    Other(String),
+}
+
+impl CurrencyCode {
+    /// Three-letter ISO 4217 code of this currency, e.g. `USD`.
+    pub fn as_iso(&self) -> &str {
+        match self {
+            Self::USD => "USD",
+            Self::EUR => "EUR",
+            Self::GBP => "GBP",
+            Self::JPY => "JPY",
+            Self::AUD => "AUD",
+            Self::CAD => "CAD",
+            Self::CHF => "CHF",
+            Self::HKD => "HKD",
+            Self::SGD => "SGD",
+            Self::SEK => "SEK",
+            Self::ARS => "ARS",
+            Self::BRL => "BRL",
+            Self::CNY => "CNY",
+            Self::COP => "COP",
+            Self::CZK => "CZK",
+            Self::DKK => "DKK",
+            Self::HUF => "HUF",
+            Self::ILS => "ILS",
+            Self::INR => "INR",
+            Self::KRW => "KRW",
+            Self::MXN => "MXN",
+            Self::NOK => "NOK",
+            Self::NZD => "NZD",
+            Self::PLN => "PLN",
+            Self::RUB => "RUB",
+            Self::THB => "THB",
+            Self::TRY => "TRY",
+            Self::TWD => "TWD",
+            Self::UAH => "UAH",
+            Self::VND => "VND",
+            Self::ZAR => "ZAR",
+            Self::Other(code) => code,
+        }
+    }
+
+    /// Number of digits after the decimal separator. Paddle amounts are in the lowest denomination of a currency, so an amount of `1000` is `10.00` USD but `1000` JPY.
+    pub fn minor_units(&self) -> u32 {
+        match self {
+            Self::JPY | Self::KRW | Self::VND => 0,
+            // Most currencies use two digits, so it's the best guess for unknown ones
+            _ => 2,
+        }
+    }
+
+    /// Commonly used symbol for this currency, e.g. `$` for USD or `CA$` for CAD.
+    ///
+    /// The ISO code is used for currencies without a known symbol.
+    pub fn symbol(&self) -> &str {
+        match self {
+            Self::USD => "$",
+            Self::EUR => "€",
+            Self::GBP => "£",
+            Self::JPY => "¥",
+            Self::AUD => "A$",
+            Self::CAD => "CA$",
+            Self::CHF => "CHF",
+            Self::HKD => "HK$",
+            Self::SGD => "S$",
+            Self::SEK => "kr",
+            Self::ARS => "AR$",
+            Self::BRL => "R$",
+            Self::CNY => "CN¥",
+            Self::COP => "COL$",
+            Self::CZK => "Kč",
+            Self::DKK => "kr.",
+            Self::HUF => "Ft",
+            Self::ILS => "₪",
+            Self::INR => "₹",
+            Self::KRW => "₩",
+            Self::MXN => "MX$",
+            Self::NOK => "kr",
+            Self::NZD => "NZ$",
+            Self::PLN => "zł",
+            Self::RUB => "₽",
+            Self::THB => "฿",
+            Self::TRY => "₺",
+            Self::TWD => "NT$",
+            Self::UAH => "₴",
+            Self::VND => "₫",
+            Self::ZAR => "R",
+            Self::Other(code) => code,
+        }
+    }
+
+    /// Format an amount in the lowest denomination of this currency for display, e.g. `1050` USD as `$10.50`, `1050` JPY as `¥1050` and `1050` SEK as `10.50 kr`.
+    pub fn format_minor(&self, amount: i64) -> String {
+        let sign = if amount < 0 { "-" } else { "" };
+        let value = self.decimal_minor(amount.unsigned_abs());
+
+        match self {
+            Self::CHF
+            | Self::SEK
+            | Self::CZK
+            | Self::DKK
+            | Self::HUF
+            | Self::NOK
+            | Self::PLN
+            | Self::Other(_) => format!("{sign}{value} {}", self.symbol()),
+            _ => format!("{sign}{}{value}", self.symbol()),
+        }
+    }
+
+    /// Format an amount in the lowest denomination of this currency as a decimal string without a currency symbol, e.g. `1050` USD as `10.50` and `1050` JPY as `1050`.
+    pub fn format_minor_decimal(&self, amount: i64) -> String {
+        let sign = if amount < 0 { "-" } else { "" };
+
+        format!("{sign}{}", self.decimal_minor(amount.unsigned_abs()))
+    }
+
+    fn decimal_minor(&self, amount: u64) -> String {
+        let minor_units = self.minor_units();
+
+        if minor_units == 0 {
+            return amount.to_string();
+        }
+
+        let divisor = 10u64.pow(minor_units);
+
+        format!(
+            "{}.{:0width$}",
+            amount / divisor,
+            amount % divisor,
+            width = minor_units as usize
+        )
+    }
+}
+
+impl AsRef<str> for CurrencyCode {
+    fn as_ref(&self) -> &str {
+        self.as_iso()
+    }
+}
+
+impl PartialEq<str> for CurrencyCode {
+    /// Compare with a three-letter ISO 4217 code, ignoring case.
+    fn eq(&self, other: &str) -> bool {
+        self.as_iso().eq_ignore_ascii_case(other)
+    }
+}
+
+impl PartialEq<&str> for CurrencyCode {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
 }
 
 /// Status of this adjustment. Set automatically by Paddle.
@@ -608,7 +1534,7 @@ pub enum AdjustmentStatus {
 }
 
 /// Three-letter ISO 4217 currency code for chargeback fees.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "strum", derive(EnumString, Display))]
 pub enum CurrencyCodeChargebacks {
     /// Australian Dollar
@@ -621,10 +1547,47 @@ pub enum CurrencyCodeChargebacks {
     GBP,
     /// United States Dollar
     USD,
+    /// Currency code that this crate doesn't know about yet, e.g. a currency Paddle started supporting after this version was released.
+    #[serde(untagged)]
+    #[cfg_attr(feature = "strum", strum(default))]
+    Other(String),
+}
+
+impl CurrencyCodeChargebacks {
+    /// Three-letter ISO 4217 code of this currency, e.g. `USD`.
+    pub fn as_iso(&self) -> &str {
+        match self {
+            Self::AUD => "AUD",
+            Self::CAD => "CAD",
+            Self::EUR => "EUR",
+            Self::GBP => "GBP",
+            Self::USD => "USD",
+            Self::Other(code) => code,
+        }
+    }
+}
+
+impl AsRef<str> for CurrencyCodeChargebacks {
+    fn as_ref(&self) -> &str {
+        self.as_iso()
+    }
+}
+
+impl PartialEq<str> for CurrencyCodeChargebacks {
+    /// Compare with a three-letter ISO 4217 code, ignoring case.
+    fn eq(&self, other: &str) -> bool {
+        self.as_iso().eq_ignore_ascii_case(other)
+    }
+}
+
+impl PartialEq<&str> for CurrencyCodeChargebacks {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
 }
 
 /// Supported three-letter ISO 4217 currency code for payouts from Paddle.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "strum", derive(EnumString, Display))]
 pub enum CurrencyCodePayouts {
     /// Australian Dollar
@@ -653,6 +1616,51 @@ pub enum CurrencyCodePayouts {
     USD,
     /// South African Rand
     ZAR,
+    /// Currency code that this crate doesn't know about yet, e.g. a currency Paddle started supporting after this version was released.
+    #[serde(untagged)]
+    #[cfg_attr(feature = "strum", strum(default))]
+    Other(String),
+}
+
+impl CurrencyCodePayouts {
+    /// Three-letter ISO 4217 code of this currency, e.g. `USD`.
+    pub fn as_iso(&self) -> &str {
+        match self {
+            Self::AUD => "AUD",
+            Self::CAD => "CAD",
+            Self::CHF => "CHF",
+            Self::CNY => "CNY",
+            Self::CZK => "CZK",
+            Self::DKK => "DKK",
+            Self::EUR => "EUR",
+            Self::GBP => "GBP",
+            Self::HUF => "HUF",
+            Self::PLN => "PLN",
+            Self::SEK => "SEK",
+            Self::USD => "USD",
+            Self::ZAR => "ZAR",
+            Self::Other(code) => code,
+        }
+    }
+}
+
+impl AsRef<str> for CurrencyCodePayouts {
+    fn as_ref(&self) -> &str {
+        self.as_iso()
+    }
+}
+
+impl PartialEq<str> for CurrencyCodePayouts {
+    /// Compare with a three-letter ISO 4217 code, ignoring case.
+    fn eq(&self, other: &str) -> bool {
+        self.as_iso().eq_ignore_ascii_case(other)
+    }
+}
+
+impl PartialEq<&str> for CurrencyCodePayouts {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
 }
 
 /// Type of adjustment for this transaction item. `tax` adjustments are automatically created by Paddle.
@@ -814,6 +1822,73 @@ pub enum DiscountType {
     Percentage,
 }
 
+/// Product or price that a discount is restricted to, parsed from the ID prefix.
+///
+/// Serialized as the plain ID, so it can be sent wherever Paddle expects a `restrict_to` entry.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(into = "String", from = "String")]
+pub enum RestrictTo {
+    /// All prices for this product can be discounted. Product IDs are prefixed with `pro_`.
+    Product(ProductID),
+    /// Only this price can be discounted. Price IDs are prefixed with `pri_`.
+    Price(PriceID),
+    /// ID with a prefix that's neither a product nor a price.
+    Other(String),
+}
+
+impl RestrictTo {
+    /// Parse a `restrict_to` entry by its prefix.
+    pub fn parse(id: &str) -> Self {
+        if id.starts_with("pro_") {
+            Self::Product(id.into())
+        } else if id.starts_with("pri_") {
+            Self::Price(id.into())
+        } else {
+            Self::Other(id.to_string())
+        }
+    }
+}
+
+impl AsRef<str> for RestrictTo {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Product(id) => id.as_ref(),
+            Self::Price(id) => id.as_ref(),
+            Self::Other(id) => id,
+        }
+    }
+}
+
+impl std::fmt::Display for RestrictTo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+impl From<String> for RestrictTo {
+    fn from(id: String) -> Self {
+        Self::parse(&id)
+    }
+}
+
+impl From<RestrictTo> for String {
+    fn from(restrict_to: RestrictTo) -> Self {
+        restrict_to.as_ref().to_string()
+    }
+}
+
+impl From<ProductID> for RestrictTo {
+    fn from(id: ProductID) -> Self {
+        Self::Product(id)
+    }
+}
+
+impl From<PriceID> for RestrictTo {
+    fn from(id: PriceID) -> Self {
+        Self::Price(id)
+    }
+}
+
 /// When this subscription change should take effect from. Defaults to `next_billing_period`, which creates a
 /// `scheduled_change` to apply the subscription change at the end of the billing period.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -884,93 +1959,213 @@ pub enum ErrorCode {
 }
 
 /// Type of event sent by Paddle, in the format `entity.event_type`.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "strum", derive(EnumString, Display))]
 pub enum EventTypeName {
     /// An [`address.created`](https://developer.paddle.com/webhooks/addresses/address-created) event.
+    #[serde(rename = "address.created")]
+    #[cfg_attr(feature = "strum", strum(serialize = "address.created"))]
     AddressCreated,
     /// An [`address.imported`](https://developer.paddle.com/webhooks/addresses/address-imported) event.
+    #[serde(rename = "address.imported")]
+    #[cfg_attr(feature = "strum", strum(serialize = "address.imported"))]
     AddressImported,
     /// An [`address.updated`](https://developer.paddle.com/webhooks/addresses/address-updated) event.
+    #[serde(rename = "address.updated")]
+    #[cfg_attr(feature = "strum", strum(serialize = "address.updated"))]
     AddressUpdated,
     /// An [`adjustment.created`](https://developer.paddle.com/webhooks/adjustments/adjustment-created) event.
+    #[serde(rename = "adjustment.created")]
+    #[cfg_attr(feature = "strum", strum(serialize = "adjustment.created"))]
     AdjustmentCreated,
     /// An [`adjustment.updated`](https://developer.paddle.com/webhooks/adjustments/adjustment-updated) event.
+    #[serde(rename = "adjustment.updated")]
+    #[cfg_attr(feature = "strum", strum(serialize = "adjustment.updated"))]
     AdjustmentUpdated,
+    /// A [`api_key.created`](https://developer.paddle.com/webhooks/api-keys/api-key-created) event.
+    #[serde(rename = "api_key.created")]
+    #[cfg_attr(feature = "strum", strum(serialize = "api_key.created"))]
+    ApiKeyCreated,
+    /// A [`api_key.updated`](https://developer.paddle.com/webhooks/api-keys/api-key-updated) event.
+    #[serde(rename = "api_key.updated")]
+    #[cfg_attr(feature = "strum", strum(serialize = "api_key.updated"))]
+    ApiKeyUpdated,
+    /// A [`api_key.expiring`](https://developer.paddle.com/webhooks/api-keys/api-key-expiring) event.
+    #[serde(rename = "api_key.expiring")]
+    #[cfg_attr(feature = "strum", strum(serialize = "api_key.expiring"))]
+    ApiKeyExpiring,
+    /// A [`api_key.expired`](https://developer.paddle.com/webhooks/api-keys/api-key-expired) event.
+    #[serde(rename = "api_key.expired")]
+    #[cfg_attr(feature = "strum", strum(serialize = "api_key.expired"))]
+    ApiKeyExpired,
+    /// A [`api_key.revoked`](https://developer.paddle.com/webhooks/api-keys/api-key-revoked) event.
+    #[serde(rename = "api_key.revoked")]
+    #[cfg_attr(feature = "strum", strum(serialize = "api_key.revoked"))]
+    ApiKeyRevoked,
     /// A [`business.created`](https://developer.paddle.com/webhooks/businesses/business-created) event.
+    #[serde(rename = "business.created")]
+    #[cfg_attr(feature = "strum", strum(serialize = "business.created"))]
     BusinessCreated,
     /// A [`business.imported`](https://developer.paddle.com/webhooks/businesses/business-imported) event.
+    #[serde(rename = "business.imported")]
+    #[cfg_attr(feature = "strum", strum(serialize = "business.imported"))]
     BusinessImported,
     /// A [`business.updated`](https://developer.paddle.com/webhooks/businesses/business-updated) event.
+    #[serde(rename = "business.updated")]
+    #[cfg_attr(feature = "strum", strum(serialize = "business.updated"))]
     BusinessUpdated,
     /// A [`customer.created`](https://developer.paddle.com/webhooks/customers/customer-created) event.
+    #[serde(rename = "customer.created")]
+    #[cfg_attr(feature = "strum", strum(serialize = "customer.created"))]
     CustomerCreated,
     /// A [`customer.imported`](https://developer.paddle.com/webhooks/customers/customer-imported) event.
+    #[serde(rename = "customer.imported")]
+    #[cfg_attr(feature = "strum", strum(serialize = "customer.imported"))]
     CustomerImported,
     /// A [`customer.updated`](https://developer.paddle.com/webhooks/customers/customer-updated) event.
+    #[serde(rename = "customer.updated")]
+    #[cfg_attr(feature = "strum", strum(serialize = "customer.updated"))]
     CustomerUpdated,
     /// A [`discount.created`](https://developer.paddle.com/webhooks/discounts/discount-created) event.
+    #[serde(rename = "discount.created")]
+    #[cfg_attr(feature = "strum", strum(serialize = "discount.created"))]
     DiscountCreated,
     /// A [`discount.imported`](https://developer.paddle.com/webhooks/discounts/discount-imported) event.
+    #[serde(rename = "discount.imported")]
+    #[cfg_attr(feature = "strum", strum(serialize = "discount.imported"))]
     DiscountImported,
     /// A [`discount.updated`](https://developer.paddle.com/webhooks/discounts/discount-updated) event.
+    #[serde(rename = "discount.updated")]
+    #[cfg_attr(feature = "strum", strum(serialize = "discount.updated"))]
     DiscountUpdated,
+    /// A [`payment_method.saved`](https://developer.paddle.com/webhooks/payment-methods/payment-method-saved) event.
+    #[serde(rename = "payment_method.saved")]
+    #[cfg_attr(feature = "strum", strum(serialize = "payment_method.saved"))]
+    PaymentMethodSaved,
+    /// A [`payment_method.deleted`](https://developer.paddle.com/webhooks/payment-methods/payment-method-deleted) event.
+    #[serde(rename = "payment_method.deleted")]
+    #[cfg_attr(feature = "strum", strum(serialize = "payment_method.deleted"))]
+    PaymentMethodDeleted,
     /// A [`payout.created`](https://developer.paddle.com/webhooks/payouts/payout-created) event.
+    #[serde(rename = "payout.created")]
+    #[cfg_attr(feature = "strum", strum(serialize = "payout.created"))]
     PayoutCreated,
     /// A [`payout.paid`](https://developer.paddle.com/webhooks/payouts/payout-paid) event.
+    #[serde(rename = "payout.paid")]
+    #[cfg_attr(feature = "strum", strum(serialize = "payout.paid"))]
     PayoutPaid,
     /// A [`price.created`](https://developer.paddle.com/webhooks/prices/price-created) event.
+    #[serde(rename = "price.created")]
+    #[cfg_attr(feature = "strum", strum(serialize = "price.created"))]
     PriceCreated,
     /// A [`price.imported`](https://developer.paddle.com/webhooks/prices/price-imported) event.
+    #[serde(rename = "price.imported")]
+    #[cfg_attr(feature = "strum", strum(serialize = "price.imported"))]
     PriceImported,
     /// A [`price.updated`](https://developer.paddle.com/webhooks/prices/price-updated) event.
+    #[serde(rename = "price.updated")]
+    #[cfg_attr(feature = "strum", strum(serialize = "price.updated"))]
     PriceUpdated,
     /// A [`product.created`](https://developer.paddle.com/webhooks/products/product-created) event.
+    #[serde(rename = "product.created")]
+    #[cfg_attr(feature = "strum", strum(serialize = "product.created"))]
     ProductCreated,
     /// A [`product.imported`](https://developer.paddle.com/webhooks/products/product-imported) event.
+    #[serde(rename = "product.imported")]
+    #[cfg_attr(feature = "strum", strum(serialize = "product.imported"))]
     ProductImported,
-    /// A [`product.created`](https://developer.paddle.com/webhooks/products/product-updated) event.
+    /// A [`product.updated`](https://developer.paddle.com/webhooks/products/product-updated) event.
+    #[serde(rename = "product.updated")]
+    #[cfg_attr(feature = "strum", strum(serialize = "product.updated"))]
     ProductUpdated,
     /// A [`report.created`](https://developer.paddle.com/webhooks/reports/report-created) event.
+    #[serde(rename = "report.created")]
+    #[cfg_attr(feature = "strum", strum(serialize = "report.created"))]
     ReportCreated,
     /// A [`report.updated`](https://developer.paddle.com/webhooks/reports/report-updated) event.
+    #[serde(rename = "report.updated")]
+    #[cfg_attr(feature = "strum", strum(serialize = "report.updated"))]
     ReportUpdated,
     /// A [`subscription.activated`](https://developer.paddle.com/webhooks/subscriptions/subscription-activated) event.
+    #[serde(rename = "subscription.activated")]
+    #[cfg_attr(feature = "strum", strum(serialize = "subscription.activated"))]
     SubscriptionActivated,
     /// A [`subscription.canceled`](https://developer.paddle.com/webhooks/subscriptions/subscription-canceled) event.
+    #[serde(rename = "subscription.canceled")]
+    #[cfg_attr(feature = "strum", strum(serialize = "subscription.canceled"))]
     SubscriptionCanceled,
     /// A [`subscription.created`](https://developer.paddle.com/webhooks/subscriptions/subscription-created) event.
+    #[serde(rename = "subscription.created")]
+    #[cfg_attr(feature = "strum", strum(serialize = "subscription.created"))]
     SubscriptionCreated,
     /// A [`subscription.imported`](https://developer.paddle.com/webhooks/subscriptions/subscription-imported) event.
+    #[serde(rename = "subscription.imported")]
+    #[cfg_attr(feature = "strum", strum(serialize = "subscription.imported"))]
     SubscriptionImported,
     /// A [`subscription.past_due`](https://developer.paddle.com/webhooks/subscriptions/subscription-past-due) event.
+    #[serde(rename = "subscription.past_due")]
+    #[cfg_attr(feature = "strum", strum(serialize = "subscription.past_due"))]
     SubscriptionPastDue,
     /// A [`subscription.paused`](https://developer.paddle.com/webhooks/subscriptions/subscription-paused) event.
+    #[serde(rename = "subscription.paused")]
+    #[cfg_attr(feature = "strum", strum(serialize = "subscription.paused"))]
     SubscriptionPaused,
     /// A [`subscription.resumed`](https://developer.paddle.com/webhooks/subscriptions/subscription-resumed) event.
+    #[serde(rename = "subscription.resumed")]
+    #[cfg_attr(feature = "strum", strum(serialize = "subscription.resumed"))]
     SubscriptionResumed,
     /// A [`subscription.trialing`](https://developer.paddle.com/webhooks/subscriptions/subscription-trialing) event.
+    #[serde(rename = "subscription.trialing")]
+    #[cfg_attr(feature = "strum", strum(serialize = "subscription.trialing"))]
     SubscriptionTrialing,
     /// A [`subscription.updated`](https://developer.paddle.com/webhooks/subscriptions/subscription-updated) event.
+    #[serde(rename = "subscription.updated")]
+    #[cfg_attr(feature = "strum", strum(serialize = "subscription.updated"))]
     SubscriptionUpdated,
     /// A [`transaction.billed`](https://developer.paddle.com/webhooks/transactions/transaction-billed) event.
+    #[serde(rename = "transaction.billed")]
+    #[cfg_attr(feature = "strum", strum(serialize = "transaction.billed"))]
     TransactionBilled,
     /// A [`transaction.canceled`](https://developer.paddle.com/webhooks/transactions/transaction-canceled) event.
+    #[serde(rename = "transaction.canceled")]
+    #[cfg_attr(feature = "strum", strum(serialize = "transaction.canceled"))]
     TransactionCanceled,
     /// A [`transaction.completed`](https://developer.paddle.com/webhooks/transactions/transaction-completed) event.
+    #[serde(rename = "transaction.completed")]
+    #[cfg_attr(feature = "strum", strum(serialize = "transaction.completed"))]
     TransactionCompleted,
     /// A [`transaction.created`](https://developer.paddle.com/webhooks/transactions/transaction-created) event.
+    #[serde(rename = "transaction.created")]
+    #[cfg_attr(feature = "strum", strum(serialize = "transaction.created"))]
     TransactionCreated,
     /// A [`transaction.paid`](https://developer.paddle.com/webhooks/transactions/transaction-paid) event.
+    #[serde(rename = "transaction.paid")]
+    #[cfg_attr(feature = "strum", strum(serialize = "transaction.paid"))]
     TransactionPaid,
     /// A [`transaction.past_due`](https://developer.paddle.com/webhooks/transactions/transaction-past-due) event.
+    #[serde(rename = "transaction.past_due")]
+    #[cfg_attr(feature = "strum", strum(serialize = "transaction.past_due"))]
     TransactionPastDue,
     /// A [`transaction.payment_failed`](https://developer.paddle.com/webhooks/transactions/transaction-payment-failed) event.
+    #[serde(rename = "transaction.payment_failed")]
+    #[cfg_attr(feature = "strum", strum(serialize = "transaction.payment_failed"))]
     TransactionPaymentFailed,
     /// A [`transaction.ready`](https://developer.paddle.com/webhooks/transactions/transaction-ready) event.
+    #[serde(rename = "transaction.ready")]
+    #[cfg_attr(feature = "strum", strum(serialize = "transaction.ready"))]
     TransactionReady,
+    /// A [`transaction.revised`](https://developer.paddle.com/webhooks/transactions/transaction-revised) event.
+    #[serde(rename = "transaction.revised")]
+    #[cfg_attr(feature = "strum", strum(serialize = "transaction.revised"))]
+    TransactionRevised,
     /// A [`transaction.updated`](https://developer.paddle.com/webhooks/transactions/transaction-updated) event.
+    #[serde(rename = "transaction.updated")]
+    #[cfg_attr(feature = "strum", strum(serialize = "transaction.updated"))]
     TransactionUpdated,
+    /// Event type that this crate doesn't know about yet, in the format `entity.event_type`.
+    #[serde(untagged)]
+    #[cfg_attr(feature = "strum", strum(default))]
+    Other(String),
 }
 
 /// Type of event sent by Paddle along with it's corresponding entity data
@@ -1130,6 +2325,120 @@ pub enum EventData {
     TransactionUpdated(crate::entities::Transaction),
 }
 
+impl EventData {
+    /// Name of the type of this event.
+    pub fn event_type(&self) -> EventTypeName {
+        match self {
+            Self::AddressCreated(_) => EventTypeName::AddressCreated,
+            Self::AddressImported(_) => EventTypeName::AddressImported,
+            Self::AddressUpdated(_) => EventTypeName::AddressUpdated,
+            Self::AdjustmentCreated(_) => EventTypeName::AdjustmentCreated,
+            Self::AdjustmentUpdated(_) => EventTypeName::AdjustmentUpdated,
+            Self::ApiKeyCreated(_) => EventTypeName::ApiKeyCreated,
+            Self::ApiKeyUpdated(_) => EventTypeName::ApiKeyUpdated,
+            Self::ApiKeyExpiring(_) => EventTypeName::ApiKeyExpiring,
+            Self::ApiKeyExpired(_) => EventTypeName::ApiKeyExpired,
+            Self::ApiKeyRevoked(_) => EventTypeName::ApiKeyRevoked,
+            Self::BusinessCreated(_) => EventTypeName::BusinessCreated,
+            Self::BusinessImported(_) => EventTypeName::BusinessImported,
+            Self::BusinessUpdated(_) => EventTypeName::BusinessUpdated,
+            Self::CustomerCreated(_) => EventTypeName::CustomerCreated,
+            Self::CustomerImported(_) => EventTypeName::CustomerImported,
+            Self::CustomerUpdated(_) => EventTypeName::CustomerUpdated,
+            Self::DiscountCreated(_) => EventTypeName::DiscountCreated,
+            Self::DiscountImported(_) => EventTypeName::DiscountImported,
+            Self::DiscountUpdated(_) => EventTypeName::DiscountUpdated,
+            Self::PaymentMethodSaved(_) => EventTypeName::PaymentMethodSaved,
+            Self::PaymentMethodDeleted(_) => EventTypeName::PaymentMethodDeleted,
+            Self::PayoutCreated(_) => EventTypeName::PayoutCreated,
+            Self::PayoutPaid(_) => EventTypeName::PayoutPaid,
+            Self::PriceCreated(_) => EventTypeName::PriceCreated,
+            Self::PriceImported(_) => EventTypeName::PriceImported,
+            Self::PriceUpdated(_) => EventTypeName::PriceUpdated,
+            Self::ProductCreated(_) => EventTypeName::ProductCreated,
+            Self::ProductImported(_) => EventTypeName::ProductImported,
+            Self::ProductUpdated(_) => EventTypeName::ProductUpdated,
+            Self::ReportCreated(_) => EventTypeName::ReportCreated,
+            Self::ReportUpdated(_) => EventTypeName::ReportUpdated,
+            Self::SubscriptionActivated(_) => EventTypeName::SubscriptionActivated,
+            Self::SubscriptionCanceled(_) => EventTypeName::SubscriptionCanceled,
+            Self::SubscriptionCreated(_) => EventTypeName::SubscriptionCreated,
+            Self::SubscriptionImported(_) => EventTypeName::SubscriptionImported,
+            Self::SubscriptionPastDue(_) => EventTypeName::SubscriptionPastDue,
+            Self::SubscriptionPaused(_) => EventTypeName::SubscriptionPaused,
+            Self::SubscriptionResumed(_) => EventTypeName::SubscriptionResumed,
+            Self::SubscriptionTrialing(_) => EventTypeName::SubscriptionTrialing,
+            Self::SubscriptionUpdated(_) => EventTypeName::SubscriptionUpdated,
+            Self::TransactionBilled(_) => EventTypeName::TransactionBilled,
+            Self::TransactionCanceled(_) => EventTypeName::TransactionCanceled,
+            Self::TransactionCompleted(_) => EventTypeName::TransactionCompleted,
+            Self::TransactionCreated(_) => EventTypeName::TransactionCreated,
+            Self::TransactionPaid(_) => EventTypeName::TransactionPaid,
+            Self::TransactionPastDue(_) => EventTypeName::TransactionPastDue,
+            Self::TransactionPaymentFailed(_) => EventTypeName::TransactionPaymentFailed,
+            Self::TransactionReady(_) => EventTypeName::TransactionReady,
+            Self::TransactionRevised(_) => EventTypeName::TransactionRevised,
+            Self::TransactionUpdated(_) => EventTypeName::TransactionUpdated,
+        }
+    }
+
+    /// Paddle ID of the entity this event is for, e.g. the subscription ID of `subscription.updated` events.
+    pub fn entity_id(&self) -> &str {
+        match self {
+            Self::AddressCreated(entity) => entity.id.as_ref(),
+            Self::AddressImported(entity) => entity.id.as_ref(),
+            Self::AddressUpdated(entity) => entity.id.as_ref(),
+            Self::AdjustmentCreated(entity) => entity.id.as_ref(),
+            Self::AdjustmentUpdated(entity) => entity.id.as_ref(),
+            Self::ApiKeyCreated(entity) => entity.id.as_ref(),
+            Self::ApiKeyUpdated(entity) => entity.id.as_ref(),
+            Self::ApiKeyExpiring(entity) => entity.id.as_ref(),
+            Self::ApiKeyExpired(entity) => entity.id.as_ref(),
+            Self::ApiKeyRevoked(entity) => entity.id.as_ref(),
+            Self::BusinessCreated(entity) => entity.id.as_ref(),
+            Self::BusinessImported(entity) => entity.id.as_ref(),
+            Self::BusinessUpdated(entity) => entity.id.as_ref(),
+            Self::CustomerCreated(entity) => entity.id.as_ref(),
+            Self::CustomerImported(entity) => entity.id.as_ref(),
+            Self::CustomerUpdated(entity) => entity.id.as_ref(),
+            Self::DiscountCreated(entity) => entity.id.as_ref(),
+            Self::DiscountImported(entity) => entity.id.as_ref(),
+            Self::DiscountUpdated(entity) => entity.id.as_ref(),
+            Self::PaymentMethodSaved(entity) => entity.id.as_ref(),
+            Self::PaymentMethodDeleted(entity) => entity.id.as_ref(),
+            Self::PayoutCreated(entity) => entity.id.as_ref(),
+            Self::PayoutPaid(entity) => entity.id.as_ref(),
+            Self::PriceCreated(entity) => entity.id.as_ref(),
+            Self::PriceImported(entity) => entity.id.as_ref(),
+            Self::PriceUpdated(entity) => entity.id.as_ref(),
+            Self::ProductCreated(entity) => entity.id.as_ref(),
+            Self::ProductImported(entity) => entity.id.as_ref(),
+            Self::ProductUpdated(entity) => entity.id.as_ref(),
+            Self::ReportCreated(entity) => entity.id.as_ref(),
+            Self::ReportUpdated(entity) => entity.id.as_ref(),
+            Self::SubscriptionActivated(entity) => entity.id.as_ref(),
+            Self::SubscriptionCanceled(entity) => entity.id.as_ref(),
+            Self::SubscriptionCreated(entity) => entity.subscription.id.as_ref(),
+            Self::SubscriptionImported(entity) => entity.id.as_ref(),
+            Self::SubscriptionPastDue(entity) => entity.id.as_ref(),
+            Self::SubscriptionPaused(entity) => entity.id.as_ref(),
+            Self::SubscriptionResumed(entity) => entity.id.as_ref(),
+            Self::SubscriptionTrialing(entity) => entity.id.as_ref(),
+            Self::SubscriptionUpdated(entity) => entity.id.as_ref(),
+            Self::TransactionBilled(entity) => entity.id.as_ref(),
+            Self::TransactionCanceled(entity) => entity.id.as_ref(),
+            Self::TransactionCompleted(entity) => entity.id.as_ref(),
+            Self::TransactionCreated(entity) => entity.id.as_ref(),
+            Self::TransactionPaid(entity) => entity.id.as_ref(),
+            Self::TransactionPastDue(entity) => entity.id.as_ref(),
+            Self::TransactionPaymentFailed(entity) => entity.id.as_ref(),
+            Self::TransactionReady(entity) => entity.id.as_ref(),
+            Self::TransactionRevised(entity) => entity.id.as_ref(),
+            Self::TransactionUpdated(entity) => entity.id.as_ref(),
+        }
+    }
+}
+
 /// Status of this subscription item. Set automatically by Paddle.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "strum", derive(EnumString, Display))]
@@ -1202,6 +2511,58 @@ impl AsRef<str> for TaxCategory {
     }
 }
 
+impl TaxCategory {
+    /// Every tax category, in the order Paddle lists them.
+    pub const ALL: [TaxCategory; 9] = [
+        Self::DigitalGoods,
+        Self::Ebooks,
+        Self::ImplementationServices,
+        Self::ProfessionalServices,
+        Self::Saas,
+        Self::SoftwareProgrammingServices,
+        Self::Standard,
+        Self::TrainingServices,
+        Self::WebsiteHosting,
+    ];
+
+    /// Name of this category as shown in the Paddle dashboard.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::DigitalGoods => "Digital goods",
+            Self::Ebooks => "Ebooks",
+            Self::ImplementationServices => "Implementation services",
+            Self::ProfessionalServices => "Professional services",
+            Self::Saas => "Software as a service (SaaS)",
+            Self::SoftwareProgrammingServices => "Software programming services",
+            Self::Standard => "Standard digital goods",
+            Self::TrainingServices => "Training services",
+            Self::WebsiteHosting => "Website hosting",
+        }
+    }
+
+    /// What kind of products belong in this category.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::DigitalGoods => "Non-customizable digital files or media (not software) acquired with an up front payment that can be accessed without any physical product being delivered.",
+            Self::Ebooks => "Digital books and educational material which is sold with permanent rights for use by the customer.",
+            Self::ImplementationServices => "Remote configuration, set-up, and integrating software on behalf of a customer.",
+            Self::ProfessionalServices => "Services that involve the application of your expertise and specialized knowledge of a software product.",
+            Self::Saas => "Products that allow users to connect to and use online or cloud-based applications over the Internet.",
+            Self::SoftwareProgrammingServices => "Services that can be used to customize and white label software products.",
+            Self::Standard => "Software products that are pre-written and can be downloaded and installed onto a local device.",
+            Self::TrainingServices => "Training and education services related to software products.",
+            Self::WebsiteHosting => "Cloud storage service for personal or corporate information, assets, or intellectual property.",
+        }
+    }
+
+    /// Whether Paddle has to approve this category for an account before products can use it. `standard` and `saas` are enabled for every account.
+    ///
+    /// Paddle has no endpoint listing the categories approved for an account. Creating a product with a category that isn't approved fails with the `product_tax_category_not_approved` error code.
+    pub fn requires_approval(&self) -> bool {
+        !matches!(self, Self::Standard | Self::Saas)
+    }
+}
+
 /// Type of Korean payment method used to pay.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "strum", derive(EnumString, Display))]
@@ -1309,6 +2670,8 @@ pub enum PaymentMethodType {
 /// Status of this notification.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "strum", derive(EnumString, Display))]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strum", strum(serialize_all = "snake_case"))]
 pub enum NotificationStatus {
     /// Paddle hasn't yet tried to deliver this notification.
     NotAttempted,
@@ -1323,6 +2686,8 @@ pub enum NotificationStatus {
 /// Describes how this notification was created.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "strum", derive(EnumString, Display))]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strum", strum(serialize_all = "snake_case"))]
 pub enum NotificationOrigin {
     /// Notification created when a subscribed event occurred.
     Event,
@@ -1553,9 +2918,28 @@ impl ReportType for BalanceReportType {
     type FilterName = BalanceReportFilterName;
 }
 
+/// Report type not known to this SDK yet, e.g. a report category Paddle added after this version was released.
+///
+/// Filter names are passed as strings, exactly as listed in the Paddle API documentation for the report type.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(transparent)]
+pub struct CustomReportType(pub String);
+
+impl CustomReportType {
+    pub fn new(report_type: impl Into<String>) -> Self {
+        Self(report_type.into())
+    }
+}
+
+impl ReportType for CustomReportType {
+    type FilterName = String;
+}
+
 /// Status of this simulation run log.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "strum", derive(EnumString, Display))]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strum", strum(serialize_all = "snake_case"))]
 pub enum SimulationEventStatus {
     /// Simulation run log is pending. Paddle hasn't yet tried to deliver the simulated event.
     Pending,
@@ -1570,6 +2954,8 @@ pub enum SimulationEventStatus {
 /// Status of this simulation run.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "strum", derive(EnumString, Display))]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strum", strum(serialize_all = "snake_case"))]
 pub enum SimulationRunStatus {
     /// Simulation run is pending. Paddle is sending events that are part of this simulation.
     Pending,
@@ -1582,6 +2968,8 @@ pub enum SimulationRunStatus {
 /// Scenario for a simulation.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "strum", derive(EnumString, Display))]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strum", strum(serialize_all = "snake_case"))]
 pub enum SimulationScenarioType {
     /// Simulates all events sent when a subscription is created.
     SubscriptionCreation,
@@ -1598,6 +2986,8 @@ pub enum SimulationScenarioType {
 /// Type of simulation.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "strum", derive(EnumString, Display))]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strum", strum(serialize_all = "snake_case"))]
 pub enum SimulationKind {
     /// Paddle simulates a single event.
     SingleEvent,
@@ -1793,3 +3183,342 @@ pub enum SubscriptionInclude {
     /// Include an object with a preview of the recurring transaction for this subscription. This is what the customer can expect to be billed when there are no prorated or one-time charges.
     RecurringTransactionDetails,
 }
+
+/// Include related entities in a product response.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "strum", derive(EnumString, Display))]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strum", strum(serialize_all = "snake_case"))]
+pub enum ProductInclude {
+    /// Include an array of prices related to this product.
+    Prices,
+}
+
+/// Include related entities in a price response.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "strum", derive(EnumString, Display))]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strum", strum(serialize_all = "snake_case"))]
+pub enum PriceInclude {
+    /// Include the product related to this price.
+    Product,
+}
+
+/// Include related entities in a transaction response.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "strum", derive(EnumString, Display))]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strum", strum(serialize_all = "snake_case"))]
+pub enum TransactionInclude {
+    /// Include the address entity related to this transaction.
+    Address,
+    /// Include an array of adjustment entities related to this transaction.
+    Adjustments,
+    /// Include an object with totals for all adjustments on this transaction.
+    AdjustmentsTotals,
+    /// Include an array of payment methods that can be used to pay for this transaction.
+    AvailablePaymentMethods,
+    /// Include the business entity related to this transaction.
+    Business,
+    /// Include the customer entity related to this transaction.
+    Customer,
+    /// Include the discount entity related to this transaction.
+    Discount,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn payment_method_events_without_details() {
+        let data: EventData = serde_json::from_value(json!({
+            "event_type": "payment_method.deleted",
+            "data": {
+                "id": "paymtd_01hs8zx6x377xfsfrt2bqsevbw",
+                "customer_id": "ctm_01hv6y1jedq4p1n0yqn5ba3ky4",
+                "address_id": "add_01hv8gq3318ktkfengj2r75gfx",
+                "type": "card",
+                "origin": "saved_during_purchase",
+                "saved_at": "2024-05-03T11:50:23.422Z",
+                "updated_at": "2024-05-03T11:50:23.422Z"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(data.event_type(), EventTypeName::PaymentMethodDeleted);
+        assert!(matches!(
+            data,
+            EventData::PaymentMethodDeleted(method) if method.card.is_none()
+        ));
+
+        let name: EventTypeName = serde_json::from_value(json!("payment_method.saved")).unwrap();
+        assert_eq!(name, EventTypeName::PaymentMethodSaved);
+    }
+
+    #[test]
+    fn event_type_names_round_trip() {
+        let names = [
+            ("address.created", EventTypeName::AddressCreated),
+            ("address.imported", EventTypeName::AddressImported),
+            ("address.updated", EventTypeName::AddressUpdated),
+            ("adjustment.created", EventTypeName::AdjustmentCreated),
+            ("adjustment.updated", EventTypeName::AdjustmentUpdated),
+            ("api_key.created", EventTypeName::ApiKeyCreated),
+            ("api_key.updated", EventTypeName::ApiKeyUpdated),
+            ("api_key.expiring", EventTypeName::ApiKeyExpiring),
+            ("api_key.expired", EventTypeName::ApiKeyExpired),
+            ("api_key.revoked", EventTypeName::ApiKeyRevoked),
+            ("business.created", EventTypeName::BusinessCreated),
+            ("business.imported", EventTypeName::BusinessImported),
+            ("business.updated", EventTypeName::BusinessUpdated),
+            ("customer.created", EventTypeName::CustomerCreated),
+            ("customer.imported", EventTypeName::CustomerImported),
+            ("customer.updated", EventTypeName::CustomerUpdated),
+            ("discount.created", EventTypeName::DiscountCreated),
+            ("discount.imported", EventTypeName::DiscountImported),
+            ("discount.updated", EventTypeName::DiscountUpdated),
+            ("payment_method.saved", EventTypeName::PaymentMethodSaved),
+            (
+                "payment_method.deleted",
+                EventTypeName::PaymentMethodDeleted,
+            ),
+            ("payout.created", EventTypeName::PayoutCreated),
+            ("payout.paid", EventTypeName::PayoutPaid),
+            ("price.created", EventTypeName::PriceCreated),
+            ("price.imported", EventTypeName::PriceImported),
+            ("price.updated", EventTypeName::PriceUpdated),
+            ("product.created", EventTypeName::ProductCreated),
+            ("product.imported", EventTypeName::ProductImported),
+            ("product.updated", EventTypeName::ProductUpdated),
+            ("report.created", EventTypeName::ReportCreated),
+            ("report.updated", EventTypeName::ReportUpdated),
+            (
+                "subscription.activated",
+                EventTypeName::SubscriptionActivated,
+            ),
+            ("subscription.canceled", EventTypeName::SubscriptionCanceled),
+            ("subscription.created", EventTypeName::SubscriptionCreated),
+            ("subscription.imported", EventTypeName::SubscriptionImported),
+            ("subscription.past_due", EventTypeName::SubscriptionPastDue),
+            ("subscription.paused", EventTypeName::SubscriptionPaused),
+            ("subscription.resumed", EventTypeName::SubscriptionResumed),
+            ("subscription.trialing", EventTypeName::SubscriptionTrialing),
+            ("subscription.updated", EventTypeName::SubscriptionUpdated),
+            ("transaction.billed", EventTypeName::TransactionBilled),
+            ("transaction.canceled", EventTypeName::TransactionCanceled),
+            ("transaction.completed", EventTypeName::TransactionCompleted),
+            ("transaction.created", EventTypeName::TransactionCreated),
+            ("transaction.paid", EventTypeName::TransactionPaid),
+            ("transaction.past_due", EventTypeName::TransactionPastDue),
+            (
+                "transaction.payment_failed",
+                EventTypeName::TransactionPaymentFailed,
+            ),
+            ("transaction.ready", EventTypeName::TransactionReady),
+            ("transaction.revised", EventTypeName::TransactionRevised),
+            ("transaction.updated", EventTypeName::TransactionUpdated),
+            (
+                "report.archived",
+                EventTypeName::Other("report.archived".to_string()),
+            ),
+        ];
+
+        for (name, event_type) in names {
+            assert_eq!(
+                serde_json::from_value::<EventTypeName>(json!(name)).unwrap(),
+                event_type
+            );
+            assert_eq!(serde_json::to_value(&event_type).unwrap(), json!(name));
+
+            #[cfg(feature = "strum")]
+            {
+                assert_eq!(name.parse::<EventTypeName>().unwrap(), event_type);
+                assert_eq!(event_type.to_string(), name);
+            }
+        }
+    }
+
+    #[test]
+    fn minor_units_of_zero_decimal_currencies() {
+        for code in [CurrencyCode::JPY, CurrencyCode::KRW, CurrencyCode::VND] {
+            assert_eq!(code.minor_units(), 0, "{code:?}");
+        }
+
+        assert_eq!(CurrencyCode::USD.minor_units(), 2);
+        assert_eq!(CurrencyCode::EUR.minor_units(), 2);
+    }
+
+    #[test]
+    fn tax_categories_needing_approval() {
+        for category in TaxCategory::ALL {
+            let name: TaxCategory = serde_json::from_value(json!(category.as_ref())).unwrap();
+            assert_eq!(name, category);
+        }
+
+        let approved = TaxCategory::ALL
+            .into_iter()
+            .filter(|category| !category.requires_approval())
+            .collect::<Vec<_>>();
+        assert_eq!(approved, [TaxCategory::Saas, TaxCategory::Standard]);
+    }
+
+    #[test]
+    fn symbols() {
+        assert_eq!(CurrencyCode::USD.symbol(), "$");
+        assert_eq!(CurrencyCode::CAD.symbol(), "CA$");
+        assert_eq!(CurrencyCode::EUR.symbol(), "€");
+        assert_eq!(CurrencyCode::CHF.symbol(), "CHF");
+    }
+
+    #[test]
+    fn format_minor_amounts() {
+        assert_eq!(CurrencyCode::USD.format_minor(1050), "$10.50");
+        assert_eq!(CurrencyCode::USD.format_minor(5), "$0.05");
+        assert_eq!(CurrencyCode::USD.format_minor(0), "$0.00");
+        assert_eq!(CurrencyCode::USD.format_minor(-1050), "-$10.50");
+        assert_eq!(
+            CurrencyCode::USD.format_minor(i64::MIN),
+            "-$92233720368547758.08"
+        );
+        assert_eq!(CurrencyCode::JPY.format_minor(1050), "¥1050");
+        assert_eq!(CurrencyCode::KRW.format_minor(-500), "-₩500");
+    }
+
+    #[test]
+    fn format_minor_decimal_amounts() {
+        assert_eq!(CurrencyCode::USD.format_minor_decimal(1050), "10.50");
+        assert_eq!(CurrencyCode::USD.format_minor_decimal(-5), "-0.05");
+        assert_eq!(CurrencyCode::JPY.format_minor_decimal(1050), "1050");
+        assert_eq!(CurrencyCode::SEK.format_minor_decimal(1050), "10.50");
+    }
+
+    #[test]
+    fn symbol_follows_the_amount_where_customary() {
+        assert_eq!(CurrencyCode::SEK.format_minor(1050), "10.50 kr");
+        assert_eq!(CurrencyCode::CHF.format_minor(-99), "-0.99 CHF");
+        assert_eq!(CurrencyCode::PLN.format_minor(100000), "1000.00 zł");
+    }
+
+    #[test]
+    fn unknown_currency_codes_round_trip() {
+        let code: CurrencyCode = serde_json::from_value(json!("XTS")).unwrap();
+        assert_eq!(code, CurrencyCode::Other("XTS".to_string()));
+        assert_eq!(serde_json::to_value(&code).unwrap(), json!("XTS"));
+        assert_eq!(code, "xts");
+
+        let code: CurrencyCodePayouts = serde_json::from_value(json!("XTS")).unwrap();
+        assert_eq!(code, CurrencyCodePayouts::Other("XTS".to_string()));
+        assert_eq!(serde_json::to_value(&code).unwrap(), json!("XTS"));
+
+        let code: CurrencyCodeChargebacks = serde_json::from_value(json!("XTS")).unwrap();
+        assert_eq!(code, CurrencyCodeChargebacks::Other("XTS".to_string()));
+        assert_eq!(serde_json::to_value(&code).unwrap(), json!("XTS"));
+    }
+
+    #[test]
+    fn known_currency_codes_are_not_other() {
+        let code: CurrencyCode = serde_json::from_value(json!("USD")).unwrap();
+        assert_eq!(code, CurrencyCode::USD);
+        assert_eq!(serde_json::to_value(&code).unwrap(), json!("USD"));
+
+        let code: CurrencyCodePayouts = serde_json::from_value(json!("EUR")).unwrap();
+        assert_eq!(code, CurrencyCodePayouts::EUR);
+
+        let code: CurrencyCodeChargebacks = serde_json::from_value(json!("GBP")).unwrap();
+        assert_eq!(code, CurrencyCodeChargebacks::GBP);
+    }
+
+    #[test]
+    fn iso_codes_compare_ignoring_case() {
+        assert_eq!(CurrencyCode::EUR.as_iso(), "EUR");
+        assert_eq!(CurrencyCode::EUR, "eur");
+        assert_ne!(CurrencyCode::EUR, "EURO");
+        assert_eq!(CurrencyCodePayouts::Other("xts".to_string()), "XTS");
+        assert_eq!(CurrencyCodeChargebacks::USD.as_ref(), "USD");
+    }
+
+    #[test]
+    fn other_currency_codes_are_formatted_with_their_code() {
+        let code = CurrencyCode::Other("XTS".to_string());
+
+        assert_eq!(code.minor_units(), 2);
+        assert_eq!(code.symbol(), "XTS");
+        assert_eq!(code.format_minor(1050), "10.50 XTS");
+        assert_eq!(code.format_minor(-1), "-0.01 XTS");
+    }
+
+    /// Every two-letter code from `AA` to `ZZ`.
+    fn alpha2_codes() -> impl Iterator<Item = String> {
+        ('A'..='Z').flat_map(|first| ('A'..='Z').map(move |second| format!("{first}{second}")))
+    }
+
+    #[test]
+    fn country_lookups_agree_in_both_directions() {
+        let mut known = 0;
+
+        for code in alpha2_codes() {
+            let country = CountryCodeSupported::from_alpha2(&code);
+            assert_eq!(country.as_alpha2(), code);
+
+            // Deserializing must pick the same variant as the lookup, or the tables have drifted from the enum
+            let parsed: CountryCodeSupported = serde_json::from_value(json!(code)).unwrap();
+            assert_eq!(parsed, country, "{code}");
+            assert_eq!(serde_json::to_value(&country).unwrap(), json!(code));
+
+            if matches!(country, CountryCodeSupported::Other(_)) {
+                assert_eq!(country.name(), None, "{code}");
+                assert!(!country.is_eu(), "{code}");
+            } else {
+                assert!(
+                    country.name().is_some_and(|name| !name.is_empty()),
+                    "{code}"
+                );
+                assert_eq!(
+                    CountryCodeSupported::from_alpha2(&code.to_lowercase()),
+                    country
+                );
+                known += 1;
+            }
+        }
+
+        assert_eq!(known, 229);
+    }
+
+    #[test]
+    fn eu_members_are_known_countries() {
+        let members = alpha2_codes()
+            .map(|code| CountryCodeSupported::from_alpha2(&code))
+            .filter(CountryCodeSupported::is_eu)
+            .collect::<Vec<_>>();
+
+        assert_eq!(members.len(), 27);
+        assert!(members
+            .iter()
+            .all(|country| !matches!(country, CountryCodeSupported::Other(_))));
+        assert!(CountryCodeSupported::DE.requires_postal_code());
+    }
+
+    #[test]
+    fn currency_lookups_agree_in_both_directions() {
+        let mut known = 0;
+
+        for code in
+            alpha2_codes().flat_map(|prefix| ('A'..='Z').map(move |last| format!("{prefix}{last}")))
+        {
+            let currency: CurrencyCode = serde_json::from_value(json!(code)).unwrap();
+            assert_eq!(currency.as_iso(), code);
+            assert_eq!(currency, code.to_lowercase().as_str());
+            assert_eq!(serde_json::to_value(&currency).unwrap(), json!(code));
+            assert!(!currency.symbol().is_empty(), "{code}");
+
+            if !matches!(currency, CurrencyCode::Other(_)) {
+                known += 1;
+            }
+        }
+
+        assert_eq!(known, 31);
+    }
+}
@@ -1,31 +1,48 @@
 //! Unique Paddle IDs
+//!
+//! Every ID type wraps an [IdString]. It's a `String` by default. With the `arc-ids` feature enabled IDs are backed by an `Arc<str>`, which makes cloning an ID
+//! free of allocations and shrinks each ID to two words. Useful when keeping a large number of IDs in memory, e.g. when mirroring events.
 
 use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
+/// Backing storage of all ID types, an owned `String` without the `arc-ids` feature.
+#[cfg(not(feature = "arc-ids"))]
+pub type IdString = String;
+
+/// Backing storage of all ID types, a shared `Arc<str>` with the `arc-ids` feature.
+#[cfg(feature = "arc-ids")]
+pub type IdString = std::sync::Arc<str>;
+
 macro_rules! paddle_id {
     ($(#[$attr:meta])* $name:ident) => {
         $(#[$attr])*
         #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
-        pub struct $name(pub String);
+        pub struct $name(pub IdString);
 
         impl From<String> for $name {
             fn from(value: String) -> Self {
-                $name(value)
+                $name(value.into())
             }
         }
 
         impl From<&str> for $name {
             fn from(value: &str) -> Self {
-                $name(value.to_string())
+                $name(value.into())
             }
         }
 
         impl From<$name> for String {
+            #[cfg(not(feature = "arc-ids"))]
             fn from(value: $name) -> Self {
                 value.0
             }
+
+            #[cfg(feature = "arc-ids")]
+            fn from(value: $name) -> Self {
+                value.0.to_string()
+            }
         }
 
         impl AsRef<str> for $name {
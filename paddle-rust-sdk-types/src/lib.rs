@@ -1,4 +1,5 @@
 pub mod entities;
 pub mod enums;
 pub mod ids;
+pub mod permissions;
 pub mod reports;
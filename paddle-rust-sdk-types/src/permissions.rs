@@ -0,0 +1,192 @@
+//! Permissions that can be granted to Paddle API keys.
+//!
+//! See the [Paddle API](https://developer.paddle.com/api-reference/about/permissions) documentation for more information.
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "strum")]
+use strum::{Display, EnumString};
+
+/// Permission granted to an API key. Determines what actions the API key can perform.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "strum", derive(EnumString, Display))]
+pub enum Permission {
+    /// Read addresses.
+    #[serde(rename = "address.read")]
+    #[cfg_attr(feature = "strum", strum(serialize = "address.read"))]
+    AddressRead,
+    /// Create and update addresses.
+    #[serde(rename = "address.write")]
+    #[cfg_attr(feature = "strum", strum(serialize = "address.write"))]
+    AddressWrite,
+    /// Read adjustments.
+    #[serde(rename = "adjustment.read")]
+    #[cfg_attr(feature = "strum", strum(serialize = "adjustment.read"))]
+    AdjustmentRead,
+    /// Create adjustments.
+    #[serde(rename = "adjustment.write")]
+    #[cfg_attr(feature = "strum", strum(serialize = "adjustment.write"))]
+    AdjustmentWrite,
+    /// Read businesses.
+    #[serde(rename = "business.read")]
+    #[cfg_attr(feature = "strum", strum(serialize = "business.read"))]
+    BusinessRead,
+    /// Create and update businesses.
+    #[serde(rename = "business.write")]
+    #[cfg_attr(feature = "strum", strum(serialize = "business.write"))]
+    BusinessWrite,
+    /// Read client-side tokens.
+    #[serde(rename = "client_token.read")]
+    #[cfg_attr(feature = "strum", strum(serialize = "client_token.read"))]
+    ClientTokenRead,
+    /// Create and update client-side tokens.
+    #[serde(rename = "client_token.write")]
+    #[cfg_attr(feature = "strum", strum(serialize = "client_token.write"))]
+    ClientTokenWrite,
+    /// Read customers and their credit balances.
+    #[serde(rename = "customer.read")]
+    #[cfg_attr(feature = "strum", strum(serialize = "customer.read"))]
+    CustomerRead,
+    /// Create and update customers.
+    #[serde(rename = "customer.write")]
+    #[cfg_attr(feature = "strum", strum(serialize = "customer.write"))]
+    CustomerWrite,
+    /// Generate customer authentication tokens.
+    #[serde(rename = "customer_auth_token.write")]
+    #[cfg_attr(feature = "strum", strum(serialize = "customer_auth_token.write"))]
+    CustomerAuthTokenWrite,
+    /// Create customer portal sessions.
+    #[serde(rename = "customer_portal_session.write")]
+    #[cfg_attr(feature = "strum", strum(serialize = "customer_portal_session.write"))]
+    CustomerPortalSessionWrite,
+    /// Read discounts.
+    #[serde(rename = "discount.read")]
+    #[cfg_attr(feature = "strum", strum(serialize = "discount.read"))]
+    DiscountRead,
+    /// Create and update discounts.
+    #[serde(rename = "discount.write")]
+    #[cfg_attr(feature = "strum", strum(serialize = "discount.write"))]
+    DiscountWrite,
+    /// Read events and event types.
+    #[serde(rename = "event.read")]
+    #[cfg_attr(feature = "strum", strum(serialize = "event.read"))]
+    EventRead,
+    /// Read notifications and notification logs.
+    #[serde(rename = "notification.read")]
+    #[cfg_attr(feature = "strum", strum(serialize = "notification.read"))]
+    NotificationRead,
+    /// Replay notifications.
+    #[serde(rename = "notification.write")]
+    #[cfg_attr(feature = "strum", strum(serialize = "notification.write"))]
+    NotificationWrite,
+    /// Read notification settings.
+    #[serde(rename = "notification_setting.read")]
+    #[cfg_attr(feature = "strum", strum(serialize = "notification_setting.read"))]
+    NotificationSettingRead,
+    /// Create, update and delete notification settings.
+    #[serde(rename = "notification_setting.write")]
+    #[cfg_attr(feature = "strum", strum(serialize = "notification_setting.write"))]
+    NotificationSettingWrite,
+    /// Read simulations, simulation runs and simulation events.
+    #[serde(rename = "notification_simulation.read")]
+    #[cfg_attr(feature = "strum", strum(serialize = "notification_simulation.read"))]
+    NotificationSimulationRead,
+    /// Create, update and run simulations.
+    #[serde(rename = "notification_simulation.write")]
+    #[cfg_attr(feature = "strum", strum(serialize = "notification_simulation.write"))]
+    NotificationSimulationWrite,
+    /// Read saved payment methods.
+    #[serde(rename = "payment_method.read")]
+    #[cfg_attr(feature = "strum", strum(serialize = "payment_method.read"))]
+    PaymentMethodRead,
+    /// Delete saved payment methods.
+    #[serde(rename = "payment_method.write")]
+    #[cfg_attr(feature = "strum", strum(serialize = "payment_method.write"))]
+    PaymentMethodWrite,
+    /// Read prices and preview prices.
+    #[serde(rename = "price.read")]
+    #[cfg_attr(feature = "strum", strum(serialize = "price.read"))]
+    PriceRead,
+    /// Create and update prices.
+    #[serde(rename = "price.write")]
+    #[cfg_attr(feature = "strum", strum(serialize = "price.write"))]
+    PriceWrite,
+    /// Read products.
+    #[serde(rename = "product.read")]
+    #[cfg_attr(feature = "strum", strum(serialize = "product.read"))]
+    ProductRead,
+    /// Create and update products.
+    #[serde(rename = "product.write")]
+    #[cfg_attr(feature = "strum", strum(serialize = "product.write"))]
+    ProductWrite,
+    /// Read reports and download report files.
+    #[serde(rename = "report.read")]
+    #[cfg_attr(feature = "strum", strum(serialize = "report.read"))]
+    ReportRead,
+    /// Create reports.
+    #[serde(rename = "report.write")]
+    #[cfg_attr(feature = "strum", strum(serialize = "report.write"))]
+    ReportWrite,
+    /// Read subscriptions and preview changes to them.
+    #[serde(rename = "subscription.read")]
+    #[cfg_attr(feature = "strum", strum(serialize = "subscription.read"))]
+    SubscriptionRead,
+    /// Update, pause, resume, cancel and charge subscriptions.
+    #[serde(rename = "subscription.write")]
+    #[cfg_attr(feature = "strum", strum(serialize = "subscription.write"))]
+    SubscriptionWrite,
+    /// Read transactions, preview transactions and get invoice PDFs.
+    #[serde(rename = "transaction.read")]
+    #[cfg_attr(feature = "strum", strum(serialize = "transaction.read"))]
+    TransactionRead,
+    /// Create, update and revise transactions.
+    #[serde(rename = "transaction.write")]
+    #[cfg_attr(feature = "strum", strum(serialize = "transaction.write"))]
+    TransactionWrite,
+}
+
+impl Permission {
+    /// Name of this permission as used by the Paddle API, e.g. `transaction.read`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AddressRead => "address.read",
+            Self::AddressWrite => "address.write",
+            Self::AdjustmentRead => "adjustment.read",
+            Self::AdjustmentWrite => "adjustment.write",
+            Self::BusinessRead => "business.read",
+            Self::BusinessWrite => "business.write",
+            Self::ClientTokenRead => "client_token.read",
+            Self::ClientTokenWrite => "client_token.write",
+            Self::CustomerRead => "customer.read",
+            Self::CustomerWrite => "customer.write",
+            Self::CustomerAuthTokenWrite => "customer_auth_token.write",
+            Self::CustomerPortalSessionWrite => "customer_portal_session.write",
+            Self::DiscountRead => "discount.read",
+            Self::DiscountWrite => "discount.write",
+            Self::EventRead => "event.read",
+            Self::NotificationRead => "notification.read",
+            Self::NotificationWrite => "notification.write",
+            Self::NotificationSettingRead => "notification_setting.read",
+            Self::NotificationSettingWrite => "notification_setting.write",
+            Self::NotificationSimulationRead => "notification_simulation.read",
+            Self::NotificationSimulationWrite => "notification_simulation.write",
+            Self::PaymentMethodRead => "payment_method.read",
+            Self::PaymentMethodWrite => "payment_method.write",
+            Self::PriceRead => "price.read",
+            Self::PriceWrite => "price.write",
+            Self::ProductRead => "product.read",
+            Self::ProductWrite => "product.write",
+            Self::ReportRead => "report.read",
+            Self::ReportWrite => "report.write",
+            Self::SubscriptionRead => "subscription.read",
+            Self::SubscriptionWrite => "subscription.write",
+            Self::TransactionRead => "transaction.read",
+            Self::TransactionWrite => "transaction.write",
+        }
+    }
+}
+
+impl AsRef<str> for Permission {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
@@ -13,11 +13,14 @@ use crate::enums::{CountryCodeSupported, Status};
 use crate::ids::{AddressID, CustomerID};
 use crate::paginated::Paginated;
 use crate::nullable::Nullable;
+use crate::permissions::Permission;
+use crate::request::PaddleRequest;
 use crate::{Paddle, Result};
 
 /// Request builder for fetching addresses from Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct AddressesList<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -47,64 +50,91 @@ impl<'a> AddressesList<'a> {
     }
 
     /// Return entities after the specified Paddle ID when working with paginated endpoints. Used in the `meta.pagination.next` URL in responses for list operations.
-    pub fn after(&mut self, address_id: impl Into<AddressID>) -> &mut Self {
+    pub fn after(mut self, address_id: impl Into<AddressID>) -> Self {
         self.after = Some(address_id.into());
         self
     }
 
     /// Return only the IDs specified.
-    pub fn ids(
-        &mut self,
-        address_ids: impl IntoIterator<Item = impl Into<AddressID>>,
-    ) -> &mut Self {
+    pub fn ids(mut self, address_ids: impl IntoIterator<Item = impl Into<AddressID>>) -> Self {
         self.id = Some(address_ids.into_iter().map(Into::into).collect());
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: id
-    pub fn order_by_asc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_asc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[ASC]", field));
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: id
-    pub fn order_by_desc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_desc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[DESC]", field));
         self
     }
 
+    /// Number of entities Paddle returns per page when [Self::per_page] isn't set.
+    pub const DEFAULT_PER_PAGE: usize = 50;
+    /// Largest page size Paddle accepts for this endpoint.
+    pub const MAX_PER_PAGE: usize = 200;
+
     /// Set how many entities are returned per page. Paddle returns the maximum number of results if a number greater than the maximum is requested.
     /// Check `meta.pagination.per_page` in the response to see how many were returned.
     ///
     /// Default: `50`; Maximum: `200`.
-    pub fn per_page(&mut self, entities_per_page: usize) -> &mut Self {
+    pub fn per_page(mut self, entities_per_page: usize) -> Self {
         self.per_page = Some(entities_per_page);
         self
     }
 
+    /// Request the largest page Paddle allows for this endpoint. Use this to fetch everything in as few requests as possible.
+    pub fn max_page_size(self) -> Self {
+        self.per_page(Self::MAX_PER_PAGE)
+    }
+
     /// Return entities that match a search query. Searches `status`, `created_at`, and `updated_at`.
-    pub fn search(&mut self, term: impl Into<String>) -> &mut Self {
+    pub fn search(mut self, term: impl Into<String>) -> Self {
         self.search = Some(term.into());
         self
     }
 
-    /// Return only prices with the specified status.
-    pub fn status(&mut self, status: Status) -> &mut Self {
+    /// Return only addresses with the specified status.
+    pub fn status(mut self, status: Status) -> Self {
         self.status = Some(status);
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub fn send(&self) -> Paginated<'_, Vec<Address>> {
-        let url = format!("/customers/{}/addresses", self.customer_id.as_ref());
+        Paginated::new(self.client, &self.path(), self)
+    }
+}
+
+impl PaddleRequest for AddressesList<'_> {
+    type Output = Vec<Address>;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!("/customers/{}/addresses", self.customer_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
 
-        Paginated::new(self.client, &url, self)
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::AddressRead]
     }
 }
 
 /// Request builder for creating customer addresses in Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct AddressCreate<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -141,62 +171,78 @@ impl<'a> AddressCreate<'a> {
     }
 
     /// Memorable description for this address.
-    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+    pub fn description(mut self, description: impl Into<String>) -> Self {
         self.description = Some(description.into());
         self
     }
 
     /// First line of the address.
-    pub fn first_line(&mut self, first_line: impl Into<String>) -> &mut Self {
+    pub fn first_line(mut self, first_line: impl Into<String>) -> Self {
         self.first_line = Some(first_line.into());
         self
     }
 
     /// Second line of the address.
-    pub fn second_line(&mut self, second_line: impl Into<String>) -> &mut Self {
+    pub fn second_line(mut self, second_line: impl Into<String>) -> Self {
         self.second_line = Some(second_line.into());
         self
     }
 
     /// City name.
-    pub fn city(&mut self, city: impl Into<String>) -> &mut Self {
+    pub fn city(mut self, city: impl Into<String>) -> Self {
         self.city = Some(city.into());
         self
     }
 
     /// Postal code. Required for US addresses
-    pub fn postal_code(&mut self, postal_code: impl Into<String>) -> &mut Self {
+    pub fn postal_code(mut self, postal_code: impl Into<String>) -> Self {
         self.postal_code = Some(postal_code.into());
         self
     }
 
     /// Region name.
-    pub fn region(&mut self, region: impl Into<String>) -> &mut Self {
+    pub fn region(mut self, region: impl Into<String>) -> Self {
         self.region = Some(region.into());
         self
     }
 
     /// Custom data to be stored with this address.
-    pub fn custom_data(&mut self, custom_data: HashMap<String, String>) -> &mut Self {
+    pub fn custom_data(mut self, custom_data: HashMap<String, String>) -> Self {
         self.custom_data = Some(custom_data);
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Address> {
-        self.client
-            .send(
-                self,
-                Method::POST,
-                &format!("/customers/{}/addresses", self.customer_id.as_ref()),
-            )
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for AddressCreate<'_> {
+    type Output = Address;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        format!("/customers/{}/addresses", self.customer_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::AddressWrite]
     }
 }
 
 /// Request builder for fetching a single address from Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct AddressGet<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -221,22 +267,38 @@ impl<'a> AddressGet<'a> {
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Address> {
-        self.client
-            .send(
-                self,
-                Method::GET,
-                &format!(
-                    "/customers/{}/addresses/{}",
-                    self.customer_id.as_ref(),
-                    self.address_id.as_ref()
-                ),
-            )
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for AddressGet<'_> {
+    type Output = Address;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "/customers/{}/addresses/{}",
+            self.customer_id.as_ref(),
+            self.address_id.as_ref()
+        )
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::AddressRead]
     }
 }
 
 /// Request builder for updating an address in Paddle API.
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct AddressUpdate<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -287,77 +349,89 @@ impl<'a> AddressUpdate<'a> {
     }
 
     /// Memorable description for this address.
-    pub fn description(&mut self, description: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn description(mut self, description: impl Into<Nullable<String>>) -> Self {
         self.description = description.into();
         self
     }
 
     /// First line of the address.
-    pub fn first_line(&mut self, first_line: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn first_line(mut self, first_line: impl Into<Nullable<String>>) -> Self {
         self.first_line = first_line.into();
         self
     }
 
     /// Second line of the address.
-    pub fn second_line(&mut self, second_line: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn second_line(mut self, second_line: impl Into<Nullable<String>>) -> Self {
         self.second_line = second_line.into();
         self
     }
 
     /// City name.
-    pub fn city(&mut self, city: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn city(mut self, city: impl Into<Nullable<String>>) -> Self {
         self.city = city.into();
         self
     }
 
     /// Postal code. Required for US addresses
-    pub fn postal_code(&mut self, postal_code: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn postal_code(mut self, postal_code: impl Into<Nullable<String>>) -> Self {
         self.postal_code = postal_code.into();
         self
     }
 
     /// Region name.
-    pub fn region(&mut self, region: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn region(mut self, region: impl Into<Nullable<String>>) -> Self {
         self.region = region.into();
         self
     }
 
     /// Country code.
-    pub fn country_code(
-        &mut self,
-        country_code: impl Into<Nullable<CountryCodeSupported>>,
-    ) -> &mut Self {
+    pub fn country_code(mut self, country_code: impl Into<Nullable<CountryCodeSupported>>) -> Self {
         self.country_code = country_code.into();
         self
     }
 
     /// Custom data to be stored with this address.
     pub fn custom_data(
-        &mut self,
+        mut self,
         custom_data: impl Into<Nullable<HashMap<String, String>>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.custom_data = custom_data.into();
         self
     }
 
     /// Status of the address.
-    pub fn status(&mut self, status: impl Into<Nullable<Status>>) -> &mut Self {
+    pub fn status(mut self, status: impl Into<Nullable<Status>>) -> Self {
         self.status = status.into();
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Address> {
-        self.client
-            .send(
-                self,
-                Method::PATCH,
-                &format!(
-                    "/customers/{}/addresses/{}",
-                    self.customer_id.as_ref(),
-                    self.address_id.as_ref()
-                ),
-            )
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for AddressUpdate<'_> {
+    type Output = Address;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "/customers/{}/addresses/{}",
+            self.customer_id.as_ref(),
+            self.address_id.as_ref()
+        )
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::AddressWrite]
     }
 }
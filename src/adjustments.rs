@@ -10,11 +10,14 @@ use crate::entities::{Adjustment, AdjustmentItemInput};
 use crate::enums::{AdjustmentAction, AdjustmentStatus, AdjustmentType, TaxMode};
 use crate::ids::{AdjustmentID, CustomerID, SubscriptionID, TransactionID};
 use crate::paginated::Paginated;
+use crate::permissions::Permission;
+use crate::request::PaddleRequest;
 use crate::{Paddle, Result};
 
 // Request builder for retrieving adjustments
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct AdjustmentsList<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -51,86 +54,124 @@ impl<'a> AdjustmentsList<'a> {
     }
 
     /// Return entities for the specified action.
-    pub fn action(&mut self, adjustment_action: AdjustmentAction) -> &mut Self {
+    pub fn action(mut self, adjustment_action: AdjustmentAction) -> Self {
         self.action = Some(adjustment_action);
         self
     }
 
     /// Return entities after the specified Paddle ID when working with paginated endpoints. Used in the `meta.pagination.next` URL in responses for list operations.
-    pub fn after(&mut self, id: impl Into<AdjustmentID>) -> &mut Self {
+    pub fn after(mut self, id: impl Into<AdjustmentID>) -> Self {
         self.after = Some(id.into());
         self
     }
 
     /// Return entities related to the specified customers.
     pub fn customer_id(
-        &mut self,
+        mut self,
         customer_ids: impl IntoIterator<Item = impl Into<CustomerID>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.customer_id = Some(customer_ids.into_iter().map(Into::into).collect());
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: `id`
-    pub fn order_by_asc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_asc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[ASC]", field));
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: `id`
-    pub fn order_by_desc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_desc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[DESC]", field));
         self
     }
 
+    /// Number of entities Paddle returns per page when [Self::per_page] isn't set.
+    pub const DEFAULT_PER_PAGE: usize = 10;
+    /// Largest page size Paddle accepts for this endpoint.
+    pub const MAX_PER_PAGE: usize = 50;
+
     /// Set how many entities are returned per page. Paddle returns the maximum number of results if a number greater than the maximum is requested.
     /// Check `meta.pagination.per_page` in the response to see how many were returned.
     ///
-    /// Default: `50`; Maximum: `200`.
-    pub fn per_page(&mut self, entities_per_page: usize) -> &mut Self {
+    /// Default: `10`; Maximum: `50`.
+    pub fn per_page(mut self, entities_per_page: usize) -> Self {
         self.per_page = Some(entities_per_page);
         self
     }
 
+    /// Request the largest page Paddle allows for this endpoint. Use this to fetch everything in as few requests as possible.
+    pub fn max_page_size(self) -> Self {
+        self.per_page(Self::MAX_PER_PAGE)
+    }
+
     /// Return entities that match the specified status.
-    pub fn status(&mut self, statuses: impl IntoIterator<Item = AdjustmentStatus>) -> &mut Self {
+    pub fn status(mut self, statuses: impl IntoIterator<Item = AdjustmentStatus>) -> Self {
         self.status = Some(statuses.into_iter().collect());
         self
     }
 
     /// Return entities related to the specified subscription.
     pub fn subscription_ids(
-        &mut self,
+        mut self,
         subscription_ids: impl IntoIterator<Item = impl Into<SubscriptionID>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.subscription_id = Some(subscription_ids.into_iter().map(Into::into).collect());
         self
     }
 
     /// Return entities related to the specified subscription.
     pub fn transaction_ids(
-        &mut self,
+        mut self,
         transaction_ids: impl IntoIterator<Item = impl Into<TransactionID>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.transaction_id = Some(transaction_ids.into_iter().map(Into::into).collect());
         self
     }
 
     /// Return only the IDs specified.
-    pub fn id(&mut self, ids: impl IntoIterator<Item = impl Into<AdjustmentID>>) -> &mut Self {
+    pub fn ids(mut self, ids: impl IntoIterator<Item = impl Into<AdjustmentID>>) -> Self {
         self.id = Some(ids.into_iter().map(Into::into).collect());
         self
     }
 
+    /// Return only the IDs specified.
+    #[deprecated(note = "use `ids` instead")]
+    pub fn id(self, ids: impl IntoIterator<Item = impl Into<AdjustmentID>>) -> Self {
+        self.ids(ids)
+    }
+
     /// Returns a paginator for fetching pages of entities from Paddle
     pub fn send(&self) -> Paginated<'_, Vec<Adjustment>> {
-        Paginated::new(self.client, "/adjustments", self)
+        Paginated::new(self.client, &self.path(), self)
+    }
+}
+
+impl PaddleRequest for AdjustmentsList<'_> {
+    type Output = Vec<Adjustment>;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        "/adjustments".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::AdjustmentRead]
     }
 }
 
 /// Request builder for creating an adjustment in Paddle.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct AdjustmentCreate<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -161,13 +202,13 @@ impl<'a> AdjustmentCreate<'a> {
     }
 
     /// Type of adjustment. Use `full` to adjust the grand total for the related transaction. Include an `items` array when creating a `partial` adjustment. If omitted, defaults to `partial`.
-    pub fn r#type(&mut self, adjustment_type: AdjustmentType) -> &mut Self {
+    pub fn r#type(mut self, adjustment_type: AdjustmentType) -> Self {
         self.r#type = Some(adjustment_type);
         self
     }
 
     /// List of transaction items to adjust. Required if `type` is not populated or set to `partial`.
-    pub fn items(&mut self, items: impl IntoIterator<Item = AdjustmentItemInput>) -> &mut Self {
+    pub fn items(mut self, items: impl IntoIterator<Item = AdjustmentItemInput>) -> Self {
         self.items = Some(items.into_iter().collect());
         self
     }
@@ -177,13 +218,34 @@ impl<'a> AdjustmentCreate<'a> {
     /// Only valid for adjustments where the `type` is `partial`.
     ///
     /// If omitted, defaults to `internal`.
-    pub fn tax_mode(&mut self, mode: TaxMode) -> &mut Self {
+    pub fn tax_mode(mut self, mode: TaxMode) -> Self {
         self.tax_mode = Some(mode);
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Adjustment> {
-        self.client.send(self, Method::POST, "/adjustments").await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for AdjustmentCreate<'_> {
+    type Output = Adjustment;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        "/adjustments".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::AdjustmentWrite]
     }
 }
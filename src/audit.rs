@@ -0,0 +1,126 @@
+//! Field level diffs between two snapshots of an entity.
+//!
+//! Webhook `*.updated` events carry the full entity after the change. Compare it with the snapshot stored when handling the previous event to log exactly which fields changed.
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::audit::diff;
+//! use paddle_rust_sdk::entities::Customer;
+//!
+//! fn log_changes(old: &Customer, new: &Customer) {
+//!     for change in diff(old, new) {
+//!         println!("{}: {} -> {}", change.path, change.old, change.new);
+//!     }
+//! }
+//! ```
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::entities::{Customer, Price, Product, Subscription};
+
+/// A single changed field.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct FieldChange {
+    /// Path to the field as it appears in the Paddle API, e.g. `billing_details.payment_terms.interval` or `items[0].quantity`.
+    pub path: String,
+    /// Value before the change. `null` when the field was added.
+    pub old: Value,
+    /// Value after the change. `null` when the field was removed.
+    pub new: Value,
+}
+
+/// Entities that can be compared with [diff].
+pub trait Diffable: Serialize + private::Sealed {}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! diffable {
+    ($($entity:ty),*) => {
+        $(
+            impl private::Sealed for $entity {}
+            impl Diffable for $entity {}
+        )*
+    };
+}
+
+diffable!(Customer, Price, Product, Subscription);
+
+/// List the fields that differ between two snapshots of the same entity.
+///
+/// Nested objects are compared field by field. Lists of the same length are compared item by item, lists with a different length are reported as a single change. Changes are sorted by field name at every level.
+///
+/// `updated_at` changes with every update and is included like any other field.
+pub fn diff<T: Diffable>(old: &T, new: &T) -> Vec<FieldChange> {
+    let old = serde_json::to_value(old).unwrap_or_default();
+    let new = serde_json::to_value(new).unwrap_or_default();
+
+    let mut changes = Vec::new();
+    diff_values(String::new(), &old, &new, &mut changes);
+    changes
+}
+
+fn diff_values(path: String, old: &Value, new: &Value, changes: &mut Vec<FieldChange>) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                let new_value = new.get(key).unwrap_or(&Value::Null);
+                diff_values(join(&path, key), old_value, new_value, changes);
+            }
+
+            for (key, new_value) in new.iter().filter(|(key, _)| !old.contains_key(*key)) {
+                diff_values(join(&path, key), &Value::Null, new_value, changes);
+            }
+        }
+        (Value::Array(old), Value::Array(new)) if old.len() == new.len() => {
+            for (index, (old, new)) in old.iter().zip(new).enumerate() {
+                diff_values(format!("{path}[{index}]"), old, new, changes);
+            }
+        }
+        (old, new) if old != new => changes.push(FieldChange {
+            path,
+            old: old.clone(),
+            new: new.clone(),
+        }),
+        _ => {}
+    }
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn lists_changed_fields() {
+        let old: Subscription = fixtures::from_value(fixtures::subscription());
+
+        let mut new = fixtures::subscription();
+        new["custom_data"] = json!({ "plan": "pro" });
+        new["items"][0]["quantity"] = json!(5);
+        let new: Subscription = fixtures::from_value(new);
+
+        let changes = diff(&old, &new);
+
+        let paths = changes
+            .iter()
+            .map(|change| change.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["custom_data", "items[0].quantity"]);
+        assert_eq!(changes[1].old, json!(1));
+        assert_eq!(changes[1].new, json!(5));
+
+        assert!(diff(&old, &old).is_empty());
+    }
+}
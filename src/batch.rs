@@ -0,0 +1,341 @@
+//! Helpers for fetching many entities by ID.
+//!
+//! List endpoints accept a list of IDs to filter by. The helpers in this module split the IDs into chunks, fetch the chunks concurrently and collect the results into a [Batch].
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::Paddle;
+//!
+//! let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+//! let batch = client
+//!     .get_prices(["pri_01gsz8x8sawmvhz1pv30nge1ke", "pri_01h1vjfevh5etwq3rb416a23h2"])
+//!     .await
+//!     .unwrap();
+//!
+//! for price_id in &batch.missing {
+//!     println!("{price_id} doesn't exist");
+//! }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::hash::Hash;
+
+use tokio::task::JoinSet;
+
+use crate::budget::Budget;
+use crate::entities::{Customer, Discount, Price, Product, Subscription, Transaction};
+use crate::enums::Status;
+use crate::ids::{CustomerID, DiscountID, PriceID, ProductID, SubscriptionID, TransactionID};
+use crate::{Error, Paddle};
+
+/// Maximum number of IDs sent in a single `id` filter.
+const IDS_PER_REQUEST: usize = 50;
+
+/// Maximum number of chunks fetched at the same time.
+const MAX_CONCURRENT_REQUESTS: usize = 4;
+
+/// Entities fetched by ID.
+#[derive(Clone, Debug)]
+pub struct Batch<ID, T> {
+    /// Entities that were found, keyed by their ID.
+    pub found: HashMap<ID, T>,
+    /// Requested IDs that Paddle didn't return.
+    pub missing: Vec<ID>,
+}
+
+impl<ID: Eq + Hash, T> Batch<ID, T> {
+    /// Get a fetched entity by ID.
+    pub fn get(&self, id: &ID) -> Option<&T> {
+        self.found.get(id)
+    }
+
+    /// Whether every requested ID was found.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+impl Paddle {
+    /// Fetch prices by ID. Archived prices are returned too.
+    pub async fn get_prices(
+        &self,
+        price_ids: impl IntoIterator<Item = impl Into<PriceID>>,
+    ) -> std::result::Result<Batch<PriceID, Price>, Error> {
+        self.fetch_batch(
+            price_ids,
+            |price: &Price| price.id.clone(),
+            |client, ids| async move {
+                let mut prices = Vec::new();
+
+                // Only active prices are returned unless another status is requested
+                for status in [Status::Active, Status::Archived] {
+                    prices.extend(
+                        client
+                            .prices_list()
+                            .ids(ids.clone())
+                            .status(status)
+                            .send()
+                            .all()
+                            .await?,
+                    );
+                }
+
+                Ok(prices)
+            },
+        )
+        .await
+    }
+
+    /// Fetch products by ID. Archived products are returned too.
+    pub async fn get_products(
+        &self,
+        product_ids: impl IntoIterator<Item = impl Into<ProductID>>,
+    ) -> std::result::Result<Batch<ProductID, Product>, Error> {
+        self.fetch_batch(
+            product_ids,
+            |product: &Product| product.id.clone(),
+            |client, ids| async move {
+                let mut products = Vec::new();
+
+                // Only active products are returned unless another status is requested
+                for status in [Status::Active, Status::Archived] {
+                    products.extend(
+                        client
+                            .products_list()
+                            .ids(ids.clone())
+                            .status(status)
+                            .send()
+                            .all()
+                            .await?,
+                    );
+                }
+
+                Ok(products)
+            },
+        )
+        .await
+    }
+
+    /// Fetch customers by ID. Archived customers are returned too.
+    pub async fn get_customers(
+        &self,
+        customer_ids: impl IntoIterator<Item = impl Into<CustomerID>>,
+    ) -> std::result::Result<Batch<CustomerID, Customer>, Error> {
+        self.fetch_batch(
+            customer_ids,
+            |customer: &Customer| customer.id.clone(),
+            |client, ids| async move {
+                let mut customers = Vec::new();
+
+                // Only active customers are returned unless another status is requested
+                for status in [Status::Active, Status::Archived] {
+                    customers.extend(
+                        client
+                            .customers_list()
+                            .ids(ids.clone())
+                            .status(status)
+                            .send()
+                            .all()
+                            .await?,
+                    );
+                }
+
+                Ok(customers)
+            },
+        )
+        .await
+    }
+
+    /// Fetch discounts by ID. Archived discounts are returned too.
+    pub async fn get_discounts(
+        &self,
+        discount_ids: impl IntoIterator<Item = impl Into<DiscountID>>,
+    ) -> std::result::Result<Batch<DiscountID, Discount>, Error> {
+        self.fetch_batch(
+            discount_ids,
+            |discount: &Discount| discount.id.clone(),
+            |client, ids| async move {
+                let mut discounts = Vec::new();
+
+                // Only active discounts are returned unless another status is requested
+                for status in [Status::Active, Status::Archived] {
+                    discounts.extend(
+                        client
+                            .discounts_list()
+                            .ids(ids.clone())
+                            .status(status)
+                            .send()
+                            .all()
+                            .await?,
+                    );
+                }
+
+                Ok(discounts)
+            },
+        )
+        .await
+    }
+
+    /// Fetch subscriptions by ID.
+    pub async fn get_subscriptions(
+        &self,
+        subscription_ids: impl IntoIterator<Item = impl Into<SubscriptionID>>,
+    ) -> std::result::Result<Batch<SubscriptionID, Subscription>, Error> {
+        self.fetch_batch(
+            subscription_ids,
+            |subscription: &Subscription| subscription.id.clone(),
+            |client, ids| async move { client.subscriptions_list().ids(ids).send().all().await },
+        )
+        .await
+    }
+
+    /// Fetch transactions by ID.
+    pub async fn get_transactions(
+        &self,
+        transaction_ids: impl IntoIterator<Item = impl Into<TransactionID>>,
+    ) -> std::result::Result<Batch<TransactionID, Transaction>, Error> {
+        self.fetch_batch(
+            transaction_ids,
+            |transaction: &Transaction| transaction.id.clone(),
+            |client, ids| async move { client.transactions_list().ids(ids).send().all().await },
+        )
+        .await
+    }
+
+    async fn fetch_batch<ID, T, Fut>(
+        &self,
+        ids: impl IntoIterator<Item = impl Into<ID>>,
+        key: impl Fn(&T) -> ID,
+        fetch: impl Fn(Paddle, Vec<ID>) -> Fut,
+    ) -> std::result::Result<Batch<ID, T>, Error>
+    where
+        ID: Clone + Eq + Hash,
+        T: Send + 'static,
+        Fut: Future<Output = std::result::Result<Vec<T>, Error>> + Send + 'static,
+    {
+        let mut seen = HashSet::new();
+        let ids = ids
+            .into_iter()
+            .map(Into::into)
+            .filter(|id| seen.insert(id.clone()))
+            .collect::<Vec<ID>>();
+
+        let mut chunks = ids.chunks(IDS_PER_REQUEST);
+        let mut requests = JoinSet::new();
+        let mut found = HashMap::with_capacity(ids.len());
+
+        loop {
+            while requests.len() < MAX_CONCURRENT_REQUESTS {
+                let Some(chunk) = chunks.next() else {
+                    break;
+                };
+
+                requests.spawn(Budget::inherit(fetch(self.clone(), chunk.to_vec())));
+            }
+
+            let Some(result) = requests.join_next().await else {
+                break;
+            };
+
+            let entities =
+                result.unwrap_or_else(|err| std::panic::resume_unwind(err.into_panic()))?;
+            found.extend(entities.into_iter().map(|entity| (key(&entity), entity)));
+        }
+
+        let missing = ids
+            .into_iter()
+            .filter(|id| !found.contains_key(id))
+            .collect();
+
+        Ok(Batch { found, missing })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::fixtures;
+
+    /// Serve `active` for requests without a status filter and `archived` for `status=archived`.
+    async fn serve_by_status(active: Value, archived: Value) -> String {
+        fixtures::serve_json(move |target| {
+            let data = if target.contains("status=archived") {
+                archived.clone()
+            } else {
+                active.clone()
+            };
+
+            async move {
+                json!({
+                    "data": [data],
+                    "meta": {
+                        "request_id": "req_1",
+                        "pagination": {
+                            "per_page": 50,
+                            "next": "http://localhost/",
+                            "has_more": false,
+                            "estimated_total": 1
+                        }
+                    }
+                })
+            }
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn archived_prices_are_found() {
+        let mut archived = fixtures::price("pri_02", "pro_01", 1000);
+        archived["status"] = json!("archived");
+        let url = serve_by_status(fixtures::price("pri_01", "pro_01", 1000), archived).await;
+
+        let client = Paddle::new("key", url).unwrap();
+        let batch = client
+            .get_prices(["pri_01", "pri_02", "pri_03"])
+            .await
+            .unwrap();
+
+        assert!(batch.get(&PriceID::from("pri_01")).is_some());
+        assert!(batch.get(&PriceID::from("pri_02")).is_some());
+        assert_eq!(batch.missing, [PriceID::from("pri_03")]);
+    }
+
+    #[tokio::test]
+    async fn archived_customers_are_found() {
+        let mut archived = fixtures::customer();
+        archived["id"] = json!("ctm_02");
+        archived["status"] = json!("archived");
+        let url = serve_by_status(fixtures::customer(), archived).await;
+
+        let client = Paddle::new("key", url).unwrap();
+        let batch = client
+            .get_customers(["ctm_01hv6y1jedq4p1n0yqn5ba3ky4", "ctm_02", "ctm_03"])
+            .await
+            .unwrap();
+
+        assert!(batch
+            .get(&CustomerID::from("ctm_01hv6y1jedq4p1n0yqn5ba3ky4"))
+            .is_some());
+        assert!(batch.get(&CustomerID::from("ctm_02")).is_some());
+        assert_eq!(batch.missing, [CustomerID::from("ctm_03")]);
+    }
+
+    #[tokio::test]
+    async fn archived_discounts_are_found() {
+        let mut archived = fixtures::discount("dsc_02");
+        archived["status"] = json!("archived");
+        let url = serve_by_status(fixtures::discount("dsc_01"), archived).await;
+
+        let client = Paddle::new("key", url).unwrap();
+        let batch = client
+            .get_discounts(["dsc_01", "dsc_02", "dsc_03"])
+            .await
+            .unwrap();
+
+        assert!(batch.get(&DiscountID::from("dsc_01")).is_some());
+        assert!(batch.get(&DiscountID::from("dsc_02")).is_some());
+        assert_eq!(batch.missing, [DiscountID::from("dsc_03")]);
+    }
+}
@@ -0,0 +1,234 @@
+//! Cap how much work hits Paddle at once across the whole app.
+//!
+//! A [Budget] has a fixed capacity that's shared by everything holding a clone of it. Every API call made by a client
+//! with [Paddle::with_budget] takes a weight of `1` until the response is received. Webhook handlers, pollers and background
+//! jobs take the weight they choose with [Budget::run], which covers the API calls they make themselves. When the budget is
+//! used up, new work waits until enough weight is released, so a burst of webhooks can't push the app into Paddle's rate limits.
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::budget::Budget;
+//! use paddle_rust_sdk::Paddle;
+//!
+//! let budget = Budget::new(20);
+//! let client = Paddle::new(std::env::var("PADDLE_API_KEY")?, Paddle::PRODUCTION)?.with_budget(budget.clone());
+//!
+//! // In the webhook handler. The handler makes up to 2 API calls at once, so it reserves a weight of 2 for them.
+//! budget
+//!     .run(2, async {
+//!         let subscription = client.subscription_get(subscription_id).send().await?;
+//!         let customer = client.customer_get(subscription.data.customer_id).send().await?;
+//!         Ok::<_, paddle_rust_sdk::Error>(())
+//!     })
+//!     .await?;
+//! ```
+//!
+//! Tasks spawned by the SDK inside [Budget::run], e.g. by [Paddle::get_prices] or prefetching paginators, are covered by
+//! the same weight. Tasks you spawn yourself aren't, their API calls wait for their own weight.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+#[cfg(doc)]
+use crate::Paddle;
+
+tokio::task_local! {
+    /// Budget whose weight the current task already holds through [Budget::run].
+    static RESERVED: Arc<Semaphore>;
+}
+
+/// Concurrency limit shared between clones. See the [module](self) documentation.
+#[derive(Clone, Debug)]
+pub struct Budget {
+    semaphore: Arc<Semaphore>,
+    capacity: u32,
+}
+
+impl Budget {
+    /// Weight of a single API call.
+    pub const REQUEST_WEIGHT: u32 = 1;
+
+    /// Budget allowing work with a combined weight of `capacity` at once. A capacity of `0` is raised to `1`.
+    pub fn new(capacity: u32) -> Self {
+        let capacity = capacity.max(1);
+
+        Self {
+            semaphore: Arc::new(Semaphore::new(capacity as usize)),
+            capacity,
+        }
+    }
+
+    /// Combined weight allowed at once.
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// Weight that can be taken right now without waiting.
+    pub fn available(&self) -> u32 {
+        self.semaphore.available_permits() as u32
+    }
+
+    /// Wait until `weight` is available and take it. The weight is released when the returned permit is dropped.
+    ///
+    /// A weight larger than the capacity is lowered to the capacity, so it can't wait forever.
+    pub async fn acquire(&self, weight: u32) -> BudgetPermit {
+        let weight = self.clamp(weight);
+        let permit = Arc::clone(&self.semaphore)
+            .acquire_many_owned(weight)
+            .await
+            .expect("budget semaphore is never closed");
+
+        BudgetPermit { permit }
+    }
+
+    /// Take `weight` if it's available right now.
+    pub fn try_acquire(&self, weight: u32) -> Option<BudgetPermit> {
+        let weight = self.clamp(weight);
+
+        Arc::clone(&self.semaphore)
+            .try_acquire_many_owned(weight)
+            .ok()
+            .map(|permit| BudgetPermit { permit })
+    }
+
+    /// Run `work` once `weight` is available, holding it until `work` completes.
+    ///
+    /// API calls made by `work` through a client using this budget are covered by `weight` and don't wait for more.
+    pub async fn run<F: Future>(&self, weight: u32, work: F) -> F::Output {
+        let _permit = self.acquire(weight).await;
+
+        RESERVED.scope(Arc::clone(&self.semaphore), work).await
+    }
+
+    /// Take the weight of an API call, unless the current task already holds weight through [Budget::run].
+    pub(crate) async fn acquire_request(&self) -> Option<BudgetPermit> {
+        let reserved = RESERVED
+            .try_with(|reserved| Arc::ptr_eq(reserved, &self.semaphore))
+            .unwrap_or(false);
+
+        if reserved {
+            None
+        } else {
+            Some(self.acquire(Self::REQUEST_WEIGHT).await)
+        }
+    }
+
+    /// Carry the weight held by the current task into `future`, so that it's still covered once spawned onto another task.
+    pub(crate) fn inherit<F: Future>(future: F) -> impl Future<Output = F::Output> {
+        let reserved = RESERVED.try_with(Arc::clone).ok();
+
+        async move {
+            match reserved {
+                Some(reserved) => RESERVED.scope(reserved, future).await,
+                None => future.await,
+            }
+        }
+    }
+
+    fn clamp(&self, weight: u32) -> u32 {
+        weight.clamp(1, self.capacity)
+    }
+}
+
+/// Weight taken from a [Budget]. Released when dropped.
+#[derive(Debug)]
+pub struct BudgetPermit {
+    permit: OwnedSemaphorePermit,
+}
+
+impl BudgetPermit {
+    /// Weight held by this permit.
+    pub fn weight(&self) -> u32 {
+        self.permit.num_permits() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::ids::PriceID;
+    use crate::{fixtures, Paddle};
+
+    #[test]
+    fn weights_are_taken_and_released() {
+        let budget = Budget::new(3);
+        let shared = budget.clone();
+
+        let permit = budget.try_acquire(2).unwrap();
+        assert_eq!(permit.weight(), 2);
+        assert_eq!(shared.available(), 1);
+        assert!(shared.try_acquire(2).is_none());
+
+        drop(permit);
+        assert_eq!(budget.available(), 3);
+        assert_eq!(budget.try_acquire(10).unwrap().weight(), 3);
+    }
+
+    #[tokio::test]
+    async fn requests_wait_for_the_budget() {
+        let url = fixtures::serve_json(|_| async {
+            json!({ "data": fixtures::customer(), "meta": { "request_id": "req_1" } })
+        })
+        .await;
+
+        let budget = Budget::new(1);
+        let client = Paddle::new("key", url).unwrap().with_budget(budget.clone());
+        let customer_id = "ctm_01hv6y1jedq4p1n0yqn5ba3ky4";
+
+        let permit = budget.acquire(1).await;
+        let request = client.customer_get(customer_id);
+        let waiting = tokio::time::timeout(Duration::from_millis(100), request.send()).await;
+        assert!(waiting.is_err());
+
+        drop(permit);
+        assert!(client.customer_get(customer_id).send().await.is_ok());
+
+        // The weight held by `run` covers the request
+        let customer = budget
+            .run(1, async { client.customer_get(customer_id).send().await })
+            .await
+            .unwrap();
+        assert_eq!(customer.data.id.as_ref(), customer_id);
+        assert_eq!(budget.available(), 1);
+    }
+
+    #[tokio::test]
+    async fn spawned_requests_are_covered_by_run() {
+        let url = fixtures::serve_json(|_| async {
+            json!({
+                "data": [fixtures::price("pri_01", "pro_01", 1000)],
+                "meta": {
+                    "request_id": "req_1",
+                    "pagination": {
+                        "per_page": 50,
+                        "next": "http://localhost/prices",
+                        "has_more": false,
+                        "estimated_total": 1
+                    }
+                }
+            })
+        })
+        .await;
+
+        let budget = Budget::new(2);
+        let client = Paddle::new("key", url).unwrap().with_budget(budget.clone());
+        let price_ids = (0..120).map(|i| format!("pri_{i:02}")).collect::<Vec<_>>();
+
+        // The batch is fetched in concurrent chunks on spawned tasks while `run` holds the whole budget
+        let batch = tokio::time::timeout(
+            Duration::from_secs(5),
+            budget.run(2, client.get_prices(price_ids)),
+        )
+        .await
+        .expect("spawned requests wait for weight held by run")
+        .unwrap();
+
+        assert!(batch.found.contains_key(&PriceID::from("pri_01")));
+        assert_eq!(budget.available(), 2);
+    }
+}
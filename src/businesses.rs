@@ -13,11 +13,14 @@ use crate::enums::Status;
 use crate::ids::{BusinessID, CustomerID};
 use crate::paginated::Paginated;
 use crate::nullable::Nullable;
+use crate::permissions::Permission;
+use crate::request::PaddleRequest;
 use crate::{Paddle, Result};
 
 /// Request builder for fetching businesses from Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct BusinessesList<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -47,64 +50,91 @@ impl<'a> BusinessesList<'a> {
     }
 
     /// Return entities after the specified Paddle ID when working with paginated endpoints. Used in the `meta.pagination.next` URL in responses for list operations.
-    pub fn after(&mut self, business_id: impl Into<BusinessID>) -> &mut Self {
+    pub fn after(mut self, business_id: impl Into<BusinessID>) -> Self {
         self.after = Some(business_id.into());
         self
     }
 
     /// Return only the IDs specified.
-    pub fn ids(
-        &mut self,
-        business_ids: impl IntoIterator<Item = impl Into<BusinessID>>,
-    ) -> &mut Self {
+    pub fn ids(mut self, business_ids: impl IntoIterator<Item = impl Into<BusinessID>>) -> Self {
         self.id = Some(business_ids.into_iter().map(Into::into).collect());
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: id
-    pub fn order_by_asc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_asc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[ASC]", field));
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: id
-    pub fn order_by_desc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_desc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[DESC]", field));
         self
     }
 
+    /// Number of entities Paddle returns per page when [Self::per_page] isn't set.
+    pub const DEFAULT_PER_PAGE: usize = 50;
+    /// Largest page size Paddle accepts for this endpoint.
+    pub const MAX_PER_PAGE: usize = 200;
+
     /// Set how many entities are returned per page. Paddle returns the maximum number of results if a number greater than the maximum is requested.
     /// Check `meta.pagination.per_page` in the response to see how many were returned.
     ///
     /// Default: `50`; Maximum: `200`.
-    pub fn per_page(&mut self, entities_per_page: usize) -> &mut Self {
+    pub fn per_page(mut self, entities_per_page: usize) -> Self {
         self.per_page = Some(entities_per_page);
         self
     }
 
+    /// Request the largest page Paddle allows for this endpoint. Use this to fetch everything in as few requests as possible.
+    pub fn max_page_size(self) -> Self {
+        self.per_page(Self::MAX_PER_PAGE)
+    }
+
     /// Return entities that match a search query. Searches `status`, `created_at`, and `updated_at`.
-    pub fn search(&mut self, term: impl Into<String>) -> &mut Self {
+    pub fn search(mut self, term: impl Into<String>) -> Self {
         self.search = Some(term.into());
         self
     }
 
     /// Return only prices with the specified status.
-    pub fn status(&mut self, status: Status) -> &mut Self {
+    pub fn status(mut self, status: Status) -> Self {
         self.status = Some(status);
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub fn send(&self) -> Paginated<'_, Vec<Business>> {
-        let url = format!("/customers/{}/businesses", self.customer_id.as_ref());
+        Paginated::new(self.client, &self.path(), self)
+    }
+}
+
+impl PaddleRequest for BusinessesList<'_> {
+    type Output = Vec<Business>;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!("/customers/{}/businesses", self.customer_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
 
-        Paginated::new(self.client, &url, self)
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::BusinessRead]
     }
 }
 
 /// Request builder for creating customer businesses in Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct BusinessCreate<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -135,44 +165,60 @@ impl<'a> BusinessCreate<'a> {
     }
 
     /// Company number for this business.
-    pub fn company_number(&mut self, company_number: impl Into<String>) -> &mut Self {
+    pub fn company_number(mut self, company_number: impl Into<String>) -> Self {
         self.company_number = Some(company_number.into());
         self
     }
 
     /// Tax identifier for this business.
-    pub fn tax_identifier(&mut self, tax_identifier: impl Into<String>) -> &mut Self {
+    pub fn tax_identifier(mut self, tax_identifier: impl Into<String>) -> Self {
         self.tax_identifier = Some(tax_identifier.into());
         self
     }
 
     /// Contact information for this business.
-    pub fn contacts(&mut self, contacts: impl IntoIterator<Item = Contact>) -> &mut Self {
+    pub fn contacts(mut self, contacts: impl IntoIterator<Item = Contact>) -> Self {
         self.contacts = Some(contacts.into_iter().collect());
         self
     }
 
     /// Custom data for this business.
-    pub fn custom_data(&mut self, custom_data: HashMap<String, String>) -> &mut Self {
+    pub fn custom_data(mut self, custom_data: HashMap<String, String>) -> Self {
         self.custom_data = Some(custom_data);
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Business> {
-        self.client
-            .send(
-                self,
-                Method::POST,
-                &format!("/customers/{}/businesses", self.customer_id.as_ref()),
-            )
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for BusinessCreate<'_> {
+    type Output = Business;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        format!("/customers/{}/businesses", self.customer_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::BusinessWrite]
     }
 }
 
 /// Request builder for fetching a single business from Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct BusinessGet<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -197,22 +243,38 @@ impl<'a> BusinessGet<'a> {
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Business> {
-        self.client
-            .send(
-                self,
-                Method::GET,
-                &format!(
-                    "/customers/{}/businesses/{}",
-                    self.customer_id.as_ref(),
-                    self.business_id.as_ref()
-                ),
-            )
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for BusinessGet<'_> {
+    type Output = Business;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "/customers/{}/businesses/{}",
+            self.customer_id.as_ref(),
+            self.business_id.as_ref()
+        )
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::BusinessRead]
     }
 }
 
 /// Request builder for updating a business in Paddle API.
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct BusinessUpdate<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -230,6 +292,8 @@ pub struct BusinessUpdate<'a> {
     contacts: Nullable<Vec<Contact>>,
     #[serde(skip_serializing_if = "Nullable::is_unchanged")]
     custom_data: Nullable<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Nullable::is_unchanged")]
+    status: Nullable<Status>,
 }
 
 impl<'a> BusinessUpdate<'a> {
@@ -247,54 +311,76 @@ impl<'a> BusinessUpdate<'a> {
             tax_identifier: Nullable::Unchanged,
             contacts: Nullable::Unchanged,
             custom_data: Nullable::Unchanged,
+            status: Nullable::Unchanged,
         }
     }
 
     /// Name of this business.
-    pub fn name(&mut self, name: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn name(mut self, name: impl Into<Nullable<String>>) -> Self {
         self.name = name.into();
         self
     }
 
     /// Company number for this business.
-    pub fn company_number(&mut self, company_number: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn company_number(mut self, company_number: impl Into<Nullable<String>>) -> Self {
         self.company_number = company_number.into();
         self
     }
 
     /// Tax identifier for this business.
-    pub fn tax_identifier(&mut self, tax_identifier: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn tax_identifier(mut self, tax_identifier: impl Into<Nullable<String>>) -> Self {
         self.tax_identifier = tax_identifier.into();
         self
     }
 
     /// Contact information for this business.
-    pub fn contacts(&mut self, contacts: impl Into<Nullable<Vec<Contact>>>) -> &mut Self {
+    pub fn contacts(mut self, contacts: impl Into<Nullable<Vec<Contact>>>) -> Self {
         self.contacts = contacts.into();
         self
     }
 
     /// Custom data for this business.
     pub fn custom_data(
-        &mut self,
+        mut self,
         custom_data: impl Into<Nullable<HashMap<String, String>>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.custom_data = custom_data.into();
         self
     }
 
+    /// Whether this entity can be used in Paddle.
+    pub fn status(mut self, status: impl Into<Nullable<Status>>) -> Self {
+        self.status = status.into();
+        self
+    }
+
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Business> {
-        self.client
-            .send(
-                self,
-                Method::PATCH,
-                &format!(
-                    "/customers/{}/businesses/{}",
-                    self.customer_id.as_ref(),
-                    self.business_id.as_ref()
-                ),
-            )
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for BusinessUpdate<'_> {
+    type Output = Business;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "/customers/{}/businesses/{}",
+            self.customer_id.as_ref(),
+            self.business_id.as_ref()
+        )
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::BusinessWrite]
     }
 }
@@ -0,0 +1,330 @@
+//! In-memory cache of products and prices, kept up to date from webhook events.
+//!
+//! Pricing pages need the catalog on every request, but it rarely changes. [CatalogCache] loads every product and price once on startup and then applies `product.*` and `price.*` webhook events, so lookups never call the Paddle API.
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::catalog_cache::CatalogCache;
+//! use paddle_rust_sdk::Paddle;
+//!
+//! let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+//! let catalog = CatalogCache::warm(&client).await.unwrap();
+//!
+//! // In your webhook handler
+//! let event = Paddle::unmarshal(request_body, "your_webhook_key", signature, MaximumVariance::default()).unwrap();
+//! catalog.apply_event(event);
+//!
+//! // In your pricing page
+//! for price in catalog.list_active_prices() {
+//!     println!("{} {}", price.id, price.unit_price.amount);
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::entities::{Event, Price, Product};
+use crate::enums::Status;
+use crate::ids::{PriceID, ProductID};
+use crate::{Error, Paddle};
+
+/// Products and prices of a Paddle catalog.
+#[derive(Debug, Default)]
+pub struct CatalogCache {
+    catalog: RwLock<Catalog>,
+}
+
+#[derive(Debug, Default)]
+struct Catalog {
+    products: HashMap<ProductID, Product>,
+    prices: HashMap<PriceID, Price>,
+}
+
+impl CatalogCache {
+    /// Create a cache holding every product and price, including archived ones.
+    pub async fn warm(client: &Paddle) -> Result<Self, Error> {
+        let cache = Self::default();
+        cache.refresh(client).await?;
+
+        Ok(cache)
+    }
+
+    /// Reload every product and price from Paddle, including archived ones, replacing the cached catalog.
+    ///
+    /// Useful after webhook deliveries were missed, e.g. while your app was down.
+    pub async fn refresh(&self, client: &Paddle) -> Result<(), Error> {
+        let mut products = Vec::new();
+        let mut prices = Vec::new();
+
+        // Only active products and prices are returned unless another status is requested
+        for status in [Status::Active, Status::Archived] {
+            products.extend(
+                client
+                    .products_list()
+                    .status(status)
+                    .max_page_size()
+                    .send()
+                    .all()
+                    .await?,
+            );
+
+            prices.extend(
+                client
+                    .prices_list()
+                    .status(status)
+                    .max_page_size()
+                    .send()
+                    .all()
+                    .await?,
+            );
+        }
+
+        let mut catalog = self.write();
+        catalog.products = products
+            .into_iter()
+            .map(|product| (product.id.clone(), product))
+            .collect();
+        catalog.prices = prices
+            .into_iter()
+            .map(|price| (price.id.clone(), price))
+            .collect();
+
+        Ok(())
+    }
+
+    /// Update the cache from a webhook event.
+    ///
+    /// Events not related to products or prices are ignored. Paddle doesn't guarantee the order of webhook deliveries, so events older than the cached entity are ignored too.
+    ///
+    /// Returns whether the cache was changed.
+    pub fn apply_event(&self, event: Event) -> bool {
+        let event = match Product::try_from(event) {
+            Ok(product) => {
+                return upsert(
+                    &mut self.write().products,
+                    product.id.clone(),
+                    product,
+                    |p| p.updated_at,
+                )
+            }
+            Err(event) => event,
+        };
+
+        match Price::try_from(event) {
+            Ok(price) => upsert(&mut self.write().prices, price.id.clone(), price, |p| {
+                p.updated_at
+            }),
+            Err(_) => false,
+        }
+    }
+
+    /// Get a cached price.
+    pub fn get_price(&self, price_id: &PriceID) -> Option<Price> {
+        self.read().prices.get(price_id).cloned()
+    }
+
+    /// Get a cached product.
+    pub fn get_product(&self, product_id: &ProductID) -> Option<Product> {
+        self.read().products.get(product_id).cloned()
+    }
+
+    /// All active prices of active products.
+    pub fn list_active_prices(&self) -> Vec<Price> {
+        let catalog = self.read();
+
+        catalog
+            .prices
+            .values()
+            .filter(|price| price.status == Status::Active)
+            .filter(|price| {
+                catalog
+                    .products
+                    .get(&price.product_id)
+                    .is_some_and(|product| product.status == Status::Active)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Active prices of a product.
+    pub fn active_prices_for_product(&self, product_id: &ProductID) -> Vec<Price> {
+        self.read()
+            .prices
+            .values()
+            .filter(|price| &price.product_id == product_id && price.status == Status::Active)
+            .cloned()
+            .collect()
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, Catalog> {
+        self.catalog.read().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Catalog> {
+        self.catalog.write().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+/// Insert or replace an entity, unless the cached one was updated more recently.
+fn upsert<ID: std::hash::Hash + Eq, T>(
+    entities: &mut HashMap<ID, T>,
+    id: ID,
+    entity: T,
+    updated_at: impl Fn(&T) -> chrono::DateTime<chrono::Utc>,
+) -> bool {
+    if let Some(cached) = entities.get(&id) {
+        if updated_at(cached) > updated_at(&entity) {
+            return false;
+        }
+    }
+
+    entities.insert(id, entity);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::fixtures;
+
+    const PRODUCT: &str = "pro_01gsz4t5hdjse780zja8vvr0001";
+    const MONTHLY: &str = "pri_01gsz8x8sawmvhz1pv30nge0001";
+    const YEARLY: &str = "pri_01gsz8x8sawmvhz1pv30nge0002";
+    const LEGACY: &str = "pri_01gsz8x8sawmvhz1pv30nge0003";
+
+    /// Serve [PRODUCT] with an active [MONTHLY] and [YEARLY] price and an archived [LEGACY] price, counting requests.
+    async fn serve_catalog(requests: Arc<AtomicUsize>) -> String {
+        fixtures::serve_responses(move |request: String| {
+            requests.fetch_add(1, Ordering::SeqCst);
+
+            async move {
+                let target = request.split(' ').nth(1).unwrap_or_default();
+                let archived = target.contains("status=archived");
+
+                let data = if target.starts_with("/products") {
+                    if archived {
+                        json!([])
+                    } else {
+                        json!([fixtures::product(PRODUCT)])
+                    }
+                } else if archived {
+                    let mut legacy = fixtures::price(LEGACY, PRODUCT, 900);
+                    legacy["status"] = "archived".into();
+                    json!([legacy])
+                } else {
+                    json!([
+                        fixtures::price(MONTHLY, PRODUCT, 1000),
+                        fixtures::price(YEARLY, PRODUCT, 10000)
+                    ])
+                };
+
+                fixtures::MockResponse::ok(json!({
+                    "data": data,
+                    "meta": {
+                        "request_id": "req_1",
+                        "pagination": {
+                            "per_page": 200,
+                            "next": "http://localhost/next",
+                            "has_more": false,
+                            "estimated_total": 2
+                        }
+                    }
+                }))
+            }
+        })
+        .await
+    }
+
+    /// `price.updated` event for [MONTHLY] with the given amount, updated at `updated_at`.
+    fn price_updated(amount: u64, updated_at: &str) -> Event {
+        let mut price: Value = fixtures::price(MONTHLY, PRODUCT, amount);
+        price["updated_at"] = updated_at.into();
+        fixtures::event("price.updated", price)
+    }
+
+    #[tokio::test]
+    async fn lookups_are_served_from_the_cache() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let url = serve_catalog(requests.clone()).await;
+        let client = Paddle::new("key", url.as_str()).unwrap();
+
+        let catalog = CatalogCache::warm(&client).await.unwrap();
+        let warm_requests = requests.load(Ordering::SeqCst);
+
+        // Hits, including archived prices
+        assert!(catalog.get_product(&PRODUCT.into()).is_some());
+        assert!(catalog.get_price(&MONTHLY.into()).is_some());
+        assert!(catalog.get_price(&LEGACY.into()).is_some());
+
+        // Misses
+        assert!(catalog
+            .get_price(&"pri_01gsz8x8sawmvhz1pv30nge0009".into())
+            .is_none());
+        assert!(catalog
+            .get_product(&"pro_01gsz4t5hdjse780zja8vvr0009".into())
+            .is_none());
+
+        let mut active: Vec<_> = catalog
+            .list_active_prices()
+            .into_iter()
+            .map(|price| price.id)
+            .collect();
+        active.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+        assert_eq!(active, [PriceID::from(MONTHLY), PriceID::from(YEARLY)]);
+
+        assert_eq!(requests.load(Ordering::SeqCst), warm_requests);
+    }
+
+    #[tokio::test]
+    async fn events_invalidate_cached_entities() {
+        let url = serve_catalog(Arc::new(AtomicUsize::new(0))).await;
+        let client = Paddle::new("key", url.as_str()).unwrap();
+        let catalog = CatalogCache::warm(&client).await.unwrap();
+
+        assert!(catalog.apply_event(price_updated(1200, "2024-05-01T00:00:00Z")));
+        let price = catalog.get_price(&MONTHLY.into()).unwrap();
+        assert_eq!(price.unit_price.amount, "1200");
+
+        // Archiving the product hides its prices
+        let mut product = fixtures::product(PRODUCT);
+        product["status"] = "archived".into();
+        product["updated_at"] = "2024-05-01T00:00:00Z".into();
+        assert!(catalog.apply_event(fixtures::event("product.updated", product)));
+        assert!(catalog.list_active_prices().is_empty());
+
+        // Unrelated events are ignored
+        assert!(!catalog.apply_event(fixtures::event("customer.updated", fixtures::customer())));
+    }
+
+    #[tokio::test]
+    async fn stale_events_are_ignored() {
+        let url = serve_catalog(Arc::new(AtomicUsize::new(0))).await;
+        let client = Paddle::new("key", url.as_str()).unwrap();
+        let catalog = CatalogCache::warm(&client).await.unwrap();
+
+        assert!(catalog.apply_event(price_updated(1200, "2024-05-01T00:00:00Z")));
+        assert!(!catalog.apply_event(price_updated(1100, "2024-04-20T00:00:00Z")));
+
+        let price = catalog.get_price(&MONTHLY.into()).unwrap();
+        assert_eq!(price.unit_price.amount, "1200");
+    }
+
+    #[tokio::test]
+    async fn refresh_replaces_the_cached_catalog() {
+        let url = serve_catalog(Arc::new(AtomicUsize::new(0))).await;
+        let client = Paddle::new("key", url.as_str()).unwrap();
+        let catalog = CatalogCache::default();
+        assert!(catalog.get_price(&MONTHLY.into()).is_none());
+
+        catalog.apply_event(price_updated(1200, "2024-05-01T00:00:00Z"));
+        catalog.refresh(&client).await.unwrap();
+
+        let price = catalog.get_price(&MONTHLY.into()).unwrap();
+        assert_eq!(price.unit_price.amount, "1000");
+        assert_eq!(catalog.active_prices_for_product(&PRODUCT.into()).len(), 2);
+    }
+}
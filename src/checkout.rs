@@ -0,0 +1,214 @@
+//! Shortcut for the most common checkout flow - selling a single catalog price.
+//!
+//! [Paddle::quick_checkout] creates a transaction for one price and returns the link to Paddle Checkout. Use [Paddle::transaction_create] when you need more control over the transaction.
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::Paddle;
+//!
+//! let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+//! let checkout = client
+//!     .quick_checkout("pri_01gsz8x8sawmvhz1pv30nge1ke", 1, Some("customer@example.com"))
+//!     .await
+//!     .unwrap();
+//!
+//! println!("Redirect the customer to {:?}", checkout.url);
+//! ```
+
+use crate::entities::Transaction;
+use crate::enums::Status;
+use crate::ids::{CustomerID, PriceID, TransactionID};
+use crate::{Error, Paddle};
+
+/// Transaction created by [Paddle::quick_checkout].
+#[derive(Clone, Debug)]
+pub struct CheckoutInfo {
+    /// Paddle ID of the created transaction.
+    pub transaction_id: TransactionID,
+    /// Paddle Checkout URL for the transaction. `None` if no default payment link is set for your account.
+    pub url: Option<String>,
+    /// Paddle ID of the customer the transaction is for, if an email was given.
+    pub customer_id: Option<CustomerID>,
+    /// The created transaction.
+    pub transaction: Transaction,
+}
+
+impl Paddle {
+    /// Create a transaction for a single catalog price and return its checkout URL.
+    ///
+    /// When `customer_email` is given, the transaction is created for the customer with that email. The customer is reactivated if it's archived, or created first if there's no such customer yet. Otherwise the customer enters their email in the checkout.
+    ///
+    /// Your default payment link is used as the checkout URL. Set it in the Paddle dashboard under Paddle > Checkout > Checkout settings.
+    pub async fn quick_checkout(
+        &self,
+        price_id: impl Into<PriceID>,
+        quantity: u32,
+        customer_email: Option<&str>,
+    ) -> std::result::Result<CheckoutInfo, Error> {
+        let customer_id = match customer_email {
+            Some(email) => Some(self.customer_id_for_email(email).await?),
+            None => None,
+        };
+
+        self.checkout_for(price_id, quantity, customer_id).await
+    }
+
+    /// Create a transaction for a single catalog price, for `customer_id` if given.
+    pub(crate) async fn checkout_for(
+        &self,
+        price_id: impl Into<PriceID>,
+        quantity: u32,
+        customer_id: Option<CustomerID>,
+    ) -> std::result::Result<CheckoutInfo, Error> {
+        let mut request = self
+            .transaction_create()
+            .append_catalog_item(price_id, quantity);
+
+        if let Some(customer_id) = &customer_id {
+            request = request.customer_id(customer_id.clone());
+        }
+
+        let transaction = request.send().await?.data;
+
+        Ok(CheckoutInfo {
+            transaction_id: transaction.id.clone(),
+            url: transaction.checkout.url.clone(),
+            customer_id,
+            transaction,
+        })
+    }
+
+    /// Find the customer with the given email or create one. An archived customer is reactivated.
+    async fn customer_id_for_email(&self, email: &str) -> std::result::Result<CustomerID, Error> {
+        // Only active customers are returned unless another status is requested
+        for status in [Status::Active, Status::Archived] {
+            let existing = self
+                .customers_list()
+                .emails([email])
+                .status(status)
+                .per_page(1)
+                .send()
+                .next()
+                .await?
+                .and_then(|page| page.data.into_iter().next());
+
+            // Emails are unique, so an archived customer must be reactivated instead of creating a new one
+            match existing {
+                Some(customer) if customer.status == Status::Archived => {
+                    return Ok(self
+                        .customer_update(customer.id)
+                        .status(Status::Active)
+                        .send()
+                        .await?
+                        .data
+                        .id);
+                }
+                Some(customer) => return Ok(customer.id),
+                None => {}
+            }
+        }
+
+        Ok(self.customer_create(email).send().await?.data.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::fixtures;
+
+    /// Serve the customer with `status` for customer lists asking for that status, recording the request line and body of every request.
+    async fn serve_customer(status: &'static str, requests: Arc<Mutex<Vec<String>>>) -> String {
+        fixtures::serve_json_raw(move |request: String| {
+            let line = request.lines().next().unwrap_or_default().to_string();
+            let body = request.split_once("\r\n\r\n").map(|(_, body)| body);
+            requests
+                .lock()
+                .unwrap()
+                .push(format!("{line} {}", body.unwrap_or_default()));
+
+            async move {
+                let mut customer = fixtures::customer();
+                customer["status"] = status.into();
+
+                let data: Value = if line.starts_with("GET /customers") {
+                    if line.contains(&format!("status={status}")) {
+                        json!([customer])
+                    } else {
+                        json!([])
+                    }
+                } else if line.starts_with("PATCH /customers") {
+                    customer["status"] = "active".into();
+                    customer
+                } else {
+                    fixtures::transaction()
+                };
+
+                json!({
+                    "data": data,
+                    "meta": {
+                        "request_id": "req_1",
+                        "pagination": {
+                            "per_page": 1,
+                            "next": "http://localhost/customers",
+                            "has_more": false,
+                            "estimated_total": 1
+                        }
+                    }
+                })
+            }
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn existing_customer_is_reused() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let url = serve_customer("active", requests.clone()).await;
+        let client = Paddle::new("key", url.as_str()).unwrap();
+
+        let checkout = client
+            .quick_checkout("pri_01gsz8x8sawmvhz1pv30nge1ke", 1, Some("sam@example.com"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            checkout.customer_id.unwrap().as_ref(),
+            "ctm_01hv6y1jedq4p1n0yqn5ba3ky4"
+        );
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2, "{requests:?}");
+        assert!(requests[1].starts_with("POST /transactions"));
+        assert!(requests[1].contains(r#""customer_id":"ctm_01hv6y1jedq4p1n0yqn5ba3ky4""#));
+    }
+
+    #[tokio::test]
+    async fn archived_customer_is_reactivated() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let url = serve_customer("archived", requests.clone()).await;
+        let client = Paddle::new("key", url.as_str()).unwrap();
+
+        let checkout = client
+            .quick_checkout("pri_01gsz8x8sawmvhz1pv30nge1ke", 1, Some("sam@example.com"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            checkout.customer_id.unwrap().as_ref(),
+            "ctm_01hv6y1jedq4p1n0yqn5ba3ky4"
+        );
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 4, "{requests:?}");
+        assert!(requests[1].contains("status=archived"));
+        assert!(requests[2].starts_with("PATCH /customers/ctm_01hv6y1jedq4p1n0yqn5ba3ky4"));
+        assert!(requests[2].ends_with(r#"{"status":"active"}"#));
+        assert!(!requests
+            .iter()
+            .any(|request| request.starts_with("POST /customers")));
+    }
+}
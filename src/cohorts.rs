@@ -0,0 +1,741 @@
+//! Helpers for finding every subscription billed for a price or product, and for moving those subscriptions to another price.
+//!
+//! Useful when deprecating a price or migrating subscribers to a new plan.
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::Paddle;
+//!
+//! let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+//! let subscriptions = client.subscriptions_for_product("pro_01h7zcgmdc6tmwtjehp3sh7azf").await.unwrap();
+//!
+//! for subscription in subscriptions {
+//!     println!("{} {:?}", subscription.id, subscription.status);
+//! }
+//! ```
+//!
+//! Before raising a price, [Paddle::price_change_notices] previews the new amount for every affected subscription and groups the results by customer, ready to be sent out as notice emails.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use crate::entities::{Money, Subscription, SubscriptionPreview};
+use crate::enums::{ProrationBillingMode, Status, SubscriptionInclude, SubscriptionStatus};
+use crate::ids::{CustomerID, PriceID, ProductID, SubscriptionID};
+use crate::transactions::TransactionItem;
+use crate::{Error, Paddle};
+
+/// Maximum number of price IDs sent in a single `price_id` filter.
+const PRICE_IDS_PER_REQUEST: usize = 50;
+
+/// Subscription statuses considered part of a cohort. Canceled subscriptions are never billed again, so they're left out.
+const COHORT_STATUSES: [SubscriptionStatus; 4] = [
+    SubscriptionStatus::Active,
+    SubscriptionStatus::Trialing,
+    SubscriptionStatus::PastDue,
+    SubscriptionStatus::Paused,
+];
+
+/// Delay between requests made by [Paddle::migrate_price] and [Paddle::price_change_notices]. Keeps a migration well below the Paddle API rate limit.
+const MIGRATION_REQUEST_DELAY: Duration = Duration::from_millis(250);
+
+/// Result of migrating a single subscription with [Paddle::migrate_price].
+#[derive(Debug)]
+pub struct PriceMigration {
+    /// Subscription this result is for.
+    pub subscription_id: SubscriptionID,
+    /// What happened to the subscription.
+    pub outcome: PriceMigrationOutcome,
+}
+
+/// Outcome of migrating a single subscription.
+#[derive(Debug)]
+pub enum PriceMigrationOutcome {
+    /// Dry run. The change was previewed but not applied.
+    Previewed(Box<SubscriptionPreview>),
+    /// The change was previewed and applied.
+    Migrated {
+        preview: Box<SubscriptionPreview>,
+        subscription: Box<Subscription>,
+    },
+    /// Previewing or applying the change failed. The subscription was left as it was.
+    Failed(Error),
+}
+
+impl PriceMigration {
+    /// Whether the subscription was previewed or migrated without errors.
+    pub fn is_success(&self) -> bool {
+        !matches!(self.outcome, PriceMigrationOutcome::Failed(_))
+    }
+}
+
+/// Subscriptions of a single customer affected by a price change. Returned by [Paddle::price_change_notices].
+#[derive(Clone, Debug)]
+pub struct CustomerPriceChange {
+    pub customer_id: CustomerID,
+    pub email: String,
+    pub name: Option<String>,
+    /// Affected subscriptions of the customer.
+    pub subscriptions: Vec<SubscriptionPriceChange>,
+}
+
+/// How a price change affects the recurring total of a subscription.
+#[derive(Clone, Debug)]
+pub struct SubscriptionPriceChange {
+    pub subscription_id: SubscriptionID,
+    /// Recurring total the customer is billed today, including tax and discounts.
+    pub current_total: Money,
+    /// Recurring total the customer will be billed once the subscription is moved to the new price.
+    pub new_total: Money,
+    /// Next billing date of the subscription, the first date the new total is charged. `None` if the subscription isn't billed again, e.g. when paused.
+    pub effective_at: Option<DateTime<Utc>>,
+}
+
+/// Result of [Paddle::price_change_notices].
+#[derive(Debug)]
+pub struct PriceChangeNotices {
+    /// Affected customers, in the order their first subscription was returned by Paddle.
+    pub customers: Vec<CustomerPriceChange>,
+    /// Subscriptions that couldn't be previewed along with their error.
+    pub failed: Vec<(SubscriptionID, Error)>,
+}
+
+impl Paddle {
+    /// Fetch all subscriptions that haven't been canceled and have an item for the given price.
+    ///
+    /// Goes through every page of results, so this may issue several requests.
+    pub async fn subscriptions_for_price(
+        &self,
+        price_id: impl Into<PriceID>,
+    ) -> std::result::Result<Vec<Subscription>, Error> {
+        self.subscriptions_for_prices([price_id.into()]).await
+    }
+
+    /// Fetch all subscriptions that haven't been canceled and have an item for any price of the given product.
+    ///
+    /// Prices of the product are listed first, including archived ones, and subscriptions are then fetched by price.
+    pub async fn subscriptions_for_product(
+        &self,
+        product_id: impl Into<ProductID>,
+    ) -> std::result::Result<Vec<Subscription>, Error> {
+        let product_id = product_id.into();
+        let mut price_ids = Vec::new();
+
+        // Only active prices are returned unless another status is requested
+        for status in [Status::Active, Status::Archived] {
+            let prices = self
+                .prices_list()
+                .product_ids([product_id.clone()])
+                .status(status)
+                .max_page_size()
+                .send()
+                .all()
+                .await?;
+
+            price_ids.extend(prices.into_iter().map(|price| price.id));
+        }
+
+        self.subscriptions_for_prices(price_ids).await
+    }
+
+    /// Fetch all subscriptions that haven't been canceled and have an item for any of the given prices.
+    ///
+    /// Each subscription is returned once, even if it has items for several of the prices.
+    pub async fn subscriptions_for_prices(
+        &self,
+        price_ids: impl IntoIterator<Item = impl Into<PriceID>>,
+    ) -> std::result::Result<Vec<Subscription>, Error> {
+        let price_ids = price_ids
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<PriceID>>();
+        let mut seen = HashSet::new();
+        let mut subscriptions = Vec::new();
+
+        for chunk in price_ids.chunks(PRICE_IDS_PER_REQUEST) {
+            let page = self
+                .subscriptions_list()
+                .price_ids(chunk.iter().cloned())
+                .status(COHORT_STATUSES)
+                .max_page_size()
+                .send()
+                .all()
+                .await?;
+
+            subscriptions.extend(
+                page.into_iter()
+                    .filter(|subscription| seen.insert(subscription.id.clone())),
+            );
+        }
+
+        Ok(subscriptions)
+    }
+
+    /// Move every subscription that hasn't been canceled from one price to another.
+    ///
+    /// For each subscription, the item for `from_price` is replaced with `to_price`, keeping its quantity and all other items. The change is previewed first and, unless `dry_run` is set, then applied using `proration_mode`.
+    ///
+    /// Subscriptions are processed one at a time with a delay between requests. A failure for one subscription is recorded in its [PriceMigration] and doesn't stop the migration. Only failing to list the affected subscriptions returns an error.
+    ///
+    /// # Example:
+    ///
+    /// ```rust,no_run
+    /// use paddle_rust_sdk::Paddle;
+    /// use paddle_rust_sdk::enums::ProrationBillingMode;
+    ///
+    /// let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+    ///
+    /// let report = client
+    ///     .migrate_price("pri_01gsz8x8sawmvhz1pv30nge1ke", "pri_01h1vjfevh5etwq3rb416a23h2", ProrationBillingMode::ProratedNextBillingPeriod, true)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// for migration in report.iter().filter(|migration| !migration.is_success()) {
+    ///     dbg!(migration);
+    /// }
+    /// ```
+    pub async fn migrate_price(
+        &self,
+        from_price: impl Into<PriceID>,
+        to_price: impl Into<PriceID>,
+        proration_mode: ProrationBillingMode,
+        dry_run: bool,
+    ) -> std::result::Result<Vec<PriceMigration>, Error> {
+        let from_price = from_price.into();
+        let to_price = to_price.into();
+
+        let subscriptions = self.subscriptions_for_price(from_price.clone()).await?;
+        let mut report = Vec::with_capacity(subscriptions.len());
+
+        for subscription in subscriptions {
+            let items = || migrated_items(&subscription, &from_price, &to_price);
+
+            tokio::time::sleep(MIGRATION_REQUEST_DELAY).await;
+
+            let preview = self
+                .subscription_preview_update(subscription.id.clone())
+                .items(items())
+                .proration_billing_mode(proration_mode)
+                .send()
+                .await;
+
+            let outcome = match preview {
+                Err(err) => PriceMigrationOutcome::Failed(err),
+                Ok(preview) if dry_run => PriceMigrationOutcome::Previewed(Box::new(preview.data)),
+                Ok(preview) => {
+                    tokio::time::sleep(MIGRATION_REQUEST_DELAY).await;
+
+                    match self
+                        .subscription_update(subscription.id.clone())
+                        .items(items())
+                        .proration_billing_mode(proration_mode)
+                        .send()
+                        .await
+                    {
+                        Ok(updated) => PriceMigrationOutcome::Migrated {
+                            preview: Box::new(preview.data),
+                            subscription: Box::new(updated.data),
+                        },
+                        Err(err) => PriceMigrationOutcome::Failed(err),
+                    }
+                }
+            };
+
+            report.push(PriceMigration {
+                subscription_id: subscription.id,
+                outcome,
+            });
+        }
+
+        Ok(report)
+    }
+}
+
+impl Paddle {
+    /// Preview how moving from one price to another changes what every affected customer pays.
+    ///
+    /// For each subscription that hasn't been canceled and has an item for `from_price`, the current recurring total is fetched and the total after swapping `from_price` for `to_price` is previewed. Nothing is changed. Apply the change with [Paddle::migrate_price] once customers have been notified.
+    ///
+    /// Subscriptions are processed one at a time with a delay between requests. A failure for one subscription is recorded in [PriceChangeNotices::failed] and doesn't stop the others. Only failing to list the affected subscriptions or their customers returns an error.
+    ///
+    /// # Example:
+    ///
+    /// ```rust,no_run
+    /// use paddle_rust_sdk::Paddle;
+    ///
+    /// let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+    ///
+    /// let notices = client
+    ///     .price_change_notices("pri_01gsz8x8sawmvhz1pv30nge1ke", "pri_01h1vjfevh5etwq3rb416a23h2")
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// for customer in notices.customers {
+    ///     for change in customer.subscriptions {
+    ///         println!(
+    ///             "{}: {} -> {} from {:?}",
+    ///             customer.email, change.current_total.amount, change.new_total.amount, change.effective_at
+    ///         );
+    ///     }
+    /// }
+    /// ```
+    pub async fn price_change_notices(
+        &self,
+        from_price: impl Into<PriceID>,
+        to_price: impl Into<PriceID>,
+    ) -> std::result::Result<PriceChangeNotices, Error> {
+        let from_price = from_price.into();
+        let to_price = to_price.into();
+
+        let subscriptions = self.subscriptions_for_price(from_price.clone()).await?;
+
+        let mut changes = Vec::with_capacity(subscriptions.len());
+        let mut failed = Vec::new();
+
+        for subscription in subscriptions {
+            match self
+                .subscription_price_change(&subscription, &from_price, &to_price)
+                .await
+            {
+                Ok(change) => changes.push((subscription.customer_id, change)),
+                Err(err) => failed.push((subscription.id, err)),
+            }
+        }
+
+        // Archived customers are fetched too, only customers that don't exist are reported as failed
+        let found = self
+            .get_customers(changes.iter().map(|(customer_id, _)| customer_id.clone()))
+            .await?
+            .found;
+
+        let mut customers: Vec<CustomerPriceChange> = Vec::new();
+
+        for (customer_id, change) in changes {
+            if let Some(customer) = customers
+                .iter_mut()
+                .find(|customer| customer.customer_id == customer_id)
+            {
+                customer.subscriptions.push(change);
+                continue;
+            }
+
+            match found.get(&customer_id) {
+                Some(customer) => customers.push(CustomerPriceChange {
+                    customer_id,
+                    email: customer.email.clone(),
+                    name: customer.name.clone(),
+                    subscriptions: vec![change],
+                }),
+                None => failed.push((
+                    change.subscription_id,
+                    Error::NotFound(customer_id.to_string()),
+                )),
+            }
+        }
+
+        Ok(PriceChangeNotices { customers, failed })
+    }
+
+    /// Current and previewed recurring totals of a single subscription.
+    async fn subscription_price_change(
+        &self,
+        subscription: &Subscription,
+        from_price: &PriceID,
+        to_price: &PriceID,
+    ) -> std::result::Result<SubscriptionPriceChange, Error> {
+        tokio::time::sleep(MIGRATION_REQUEST_DELAY).await;
+
+        let current = self
+            .subscription_get(subscription.id.clone())
+            .include([SubscriptionInclude::RecurringTransactionDetails])
+            .send()
+            .await?
+            .data
+            .recurring_transaction_details
+            .ok_or_else(|| Error::NoRecurringTransaction(subscription.id.clone()))?;
+
+        tokio::time::sleep(MIGRATION_REQUEST_DELAY).await;
+
+        let preview = self
+            .subscription_preview_update(subscription.id.clone())
+            .items(migrated_items(subscription, from_price, to_price))
+            .proration_billing_mode(ProrationBillingMode::DoNotBill)
+            .send()
+            .await?
+            .data;
+
+        Ok(SubscriptionPriceChange {
+            subscription_id: subscription.id.clone(),
+            current_total: current.totals.total_money(),
+            new_total: preview.recurring_transaction_details.totals.total_money(),
+            effective_at: subscription.next_billed_at,
+        })
+    }
+}
+
+/// Recurring items of `subscription` with `from_price` swapped for `to_price`.
+fn migrated_items(
+    subscription: &Subscription,
+    from_price: &PriceID,
+    to_price: &PriceID,
+) -> Vec<TransactionItem> {
+    subscription
+        .items
+        .iter()
+        .filter(|item| item.recurring)
+        .map(|item| TransactionItem::CatalogItem {
+            price_id: if item.price.id == *from_price {
+                to_price.clone()
+            } else {
+                item.price.id.clone()
+            },
+            quantity: item.quantity as u32,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    const OLD_PRICE: &str = "pri_01gsz8x8sawmvhz1pv30nge0001";
+    const NEW_PRICE: &str = "pri_01h1vjfevh5etwq3rb416a23h2";
+    const MIGRATED: &str = "sub_01hv8x29kz0t586xy6zn1a62ny";
+    const FAILING: &str = "sub_01hv8x29kz0t586xy6zn1a0002";
+
+    #[test]
+    fn only_the_old_price_is_swapped() {
+        let mut subscription = fixtures::subscription();
+        let mut other = subscription["items"][0].clone();
+        other["price"]["id"] = "pri_01gsz8x8sawmvhz1pv30nge0002".into();
+        other["quantity"] = 3.into();
+        let mut one_time = other.clone();
+        one_time["recurring"] = false.into();
+        subscription["items"]
+            .as_array_mut()
+            .unwrap()
+            .extend([other, one_time]);
+
+        let items = migrated_items(
+            &fixtures::from_value(subscription),
+            &OLD_PRICE.into(),
+            &NEW_PRICE.into(),
+        );
+
+        assert_eq!(
+            serde_json::to_value(items).unwrap(),
+            json!([
+                { "price_id": NEW_PRICE, "quantity": 1 },
+                { "price_id": "pri_01gsz8x8sawmvhz1pv30nge0002", "quantity": 3 }
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn subscriptions_for_product_include_archived_prices() {
+        let url = fixtures::serve_json(|target| async move {
+            let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+
+            let data = match path {
+                "/prices" if query.contains("status=archived") => {
+                    json!([fixtures::price(OLD_PRICE, "pro_01", 1000)])
+                }
+                "/subscriptions" if query.contains(OLD_PRICE) => {
+                    json!([fixtures::subscription()])
+                }
+                _ => json!([]),
+            };
+
+            json!({
+                "data": data,
+                "meta": {
+                    "request_id": "req_1",
+                    "pagination": {
+                        "per_page": 200,
+                        "next": format!("http://localhost{path}"),
+                        "has_more": false,
+                        "estimated_total": 1
+                    }
+                }
+            })
+        })
+        .await;
+        let client = Paddle::new("test", url).unwrap();
+
+        let subscriptions = client.subscriptions_for_product("pro_01").await.unwrap();
+
+        assert_eq!(subscriptions.len(), 1);
+        assert_eq!(subscriptions[0].id.as_ref(), MIGRATED);
+    }
+
+    #[tokio::test]
+    async fn price_change_notices_include_archived_customers() {
+        let url = fixtures::serve_json(|target| async move {
+            let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+
+            let mut orphaned = fixtures::subscription();
+            orphaned["id"] = FAILING.into();
+            orphaned["customer_id"] = "ctm_01hv6y1jedq4p1n0yqn5ba0002".into();
+
+            let details = json!({
+                "tax_rates_used": [],
+                "totals": fixtures::transaction_totals(3000, None),
+                "line_items": []
+            });
+
+            let data = match path {
+                "/subscriptions" => json!([fixtures::subscription(), orphaned]),
+                // The customer is archived, the other one doesn't exist
+                "/customers" if query.contains("status=archived") => {
+                    let mut customer = fixtures::customer();
+                    customer["status"] = "archived".into();
+                    json!([customer])
+                }
+                "/customers" => json!([]),
+                _ => {
+                    let mut subscription = fixtures::subscription();
+                    subscription["recurring_transaction_details"] = details.clone();
+                    subscription["next_transaction"] = json!({
+                        "billing_period": fixtures::billing_period(),
+                        "details": details,
+                        "adjustments": []
+                    });
+                    subscription
+                }
+            };
+
+            json!({
+                "data": data,
+                "meta": {
+                    "request_id": "req_1",
+                    "pagination": {
+                        "per_page": 200,
+                        "next": format!("http://localhost{path}"),
+                        "has_more": false,
+                        "estimated_total": 2
+                    }
+                }
+            })
+        })
+        .await;
+        let client = Paddle::new("test", url).unwrap();
+
+        let notices = client
+            .price_change_notices(OLD_PRICE, NEW_PRICE)
+            .await
+            .unwrap();
+
+        assert_eq!(notices.customers.len(), 1);
+        assert_eq!(notices.customers[0].email, "sam@example.com");
+        assert_eq!(notices.failed.len(), 1);
+        assert_eq!(notices.failed[0].0.as_ref(), FAILING);
+        assert!(matches!(
+            &notices.failed[0].1,
+            Error::NotFound(id) if id == "ctm_01hv6y1jedq4p1n0yqn5ba0002"
+        ));
+    }
+
+    /// Serve two subscriptions for [OLD_PRICE]. Previewing [FAILING] returns an invalid response. Returns the base URL and the paths requested.
+    async fn serve_migration() -> (String, Arc<Mutex<Vec<String>>>) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let url = fixtures::serve_json({
+            let requests = requests.clone();
+
+            move |target| {
+                let path = target.split('?').next().unwrap().to_string();
+                requests.lock().unwrap().push(path.clone());
+
+                let mut failing = fixtures::subscription();
+                failing["id"] = FAILING.into();
+
+                let data = if path == "/subscriptions" {
+                    json!([fixtures::subscription(), failing])
+                } else if path == format!("/subscriptions/{FAILING}/preview") {
+                    json!({})
+                } else if path.ends_with("/preview") {
+                    let details = json!({
+                        "tax_rates_used": [],
+                        "totals": fixtures::transaction_totals(3000, Some(180)),
+                        "line_items": []
+                    });
+                    let mut preview = fixtures::subscription();
+                    preview["next_transaction"] = json!({
+                        "billing_period": fixtures::billing_period(),
+                        "details": details,
+                        "adjustments": []
+                    });
+                    preview["recurring_transaction_details"] = details;
+                    preview
+                } else {
+                    fixtures::subscription()
+                };
+
+                async move {
+                    json!({
+                        "data": data,
+                        "meta": {
+                            "request_id": "req_1",
+                            "pagination": {
+                                "per_page": 200,
+                                "next": "http://localhost/subscriptions",
+                                "has_more": false,
+                                "estimated_total": 2
+                            }
+                        }
+                    })
+                }
+            }
+        })
+        .await;
+
+        (url, requests)
+    }
+
+    #[tokio::test]
+    async fn dry_run_only_previews() {
+        let (url, requests) = serve_migration().await;
+        let client = Paddle::new("test", url).unwrap();
+
+        let report = client
+            .migrate_price(
+                OLD_PRICE,
+                NEW_PRICE,
+                ProrationBillingMode::ProratedNextBillingPeriod,
+                true,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(report.len(), 2);
+        assert!(matches!(
+            report[0].outcome,
+            PriceMigrationOutcome::Previewed(_)
+        ));
+        assert_eq!(report[1].subscription_id.as_ref(), FAILING);
+        assert!(!report[1].is_success());
+
+        assert_eq!(
+            *requests.lock().unwrap(),
+            [
+                "/subscriptions".to_string(),
+                format!("/subscriptions/{MIGRATED}/preview"),
+                format!("/subscriptions/{FAILING}/preview"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn failures_dont_stop_the_migration() {
+        let (url, requests) = serve_migration().await;
+        let client = Paddle::new("test", url).unwrap();
+
+        let report = client
+            .migrate_price(
+                OLD_PRICE,
+                NEW_PRICE,
+                ProrationBillingMode::ProratedImmediately,
+                false,
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            report[0].outcome,
+            PriceMigrationOutcome::Migrated { .. }
+        ));
+        assert!(matches!(
+            report[1].outcome,
+            PriceMigrationOutcome::Failed(Error::JsonError(_))
+        ));
+
+        // The failed subscription isn't updated
+        assert_eq!(
+            *requests.lock().unwrap(),
+            [
+                "/subscriptions".to_string(),
+                format!("/subscriptions/{MIGRATED}/preview"),
+                format!("/subscriptions/{MIGRATED}"),
+                format!("/subscriptions/{FAILING}/preview"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn price_change_notices_are_grouped_by_customer() {
+        let url = fixtures::serve_json(|target| async move {
+            let path = target.split('?').next().unwrap();
+
+            let mut second = fixtures::subscription();
+            second["id"] = "sub_01hv8x29kz0t586xy6zn1a0003".into();
+            let mut failing = fixtures::subscription();
+            failing["id"] = FAILING.into();
+
+            let details = |subtotal| {
+                json!({
+                    "tax_rates_used": [],
+                    "totals": fixtures::transaction_totals(subtotal, None),
+                    "line_items": []
+                })
+            };
+
+            let data = if path == "/subscriptions" {
+                json!([fixtures::subscription(), second, failing])
+            } else if path == "/customers" {
+                json!([fixtures::customer()])
+            } else if path == format!("/subscriptions/{FAILING}/preview") {
+                json!({})
+            } else if path.ends_with("/preview") {
+                let mut preview = fixtures::subscription();
+                preview["next_transaction"] = json!({
+                    "billing_period": fixtures::billing_period(),
+                    "details": details(4000),
+                    "adjustments": []
+                });
+                preview["recurring_transaction_details"] = details(4000);
+                preview
+            } else {
+                let mut subscription = fixtures::subscription();
+                subscription["recurring_transaction_details"] = details(3000);
+                subscription
+            };
+
+            json!({
+                "data": data,
+                "meta": {
+                    "request_id": "req_1",
+                    "pagination": {
+                        "per_page": 200,
+                        "next": "http://localhost/subscriptions",
+                        "has_more": false,
+                        "estimated_total": 3
+                    }
+                }
+            })
+        })
+        .await;
+        let client = Paddle::new("test", url).unwrap();
+
+        let notices = client
+            .price_change_notices(OLD_PRICE, NEW_PRICE)
+            .await
+            .unwrap();
+
+        assert_eq!(notices.customers.len(), 1);
+        let customer = &notices.customers[0];
+        assert_eq!(customer.email, "sam@example.com");
+        assert_eq!(customer.subscriptions.len(), 2);
+        assert_eq!(customer.subscriptions[0].current_total.amount, "3600");
+        assert_eq!(customer.subscriptions[0].new_total.amount, "4800");
+        assert!(customer.subscriptions[0].effective_at.is_some());
+
+        assert_eq!(notices.failed.len(), 1);
+        assert_eq!(notices.failed[0].0.as_ref(), FAILING);
+    }
+}
@@ -0,0 +1,289 @@
+//! Hooks for normalizing `custom_data` sent to and received from Paddle.
+//!
+//! Register a [CustomDataHook] on the client with [Paddle::with_custom_data_hook](crate::Paddle::with_custom_data_hook). It's called for every `custom_data` object in request bodies before they're sent and in responses before they're deserialized, including `custom_data` of nested entities like non-catalog prices. Set it on a [WebhookVerifier](crate::webhooks::WebhookVerifier) to normalize webhook events too.
+//!
+//! [CustomDataLimits] strips null values and rejects oversized `custom_data` before the request is made, instead of waiting for the API to reject it.
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::custom_data::CustomDataLimits;
+//! use paddle_rust_sdk::Paddle;
+//!
+//! let limits = CustomDataLimits {
+//!     strip_nulls: true,
+//!     max_keys: Some(50),
+//!     max_bytes: Some(2048),
+//! };
+//!
+//! let client = Paddle::new("your_api_key", Paddle::SANDBOX)
+//!     .unwrap()
+//!     .with_custom_data_hook(limits);
+//! ```
+
+use std::fmt;
+
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::response::{self, SuccessResponse};
+use crate::Error;
+
+/// Normalizes `custom_data` on the way to and from Paddle.
+pub trait CustomDataHook: fmt::Debug + Send + Sync {
+    /// Called with every `custom_data` object of a request body before the request is sent. Return an error to abort the request, e.g. [Error::CustomData].
+    fn outgoing(&self, custom_data: &mut Map<String, Value>) -> Result<(), Error> {
+        let _ = custom_data;
+        Ok(())
+    }
+
+    /// Called with every `custom_data` object of a response before it's deserialized.
+    fn incoming(&self, custom_data: &mut Map<String, Value>) {
+        let _ = custom_data;
+    }
+}
+
+/// [CustomDataHook] enforcing limits on `custom_data` before it's sent to Paddle.
+#[derive(Clone, Debug, Default)]
+pub struct CustomDataLimits {
+    /// Remove keys with `null` values, both from requests and responses.
+    pub strip_nulls: bool,
+    /// Maximum number of top level keys.
+    pub max_keys: Option<usize>,
+    /// Maximum size of `custom_data` serialized as JSON, in bytes.
+    pub max_bytes: Option<usize>,
+}
+
+impl CustomDataHook for CustomDataLimits {
+    fn outgoing(&self, custom_data: &mut Map<String, Value>) -> Result<(), Error> {
+        if self.strip_nulls {
+            custom_data.retain(|_, value| !value.is_null());
+        }
+
+        if let Some(max_keys) = self.max_keys {
+            if custom_data.len() > max_keys {
+                return Err(Error::CustomData(format!(
+                    "custom_data has {} keys, at most {} are allowed",
+                    custom_data.len(),
+                    max_keys
+                )));
+            }
+        }
+
+        if let Some(max_bytes) = self.max_bytes {
+            let size = serde_json::to_vec(custom_data)?.len();
+
+            if size > max_bytes {
+                return Err(Error::CustomData(format!(
+                    "custom_data is {} bytes, at most {} are allowed",
+                    size, max_bytes
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn incoming(&self, custom_data: &mut Map<String, Value>) {
+        if self.strip_nulls {
+            custom_data.retain(|_, value| !value.is_null());
+        }
+    }
+}
+
+/// Serialize a request body and run the hook on every `custom_data` object in it.
+pub(crate) fn normalize_outgoing(
+    request: &impl Serialize,
+    hook: &dyn CustomDataHook,
+) -> Result<Value, Error> {
+    let mut body = serde_json::to_value(request)?;
+    visit(&mut body, &mut |custom_data| hook.outgoing(custom_data))?;
+
+    Ok(body)
+}
+
+/// Parse a response body, running the hook on every `custom_data` object before deserializing it.
+pub(crate) fn from_slice<T: DeserializeOwned>(
+    status: StatusCode,
+    body: &[u8],
+    hook: &dyn CustomDataHook,
+) -> Result<SuccessResponse<T>, Error> {
+    if !status.is_success() {
+        return response::from_slice(status, body);
+    }
+
+    Ok(serde_json::from_value(normalize_incoming(body, hook)?)?)
+}
+
+/// Parse a JSON body, running the hook on every `custom_data` object. Used for responses and webhook events.
+pub(crate) fn normalize_incoming(body: &[u8], hook: &dyn CustomDataHook) -> Result<Value, Error> {
+    let mut body: Value = serde_json::from_slice(body)?;
+    visit(&mut body, &mut |custom_data| {
+        hook.incoming(custom_data);
+        Ok(())
+    })?;
+
+    Ok(body)
+}
+
+fn visit(
+    value: &mut Value,
+    f: &mut impl FnMut(&mut Map<String, Value>) -> Result<(), Error>,
+) -> Result<(), Error> {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                match value {
+                    // Contents of custom_data are not visited, they belong to the user
+                    Value::Object(custom_data) if key == "custom_data" => f(custom_data)?,
+                    value => visit(value, f)?,
+                }
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                visit(value, f)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    /// Adds a `seen` key to every `custom_data` object it's called with.
+    #[derive(Debug)]
+    struct MarkSeen;
+
+    impl CustomDataHook for MarkSeen {
+        fn outgoing(&self, custom_data: &mut Map<String, Value>) -> Result<(), Error> {
+            custom_data.insert("seen".to_string(), true.into());
+            Ok(())
+        }
+
+        fn incoming(&self, custom_data: &mut Map<String, Value>) {
+            custom_data.insert("seen".to_string(), true.into());
+        }
+    }
+
+    #[test]
+    fn nested_custom_data_is_visited_once() {
+        let request = json!({
+            "custom_data": { "nested": { "custom_data": { "kept": "as is" } } },
+            "items": [
+                { "price": { "custom_data": {} } },
+                { "price": { "custom_data": null } }
+            ]
+        });
+
+        let body = normalize_outgoing(&request, &MarkSeen).unwrap();
+
+        assert_eq!(
+            body,
+            json!({
+                "custom_data": {
+                    "nested": { "custom_data": { "kept": "as is" } },
+                    "seen": true
+                },
+                "items": [
+                    { "price": { "custom_data": { "seen": true } } },
+                    { "price": { "custom_data": null } }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn limits_strip_nulls() {
+        let limits = CustomDataLimits {
+            strip_nulls: true,
+            ..Default::default()
+        };
+
+        let body = normalize_outgoing(
+            &json!({ "custom_data": { "plan": "pro", "coupon": null } }),
+            &limits,
+        )
+        .unwrap();
+        assert_eq!(body, json!({ "custom_data": { "plan": "pro" } }));
+
+        let mut incoming = json!({ "coupon": null, "plan": "pro" });
+        limits.incoming(incoming.as_object_mut().unwrap());
+        assert_eq!(incoming, json!({ "plan": "pro" }));
+    }
+
+    #[test]
+    fn limits_reject_oversized_custom_data() {
+        let request = json!({ "custom_data": { "a": 1, "b": 2, "c": null } });
+
+        let limits = CustomDataLimits {
+            max_keys: Some(2),
+            ..Default::default()
+        };
+        let err = normalize_outgoing(&request, &limits).unwrap_err();
+        assert!(
+            matches!(&err, Error::CustomData(message) if message.contains("3 keys")),
+            "{err}"
+        );
+
+        // Nulls are stripped before the keys are counted
+        let limits = CustomDataLimits {
+            strip_nulls: true,
+            max_keys: Some(2),
+            ..Default::default()
+        };
+        assert!(normalize_outgoing(&request, &limits).is_ok());
+
+        let limits = CustomDataLimits {
+            max_bytes: Some(10),
+            ..Default::default()
+        };
+        let err = normalize_outgoing(&request, &limits).unwrap_err();
+        assert!(
+            matches!(&err, Error::CustomData(message) if message.contains("at most 10")),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn responses_are_normalized_before_deserializing() {
+        let body = json!({
+            "data": [{ "id": 1, "custom_data": {} }, { "id": 2, "custom_data": null }],
+            "meta": { "request_id": "req_1" }
+        });
+
+        let response: SuccessResponse<Value> =
+            from_slice(StatusCode::OK, body.to_string().as_bytes(), &MarkSeen).unwrap();
+        assert_eq!(
+            response.data,
+            json!([{ "id": 1, "custom_data": { "seen": true } }, { "id": 2, "custom_data": null }])
+        );
+    }
+
+    #[test]
+    fn error_responses_are_returned_as_errors() {
+        let body = json!({
+            "error": {
+                "type": "request_error",
+                "code": "not_found",
+                "detail": "Entity not found",
+                "documentation_url": "https://developer.paddle.com/errors/shared/not_found"
+            },
+            "meta": { "request_id": "req_1" }
+        });
+
+        let err = from_slice::<Value>(
+            StatusCode::NOT_FOUND,
+            body.to_string().as_bytes(),
+            &MarkSeen,
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::PaddleApi(err) if err.error.code == "not_found"));
+    }
+}
@@ -3,21 +3,26 @@
 //! See the [Paddle API](https://developer.paddle.com/api-reference/customers/overview) documentation for more information.
 
 use std::collections::HashMap;
+use std::ops::Deref;
+use std::time::{Duration, Instant};
 
 use reqwest::Method;
 use serde::Serialize;
 use serde_with::skip_serializing_none;
 
-use crate::entities::{CreditBalance, Customer, CustomerPortalSession};
+use crate::entities::{CreditBalances, Customer, CustomerPortalSession};
 use crate::enums::Status;
 use crate::ids::{CustomerID, SubscriptionID};
 use crate::paginated::Paginated;
 use crate::nullable::Nullable;
-use crate::{Paddle, Result};
+use crate::permissions::Permission;
+use crate::request::PaddleRequest;
+use crate::{Error, Paddle, Result};
 
 /// Request builder for fetching customers from Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct CustomersList<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -47,68 +52,97 @@ impl<'a> CustomersList<'a> {
     }
 
     /// Return entities after the specified Paddle ID when working with paginated endpoints. Used in the `meta.pagination.next` URL in responses for list operations.
-    pub fn after(&mut self, customer_id: impl Into<CustomerID>) -> &mut Self {
+    pub fn after(mut self, customer_id: impl Into<CustomerID>) -> Self {
         self.after = Some(customer_id.into());
         self
     }
 
     /// Return entities that exactly match the specified email addresses
-    pub fn emails(&mut self, emails: impl IntoIterator<Item = impl AsRef<str>>) -> &mut Self {
+    pub fn emails(mut self, emails: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
         self.email = Some(emails.into_iter().map(|s| s.as_ref().to_string()).collect());
         self
     }
 
     /// Return only the IDs specified.
-    pub fn ids(
-        &mut self,
-        customer_ids: impl IntoIterator<Item = impl Into<CustomerID>>,
-    ) -> &mut Self {
+    pub fn ids(mut self, customer_ids: impl IntoIterator<Item = impl Into<CustomerID>>) -> Self {
         self.id = Some(customer_ids.into_iter().map(Into::into).collect());
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: id
-    pub fn order_by_asc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_asc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[ASC]", field));
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: id
-    pub fn order_by_desc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_desc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[DESC]", field));
         self
     }
 
+    /// Number of entities Paddle returns per page when [Self::per_page] isn't set.
+    pub const DEFAULT_PER_PAGE: usize = 50;
+    /// Largest page size Paddle accepts for this endpoint.
+    pub const MAX_PER_PAGE: usize = 200;
+
     /// Set how many entities are returned per page. Paddle returns the maximum number of results if a number greater than the maximum is requested.
     /// Check `meta.pagination.per_page` in the response to see how many were returned.
     ///
     /// Default: `50`; Maximum: `200`.
-    pub fn per_page(&mut self, entities_per_page: usize) -> &mut Self {
+    pub fn per_page(mut self, entities_per_page: usize) -> Self {
         self.per_page = Some(entities_per_page);
         self
     }
 
+    /// Request the largest page Paddle allows for this endpoint. Use this to fetch everything in as few requests as possible.
+    pub fn max_page_size(self) -> Self {
+        self.per_page(Self::MAX_PER_PAGE)
+    }
+
     /// Return entities that match a search query. Searches `id`, `name`, and `email` fields. Use the email filter for precise matching of email addresses.
-    pub fn search(&mut self, term: impl Into<String>) -> &mut Self {
+    pub fn search(mut self, term: impl Into<String>) -> Self {
         self.search = Some(term.into());
         self
     }
 
     /// Return only prices with the specified status.
-    pub fn status(&mut self, status: Status) -> &mut Self {
+    pub fn status(mut self, status: Status) -> Self {
         self.status = Some(status);
         self
     }
 
     /// Returns a paginator for fetching pages of entities from Paddle
     pub fn send(&self) -> Paginated<'_, Vec<Customer>> {
-        Paginated::new(self.client, "/customers", self)
+        Paginated::new(self.client, &self.path(), self)
+    }
+}
+
+impl PaddleRequest for CustomersList<'_> {
+    type Output = Vec<Customer>;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        "/customers".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::CustomerRead]
     }
 }
 
 /// Request builder for creating customers in Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct CustomerCreate<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -130,31 +164,53 @@ impl<'a> CustomerCreate<'a> {
     }
 
     /// Full name of this customer. Required when creating transactions where `collection_mode` is `manual` (invoices).
-    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
         self.name = Some(name.into());
         self
     }
 
     /// Your own structured key-value data.
-    pub fn custom_data(&mut self, custom_data: HashMap<String, String>) -> &mut Self {
+    pub fn custom_data(mut self, custom_data: HashMap<String, String>) -> Self {
         self.custom_data = Some(custom_data);
         self
     }
 
     /// Valid IETF BCP 47 short form locale tag. If omitted, defaults to `en`.
-    pub fn locale(&mut self, locale: impl Into<String>) -> &mut Self {
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
         self.locale = Some(locale.into());
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Customer> {
-        self.client.send(self, Method::POST, "/customers").await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for CustomerCreate<'_> {
+    type Output = Customer;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        "/customers".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::CustomerWrite]
     }
 }
 
 /// Request builder for fetching a single customer from Paddle API.
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct CustomerGet<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -172,18 +228,34 @@ impl<'a> CustomerGet<'a> {
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Customer> {
-        self.client
-            .send(
-                self,
-                Method::GET,
-                &format!("/customers/{}", self.customer_id.as_ref()),
-            )
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for CustomerGet<'_> {
+    type Output = Customer;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!("/customers/{}", self.customer_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::CustomerRead]
     }
 }
 
 /// Request builder for updating a customer in Paddle API.
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct CustomerUpdate<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -215,53 +287,69 @@ impl<'a> CustomerUpdate<'a> {
     }
 
     /// Full name of this customer. Required when creating transactions where `collection_mode` is `manual` (invoices).
-    pub fn name(&mut self, name: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn name(mut self, name: impl Into<Nullable<String>>) -> Self {
         self.name = name.into();
         self
     }
 
     /// Email address for this customer.
-    pub fn email(&mut self, email: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn email(mut self, email: impl Into<Nullable<String>>) -> Self {
         self.email = email.into();
         self
     }
 
     /// Whether this entity can be used in Paddle.
-    pub fn status(&mut self, status: impl Into<Nullable<Status>>) -> &mut Self {
+    pub fn status(mut self, status: impl Into<Nullable<Status>>) -> Self {
         self.status = status.into();
         self
     }
 
     /// Your own structured key-value data.
     pub fn custom_data(
-        &mut self,
+        mut self,
         custom_data: impl Into<Nullable<HashMap<String, String>>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.custom_data = custom_data.into();
         self
     }
 
     /// Valid IETF BCP 47 short form locale tag.
-    pub fn locale(&mut self, locale: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn locale(mut self, locale: impl Into<Nullable<String>>) -> Self {
         self.locale = locale.into();
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Customer> {
-        self.client
-            .send(
-                self,
-                Method::PATCH,
-                &format!("/customers/{}", self.customer_id.as_ref()),
-            )
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for CustomerUpdate<'_> {
+    type Output = Customer;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn path(&self) -> String {
+        format!("/customers/{}", self.customer_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::CustomerWrite]
     }
 }
 
 /// Request builder for retrieving credit balances for each currency for a customer.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct CustomerCreditBalances<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -278,20 +366,36 @@ impl<'a> CustomerCreditBalances<'a> {
     }
 
     /// Send the request to Paddle and return the response.
-    pub async fn send(&self) -> Result<Vec<CreditBalance>> {
-        self.client
-            .send(
-                self,
-                Method::GET,
-                &format!("/customers/{}/credit-balances", self.customer_id.as_ref()),
-            )
-            .await
+    pub async fn send(&self) -> Result<CreditBalances> {
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for CustomerCreditBalances<'_> {
+    type Output = CreditBalances;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!("/customers/{}/credit-balances", self.customer_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::CustomerRead]
     }
 }
 
 /// Request builder for creating customer portal sessions
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct PortalSessionCreate<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -311,21 +415,129 @@ impl<'a> PortalSessionCreate<'a> {
 
     /// List of subscriptions to create authenticated customer portal deep links for.
     pub fn subscription_ids(
-        &mut self,
+        mut self,
         subscription_ids: impl IntoIterator<Item = impl Into<SubscriptionID>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.subscription_ids = Some(subscription_ids.into_iter().map(Into::into).collect());
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<CustomerPortalSession> {
-        self.client
-            .send(
-                self,
-                Method::POST,
-                &format!("/customers/{}/portal-sessions", self.customer_id.as_ref()),
-            )
+        self.client.execute(self).await
+    }
+
+    /// Send the request and wrap the session in a [GuardedPortalSession], which flags links used longer than `max_age` after they were received.
+    pub async fn send_guarded(
+        &self,
+        max_age: Duration,
+    ) -> std::result::Result<GuardedPortalSession, Error> {
+        let session = self.send().await?.data;
+
+        Ok(GuardedPortalSession {
+            session,
+            received_at: Instant::now(),
+            max_age,
+        })
+    }
+}
+
+/// Customer portal session that guards against its links being stored.
+///
+/// Portal links contain a temporary token and should be generated each time a customer needs them. This wrapper is neither `Clone` nor `Serialize`, and in debug builds it panics when the session is accessed more than `max_age` after it was received, which surfaces links that are accidentally cached.
+#[derive(Debug)]
+pub struct GuardedPortalSession {
+    session: CustomerPortalSession,
+    received_at: Instant,
+    max_age: Duration,
+}
+
+impl GuardedPortalSession {
+    /// Whether the session was received more than `max_age` ago.
+    pub fn is_stale(&self) -> bool {
+        self.received_at.elapsed() > self.max_age
+    }
+
+    /// Unwrap the session, removing the guard.
+    pub fn into_inner(self) -> CustomerPortalSession {
+        self.session
+    }
+}
+
+impl Deref for GuardedPortalSession {
+    type Target = CustomerPortalSession;
+
+    fn deref(&self) -> &Self::Target {
+        debug_assert!(
+            !self.is_stale(),
+            "customer portal session {} used {:?} after it was created. Create a new session instead of storing its links",
+            self.session.id,
+            self.received_at.elapsed()
+        );
+
+        &self.session
+    }
+}
+
+impl PaddleRequest for PortalSessionCreate<'_> {
+    type Output = CustomerPortalSession;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        format!("/customers/{}/portal-sessions", self.customer_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::CustomerPortalSessionWrite]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::enums::CurrencyCode;
+    use crate::fixtures;
+
+    #[tokio::test]
+    async fn credit_balances_by_currency() {
+        let url = fixtures::serve_json(|_| async {
+            json!({
+                "data": [{
+                    "customer_id": "ctm_01hv6y1jedq4p1n0yqn5ba3ky4",
+                    "currency_code": "USD",
+                    "balance": { "available": "2500", "reserved": "0", "used": "1000" }
+                }],
+                "meta": { "request_id": "req_1" }
+            })
+        })
+        .await;
+
+        let client = Paddle::new("key", url.as_str()).unwrap();
+        let balances = client
+            .customer_credit_balances("ctm_01hv6y1jedq4p1n0yqn5ba3ky4")
+            .send()
             .await
+            .unwrap()
+            .data;
+
+        assert_eq!(balances.len(), 1);
+        assert_eq!(
+            balances
+                .for_currency(CurrencyCode::USD)
+                .and_then(|balance| balance.used_minor()),
+            Some(1000)
+        );
+        assert_eq!(balances.available_minor(CurrencyCode::USD), Some(2500));
+        assert_eq!(balances.available_minor(CurrencyCode::EUR), Some(0));
     }
 }
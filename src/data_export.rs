@@ -0,0 +1,202 @@
+//! Collect everything Paddle stores about a customer, e.g. to answer a GDPR data subject access request.
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::data_export::INTERNAL_FIELDS;
+//! use paddle_rust_sdk::Paddle;
+//!
+//! let client = Paddle::new(std::env::var("PADDLE_API_KEY")?, Paddle::PRODUCTION)?;
+//!
+//! let export = client.export_customer_data("ctm_01hv6y1jedq4p1n0yqn5ba3ky4").await?;
+//!
+//! // Leave out data that's meant for internal use only before sending the export to the customer
+//! let json = serde_json::to_string_pretty(&export.redacted(INTERNAL_FIELDS))?;
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::entities::{
+    Address, Adjustment, Business, Customer, PaymentMethod, Subscription, Transaction,
+};
+use crate::enums::Status;
+use crate::ids::CustomerID;
+use crate::{Error, Paddle};
+
+/// Fields that usually hold data for internal use rather than data about the customer: your own `custom_data` and `import_meta` from migrations.
+pub const INTERNAL_FIELDS: &[&str] = &["custom_data", "import_meta"];
+
+/// Every entity related to a customer.
+#[derive(Clone, Debug, Serialize)]
+pub struct CustomerDataExport {
+    /// When the data was fetched from Paddle.
+    pub exported_at: DateTime<Utc>,
+    pub customer: Customer,
+    /// Active and archived addresses.
+    pub addresses: Vec<Address>,
+    /// Active and archived businesses.
+    pub businesses: Vec<Business>,
+    /// Saved payment methods.
+    pub payment_methods: Vec<PaymentMethod>,
+    pub subscriptions: Vec<Subscription>,
+    pub transactions: Vec<Transaction>,
+    pub adjustments: Vec<Adjustment>,
+}
+
+impl CustomerDataExport {
+    /// Serialize the export with every field named in `fields` removed, at any depth.
+    ///
+    /// Use this to leave out data that shouldn't be shared with the customer, see [INTERNAL_FIELDS].
+    pub fn redacted(&self, fields: &[&str]) -> Value {
+        let mut value = serde_json::to_value(self).unwrap_or_default();
+        remove_fields(&mut value, fields);
+        value
+    }
+}
+
+fn remove_fields(value: &mut Value, fields: &[&str]) {
+    match value {
+        Value::Object(object) => {
+            object.retain(|key, _| !fields.contains(&key.as_str()));
+            object
+                .values_mut()
+                .for_each(|value| remove_fields(value, fields));
+        }
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| remove_fields(value, fields)),
+        _ => {}
+    }
+}
+
+pub(crate) async fn export_customer_data(
+    client: &Paddle,
+    customer_id: CustomerID,
+) -> Result<CustomerDataExport, Error> {
+    let exported_at = Utc::now();
+    let customer = client.customer_get(customer_id.clone()).send().await?.data;
+
+    let mut addresses = Vec::new();
+    let mut businesses = Vec::new();
+
+    // Only active addresses and businesses are returned unless another status is requested
+    for status in [Status::Active, Status::Archived] {
+        addresses.extend(
+            client
+                .addresses_list(customer_id.clone())
+                .status(status)
+                .max_page_size()
+                .send()
+                .all()
+                .await?,
+        );
+
+        businesses.extend(
+            client
+                .businesses_list(customer_id.clone())
+                .status(status)
+                .max_page_size()
+                .send()
+                .all()
+                .await?,
+        );
+    }
+
+    let payment_methods = client
+        .payment_methods_list(customer_id.clone())
+        .max_page_size()
+        .send()
+        .all()
+        .await?;
+
+    let subscriptions = client
+        .subscriptions_list()
+        .customer_id([customer_id.clone()])
+        .max_page_size()
+        .send()
+        .all()
+        .await?;
+
+    let transactions = client
+        .transactions_list()
+        .customer_id([customer_id.clone()])
+        .max_page_size()
+        .send()
+        .all()
+        .await?;
+
+    let adjustments = client
+        .adjustments_list()
+        .customer_id([customer_id])
+        .max_page_size()
+        .send()
+        .all()
+        .await?;
+
+    Ok(CustomerDataExport {
+        exported_at,
+        customer,
+        addresses,
+        businesses,
+        payment_methods,
+        subscriptions,
+        transactions,
+        adjustments,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    #[tokio::test]
+    async fn collects_and_redacts_customer_data() {
+        let url = fixtures::serve_json(|target| async move {
+            let path = target.split('?').next().unwrap_or_default().to_string();
+
+            let data = match path.as_str() {
+                "/customers/ctm_01hv6y1jedq4p1n0yqn5ba3ky4" => {
+                    let mut customer = fixtures::customer();
+                    customer["custom_data"] = json!({"crm_id": "internal"});
+                    return json!({"data": customer, "meta": {"request_id": "req_1"}});
+                }
+                "/subscriptions" => json!([fixtures::subscription()]),
+                "/transactions" => json!([fixtures::transaction()]),
+                _ => json!([]),
+            };
+
+            json!({
+                "data": data,
+                "meta": {
+                    "request_id": "req_1",
+                    "pagination": {
+                        "per_page": 50,
+                        "next": format!("http://localhost{path}"),
+                        "has_more": false,
+                        "estimated_total": 1
+                    }
+                }
+            })
+        })
+        .await;
+
+        let client = Paddle::new("key", url).unwrap();
+        let export = client
+            .export_customer_data("ctm_01hv6y1jedq4p1n0yqn5ba3ky4")
+            .await
+            .unwrap();
+
+        assert_eq!(export.customer.email, "sam@example.com");
+        assert!(export.addresses.is_empty());
+        assert_eq!(export.subscriptions.len(), 1);
+        assert_eq!(export.transactions.len(), 1);
+
+        let redacted = export.redacted(INTERNAL_FIELDS);
+        assert!(redacted["customer"].get("custom_data").is_none());
+        assert!(redacted["subscriptions"][0].get("custom_data").is_none());
+        assert_eq!(redacted["customer"]["email"], "sam@example.com");
+    }
+}
@@ -0,0 +1,88 @@
+//! Defaults applied to new request builders.
+//!
+//! Services often send the same options with every request. Set them once on the client with [Paddle::with_defaults](crate::Paddle::with_defaults) instead. Defaults only prefill new builders, every value can still be overridden on a single request.
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::defaults::RequestDefaults;
+//! use paddle_rust_sdk::enums::{CollectionMode, CurrencyCode, SubscriptionInclude};
+//! use paddle_rust_sdk::Paddle;
+//!
+//! let defaults = RequestDefaults::default()
+//!     .collection_mode(CollectionMode::Manual)
+//!     .currency_code(CurrencyCode::EUR)
+//!     .subscription_include([SubscriptionInclude::NextTransaction]);
+//!
+//! let client = Paddle::new("your_api_key", Paddle::SANDBOX)
+//!     .unwrap()
+//!     .with_defaults(defaults);
+//!
+//! // Created with `collection_mode` set to `manual` and `currency_code` set to `EUR`
+//! let transaction = client.transaction_create().customer_id("ctm_01jqztc78e1xfdgwhcgjzdrvgd").send().await.unwrap();
+//! ```
+
+use crate::enums::{CollectionMode, CurrencyCode, SubscriptionInclude, TransactionInclude};
+
+/// Values prefilled on new request builders created by a client.
+#[derive(Clone, Debug, Default)]
+pub struct RequestDefaults {
+    pub(crate) collection_mode: Option<CollectionMode>,
+    pub(crate) currency_code: Option<CurrencyCode>,
+    pub(crate) transaction_include: Option<Vec<TransactionInclude>>,
+    pub(crate) subscription_include: Option<Vec<SubscriptionInclude>>,
+}
+
+impl RequestDefaults {
+    /// Collection mode of transactions created with [Paddle::transaction_create](crate::Paddle::transaction_create).
+    pub fn collection_mode(mut self, mode: CollectionMode) -> Self {
+        self.collection_mode = Some(mode);
+        self
+    }
+
+    /// Currency of transactions created with [Paddle::transaction_create](crate::Paddle::transaction_create).
+    pub fn currency_code(mut self, currency_code: CurrencyCode) -> Self {
+        self.currency_code = Some(currency_code);
+        self
+    }
+
+    /// Related entities included when creating transactions with [Paddle::transaction_create](crate::Paddle::transaction_create).
+    pub fn transaction_include(
+        mut self,
+        entities: impl IntoIterator<Item = TransactionInclude>,
+    ) -> Self {
+        self.transaction_include = Some(entities.into_iter().collect());
+        self
+    }
+
+    /// Related entities included when fetching subscriptions with [Paddle::subscription_get](crate::Paddle::subscription_get).
+    pub fn subscription_include(
+        mut self,
+        entities: impl IntoIterator<Item = SubscriptionInclude>,
+    ) -> Self {
+        self.subscription_include = Some(entities.into_iter().collect());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Paddle;
+
+    #[test]
+    fn prefills_new_builders() {
+        let client = Paddle::new("key", Paddle::SANDBOX).unwrap().with_defaults(
+            RequestDefaults::default()
+                .collection_mode(CollectionMode::Manual)
+                .currency_code(CurrencyCode::EUR),
+        );
+
+        let transaction = client.transaction_create();
+        let body = serde_json::to_value(&transaction).unwrap();
+        assert_eq!(body["collection_mode"], "manual");
+        assert_eq!(body["currency_code"], "EUR");
+
+        let transaction = transaction.currency_code(CurrencyCode::USD);
+        let body = serde_json::to_value(&transaction).unwrap();
+        assert_eq!(body["currency_code"], "USD");
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! See the [Paddle API](https://developer.paddle.com/api-reference/discounts/overview) documentation for more information.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, RandomState};
 
 use chrono::{DateTime, Utc};
 use reqwest::Method;
@@ -14,11 +15,14 @@ use crate::enums::{CurrencyCode, DiscountType, Status};
 use crate::ids::DiscountID;
 use crate::paginated::Paginated;
 use crate::nullable::Nullable;
-use crate::{Paddle, Result};
+use crate::permissions::Permission;
+use crate::request::PaddleRequest;
+use crate::{Error, Paddle, Result};
 
 /// Request builder for fetching discounts from Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct DiscountsList<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -46,62 +50,91 @@ impl<'a> DiscountsList<'a> {
     }
 
     /// Return entities after the specified Paddle ID when working with paginated endpoints. Used in the `meta.pagination.next` URL in responses for list operations.
-    pub fn after(&mut self, discount_id: impl Into<DiscountID>) -> &mut Self {
+    pub fn after(mut self, discount_id: impl Into<DiscountID>) -> Self {
         self.after = Some(discount_id.into());
         self
     }
 
     /// Return only entities that match the discount codes provided
-    pub fn codes(&mut self, codes: impl IntoIterator<Item = impl AsRef<str>>) -> &mut Self {
+    pub fn codes(mut self, codes: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
         self.code = Some(codes.into_iter().map(|s| s.as_ref().to_string()).collect());
         self
     }
 
     /// Return only the IDs specified.
-    pub fn ids(
-        &mut self,
-        discount_ids: impl IntoIterator<Item = impl Into<DiscountID>>,
-    ) -> &mut Self {
+    pub fn ids(mut self, discount_ids: impl IntoIterator<Item = impl Into<DiscountID>>) -> Self {
         self.id = Some(discount_ids.into_iter().map(Into::into).collect());
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: created_at and id
-    pub fn order_by_asc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_asc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[ASC]", field));
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: created_at and id
-    pub fn order_by_desc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_desc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[DESC]", field));
         self
     }
 
+    /// Number of entities Paddle returns per page when [Self::per_page] isn't set.
+    pub const DEFAULT_PER_PAGE: usize = 50;
+    /// Largest page size Paddle accepts for this endpoint.
+    pub const MAX_PER_PAGE: usize = 200;
+
     /// Set how many entities are returned per page. Paddle returns the maximum number of results if a number greater than the maximum is requested.
     /// Check `meta.pagination.per_page` in the response to see how many were returned.
     ///
     /// Default: `50`; Maximum: `200`.
-    pub fn per_page(&mut self, entities_per_page: usize) -> &mut Self {
+    pub fn per_page(mut self, entities_per_page: usize) -> Self {
         self.per_page = Some(entities_per_page);
         self
     }
 
+    /// Request the largest page Paddle allows for this endpoint. Use this to fetch everything in as few requests as possible.
+    pub fn max_page_size(self) -> Self {
+        self.per_page(Self::MAX_PER_PAGE)
+    }
+
     /// Return only prices with the specified status.
-    pub fn status(&mut self, status: Status) -> &mut Self {
+    pub fn status(mut self, status: Status) -> Self {
         self.status = Some(status);
         self
     }
 
     /// Returns a paginator for fetching pages of entities from Paddle
     pub fn send(&self) -> Paginated<'_, Vec<Discount>> {
-        Paginated::new(self.client, "/discounts", self)
+        Paginated::new(self.client, &self.path(), self)
+    }
+}
+
+impl PaddleRequest for DiscountsList<'_> {
+    type Output = Vec<Discount>;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        "/discounts".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::DiscountRead]
     }
 }
 
 /// Request builder for creating a discount in Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct DiscountCreate<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -144,7 +177,7 @@ impl<'a> DiscountCreate<'a> {
     }
 
     /// Whether this discount can be redeemed by customers at checkout (true) or not (false).
-    pub fn enabled_for_checkout(&mut self, enabled: bool) -> &mut Self {
+    pub fn enabled_for_checkout(mut self, enabled: bool) -> Self {
         self.enabled_for_checkout = enabled;
         self
     }
@@ -152,25 +185,25 @@ impl<'a> DiscountCreate<'a> {
     /// Unique code that customers can use to redeem this discount at checkout. Use letters and numbers only, up to 32 characters. Not case-sensitive.
     ///
     /// If omitted and enabled_for_checkout is true, Paddle generates a random 10-character code.
-    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+    pub fn code(mut self, code: impl Into<String>) -> Self {
         self.code = Some(code.into());
         self
     }
 
     /// Supported three-letter ISO 4217 currency code. Required where discount type is [DiscountType::Flat] or [DiscountType::FlatPerSeat].
-    pub fn currency_code(&mut self, currency_code: CurrencyCode) -> &mut Self {
+    pub fn currency_code(mut self, currency_code: CurrencyCode) -> Self {
         self.currency_code = Some(currency_code);
         self
     }
 
     /// Whether this discount applies for multiple subscription billing periods (`true`) or not (`false`). If omitted, defaults to `false`.
-    pub fn recur(&mut self, recur: bool) -> &mut Self {
+    pub fn recur(mut self, recur: bool) -> Self {
         self.recur = recur;
         self
     }
 
     /// Number of subscription billing periods that this discount recurs for. Requires recur. `null` if this discount recurs forever.
-    pub fn maximum_recurring_intervals(&mut self, maximum_recurring_intervals: u64) -> &mut Self {
+    pub fn maximum_recurring_intervals(mut self, maximum_recurring_intervals: u64) -> Self {
         self.maximum_recurring_intervals = Some(maximum_recurring_intervals);
         self
     }
@@ -178,16 +211,13 @@ impl<'a> DiscountCreate<'a> {
     /// Maximum number of times this discount can be redeemed. This is an overall limit for this discount, rather than a per-customer limit. `null` if this discount can be redeemed an unlimited amount of times.
     ///
     /// Paddle counts a usage as a redemption on a checkout, transaction, or the initial application against a subscription. Transactions created for subscription renewals, midcycle changes, and one-time charges aren't considered a redemption.
-    pub fn usage_limit(&mut self, usage_limit: u64) -> &mut Self {
+    pub fn usage_limit(mut self, usage_limit: u64) -> Self {
         self.usage_limit = Some(usage_limit);
         self
     }
 
     /// Product or price IDs that this discount is for. When including a product ID, all prices for that product can be discounted. `null` if this discount applies to all products and prices.
-    pub fn restrict_to(
-        &mut self,
-        restrict_to: impl IntoIterator<Item = impl AsRef<str>>,
-    ) -> &mut Self {
+    pub fn restrict_to(mut self, restrict_to: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
         self.restrict_to = Some(
             restrict_to
                 .into_iter()
@@ -200,25 +230,47 @@ impl<'a> DiscountCreate<'a> {
     /// Datetime when this discount expires. Discount can no longer be redeemed after this date has elapsed. `null` if this discount can be redeemed forever.
     ///
     /// Expired discounts can't be redeemed against transactions or checkouts, but can be applied when updating subscriptions.
-    pub fn expires_at(&mut self, expires_at: DateTime<Utc>) -> &mut Self {
+    pub fn expires_at(mut self, expires_at: DateTime<Utc>) -> Self {
         self.expires_at = Some(expires_at);
         self
     }
 
     /// Set custom data for this discount.
-    pub fn custom_data(&mut self, custom_data: HashMap<String, String>) -> &mut Self {
+    pub fn custom_data(mut self, custom_data: HashMap<String, String>) -> Self {
         self.custom_data = Some(custom_data);
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Discount> {
-        self.client.send(self, Method::POST, "/discounts").await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for DiscountCreate<'_> {
+    type Output = Discount;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        "/discounts".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::DiscountWrite]
     }
 }
 
 /// Request builder for fetching a single discount from Paddle API.
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct DiscountGet<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -236,18 +288,34 @@ impl<'a> DiscountGet<'a> {
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Discount> {
-        self.client
-            .send(
-                self,
-                Method::GET,
-                &format!("/discounts/{}", self.discount_id.as_ref()),
-            )
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for DiscountGet<'_> {
+    type Output = Discount;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!("/discounts/{}", self.discount_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::DiscountRead]
     }
 }
 
 /// Request builder for updating discounts in Paddle API.
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct DiscountUpdate<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -303,58 +371,58 @@ impl<'a> DiscountUpdate<'a> {
     }
 
     /// Whether this entity can be used in Paddle.
-    pub fn status(&mut self, status: impl Into<Nullable<Status>>) -> &mut Self {
+    pub fn status(mut self, status: impl Into<Nullable<Status>>) -> Self {
         self.status = status.into();
         self
     }
 
     /// Short description for this discount for your reference. Not shown to customers.
-    pub fn description(&mut self, description: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn description(mut self, description: impl Into<Nullable<String>>) -> Self {
         self.description = description.into();
         self
     }
 
     /// Whether this discount can be redeemed by customers at checkout (true) or not (false).
     pub fn enabled_for_checkout(
-        &mut self,
+        mut self,
         enabled: impl Into<Nullable<bool>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.enabled_for_checkout = enabled.into();
         self
     }
 
     /// Unique code that customers can use to redeem this discount at checkout. Not case-sensitive.
-    pub fn code(&mut self, code: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn code(mut self, code: impl Into<Nullable<String>>) -> Self {
         self.code = code.into();
         self
     }
 
     /// Type of discount. Determines how this discount impacts the checkout or transaction total.
     pub fn discount_type(
-        &mut self,
+        mut self,
         discount_type: impl Into<Nullable<DiscountType>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.r#type = discount_type.into();
         self
     }
 
     /// Amount to discount by. For percentage discounts, must be an amount between 0.01 and 100. For flat and flat_per_seat discounts, amount in the lowest denomination for a currency.
-    pub fn amount(&mut self, amount: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn amount(mut self, amount: impl Into<Nullable<String>>) -> Self {
         self.amount = amount.into();
         self
     }
 
     /// Supported three-letter ISO 4217 currency code. Required where discount type is [DiscountType::Flat] or [DiscountType::FlatPerSeat].
     pub fn currency_code(
-        &mut self,
+        mut self,
         currency_code: impl Into<Nullable<CurrencyCode>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.currency_code = currency_code.into();
         self
     }
 
     /// Whether this discount applies for multiple subscription billing periods (`true`) or not (`false`). If omitted, defaults to `false`.
-    pub fn recur(&mut self, recur: impl Into<Nullable<bool>>) -> &mut Self {
+    pub fn recur(mut self, recur: impl Into<Nullable<bool>>) -> Self {
         self.recur = recur.into();
         self
     }
@@ -363,24 +431,24 @@ impl<'a> DiscountUpdate<'a> {
     ///
     /// Subscription renewals, midcycle changes, and one-time charges billed to a subscription aren't considered a redemption. `times_used` is not incremented in these cases.
     pub fn maximum_recurring_intervals(
-        &mut self,
+        mut self,
         maximum_recurring_intervals: impl Into<Nullable<u64>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.maximum_recurring_intervals = maximum_recurring_intervals.into();
         self
     }
 
     /// Maximum number of times this discount can be redeemed. This is an overall limit for this discount, rather than a per-customer limit. `null` if this discount can be redeemed an unlimited amount of times.
-    pub fn usage_limit(&mut self, usage_limit: impl Into<Nullable<u64>>) -> &mut Self {
+    pub fn usage_limit(mut self, usage_limit: impl Into<Nullable<u64>>) -> Self {
         self.usage_limit = usage_limit.into();
         self
     }
 
     /// Product or price IDs that this discount is for. When including a product ID, all prices for that product can be discounted. `null` if this discount applies to all products and prices.
     pub fn restrict_to(
-        &mut self,
+        mut self,
         restrict_to: impl Into<Nullable<Vec<String>>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.restrict_to = restrict_to.into();
         self
     }
@@ -389,30 +457,289 @@ impl<'a> DiscountUpdate<'a> {
     ///
     /// Expired discounts can't be redeemed against transactions or checkouts, but can be applied when updating subscriptions.
     pub fn expires_at(
-        &mut self,
+        mut self,
         expires_at: impl Into<Nullable<DateTime<Utc>>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.expires_at = expires_at.into();
         self
     }
 
     /// Set custom data for this discount.
     pub fn custom_data(
-        &mut self,
+        mut self,
         custom_data: impl Into<Nullable<HashMap<String, String>>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.custom_data = custom_data.into();
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Discount> {
-        self.client
-            .send(
-                self,
-                Method::PATCH,
-                &format!("/discounts/{}", self.discount_id.as_ref()),
-            )
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for DiscountUpdate<'_> {
+    type Output = Discount;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn path(&self) -> String {
+        format!("/discounts/{}", self.discount_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::DiscountWrite]
+    }
+}
+
+/// Number of random characters appended to the code prefix by [generate_codes].
+const CODE_SUFFIX_LEN: usize = 8;
+
+/// Longest code Paddle accepts.
+const MAX_CODE_LEN: usize = 32;
+
+/// Characters of generated code suffixes. Leaves out `0`, `1`, `I` and `O`, which are easily confused.
+const CODE_ALPHABET: &[u8; 32] = b"23456789ABCDEFGHJKLMNPQRSTUVWXYZ";
+
+/// Number of times [generate_codes] retries a rate limited request before giving up on a code.
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+
+/// Discounts created by [generate_codes].
+#[derive(Debug)]
+pub struct GeneratedCodes {
+    /// Discounts that were created.
+    pub created: Vec<Discount>,
+    /// Codes that couldn't be created along with their error.
+    pub failed: Vec<(String, Error)>,
+}
+
+impl GeneratedCodes {
+    /// Created discounts as CSV with a `code,discount_id,expires_at` header row.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("code,discount_id,expires_at\n");
+
+        for discount in &self.created {
+            csv.push_str(&format!(
+                "{},{},{}\n",
+                discount.code.as_deref().unwrap_or_default(),
+                discount.id,
+                discount
+                    .expires_at
+                    .map(|expires_at| expires_at.to_rfc3339())
+                    .unwrap_or_default()
+            ));
+        }
+
+        csv
+    }
+}
+
+/// Create `count` single-use discounts with unique codes, e.g. for a promo campaign.
+///
+/// Every discount is a copy of `template` with `usage_limit` set to 1. The code set on the template is used as a prefix and 8 random letters and digits are appended to it, e.g. `SUMMER` becomes `SUMMER7KQ2XJ4M`. Codes are checkout codes, so `enabled_for_checkout` is set as well.
+///
+/// Discounts are created one at a time. Rate limited requests are retried after the delay Paddle asks for. Codes that fail are recorded in [GeneratedCodes::failed] and don't stop the others.
+///
+/// ```rust,no_run
+/// use paddle_rust_sdk::discounts::generate_codes;
+/// use paddle_rust_sdk::enums::DiscountType;
+///
+/// let template = client
+///     .discount_create("20", "Summer campaign", DiscountType::Percentage)
+///     .code("SUMMER");
+///
+/// let codes = generate_codes(&template, 500).await?;
+/// std::fs::write("codes.csv", codes.to_csv())?;
+/// ```
+pub async fn generate_codes(
+    template: &DiscountCreate<'_>,
+    count: usize,
+) -> std::result::Result<GeneratedCodes, Error> {
+    let prefix = template.code.clone().unwrap_or_default();
+
+    if prefix.len() + CODE_SUFFIX_LEN > MAX_CODE_LEN {
+        return Err(Error::Validation(format!(
+            "code prefix can be at most {} characters, got {}",
+            MAX_CODE_LEN - CODE_SUFFIX_LEN,
+            prefix.len()
+        )));
+    }
+
+    if !prefix.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(Error::Validation(
+            "code prefix can only contain letters and numbers".to_string(),
+        ));
+    }
+
+    let random = RandomState::new();
+    let mut codes = HashSet::with_capacity(count);
+    let mut counter = 0u64;
+
+    while codes.len() < count {
+        counter += 1;
+        codes.insert(format!("{prefix}{}", code_suffix(&random, counter)));
+    }
+
+    let mut generated = GeneratedCodes {
+        created: Vec::with_capacity(count),
+        failed: Vec::new(),
+    };
+
+    for code in codes {
+        let request = template
+            .clone()
+            .code(code.clone())
+            .usage_limit(1)
+            .enabled_for_checkout(true);
+
+        let mut retries = 0;
+
+        loop {
+            match request.send().await {
+                Ok(response) => generated.created.push(response.data),
+                Err(err) => match err.retry_after() {
+                    Some(delay) if retries < MAX_RATE_LIMIT_RETRIES => {
+                        retries += 1;
+                        tokio::time::sleep(delay).await;
+                        continue;
+                    }
+                    _ => generated.failed.push((code, err)),
+                },
+            }
+
+            break;
+        }
+    }
+
+    Ok(generated)
+}
+
+/// Random code suffix derived from `counter`.
+fn code_suffix(random: &RandomState, counter: u64) -> String {
+    let mut hash = random.hash_one(counter);
+
+    (0..CODE_SUFFIX_LEN)
+        .map(|_| {
+            let c = CODE_ALPHABET[(hash % 32) as usize] as char;
+            hash /= 32;
+            c
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::enums::RestrictTo;
+    use crate::fixtures;
+
+    #[tokio::test]
+    async fn generates_unique_prefixed_codes() {
+        let url = fixtures::serve_json(|_| async {
+            json!({
+                "data": {
+                    "id": "dsc_01gv5kpg05xp104ek2fmgjwttf",
+                    "status": "active",
+                    "description": "Summer campaign",
+                    "enabled_for_checkout": true,
+                    "code": "SUMMER7KQ2XJ4M",
+                    "type": "percentage",
+                    "amount": "20",
+                    "currency_code": null,
+                    "recur": false,
+                    "maximum_recurring_intervals": null,
+                    "usage_limit": 1,
+                    "restrict_to": null,
+                    "expires_at": null,
+                    "custom_data": null,
+                    "times_used": 0,
+                    "created_at": fixtures::CREATED_AT,
+                    "updated_at": fixtures::CREATED_AT,
+                    "import_meta": null
+                },
+                "meta": { "request_id": "req_1" }
+            })
+        })
+        .await;
+        let client = Paddle::new("key", url.as_str()).unwrap();
+
+        let template = client
+            .discount_create("20", "Summer campaign", DiscountType::Percentage)
+            .code("SUMMER");
+
+        let codes = generate_codes(&template, 3).await.unwrap();
+        assert_eq!(codes.created.len(), 3);
+        assert!(codes.failed.is_empty());
+        assert_eq!(
+            codes.to_csv().lines().nth(1),
+            Some("SUMMER7KQ2XJ4M,dsc_01gv5kpg05xp104ek2fmgjwttf,")
+        );
+
+        let random = RandomState::new();
+        let suffixes = (0..1000)
+            .map(|counter| code_suffix(&random, counter))
+            .collect::<HashSet<_>>();
+        assert_eq!(suffixes.len(), 1000);
+
+        let template = template.code("A".repeat(25));
+        assert!(matches!(
+            generate_codes(&template, 1).await,
+            Err(Error::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn restrictions_are_parsed_by_prefix() {
+        let discount: Discount = fixtures::from_value(json!({
+            "id": "dsc_01gv5kpg05xp104ek2fmgjwttf",
+            "status": "active",
+            "description": "Summer campaign",
+            "enabled_for_checkout": true,
+            "code": null,
+            "type": "percentage",
+            "amount": "20",
+            "currency_code": null,
+            "recur": false,
+            "maximum_recurring_intervals": null,
+            "usage_limit": null,
+            "restrict_to": ["pro_01gsz4t5hdjse780zja8vvr7jg", "pri_01gsz8x8sawmvhz1pv30nge1ke"],
+            "expires_at": null,
+            "custom_data": null,
+            "created_at": fixtures::CREATED_AT,
+            "updated_at": fixtures::CREATED_AT,
+            "import_meta": null
+        }));
+
+        let restrictions = discount.restrictions().unwrap();
+        assert_eq!(
+            restrictions,
+            [
+                RestrictTo::Product("pro_01gsz4t5hdjse780zja8vvr7jg".into()),
+                RestrictTo::Price("pri_01gsz8x8sawmvhz1pv30nge1ke".into()),
+            ]
+        );
+        assert_eq!(
+            RestrictTo::parse("txn_01"),
+            RestrictTo::Other("txn_01".to_string())
+        );
+
+        let client = Paddle::new("key", Paddle::SANDBOX).unwrap();
+        let create = client
+            .discount_create("20", "Summer campaign", DiscountType::Percentage)
+            .restrict_to(&restrictions);
+        assert_eq!(
+            serde_json::to_value(&create).unwrap()["restrict_to"],
+            json!(discount.restrict_to)
+        );
     }
 }
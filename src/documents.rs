@@ -0,0 +1,411 @@
+//! Downloaded PDF documents, such as transaction invoices.
+//!
+//! Paddle returns short lived links to documents. [Document] holds the downloaded file along with the headers needed to serve it to your customers.
+//!
+//! [ExpiringUrl] keeps track of when a link expires, so it's not cached or handed out after it stops working.
+
+use chrono::{DateTime, Duration, Utc};
+use reqwest::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+
+use crate::entities::Adjustment;
+use crate::enums::Disposition;
+use crate::ids::{AdjustmentID, PaddleID, TransactionID};
+use crate::{Error, Paddle};
+
+/// How long before the documented expiry time a link is considered expired. Accounts for the time between Paddle generating the link and the response being received.
+const EXPIRY_MARGIN: Duration = Duration::seconds(10);
+
+/// A document downloaded from Paddle.
+#[derive(Clone, Debug)]
+pub struct Document {
+    /// Contents of the file.
+    pub bytes: Vec<u8>,
+    /// Suggested file name, e.g. `invoice_325-10566.pdf`.
+    pub filename: String,
+    /// MIME type of the file. Typically `application/pdf`.
+    pub content_type: String,
+    /// Whether the file should be displayed in the browser or downloaded.
+    pub disposition: Disposition,
+}
+
+impl Document {
+    /// Value for the `Content-Disposition` header when serving this document.
+    pub fn content_disposition(&self) -> String {
+        let disposition = match self.disposition {
+            Disposition::Attachment => "attachment",
+            Disposition::Inline => "inline",
+        };
+
+        format!(
+            "{}; filename=\"{}\"",
+            disposition,
+            self.filename.replace(['"', '\\'], "")
+        )
+    }
+}
+
+/// A credit note downloaded along with the adjustment it was issued for. Returned by [Paddle::adjustment_credit_note_pdf].
+#[derive(Clone, Debug)]
+pub struct CreditNote {
+    /// The credit note PDF.
+    pub document: Document,
+    /// The adjustment, for its totals and reason.
+    pub adjustment: Adjustment,
+}
+
+/// A link to a document that stops working after some time.
+#[derive(Clone, Debug)]
+pub struct ExpiringUrl {
+    /// The link.
+    pub url: String,
+    /// When the link was received from Paddle.
+    pub fetched_at: DateTime<Utc>,
+    /// How long the link is valid for after it's generated.
+    pub ttl: Duration,
+    source: UrlSource,
+}
+
+/// What an [ExpiringUrl] links to. Used to request a new link.
+#[derive(Clone, Debug)]
+enum UrlSource {
+    Invoice(TransactionID, Disposition),
+    CreditNote(AdjustmentID, Disposition),
+    Report(PaddleID),
+}
+
+impl ExpiringUrl {
+    /// Invoice and credit note links expire after an hour.
+    const DOCUMENT_TTL: Duration = Duration::hours(1);
+    /// Report links expire after 3 minutes.
+    const REPORT_TTL: Duration = Duration::minutes(3);
+
+    /// When the link stops working.
+    pub fn expires_at(&self) -> DateTime<Utc> {
+        self.fetched_at + self.ttl
+    }
+
+    /// Whether the link has expired or is about to expire.
+    ///
+    /// Paddle doesn't return the exact expiry time of links, so this is an estimate based on when the link was received.
+    pub fn is_probably_expired(&self) -> bool {
+        Utc::now() + EXPIRY_MARGIN >= self.expires_at()
+    }
+
+    /// Request a new link to the same document.
+    pub async fn refetch(&self, client: &Paddle) -> Result<ExpiringUrl, Error> {
+        fetch_url(client, self.source.clone()).await
+    }
+
+    /// Return the link, requesting a new one first if it has probably expired.
+    pub async fn fresh(&mut self, client: &Paddle) -> Result<&str, Error> {
+        if self.is_probably_expired() {
+            *self = self.refetch(client).await?;
+        }
+
+        Ok(&self.url)
+    }
+}
+
+impl Paddle {
+    /// Same as [Paddle::transaction_invoice], returning an [ExpiringUrl] that keeps track of when the link expires.
+    pub async fn expiring_invoice_url(
+        &self,
+        transaction_id: impl Into<TransactionID>,
+        disposition: Disposition,
+    ) -> Result<ExpiringUrl, Error> {
+        fetch_url(self, UrlSource::Invoice(transaction_id.into(), disposition)).await
+    }
+
+    /// Same as [Paddle::adjustment_credit_note], returning an [ExpiringUrl] that keeps track of when the link expires.
+    pub async fn expiring_credit_note_url(
+        &self,
+        adjustment_id: impl Into<AdjustmentID>,
+        disposition: Disposition,
+    ) -> Result<ExpiringUrl, Error> {
+        fetch_url(
+            self,
+            UrlSource::CreditNote(adjustment_id.into(), disposition),
+        )
+        .await
+    }
+
+    /// Same as [Paddle::report_download_url], returning an [ExpiringUrl] that keeps track of when the link expires.
+    pub async fn expiring_report_url(
+        &self,
+        report_id: impl Into<PaddleID>,
+    ) -> Result<ExpiringUrl, Error> {
+        fetch_url(self, UrlSource::Report(report_id.into())).await
+    }
+}
+
+async fn fetch_url(client: &Paddle, source: UrlSource) -> Result<ExpiringUrl, Error> {
+    let (response, ttl) = match &source {
+        UrlSource::Invoice(transaction_id, disposition) => (
+            client
+                .transaction_invoice(transaction_id.clone(), *disposition)
+                .await?,
+            ExpiringUrl::DOCUMENT_TTL,
+        ),
+        UrlSource::CreditNote(adjustment_id, disposition) => (
+            client
+                .adjustment_credit_note(adjustment_id.clone(), *disposition)
+                .await?,
+            ExpiringUrl::DOCUMENT_TTL,
+        ),
+        UrlSource::Report(report_id) => (
+            client.report_download_url(report_id.clone()).await?,
+            ExpiringUrl::REPORT_TTL,
+        ),
+    };
+
+    Ok(ExpiringUrl {
+        url: response.data.url.ok_or(Error::MissingUrl)?,
+        fetched_at: Utc::now(),
+        ttl,
+        source,
+    })
+}
+
+/// Download a document from a link returned by Paddle.
+///
+/// The file name is taken from the `Content-Disposition` header of the response, then from the last segment of the URL and finally `fallback_filename` is used.
+pub(crate) async fn download(
+    client: &Paddle,
+    url: Option<String>,
+    disposition: Disposition,
+    fallback_filename: String,
+) -> Result<Document, Error> {
+    let url = url.ok_or(Error::MissingUrl)?;
+    let response = client
+        .http
+        .get(url.as_str())
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let header = |name| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+
+    let content_type = header(CONTENT_TYPE).unwrap_or_else(|| "application/pdf".to_string());
+    let filename = header(CONTENT_DISPOSITION)
+        .as_deref()
+        .and_then(filename_from_content_disposition)
+        .or_else(|| filename_from_url(&url))
+        .unwrap_or(fallback_filename);
+
+    let bytes = response.bytes().await?.to_vec();
+
+    Ok(Document {
+        bytes,
+        filename,
+        content_type,
+        disposition,
+    })
+}
+
+/// File name from a `Content-Disposition` header. The RFC 6266 `filename*` parameter is preferred over `filename` when both are present.
+fn filename_from_content_disposition(value: &str) -> Option<String> {
+    let parameter = |name: &str| {
+        value.split(';').map(str::trim).find_map(|part| {
+            let (key, value) = part.split_once('=')?;
+            key.trim().eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+    };
+
+    parameter("filename*")
+        .and_then(decode_ext_value)
+        .or_else(|| parameter("filename").map(|name| name.trim_matches('"').to_string()))
+        .filter(|name| !name.is_empty())
+}
+
+/// Decode an RFC 8187 extended parameter value, e.g. `UTF-8''invoice%20325.pdf`.
+fn decode_ext_value(value: &str) -> Option<String> {
+    let mut parts = value.splitn(3, '\'');
+    let charset = parts.next()?;
+    let _language = parts.next()?;
+    let encoded = parts.next()?.as_bytes();
+
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut i = 0;
+
+    while i < encoded.len() {
+        if encoded[i] == b'%' {
+            let hex = std::str::from_utf8(encoded.get(i + 1..i + 3)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            bytes.push(encoded[i]);
+            i += 1;
+        }
+    }
+
+    if charset.eq_ignore_ascii_case("utf-8") {
+        String::from_utf8(bytes).ok()
+    } else if charset.eq_ignore_ascii_case("iso-8859-1") {
+        Some(bytes.into_iter().map(char::from).collect())
+    } else {
+        None
+    }
+}
+
+fn filename_from_url(url: &str) -> Option<String> {
+    let url = url::Url::parse(url).ok()?;
+    let name = url.path_segments()?.next_back()?;
+
+    name.ends_with(".pdf").then(|| name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn filenames_from_content_disposition() {
+        let filename = filename_from_content_disposition;
+
+        assert_eq!(
+            filename("attachment; filename=\"invoice_325-10566.pdf\""),
+            Some("invoice_325-10566.pdf".to_string())
+        );
+        assert_eq!(
+            filename("inline;filename=invoice.pdf"),
+            Some("invoice.pdf".to_string())
+        );
+        assert_eq!(
+            filename(
+                "attachment; filename=\"invoice.pdf\"; filename*=UTF-8''facture%20n%C2%B0325.pdf"
+            ),
+            Some("facture n°325.pdf".to_string())
+        );
+        assert_eq!(
+            filename("attachment; FILENAME*=iso-8859-1'fr'factur%E9.pdf"),
+            Some("facturé.pdf".to_string())
+        );
+
+        // Invalid extended values fall back to the plain file name
+        assert_eq!(
+            filename("attachment; filename*=UTF-8''%ZZ.pdf; filename=invoice.pdf"),
+            Some("invoice.pdf".to_string())
+        );
+        assert_eq!(filename("attachment; filename=\"\""), None);
+        assert_eq!(filename("attachment"), None);
+    }
+
+    #[tokio::test]
+    async fn downloads_use_the_content_disposition_filename() {
+        let url = fixtures::serve_responses(|request: String| async move {
+            let host = request
+                .lines()
+                .find_map(|line| line.strip_prefix("host: "))
+                .unwrap_or_default()
+                .to_string();
+
+            if request.starts_with("GET /transactions/") {
+                fixtures::MockResponse::ok(json!({
+                    "data": { "url": format!("http://{host}/files/invoice.pdf") },
+                    "meta": { "request_id": "req_1" }
+                }))
+            } else {
+                fixtures::MockResponse {
+                    headers: vec![(
+                        "content-disposition",
+                        "attachment; filename*=UTF-8''invoice%20325.pdf".to_string(),
+                    )],
+                    ..fixtures::MockResponse::ok(json!("%PDF-1.7"))
+                }
+            }
+        })
+        .await;
+        let client = Paddle::new("key", url.as_str()).unwrap();
+
+        let document = client
+            .fetch_invoice("txn_01hv8wptq8987qeep44cyrewp9", Disposition::Attachment)
+            .await
+            .unwrap();
+
+        assert_eq!(document.filename, "invoice 325.pdf");
+        assert_eq!(document.bytes, b"\"%PDF-1.7\"");
+        assert_eq!(
+            document.content_disposition(),
+            "attachment; filename=\"invoice 325.pdf\""
+        );
+    }
+
+    #[tokio::test]
+    async fn expiring_urls_are_refetched_once_expired() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let url = fixtures::serve_json({
+            let requests = requests.clone();
+
+            move |target| {
+                let n = requests.fetch_add(1, Ordering::SeqCst);
+
+                async move {
+                    json!({
+                        "data": { "url": format!("https://sandbox-api.paddle.com{target}/{n}") },
+                        "meta": { "request_id": "req_1" }
+                    })
+                }
+            }
+        })
+        .await;
+        let client = Paddle::new("key", url.as_str()).unwrap();
+
+        let mut invoice = client
+            .expiring_invoice_url("txn_01hv8wptq8987qeep44cyrewp9", Disposition::Inline)
+            .await
+            .unwrap();
+        assert_eq!(invoice.ttl, Duration::hours(1));
+        assert_eq!(
+            invoice.expires_at(),
+            invoice.fetched_at + Duration::hours(1)
+        );
+        assert!(!invoice.is_probably_expired());
+
+        // Still fresh, so no new link is requested
+        let first = invoice.fresh(&client).await.unwrap().to_string();
+        assert!(first.contains("/transactions/txn_01hv8wptq8987qeep44cyrewp9/invoice"));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        // Links about to expire count as expired
+        invoice.fetched_at = Utc::now() - Duration::hours(1) + Duration::seconds(5);
+        assert!(invoice.is_probably_expired());
+
+        let second = invoice.fresh(&client).await.unwrap().to_string();
+        assert_ne!(first, second);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        assert!(!invoice.is_probably_expired());
+
+        let credit_note = client
+            .expiring_credit_note_url("adj_01hvgf2s84dr6reszzg29zbvcm", Disposition::Inline)
+            .await
+            .unwrap();
+        assert_eq!(credit_note.ttl, Duration::hours(1));
+        assert!(credit_note
+            .url
+            .contains("/adjustments/adj_01hvgf2s84dr6reszzg29zbvcm/credit-note"));
+
+        let report = client
+            .expiring_report_url("rep_01hvgdpayq6kjzh3v1ygn7ayg4")
+            .await
+            .unwrap();
+        assert_eq!(report.ttl, Duration::minutes(3));
+
+        let refetched = report.refetch(&client).await.unwrap();
+        assert!(refetched
+            .url
+            .contains("/reports/rep_01hvgdpayq6kjzh3v1ygn7ayg4/download-url"));
+        assert_ne!(refetched.url, report.url);
+    }
+}
@@ -0,0 +1,450 @@
+//! Policy for deciding whether a customer keeps access while their subscription is past due.
+//!
+//! When a renewal payment fails, Paddle marks the subscription as `past_due` and retries the payment. A [GracePolicy] turns the subscription status and the error of the last payment attempt into an [AccessDecision], so that every part of your app handles failed payments the same way.
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::dunning::{AccessDecision, GracePolicy};
+//!
+//! let policy = GracePolicy::default();
+//! let error = paddle_rust_sdk::dunning::last_payment_error(&transaction);
+//!
+//! match policy.decide_subscription(&subscription, error, chrono::Utc::now()) {
+//!     AccessDecision::Allow => {}
+//!     AccessDecision::Retry { block_at } => println!("Payment failed, retrying. Access ends at {block_at}"),
+//!     AccessDecision::UpdatePaymentMethod { block_at } => println!("Please update your card before {block_at}"),
+//!     AccessDecision::Block(reason) => println!("Access blocked: {reason:?}"),
+//! }
+//! ```
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::entities::{Subscription, Transaction, TransactionPaymentAttempt};
+use crate::enums::{ErrorCode, SubscriptionStatus};
+
+/// What to do with a customer's access.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccessDecision {
+    /// Subscription is in good standing.
+    Allow,
+    /// Last payment failed with an error that may go away on its own, e.g. insufficient funds. Paddle keeps retrying. Access is kept until `block_at`.
+    Retry { block_at: DateTime<Utc> },
+    /// Last payment failed with an error that retrying won't fix, e.g. an expired card. The customer has to update their payment method before `block_at`.
+    UpdatePaymentMethod { block_at: DateTime<Utc> },
+    /// Access should be blocked now.
+    Block(BlockReason),
+}
+
+impl AccessDecision {
+    /// Whether the customer should have access right now.
+    pub fn has_access(&self) -> bool {
+        !matches!(self, Self::Block(_))
+    }
+}
+
+/// Why access was blocked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlockReason {
+    /// Subscription is canceled.
+    Canceled,
+    /// Subscription is paused.
+    Paused,
+    /// Subscription has been past due for longer than the grace period.
+    GracePeriodExpired,
+    /// Payment failed with an error configured to block access immediately.
+    PaymentFailed(ErrorCode),
+}
+
+/// Configuration for deciding access of past due subscriptions.
+#[derive(Clone, Debug)]
+pub struct GracePolicy {
+    /// How long access is kept after a payment fails with a retryable error.
+    pub grace_period: Duration,
+    /// How long access is kept after a payment fails with an error that requires the customer to update their payment method.
+    pub update_payment_method_grace_period: Duration,
+    /// Errors that require the customer to update their payment method.
+    pub non_retryable: Vec<ErrorCode>,
+    /// Errors that block access immediately, without a grace period.
+    pub block_immediately: Vec<ErrorCode>,
+    /// Whether paused subscriptions lose access.
+    pub block_paused: bool,
+}
+
+impl Default for GracePolicy {
+    fn default() -> Self {
+        Self {
+            grace_period: Duration::days(14),
+            update_payment_method_grace_period: Duration::days(3),
+            non_retryable: vec![
+                ErrorCode::BlockedCard,
+                ErrorCode::Canceled,
+                ErrorCode::DeclinedNotRetryable,
+                ErrorCode::ExpiredCard,
+                ErrorCode::InvalidPaymentDetails,
+                ErrorCode::RedactedPaymentMethod,
+                ErrorCode::TransactionNotPermitted,
+            ],
+            block_immediately: vec![ErrorCode::Fraud],
+            block_paused: true,
+        }
+    }
+}
+
+impl GracePolicy {
+    /// Decide access for a subscription with the given status.
+    ///
+    /// - `past_due_since` - when the subscription became past due. Defaults to `now` when not known.
+    /// - `last_error` - error of the last failed payment attempt, if any.
+    pub fn decide(
+        &self,
+        status: SubscriptionStatus,
+        past_due_since: Option<DateTime<Utc>>,
+        last_error: Option<ErrorCode>,
+        now: DateTime<Utc>,
+    ) -> AccessDecision {
+        match status {
+            SubscriptionStatus::Active | SubscriptionStatus::Trialing => AccessDecision::Allow,
+            SubscriptionStatus::Canceled => AccessDecision::Block(BlockReason::Canceled),
+            SubscriptionStatus::Paused if self.block_paused => {
+                AccessDecision::Block(BlockReason::Paused)
+            }
+            SubscriptionStatus::Paused => AccessDecision::Allow,
+            SubscriptionStatus::PastDue => {
+                if let Some(code) = last_error.filter(|code| self.block_immediately.contains(code))
+                {
+                    return AccessDecision::Block(BlockReason::PaymentFailed(code));
+                }
+
+                let retryable = !last_error.is_some_and(|code| self.non_retryable.contains(&code));
+                let grace_period = if retryable {
+                    self.grace_period
+                } else {
+                    self.update_payment_method_grace_period
+                };
+
+                let block_at = past_due_since.unwrap_or(now) + grace_period;
+
+                if now >= block_at {
+                    AccessDecision::Block(BlockReason::GracePeriodExpired)
+                } else if retryable {
+                    AccessDecision::Retry { block_at }
+                } else {
+                    AccessDecision::UpdatePaymentMethod { block_at }
+                }
+            }
+        }
+    }
+
+    /// Decide access for a subscription.
+    ///
+    /// Subscriptions become past due when the renewal payment at the start of a billing period fails, so the start of the current billing period is used as the time the subscription became past due.
+    pub fn decide_subscription(
+        &self,
+        subscription: &Subscription,
+        last_error: Option<ErrorCode>,
+        now: DateTime<Utc>,
+    ) -> AccessDecision {
+        let past_due_since = subscription
+            .current_billing_period
+            .as_ref()
+            .map(|period| period.starts_at);
+
+        self.decide(subscription.status, past_due_since, last_error, now)
+    }
+}
+
+/// Error of the most recent payment attempt of a transaction. `None` if the transaction has no payment attempts or the last one didn't fail.
+pub fn last_payment_error(transaction: &Transaction) -> Option<ErrorCode> {
+    // Paddle returns payment attempts sorted by `created_at`, most recent first.
+    transaction
+        .payments
+        .first()
+        .and_then(|payment| payment.error_code)
+}
+
+/// Payment attempts of `current` that are new or changed since `previous`, most recent first.
+///
+/// Pass the transaction from two consecutive `transaction.*` webhook events for the same transaction, e.g. `transaction.updated` followed by `transaction.payment_failed`. An attempt is included if its ID isn't in `previous`, or if its status or error code differ. When `previous` is `None`, every attempt is new.
+///
+/// ```rust,no_run
+/// use paddle_rust_sdk::dunning::changed_payment_attempts;
+/// use paddle_rust_sdk::enums::ErrorCode;
+///
+/// for attempt in changed_payment_attempts(previous.as_ref(), &current) {
+///     if attempt.error_code == Some(ErrorCode::Fraud) {
+///         println!("Possible fraud on {}", current.id);
+///     }
+/// }
+/// ```
+pub fn changed_payment_attempts<'a>(
+    previous: Option<&Transaction>,
+    current: &'a Transaction,
+) -> Vec<&'a TransactionPaymentAttempt> {
+    current
+        .payments
+        .iter()
+        .filter(|attempt| {
+            let seen = previous.and_then(|previous| {
+                previous
+                    .payments
+                    .iter()
+                    .find(|seen| seen.payment_attempt_id == attempt.payment_attempt_id)
+            });
+
+            match seen {
+                Some(seen) => {
+                    seen.status != attempt.status || seen.error_code != attempt.error_code
+                }
+                None => true,
+            }
+        })
+        .collect()
+}
+
+/// Error codes of payment attempts that failed since `previous`, most recent first.
+///
+/// See [changed_payment_attempts].
+pub fn new_payment_errors(previous: Option<&Transaction>, current: &Transaction) -> Vec<ErrorCode> {
+    changed_payment_attempts(previous, current)
+        .into_iter()
+        .filter_map(|attempt| attempt.error_code)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::fixtures;
+
+    fn at(time: &str) -> DateTime<Utc> {
+        time.parse().unwrap()
+    }
+
+    fn decide(last_error: Option<ErrorCode>, now: &str) -> AccessDecision {
+        GracePolicy::default().decide(
+            SubscriptionStatus::PastDue,
+            Some(at("2024-04-12T00:00:00Z")),
+            last_error,
+            at(now),
+        )
+    }
+
+    #[test]
+    fn status_decides_access_outside_of_dunning() {
+        let policy = GracePolicy::default();
+        let now = at("2024-04-12T00:00:00Z");
+
+        for status in [SubscriptionStatus::Active, SubscriptionStatus::Trialing] {
+            assert_eq!(
+                policy.decide(status, None, Some(ErrorCode::Fraud), now),
+                AccessDecision::Allow
+            );
+        }
+
+        assert_eq!(
+            policy.decide(SubscriptionStatus::Canceled, None, None, now),
+            AccessDecision::Block(BlockReason::Canceled)
+        );
+        assert_eq!(
+            policy.decide(SubscriptionStatus::Paused, None, None, now),
+            AccessDecision::Block(BlockReason::Paused)
+        );
+
+        let policy = GracePolicy {
+            block_paused: false,
+            ..GracePolicy::default()
+        };
+        assert!(policy
+            .decide(SubscriptionStatus::Paused, None, None, now)
+            .has_access());
+    }
+
+    #[test]
+    fn retryable_errors_get_the_grace_period() {
+        assert_eq!(
+            decide(Some(ErrorCode::NotEnoughBalance), "2024-04-20T00:00:00Z"),
+            AccessDecision::Retry {
+                block_at: at("2024-04-26T00:00:00Z")
+            }
+        );
+        assert_eq!(
+            decide(None, "2024-04-25T23:59:59Z"),
+            AccessDecision::Retry {
+                block_at: at("2024-04-26T00:00:00Z")
+            }
+        );
+        assert_eq!(
+            decide(Some(ErrorCode::NotEnoughBalance), "2024-04-26T00:00:00Z"),
+            AccessDecision::Block(BlockReason::GracePeriodExpired)
+        );
+    }
+
+    #[test]
+    fn non_retryable_errors_get_a_shorter_grace_period() {
+        assert_eq!(
+            decide(Some(ErrorCode::ExpiredCard), "2024-04-13T00:00:00Z"),
+            AccessDecision::UpdatePaymentMethod {
+                block_at: at("2024-04-15T00:00:00Z")
+            }
+        );
+        assert_eq!(
+            decide(Some(ErrorCode::ExpiredCard), "2024-04-15T00:00:00Z"),
+            AccessDecision::Block(BlockReason::GracePeriodExpired)
+        );
+    }
+
+    #[test]
+    fn fraud_blocks_immediately() {
+        let decision = decide(Some(ErrorCode::Fraud), "2024-04-12T00:00:01Z");
+
+        assert_eq!(
+            decision,
+            AccessDecision::Block(BlockReason::PaymentFailed(ErrorCode::Fraud))
+        );
+        assert!(!decision.has_access());
+    }
+
+    #[test]
+    fn unknown_past_due_start_counts_from_now() {
+        let now = at("2024-04-12T00:00:00Z");
+
+        assert_eq!(
+            GracePolicy::default().decide(SubscriptionStatus::PastDue, None, None, now),
+            AccessDecision::Retry {
+                block_at: at("2024-04-26T00:00:00Z")
+            }
+        );
+    }
+
+    #[test]
+    fn subscriptions_are_past_due_since_the_billing_period_started() {
+        let mut subscription = fixtures::subscription();
+        subscription["status"] = "past_due".into();
+        let subscription: Subscription = fixtures::from_value(subscription);
+
+        assert_eq!(
+            GracePolicy::default().decide_subscription(
+                &subscription,
+                None,
+                at("2024-04-20T00:00:00Z")
+            ),
+            AccessDecision::Retry {
+                block_at: at("2024-04-26T10:18:47.635628Z")
+            }
+        );
+    }
+
+    fn payment(id: &str, status: &str, error_code: Option<&str>) -> Value {
+        json!({
+            "payment_attempt_id": id,
+            "stored_payment_method_id": "7636e781-3d2a-4c3f-8a49-0a0b1f3b0001",
+            "payment_method_id": null,
+            "amount": "3600",
+            "status": status,
+            "error_code": error_code,
+            "method_details": { "type": "card", "card": null, "south_korea_local_card": null, "paypal": null },
+            "created_at": fixtures::CREATED_AT,
+            "captured_at": null
+        })
+    }
+
+    #[test]
+    fn last_payment_error_uses_the_most_recent_attempt() {
+        let mut transaction = fixtures::transaction();
+        let typed: Transaction = fixtures::from_value(transaction.clone());
+        assert_eq!(last_payment_error(&typed), None);
+
+        transaction["payments"] = json!([
+            payment(
+                "a3b2c1d0-0000-0000-0000-000000000002",
+                "error",
+                Some("expired_card")
+            ),
+            payment(
+                "a3b2c1d0-0000-0000-0000-000000000001",
+                "error",
+                Some("not_enough_balance")
+            ),
+        ]);
+        let typed: Transaction = fixtures::from_value(transaction.clone());
+        assert_eq!(last_payment_error(&typed), Some(ErrorCode::ExpiredCard));
+
+        transaction["payments"][0] =
+            payment("a3b2c1d0-0000-0000-0000-000000000003", "captured", None);
+        let typed: Transaction = fixtures::from_value(transaction);
+        assert_eq!(last_payment_error(&typed), None);
+    }
+
+    /// Transaction with the given payment attempts, most recent first.
+    fn with_payments(payments: Vec<Value>) -> Transaction {
+        let mut transaction = fixtures::transaction();
+        transaction["payments"] = payments.into();
+        fixtures::from_value(transaction)
+    }
+
+    const FIRST: &str = "a3b2c1d0-0000-0000-0000-000000000001";
+    const SECOND: &str = "a3b2c1d0-0000-0000-0000-000000000002";
+
+    #[test]
+    fn every_attempt_is_new_without_a_previous_transaction() {
+        let current = with_payments(vec![
+            payment(SECOND, "error", Some("expired_card")),
+            payment(FIRST, "error", Some("not_enough_balance")),
+        ]);
+
+        assert_eq!(changed_payment_attempts(None, &current).len(), 2);
+        assert_eq!(
+            new_payment_errors(None, &current),
+            [ErrorCode::ExpiredCard, ErrorCode::NotEnoughBalance]
+        );
+    }
+
+    #[test]
+    fn unchanged_attempts_are_skipped() {
+        let previous = with_payments(vec![payment(FIRST, "error", Some("not_enough_balance"))]);
+        let current = previous.clone();
+
+        assert!(changed_payment_attempts(Some(&previous), &current).is_empty());
+        assert!(new_payment_errors(Some(&previous), &current).is_empty());
+    }
+
+    #[test]
+    fn new_attempts_are_included() {
+        let previous = with_payments(vec![payment(FIRST, "error", Some("not_enough_balance"))]);
+        let current = with_payments(vec![
+            payment(SECOND, "error", Some("expired_card")),
+            payment(FIRST, "error", Some("not_enough_balance")),
+        ]);
+
+        let changed = changed_payment_attempts(Some(&previous), &current);
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].payment_attempt_id, SECOND);
+        assert_eq!(
+            new_payment_errors(Some(&previous), &current),
+            [ErrorCode::ExpiredCard]
+        );
+    }
+
+    #[test]
+    fn attempts_with_a_changed_status_are_included() {
+        let previous = with_payments(vec![payment(FIRST, "authorized", None)]);
+        let current = with_payments(vec![payment(FIRST, "error", Some("fraud"))]);
+
+        let changed = changed_payment_attempts(Some(&previous), &current);
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].payment_attempt_id, FIRST);
+        assert_eq!(
+            new_payment_errors(Some(&previous), &current),
+            [ErrorCode::Fraud]
+        );
+
+        // Captured attempts are reported as changed, but aren't errors
+        let captured = with_payments(vec![payment(FIRST, "captured", None)]);
+        assert_eq!(
+            changed_payment_attempts(Some(&previous), &captured).len(),
+            1
+        );
+        assert!(new_payment_errors(Some(&previous), &captured).is_empty());
+    }
+}
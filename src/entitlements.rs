@@ -0,0 +1,390 @@
+//! Entitlement state derived from subscriptions and kept up to date from webhook events.
+//!
+//! An [Entitlement] is a small summary of a subscription - which prices and products it's for, until when it's paid for and whether it's trialing or canceled. It's meant for gating features in your app without calling the Paddle API on every request.
+//!
+//! Entitlements are persisted using an [EntitlementStore]. [MemoryEntitlementStore] keeps them in memory, implement the trait to store them in your database.
+//!
+//! The store is read and then written without a compare-and-set, so events for the same subscription must be applied one at a time. Run [apply_event] inside [EntityLocks::run](crate::webhooks::EntityLocks::run) when webhook requests are handled concurrently.
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::entitlements::{self, EntitlementStore, MemoryEntitlementStore};
+//! use paddle_rust_sdk::webhooks::{EntityLocks, MaximumVariance};
+//! use paddle_rust_sdk::Paddle;
+//!
+//! // Shared between all requests, e.g. as application state
+//! let store = MemoryEntitlementStore::default();
+//! let locks = EntityLocks::new();
+//!
+//! let event = Paddle::unmarshal(request_body, "your_webhook_key", signature, MaximumVariance::default()).unwrap();
+//! locks.run(&event, entitlements::apply_event(&store, event.clone())).await.unwrap();
+//!
+//! let entitlements = store.for_customer(&"ctm_01hv6y1jedq4p1n0yqn5ba3ky4".into()).await.unwrap();
+//! let has_pro = entitlements.iter().any(|entitlement| entitlement.grants_product(&"pro_01gsz4t5hdjse780zja8vvr7jg".into()));
+//! ```
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::future::Future;
+use std::sync::RwLock;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{Event, Subscription};
+use crate::enums::{ScheduledChangeAction, SubscriptionItemStatus, SubscriptionStatus};
+use crate::ids::{CustomerID, PriceID, ProductID, SubscriptionID};
+
+/// A price the customer is entitled to through a subscription.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EntitledPlan {
+    /// Paddle ID of the price.
+    pub price_id: PriceID,
+    /// Paddle ID of the product the price is for.
+    pub product_id: ProductID,
+    /// Quantity of the price on the subscription, e.g. the number of seats.
+    pub quantity: i64,
+}
+
+/// Entitlement state of a single subscription.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Entitlement {
+    /// Subscription this entitlement is derived from.
+    pub subscription_id: SubscriptionID,
+    /// Customer the subscription is for.
+    pub customer_id: CustomerID,
+    /// Status of the subscription.
+    pub status: SubscriptionStatus,
+    /// Active and trialing recurring items on the subscription.
+    pub plans: Vec<EntitledPlan>,
+    /// When the current billing period ends. `None` for paused and canceled subscriptions.
+    pub period_ends_at: Option<DateTime<Utc>>,
+    /// Whether the subscription is in its trial period.
+    pub trialing: bool,
+    /// Whether the subscription is canceled.
+    pub canceled: bool,
+    /// When the subscription is scheduled to be canceled, if a cancellation is scheduled.
+    pub cancels_at: Option<DateTime<Utc>>,
+    /// When the subscription was last updated in Paddle. Used to ignore events that arrive out of order.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Entitlement {
+    /// Whether the subscription currently grants access. True for active and trialing subscriptions.
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self.status,
+            SubscriptionStatus::Active | SubscriptionStatus::Trialing
+        )
+    }
+
+    /// Whether the subscription currently grants access to the given product.
+    pub fn grants_product(&self, product_id: &ProductID) -> bool {
+        self.is_active() && self.plans.iter().any(|plan| &plan.product_id == product_id)
+    }
+
+    /// Whether the subscription currently grants access to the given price.
+    pub fn grants_price(&self, price_id: &PriceID) -> bool {
+        self.is_active() && self.plans.iter().any(|plan| &plan.price_id == price_id)
+    }
+
+    /// Total quantity of the given product across all plans, e.g. the number of seats. Zero if the subscription doesn't grant access.
+    pub fn quantity(&self, product_id: &ProductID) -> i64 {
+        if !self.is_active() {
+            return 0;
+        }
+
+        self.plans
+            .iter()
+            .filter(|plan| &plan.product_id == product_id)
+            .map(|plan| plan.quantity)
+            .sum()
+    }
+}
+
+impl From<&Subscription> for Entitlement {
+    fn from(subscription: &Subscription) -> Self {
+        let plans = subscription
+            .items
+            .iter()
+            .filter(|item| {
+                item.recurring
+                    && matches!(
+                        item.status,
+                        SubscriptionItemStatus::Active | SubscriptionItemStatus::Trialing
+                    )
+            })
+            .map(|item| EntitledPlan {
+                price_id: item.price.id.clone(),
+                product_id: item.product.id.clone(),
+                quantity: item.quantity,
+            })
+            .collect();
+
+        let cancels_at = subscription
+            .scheduled_change
+            .as_ref()
+            .filter(|change| change.action == ScheduledChangeAction::Cancel)
+            .map(|change| change.effective_at.with_timezone(&Utc));
+
+        Self {
+            subscription_id: subscription.id.clone(),
+            customer_id: subscription.customer_id.clone(),
+            status: subscription.status,
+            plans,
+            period_ends_at: subscription
+                .current_billing_period
+                .as_ref()
+                .map(|period| period.ends_at),
+            trialing: subscription.status == SubscriptionStatus::Trialing,
+            canceled: subscription.status == SubscriptionStatus::Canceled,
+            cancels_at,
+            updated_at: subscription.updated_at,
+        }
+    }
+}
+
+impl From<Subscription> for Entitlement {
+    fn from(subscription: Subscription) -> Self {
+        Self::from(&subscription)
+    }
+}
+
+/// Persistence for entitlements.
+pub trait EntitlementStore {
+    /// Error returned by the store.
+    type Error;
+
+    /// Load the entitlement of a subscription.
+    fn get(
+        &self,
+        subscription_id: &SubscriptionID,
+    ) -> impl Future<Output = Result<Option<Entitlement>, Self::Error>> + Send;
+
+    /// Insert or replace the entitlement of a subscription.
+    fn put(&self, entitlement: Entitlement)
+        -> impl Future<Output = Result<(), Self::Error>> + Send;
+
+    /// Load the entitlements of all subscriptions of a customer.
+    fn for_customer(
+        &self,
+        customer_id: &CustomerID,
+    ) -> impl Future<Output = Result<Vec<Entitlement>, Self::Error>> + Send;
+}
+
+/// [EntitlementStore] keeping entitlements in memory. Entitlements are lost when the process exits.
+#[derive(Debug, Default)]
+pub struct MemoryEntitlementStore {
+    entitlements: RwLock<HashMap<SubscriptionID, Entitlement>>,
+}
+
+impl EntitlementStore for MemoryEntitlementStore {
+    type Error = Infallible;
+
+    async fn get(
+        &self,
+        subscription_id: &SubscriptionID,
+    ) -> Result<Option<Entitlement>, Infallible> {
+        let entitlements = self
+            .entitlements
+            .read()
+            .unwrap_or_else(|err| err.into_inner());
+        Ok(entitlements.get(subscription_id).cloned())
+    }
+
+    async fn put(&self, entitlement: Entitlement) -> Result<(), Infallible> {
+        let mut entitlements = self
+            .entitlements
+            .write()
+            .unwrap_or_else(|err| err.into_inner());
+        entitlements.insert(entitlement.subscription_id.clone(), entitlement);
+        Ok(())
+    }
+
+    async fn for_customer(&self, customer_id: &CustomerID) -> Result<Vec<Entitlement>, Infallible> {
+        let entitlements = self
+            .entitlements
+            .read()
+            .unwrap_or_else(|err| err.into_inner());
+
+        Ok(entitlements
+            .values()
+            .filter(|entitlement| &entitlement.customer_id == customer_id)
+            .cloned()
+            .collect())
+    }
+}
+
+/// Store the entitlement of a subscription, unless a more recent state is already stored.
+///
+/// `updated_at` is compared with the stored entitlement before it's replaced, but the two steps aren't atomic. Callers must apply subscriptions one at a time per subscription ID, e.g. with [EntityLocks::run_keyed](crate::webhooks::EntityLocks::run_keyed), otherwise an older state can overwrite a newer one.
+///
+/// Returns the stored entitlement, or `None` if the subscription is older than the stored state.
+pub async fn apply_subscription<S: EntitlementStore>(
+    store: &S,
+    subscription: &Subscription,
+) -> Result<Option<Entitlement>, S::Error> {
+    if let Some(existing) = store.get(&subscription.id).await? {
+        if existing.updated_at > subscription.updated_at {
+            return Ok(None);
+        }
+    }
+
+    let entitlement = Entitlement::from(subscription);
+    store.put(entitlement.clone()).await?;
+
+    Ok(Some(entitlement))
+}
+
+/// Update the stored entitlements from a webhook event.
+///
+/// Events not related to subscriptions are ignored. Paddle doesn't guarantee the order of webhook deliveries, so events older than the stored state are ignored too. Like [apply_subscription], events for the same subscription must not be applied concurrently, wrap the call in [EntityLocks::run](crate::webhooks::EntityLocks::run).
+///
+/// Returns the stored entitlement, or `None` if the event was ignored.
+pub async fn apply_event<S: EntitlementStore>(
+    store: &S,
+    event: Event,
+) -> Result<Option<Entitlement>, S::Error> {
+    match Subscription::try_from(event) {
+        Ok(subscription) => apply_subscription(store, &subscription).await,
+        Err(_) => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::fixtures;
+
+    const PRODUCT: &str = "pro_01gsz4t5hdjse780zja8vvr0001";
+    const ADD_ON: &str = "pro_01gsz4t5hdjse780zja8vvr0002";
+
+    /// Subscription with one seat of [PRODUCT] and `seats` of [ADD_ON], one of them inactive and one a one-time charge.
+    fn subscription(seats: i64) -> Value {
+        let mut subscription = fixtures::subscription();
+        let mut add_on = subscription["items"][0].clone();
+        add_on["price"]["id"] = "pri_01gsz8x8sawmvhz1pv30nge0002".into();
+        add_on["product"]["id"] = ADD_ON.into();
+        add_on["quantity"] = seats.into();
+
+        let mut inactive = add_on.clone();
+        inactive["status"] = "inactive".into();
+        let mut one_time = add_on.clone();
+        one_time["recurring"] = false.into();
+
+        subscription["items"]
+            .as_array_mut()
+            .unwrap()
+            .extend([add_on, inactive, one_time]);
+        subscription
+    }
+
+    #[test]
+    fn entitlement_from_subscription() {
+        let entitlement = Entitlement::from(fixtures::from_value::<Subscription>(subscription(2)));
+
+        assert_eq!(entitlement.plans.len(), 2);
+        assert!(entitlement.is_active());
+        assert!(!entitlement.trialing);
+        assert!(!entitlement.canceled);
+        assert_eq!(entitlement.cancels_at, None);
+        assert_eq!(
+            entitlement.period_ends_at,
+            Some("2024-05-12T10:18:47.635628Z".parse().unwrap())
+        );
+
+        assert!(entitlement.grants_product(&PRODUCT.into()));
+        assert!(entitlement.grants_price(&"pri_01gsz8x8sawmvhz1pv30nge0002".into()));
+        assert!(!entitlement.grants_product(&"pro_01gsz4t5hdjse780zja8vvr0003".into()));
+        assert_eq!(entitlement.quantity(&ADD_ON.into()), 2);
+    }
+
+    #[test]
+    fn scheduled_cancellation_keeps_access() {
+        let mut subscription = subscription(1);
+        subscription["scheduled_change"] = json!({
+            "action": "cancel",
+            "effective_at": "2024-05-12T10:18:47.635628Z",
+            "resume_at": null
+        });
+
+        let entitlement = Entitlement::from(fixtures::from_value::<Subscription>(subscription));
+        assert!(entitlement.grants_product(&PRODUCT.into()));
+        assert_eq!(
+            entitlement.cancels_at,
+            Some("2024-05-12T10:18:47.635628Z".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn inactive_subscriptions_grant_nothing() {
+        for status in ["past_due", "paused", "canceled"] {
+            let mut subscription = subscription(2);
+            subscription["status"] = status.into();
+
+            let entitlement = Entitlement::from(fixtures::from_value::<Subscription>(subscription));
+            assert!(!entitlement.is_active(), "{status}");
+            assert!(!entitlement.grants_product(&PRODUCT.into()), "{status}");
+            assert_eq!(entitlement.quantity(&ADD_ON.into()), 0, "{status}");
+            assert_eq!(entitlement.canceled, status == "canceled");
+        }
+
+        // Paddle marks the items of a trialing subscription as trialing too
+        let mut subscription = subscription(2);
+        subscription["status"] = "trialing".into();
+        for item in subscription["items"].as_array_mut().unwrap() {
+            if item["status"] == "active" {
+                item["status"] = "trialing".into();
+            }
+        }
+        let entitlement = Entitlement::from(fixtures::from_value::<Subscription>(subscription));
+        assert!(entitlement.trialing);
+        assert!(entitlement.grants_product(&PRODUCT.into()));
+        assert_eq!(entitlement.quantity(&ADD_ON.into()), 2);
+    }
+
+    #[tokio::test]
+    async fn older_events_are_ignored() {
+        let store = MemoryEntitlementStore::default();
+
+        let mut newer = subscription(3);
+        newer["updated_at"] = "2024-04-13T00:00:00Z".into();
+        let applied = apply_event(&store, fixtures::event("subscription.updated", newer))
+            .await
+            .unwrap();
+        assert_eq!(applied.unwrap().quantity(&ADD_ON.into()), 3);
+
+        let older = subscription(1);
+        let applied = apply_event(&store, fixtures::event("subscription.updated", older))
+            .await
+            .unwrap();
+        assert_eq!(applied, None);
+
+        let stored = store
+            .for_customer(&"ctm_01hv6y1jedq4p1n0yqn5ba3ky4".into())
+            .await
+            .unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].quantity(&ADD_ON.into()), 3);
+    }
+
+    #[tokio::test]
+    async fn other_events_are_ignored() {
+        let store = MemoryEntitlementStore::default();
+
+        let applied = apply_event(
+            &store,
+            fixtures::event("transaction.completed", fixtures::transaction()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(applied, None);
+        assert!(store
+            .get(&"sub_01hv8x29kz0t586xy6zn1a62ny".into())
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -4,8 +4,11 @@ use std::error;
 use std::fmt;
 
 use chrono::Duration;
+use reqwest::StatusCode;
 use serde::Deserialize;
 
+use crate::enums::ReportStatus;
+use crate::ids::{PaddleID, SimulationRunID, SubscriptionID, TransactionID};
 use crate::ErrorResponse;
 
 /// Type of error encountered.
@@ -45,6 +48,48 @@ impl fmt::Display for SignatureError {
     }
 }
 
+/// Reason a webhook request was rejected by [WebhookPreflight](crate::webhooks::WebhookPreflight) before its signature was checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookRejection {
+    /// Request body is larger than the allowed limit.
+    BodyTooLarge { size: usize, limit: usize },
+    /// Request body is empty.
+    EmptyBody,
+    /// No `Content-Type` header provided.
+    MissingContentType,
+    /// `Content-Type` header is not `application/json`. Holds the received value.
+    UnsupportedContentType(String),
+    /// No `Paddle-Signature` header provided.
+    MissingSignature,
+}
+
+impl WebhookRejection {
+    /// HTTP status code to respond with.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            Self::BodyTooLarge { .. } => 413,
+            Self::MissingContentType | Self::UnsupportedContentType(_) => 415,
+            Self::EmptyBody | Self::MissingSignature => 400,
+        }
+    }
+}
+
+impl fmt::Display for WebhookRejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::BodyTooLarge { size, limit } => {
+                write!(f, "body of {size} bytes exceeds the limit of {limit} bytes")
+            }
+            Self::EmptyBody => write!(f, "empty body"),
+            Self::MissingContentType => write!(f, "missing content type"),
+            Self::UnsupportedContentType(content_type) => {
+                write!(f, "unsupported content type: {content_type}")
+            }
+            Self::MissingSignature => write!(f, "missing signature header"),
+        }
+    }
+}
+
 /// Error struct for a single invalid field.
 #[derive(Debug, Deserialize)]
 pub struct ValidationError {
@@ -68,6 +113,52 @@ pub struct PaddleApiError {
     pub documentation_url: String,
     /// List of validation errors.
     pub errors: Option<Vec<ValidationError>>,
+    /// HTTP status code of the response, e.g. `409 Conflict`. `None` if the error wasn't parsed from a response.
+    #[serde(skip)]
+    pub status_code: Option<StatusCode>,
+}
+
+/// Error codes returned by the Paddle API that callers commonly handle. See the [error reference](https://developer.paddle.com/errors/overview) for every code.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ErrorCode {
+    /// Payment for a subscription change or one-time charge failed and, because `on_payment_failure` is `prevent_change`, the subscription wasn't changed. Ask the customer to update their payment method.
+    SubscriptionPaymentDeclined,
+    /// The subscription has pending changes and can't be updated until they're applied.
+    SubscriptionLockedPendingChanges,
+    /// The requested entity doesn't exist.
+    NotFound,
+    /// One or more fields failed validation. Details are in [PaddleApiError::errors].
+    InvalidField,
+    /// Too many requests were made. See [Error::retry_after].
+    TooManyRequests,
+    /// The API key doesn't have the permissions the request needs.
+    Forbidden,
+    /// The product's tax category isn't approved for the account. See [TaxCategory::requires_approval](crate::enums::TaxCategory::requires_approval).
+    ProductTaxCategoryNotApproved,
+    /// Any other code.
+    Other(String),
+}
+
+impl From<&str> for ErrorCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "subscription_payment_declined" => Self::SubscriptionPaymentDeclined,
+            "subscription_locked_pending_changes" => Self::SubscriptionLockedPendingChanges,
+            "not_found" => Self::NotFound,
+            "invalid_field" => Self::InvalidField,
+            "too_many_requests" => Self::TooManyRequests,
+            "forbidden" => Self::Forbidden,
+            "product_tax_category_not_approved" => Self::ProductTaxCategoryNotApproved,
+            code => Self::Other(code.to_string()),
+        }
+    }
+}
+
+impl PaddleApiError {
+    /// Typed [code](Self::code) of this error.
+    pub fn error_code(&self) -> ErrorCode {
+        ErrorCode::from(self.code.as_str())
+    }
 }
 
 /// Paddle SDK Error type
@@ -80,9 +171,108 @@ pub enum Error {
     PaddleApi(ErrorResponse),
     QueryString(serde_qs::Error),
     PaddleSignature(SignatureError),
+    /// Webhook request failed preflight checks.
+    WebhookRejected(WebhookRejection),
     ParseIntError(std::num::ParseIntError),
     MacError(hmac::digest::MacError),
     JsonError(serde_json::Error),
+    /// Webhook events are delivered in a Paddle API version that this crate doesn't model.
+    UnsupportedApiVersion(i64),
+    /// Paddle didn't return a link for the requested document or checkout.
+    MissingUrl,
+    /// `custom_data` was rejected by a [CustomDataHook](crate::custom_data::CustomDataHook).
+    CustomData(String),
+    /// Request was rejected before being sent because it would fail validation by Paddle.
+    Validation(String),
+    /// Report didn't become ready to download. Holds the ID of the report and its last known status.
+    ReportNotReady(PaddleID, ReportStatus),
+    /// Simulation run didn't deliver its events in time. Holds the ID of the run.
+    SimulationNotCompleted(SimulationRunID),
+    /// A downloaded report couldn't be parsed.
+    InvalidReport(String),
+    /// No subscription was created for the transaction in time. Holds the ID of the transaction.
+    SubscriptionNotCreated(TransactionID),
+    /// Paddle didn't return an entity that was looked up. Holds the ID of the entity.
+    NotFound(String),
+    /// Subscription has no recurring transaction, e.g. because it's canceled, so its totals can't be compared. Holds the ID of the subscription.
+    NoRecurringTransaction(SubscriptionID),
+    /// An IPv4 address or CIDR block couldn't be parsed. Holds the text that was parsed.
+    InvalidCidr(String),
+    /// Paddle responded successfully, but with data that can't be used.
+    UnexpectedResponse(String),
+}
+
+impl Error {
+    /// Typed code of the error returned by the Paddle API. `None` for errors that didn't come from the API.
+    pub fn api_error_code(&self) -> Option<ErrorCode> {
+        match self {
+            Self::PaddleApi(err) => Some(err.error.error_code()),
+            _ => None,
+        }
+    }
+
+    /// Whether a subscription change or one-time charge was prevented because payment failed.
+    ///
+    /// Returned when `on_payment_failure` is `prevent_change`, the default. Prompt the customer to update their payment method and try again.
+    pub fn is_payment_declined(&self) -> bool {
+        self.api_error_code() == Some(ErrorCode::SubscriptionPaymentDeclined)
+    }
+
+    /// Whether Paddle rejected the request because too many requests were made. Retry after [Error::retry_after].
+    pub fn is_rate_limit(&self) -> bool {
+        self.status_code() == Some(StatusCode::TOO_MANY_REQUESTS)
+            || self.api_error_code() == Some(ErrorCode::TooManyRequests)
+    }
+
+    /// Whether the request conflicts with the current state of the entity, e.g. a subscription with pending changes. Fetch the entity again before deciding whether to retry.
+    pub fn is_conflict(&self) -> bool {
+        self.status_code() == Some(StatusCode::CONFLICT)
+            || self.api_error_code() == Some(ErrorCode::SubscriptionLockedPendingChanges)
+    }
+
+    /// Whether sending the same request again may succeed: the request was rate limited, Paddle had an internal error or the connection failed or timed out.
+    ///
+    /// Errors caused by the request itself, like validation errors, aren't retryable.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Request(err) => err.is_timeout() || err.is_connect(),
+            Self::PaddleApi(err) => {
+                self.is_rate_limit()
+                    || matches!(err.error.error_type, ErrorType::ApiError)
+                    || err
+                        .error
+                        .status_code
+                        .is_some_and(|status| status.is_server_error())
+            }
+            _ => false,
+        }
+    }
+
+    /// HTTP status code of the response, for errors returned by the Paddle API.
+    pub fn status_code(&self) -> Option<StatusCode> {
+        match self {
+            Self::PaddleApi(err) => err.error.status_code,
+            _ => None,
+        }
+    }
+
+    /// How long to wait before retrying the request, when Paddle rate limited it.
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        match self {
+            Self::PaddleApi(err) => err.retry_after,
+            _ => None,
+        }
+    }
+
+    pub(crate) fn with_retry_after(self, retry_after: Option<std::time::Duration>) -> Self {
+        match self {
+            Self::PaddleApi(mut err) => {
+                err.retry_after = retry_after;
+                Self::PaddleApi(err)
+            }
+            err => err,
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -93,9 +283,39 @@ impl fmt::Display for Error {
             Self::PaddleApi(err) => write!(f, "Paddle error: {}", err.error.detail),
             Self::QueryString(err) => write!(f, "Query string error: {}", err),
             Self::PaddleSignature(err) => write!(f, "Paddle signature error: {}", err),
+            Self::WebhookRejected(err) => write!(f, "Webhook rejected: {}", err),
             Self::ParseIntError(err) => write!(f, "Integer parsing error: {}", err),
             Self::MacError(err) => write!(f, "Hmac error: {}", err),
             Self::JsonError(err) => write!(f, "Serde json error: {}", err),
+            Self::UnsupportedApiVersion(version) => write!(
+                f,
+                "Unsupported API version: {}, expected {}",
+                version,
+                crate::webhooks::SUPPORTED_API_VERSION
+            ),
+            Self::MissingUrl => write!(f, "Paddle didn't return a URL"),
+            Self::CustomData(err) => write!(f, "Invalid custom data: {}", err),
+            Self::Validation(err) => write!(f, "Invalid request: {}", err),
+            Self::ReportNotReady(report_id, status) => {
+                write!(f, "Report {} is not ready, status: {:?}", report_id, status)
+            }
+            Self::SimulationNotCompleted(run_id) => {
+                write!(f, "Simulation run {} didn't complete in time", run_id)
+            }
+            Self::InvalidReport(err) => write!(f, "Invalid report: {}", err),
+            Self::SubscriptionNotCreated(transaction_id) => write!(
+                f,
+                "No subscription was created for transaction {}",
+                transaction_id
+            ),
+            Self::NotFound(id) => write!(f, "{} was not found", id),
+            Self::NoRecurringTransaction(subscription_id) => write!(
+                f,
+                "Subscription {} has no recurring transaction",
+                subscription_id
+            ),
+            Self::InvalidCidr(cidr) => write!(f, "Invalid IPv4 CIDR block: {}", cidr),
+            Self::UnexpectedResponse(err) => write!(f, "Unexpected response: {}", err),
         }
     }
 }
@@ -108,9 +328,22 @@ impl error::Error for Error {
             Self::PaddleApi(_) => None,
             Self::QueryString(err) => Some(err),
             Self::PaddleSignature(_) => None,
+            Self::WebhookRejected(_) => None,
             Self::ParseIntError(err) => Some(err),
             Self::MacError(err) => Some(err),
             Self::JsonError(err) => Some(err),
+            Self::UnsupportedApiVersion(_) => None,
+            Self::MissingUrl => None,
+            Self::CustomData(_) => None,
+            Self::Validation(_) => None,
+            Self::ReportNotReady(..) => None,
+            Self::SimulationNotCompleted(_) => None,
+            Self::InvalidReport(_) => None,
+            Self::SubscriptionNotCreated(_) => None,
+            Self::NotFound(_) => None,
+            Self::NoRecurringTransaction(_) => None,
+            Self::InvalidCidr(_) => None,
+            Self::UnexpectedResponse(_) => None,
         }
     }
 }
@@ -133,6 +366,12 @@ impl From<serde_qs::Error> for Error {
     }
 }
 
+impl From<WebhookRejection> for Error {
+    fn from(err: WebhookRejection) -> Self {
+        Self::WebhookRejected(err)
+    }
+}
+
 impl From<std::num::ParseIntError> for Error {
     fn from(err: std::num::ParseIntError) -> Self {
         Self::ParseIntError(err)
@@ -150,3 +389,83 @@ impl From<serde_json::Error> for Error {
         Self::JsonError(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn payment_declined_is_distinguishable() {
+        let response: ErrorResponse = fixtures::from_value(json!({
+            "error": {
+                "type": "request_error",
+                "code": "subscription_payment_declined",
+                "detail": "Payment for the subscription update was declined.",
+                "documentation_url": "https://developer.paddle.com/errors/subscriptions/subscription_payment_declined"
+            },
+            "meta": { "request_id": "req_1" }
+        }));
+        let err = Error::PaddleApi(response);
+
+        assert!(err.is_payment_declined());
+        assert_eq!(
+            ErrorCode::from("entity_archived"),
+            ErrorCode::Other("entity_archived".to_string())
+        );
+        assert_eq!(Error::MissingUrl.api_error_code(), None);
+    }
+
+    #[test]
+    fn errors_are_classified_by_status_and_code() {
+        let error = |status: StatusCode, error_type: &str, code: &str| {
+            let body = json!({
+                "error": {
+                    "type": error_type,
+                    "code": code,
+                    "detail": "Something went wrong.",
+                    "documentation_url": "https://developer.paddle.com/errors/overview"
+                },
+                "meta": { "request_id": "req_1" }
+            });
+
+            crate::response::from_slice::<()>(status, body.to_string().as_bytes()).unwrap_err()
+        };
+
+        let rate_limited = error(
+            StatusCode::TOO_MANY_REQUESTS,
+            "request_error",
+            "too_many_requests",
+        );
+        assert!(rate_limited.is_rate_limit());
+        assert!(rate_limited.is_retryable());
+
+        let internal = error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "api_error",
+            "internal_error",
+        );
+        assert!(internal.is_retryable());
+        assert!(!internal.is_rate_limit());
+
+        let locked = error(
+            StatusCode::CONFLICT,
+            "request_error",
+            "subscription_locked_pending_changes",
+        );
+        assert!(locked.is_conflict());
+        assert!(!locked.is_retryable());
+
+        let invalid = error(StatusCode::BAD_REQUEST, "request_error", "invalid_field");
+        assert_eq!(invalid.status_code(), Some(StatusCode::BAD_REQUEST));
+        assert!(matches!(
+            &invalid,
+            Error::PaddleApi(err) if err.error.status_code == Some(StatusCode::BAD_REQUEST)
+        ));
+        assert!(!invalid.is_retryable());
+        assert!(!invalid.is_conflict());
+        assert!(!Error::MissingUrl.is_retryable());
+    }
+}
@@ -2,14 +2,21 @@
 //!
 //! See the [Paddle API](https://developer.paddle.com/api-reference/pricing-preview/overview) documentation for more information.
 
+use std::future::Future;
+
+use chrono::{DateTime, Utc};
+use reqwest::Method;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use serde_with::skip_serializing_none;
 
 use crate::entities::Event;
+use crate::enums::EventData;
 use crate::ids::PaddleID;
 use crate::paginated::Paginated;
-use crate::Paddle;
+use crate::permissions::Permission;
+use crate::request::PaddleRequest;
+use crate::{Error, Paddle};
 
 pub trait ReportType: Serialize {
     type FilterName: Serialize + DeserializeOwned;
@@ -17,10 +24,13 @@ pub trait ReportType: Serialize {
 
 /// Request builder for querying Paddle for events.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct EventsList<'a> {
     #[serde(skip)]
     client: &'a Paddle,
+    #[serde(skip)]
+    entity_id: Option<String>,
     after: Option<PaddleID>,
     order_by: Option<String>,
     per_page: Option<usize>,
@@ -30,6 +40,7 @@ impl<'a> EventsList<'a> {
     pub fn new(client: &'a Paddle) -> Self {
         Self {
             client,
+            entity_id: None,
             after: None,
             order_by: None,
             per_page: None,
@@ -37,34 +48,301 @@ impl<'a> EventsList<'a> {
     }
 
     /// Return entities after the specified Paddle ID when working with paginated endpoints. Used in the `meta.pagination.next` URL in responses for list operations.
-    pub fn after(&mut self, report_id: impl Into<PaddleID>) -> &mut Self {
+    pub fn after(mut self, report_id: impl Into<PaddleID>) -> Self {
         self.after = Some(report_id.into());
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: `id`
-    pub fn order_by_asc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_asc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[ASC]", field));
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: `id`
-    pub fn order_by_desc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_desc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[DESC]", field));
         self
     }
 
+    /// Number of entities Paddle returns per page when [Self::per_page] isn't set.
+    pub const DEFAULT_PER_PAGE: usize = 50;
+    /// Largest page size Paddle accepts for this endpoint.
+    pub const MAX_PER_PAGE: usize = 200;
+
     /// Set how many entities are returned per page. Paddle returns the maximum number of results if a number greater than the maximum is requested.
     /// Check `meta.pagination.per_page` in the response to see how many were returned.
     ///
     /// Default: `50`; Maximum: `200`.
-    pub fn per_page(&mut self, entities_per_page: usize) -> &mut Self {
+    pub fn per_page(mut self, entities_per_page: usize) -> Self {
         self.per_page = Some(entities_per_page);
         self
     }
 
+    /// Request the largest page Paddle allows for this endpoint. Use this to fetch everything in as few requests as possible.
+    pub fn max_page_size(self) -> Self {
+        self.per_page(Self::MAX_PER_PAGE)
+    }
+
+    /// Only return events for this entity, e.g. to build the activity timeline of a subscription or a customer.
+    ///
+    /// Events match when the entity they're about has this ID, or when it belongs to a customer or subscription with this ID, e.g. the transactions of a subscription.
+    /// Paddle can't filter events by entity, so every event is still fetched and the others are dropped from each page.
+    ///
+    /// ```rust,no_run
+    /// let events = client
+    ///     .events_list()
+    ///     .for_entity("sub_01hv8x29kz0t586xy6zn1a62ny")
+    ///     .max_page_size()
+    ///     .send()
+    ///     .all()
+    ///     .await?;
+    /// ```
+    pub fn for_entity(mut self, entity_id: impl AsRef<str>) -> Self {
+        self.entity_id = Some(entity_id.as_ref().to_string());
+        self
+    }
+
     /// Returns a paginator for fetching pages of entities from Paddle
     pub fn send(&self) -> Paginated<'_, Vec<Event>> {
-        Paginated::new(self.client, "/events", self)
+        let paginated = Paginated::new(self.client, &self.path(), self);
+
+        match self.entity_id.clone() {
+            Some(entity_id) => {
+                paginated.filter(move |event: &Event| is_for_entity(&event.data, &entity_id))
+            }
+            None => paginated,
+        }
+    }
+}
+
+/// Whether an event is about `entity_id` or about an entity belonging to the customer or subscription `entity_id`.
+fn is_for_entity(data: &EventData, entity_id: &str) -> bool {
+    if data.entity_id() == entity_id {
+        return true;
+    }
+
+    let (customer_id, subscription_id) = match data {
+        EventData::AddressCreated(address)
+        | EventData::AddressImported(address)
+        | EventData::AddressUpdated(address) => (Some(&address.customer_id), None),
+        EventData::BusinessCreated(business)
+        | EventData::BusinessImported(business)
+        | EventData::BusinessUpdated(business) => (Some(&business.customer_id), None),
+        EventData::PaymentMethodSaved(payment_method)
+        | EventData::PaymentMethodDeleted(payment_method) => {
+            (Some(&payment_method.customer_id), None)
+        }
+        EventData::AdjustmentCreated(adjustment) | EventData::AdjustmentUpdated(adjustment) => (
+            Some(&adjustment.customer_id),
+            adjustment.subscription_id.as_ref(),
+        ),
+        EventData::SubscriptionCreated(event) => (Some(&event.subscription.customer_id), None),
+        EventData::SubscriptionActivated(subscription)
+        | EventData::SubscriptionCanceled(subscription)
+        | EventData::SubscriptionImported(subscription)
+        | EventData::SubscriptionPastDue(subscription)
+        | EventData::SubscriptionPaused(subscription)
+        | EventData::SubscriptionResumed(subscription)
+        | EventData::SubscriptionTrialing(subscription)
+        | EventData::SubscriptionUpdated(subscription) => (Some(&subscription.customer_id), None),
+        EventData::TransactionBilled(transaction)
+        | EventData::TransactionCanceled(transaction)
+        | EventData::TransactionCompleted(transaction)
+        | EventData::TransactionCreated(transaction)
+        | EventData::TransactionPaid(transaction)
+        | EventData::TransactionPastDue(transaction)
+        | EventData::TransactionPaymentFailed(transaction)
+        | EventData::TransactionReady(transaction)
+        | EventData::TransactionRevised(transaction)
+        | EventData::TransactionUpdated(transaction) => (
+            transaction.customer_id.as_ref(),
+            transaction.subscription_id.as_ref(),
+        ),
+        _ => (None, None),
+    };
+
+    customer_id.is_some_and(|id| id.as_ref() == entity_id)
+        || subscription_id.is_some_and(|id| id.as_ref() == entity_id)
+}
+
+impl PaddleRequest for EventsList<'_> {
+    type Output = Vec<Event>;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        "/events".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::EventRead]
+    }
+}
+
+/// Replay every event that occurred since `from` through `handler`, oldest first.
+///
+/// Use this to recover after webhooks were missed, e.g. because your endpoint was down. `handler` receives the same [Event] a webhook delivery would, so the webhook handler can be reused. Paddle keeps events for 90 days, older events can't be replayed.
+///
+/// Stops at the first error returned by `handler` or by Paddle. Returns how many events were handled.
+///
+/// ```rust,no_run
+/// use chrono::{Duration, Utc};
+/// use paddle_rust_sdk::events;
+///
+/// let replayed = events::backfill(&client, Utc::now() - Duration::hours(6), |event| async move {
+///     handle_webhook(event).await
+/// })
+/// .await?;
+/// ```
+pub async fn backfill<F, Fut, E>(
+    client: &Paddle,
+    from: DateTime<Utc>,
+    mut handler: F,
+) -> Result<usize, E>
+where
+    F: FnMut(Event) -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+    E: From<Error>,
+{
+    let list = client.events_list().order_by_asc("id").max_page_size();
+
+    let mut pages = list.send();
+    let mut handled = 0;
+
+    while let Some(page) = pages.next().await? {
+        for event in page.data {
+            if event.occurred_at < from {
+                continue;
+            }
+
+            handler(event).await?;
+            handled += 1;
+        }
+    }
+
+    Ok(handled)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::enums::EventTypeName;
+    use crate::fixtures;
+
+    #[tokio::test]
+    async fn backfill_replays_events_since() {
+        let url = fixtures::serve_json(|target| async move {
+            let (event_id, occurred_at, next) = if target.contains("after=") {
+                ("evt_02", "2024-04-12T12:00:00Z", "/events?after=evt_02")
+            } else {
+                ("evt_01", "2024-04-12T08:00:00Z", "/events?after=evt_01")
+            };
+
+            let mut event =
+                serde_json::to_value(fixtures::event("customer.updated", fixtures::customer()))
+                    .unwrap();
+            event["event_id"] = json!(event_id);
+            event["occurred_at"] = json!(occurred_at);
+
+            json!({
+                "data": [event],
+                "meta": {
+                    "request_id": "req_1",
+                    "pagination": {
+                        "per_page": 1,
+                        "next": format!("http://localhost{next}"),
+                        "has_more": event_id == "evt_01",
+                        "estimated_total": 2
+                    }
+                }
+            })
+        })
+        .await;
+
+        let client = Paddle::new("key", url.as_str()).unwrap();
+        let from = "2024-04-12T10:00:00Z".parse().unwrap();
+        let replayed = Mutex::new(Vec::new());
+
+        let handled = backfill(&client, from, |event| {
+            replayed.lock().unwrap().push(event.event_id.to_string());
+            async { Ok::<_, Error>(()) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(handled, 1);
+        assert_eq!(*replayed.lock().unwrap(), ["evt_02"]);
+    }
+
+    #[tokio::test]
+    async fn for_entity_keeps_events_of_the_entity_and_its_children() {
+        let url = fixtures::serve_json(|_| async move {
+            let mut other = fixtures::subscription();
+            other["id"] = json!("sub_01hv915hmgnwqd9n5yxgy8t60c");
+            other["customer_id"] = json!("ctm_01hv915ahkdxzdgxpjhbbtrgkt");
+
+            json!({
+                "data": [
+                    fixtures::event("subscription.updated", fixtures::subscription()),
+                    fixtures::event("transaction.paid", fixtures::transaction()),
+                    fixtures::event("subscription.updated", other),
+                    fixtures::event("customer.updated", fixtures::customer()),
+                ],
+                "meta": {
+                    "request_id": "req_1",
+                    "pagination": {
+                        "per_page": 50,
+                        "next": "http://localhost/events?after=evt_01",
+                        "has_more": false,
+                        "estimated_total": 4
+                    }
+                }
+            })
+        })
+        .await;
+
+        let client = Paddle::new("key", url.as_str()).unwrap();
+        let event_types = |events: Vec<Event>| {
+            events
+                .into_iter()
+                .map(|event| event.data.event_type())
+                .collect::<Vec<_>>()
+        };
+
+        let events = client
+            .events_list()
+            .for_entity("sub_01hv8x29kz0t586xy6zn1a62ny")
+            .send()
+            .all()
+            .await
+            .unwrap();
+        assert_eq!(
+            event_types(events),
+            [
+                EventTypeName::SubscriptionUpdated,
+                EventTypeName::TransactionPaid
+            ]
+        );
+
+        let events = client
+            .events_list()
+            .for_entity("ctm_01hv6y1jedq4p1n0yqn5ba3ky4")
+            .send()
+            .all()
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 3);
     }
 }
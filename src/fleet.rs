@@ -0,0 +1,227 @@
+//! Working with several Paddle accounts at once.
+//!
+//! Companies that run a separate Paddle account per region or brand can group their clients in a [PaddleFleet] and list entities from every account in one call. Every result is tagged with the name of the account it came from, so the results can be combined into unified reports.
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::fleet::PaddleFleet;
+//! use paddle_rust_sdk::Paddle;
+//!
+//! let fleet = PaddleFleet::new()
+//!     .account("eu", Paddle::new("eu_api_key", Paddle::PRODUCTION).unwrap())
+//!     .account("us", Paddle::new("us_api_key", Paddle::PRODUCTION).unwrap());
+//!
+//! let customers = fleet.customers().await;
+//!
+//! for (account, err) in &customers.failed {
+//!     eprintln!("Failed to list customers for {account}: {err}");
+//! }
+//!
+//! for customer in customers.items {
+//!     println!("{}: {}", customer.account, customer.data.email);
+//! }
+//! ```
+
+use std::future::Future;
+
+use tokio::task::JoinSet;
+
+use crate::budget::Budget;
+use crate::entities::{Customer, Price, Product, Subscription, Transaction};
+use crate::{Error, Paddle};
+
+/// A group of clients for different Paddle accounts, identified by name.
+#[derive(Clone, Debug, Default)]
+pub struct PaddleFleet {
+    accounts: Vec<(String, Paddle)>,
+}
+
+/// A value along with the name of the account it was fetched from.
+#[derive(Clone, Debug)]
+pub struct Tagged<T> {
+    /// Name the account was added to the fleet with.
+    pub account: String,
+    pub data: T,
+}
+
+/// Results of a request sent to every account of a fleet.
+#[derive(Debug)]
+pub struct FleetResults<T> {
+    /// Entities returned by the accounts that succeeded, in the order accounts were added.
+    pub items: Vec<Tagged<T>>,
+    /// Accounts that failed along with their error.
+    pub failed: Vec<(String, Error)>,
+}
+
+impl<T> FleetResults<T> {
+    /// Whether every account succeeded.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+impl PaddleFleet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a client for an account. Adding a name that's already in the fleet replaces its client.
+    pub fn account(mut self, name: impl Into<String>, client: Paddle) -> Self {
+        let name = name.into();
+
+        match self
+            .accounts
+            .iter_mut()
+            .find(|(account, _)| *account == name)
+        {
+            Some((_, existing)) => *existing = client,
+            None => self.accounts.push((name, client)),
+        }
+
+        self
+    }
+
+    /// Get the client for an account by name.
+    pub fn get(&self, name: &str) -> Option<&Paddle> {
+        self.accounts
+            .iter()
+            .find(|(account, _)| account == name)
+            .map(|(_, client)| client)
+    }
+
+    /// Names and clients of every account in the fleet.
+    pub fn accounts(&self) -> impl Iterator<Item = (&str, &Paddle)> {
+        self.accounts
+            .iter()
+            .map(|(account, client)| (account.as_str(), client))
+    }
+
+    /// List customers from every account. Only `active` customers are returned.
+    pub async fn customers(&self) -> FleetResults<Customer> {
+        self.fan_out(|client| async move { client.customers_list().send().all().await })
+            .await
+    }
+
+    /// List products from every account. Only `active` products are returned.
+    pub async fn products(&self) -> FleetResults<Product> {
+        self.fan_out(|client| async move { client.products_list().send().all().await })
+            .await
+    }
+
+    /// List prices from every account. Only `active` prices are returned.
+    pub async fn prices(&self) -> FleetResults<Price> {
+        self.fan_out(|client| async move { client.prices_list().send().all().await })
+            .await
+    }
+
+    /// List subscriptions from every account.
+    pub async fn subscriptions(&self) -> FleetResults<Subscription> {
+        self.fan_out(|client| async move { client.subscriptions_list().send().all().await })
+            .await
+    }
+
+    /// List transactions from every account.
+    pub async fn transactions(&self) -> FleetResults<Transaction> {
+        self.fan_out(|client| async move { client.transactions_list().send().all().await })
+            .await
+    }
+
+    /// Send a request to every account at the same time and tag the returned entities with the account name.
+    ///
+    /// Use this to apply filters the other methods don't:
+    ///
+    /// ```rust,no_run
+    /// use paddle_rust_sdk::enums::TransactionStatus;
+    ///
+    /// let completed = fleet
+    ///     .fan_out(|client| async move {
+    ///         client
+    ///             .transactions_list()
+    ///             .status([TransactionStatus::Completed])
+    ///             .send()
+    ///             .all()
+    ///             .await
+    ///     })
+    ///     .await;
+    /// ```
+    pub async fn fan_out<T, Fut>(&self, fetch: impl Fn(Paddle) -> Fut) -> FleetResults<T>
+    where
+        T: Send + 'static,
+        Fut: Future<Output = std::result::Result<Vec<T>, Error>> + Send + 'static,
+    {
+        let mut requests = JoinSet::new();
+
+        for (index, (_, client)) in self.accounts.iter().enumerate() {
+            let request = fetch(client.clone());
+            requests.spawn(Budget::inherit(async move { (index, request.await) }));
+        }
+
+        let mut results = Vec::with_capacity(self.accounts.len());
+
+        while let Some(result) = requests.join_next().await {
+            results.push(result.unwrap_or_else(|err| std::panic::resume_unwind(err.into_panic())));
+        }
+
+        results.sort_by_key(|(index, _)| *index);
+
+        let mut items = Vec::new();
+        let mut failed = Vec::new();
+
+        for (index, result) in results {
+            let account = &self.accounts[index].0;
+
+            match result {
+                Ok(entities) => items.extend(entities.into_iter().map(|data| Tagged {
+                    account: account.clone(),
+                    data,
+                })),
+                Err(err) => failed.push((account.clone(), err)),
+            }
+        }
+
+        FleetResults { items, failed }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    #[tokio::test]
+    async fn tags_results_and_collects_failures() {
+        let url = fixtures::serve_json(|_| async {
+            json!({
+                "data": [fixtures::subscription()],
+                "meta": {
+                    "request_id": "req_1",
+                    "pagination": {
+                        "per_page": 200,
+                        "next": "http://localhost/subscriptions",
+                        "has_more": false,
+                        "estimated_total": 1
+                    }
+                }
+            })
+        })
+        .await;
+        let broken = fixtures::serve_json(|_| async { json!({}) }).await;
+
+        let fleet = PaddleFleet::new()
+            .account("eu", Paddle::new("key", url.as_str()).unwrap())
+            .account("us", Paddle::new("key", broken.as_str()).unwrap())
+            .account("apac", Paddle::new("key", url.as_str()).unwrap());
+
+        let results = fleet.subscriptions().await;
+
+        let accounts = results
+            .items
+            .iter()
+            .map(|item| item.account.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(accounts, ["eu", "apac"]);
+        assert_eq!(results.failed.len(), 1);
+        assert_eq!(results.failed[0].0, "us");
+    }
+}
@@ -0,0 +1,306 @@
+//! Helpers for following the ID fields of an entity to the entities they reference.
+//!
+//! Each trait is implemented for an entity type and issues one request per call. Bring the trait into scope to use it:
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::{hydrate::TransactionRefs, Paddle};
+//!
+//! let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+//! let transaction = client.transaction_get("txn_01hv8wptq8987qeep44cyrewp9").send().await.unwrap().data.transaction;
+//! let customer = transaction.customer(&client).await.unwrap();
+//! ```
+//!
+//! To resolve references of many entities at once, use the batch helpers like [customers_with_subscriptions]. They fetch referenced entities with ID filters instead of one request per entity.
+
+use std::collections::HashMap;
+use std::future::Future;
+
+use crate::entities::{
+    Address, Adjustment, Business, Customer, Subscription, SubscriptionWithInclude, Transaction,
+    TransactionWithIncludes,
+};
+use crate::enums::SubscriptionStatus;
+use crate::ids::CustomerID;
+use crate::response::SuccessResponse;
+use crate::{Error, Paddle, Result};
+
+/// Result of following an optional reference. `Ok(None)` when the referencing entity has no ID set.
+pub type OptionalResult<T> = std::result::Result<Option<SuccessResponse<T>>, Error>;
+
+/// Follow references from a [Transaction] to related entities.
+pub trait TransactionRefs {
+    /// Fetch the customer this transaction is for.
+    fn customer(&self, client: &Paddle) -> impl Future<Output = OptionalResult<Customer>> + Send;
+
+    /// Fetch the address this transaction is for.
+    fn address(&self, client: &Paddle) -> impl Future<Output = OptionalResult<Address>> + Send;
+
+    /// Fetch the business this transaction is for.
+    fn business(&self, client: &Paddle) -> impl Future<Output = OptionalResult<Business>> + Send;
+
+    /// Fetch the subscription this transaction is for.
+    fn subscription(
+        &self,
+        client: &Paddle,
+    ) -> impl Future<Output = OptionalResult<SubscriptionWithInclude>> + Send;
+}
+
+impl TransactionRefs for Transaction {
+    async fn customer(&self, client: &Paddle) -> OptionalResult<Customer> {
+        match &self.customer_id {
+            Some(customer_id) => client
+                .customer_get(customer_id.clone())
+                .send()
+                .await
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    async fn address(&self, client: &Paddle) -> OptionalResult<Address> {
+        match (&self.customer_id, &self.address_id) {
+            (Some(customer_id), Some(address_id)) => client
+                .address_get(customer_id.clone(), address_id.clone())
+                .send()
+                .await
+                .map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    async fn business(&self, client: &Paddle) -> OptionalResult<Business> {
+        match (&self.customer_id, &self.business_id) {
+            (Some(customer_id), Some(business_id)) => client
+                .business_get(customer_id.clone(), business_id.clone())
+                .send()
+                .await
+                .map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    async fn subscription(&self, client: &Paddle) -> OptionalResult<SubscriptionWithInclude> {
+        match &self.subscription_id {
+            Some(subscription_id) => client
+                .subscription_get(subscription_id.clone())
+                .send()
+                .await
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Follow references from a [Subscription] to related entities.
+pub trait SubscriptionRefs {
+    /// Fetch the customer this subscription is for.
+    fn customer(&self, client: &Paddle) -> impl Future<Output = Result<Customer>> + Send;
+
+    /// Fetch the address this subscription is for.
+    fn address(&self, client: &Paddle) -> impl Future<Output = Result<Address>> + Send;
+
+    /// Fetch the business this subscription is for.
+    fn business(&self, client: &Paddle) -> impl Future<Output = OptionalResult<Business>> + Send;
+}
+
+impl SubscriptionRefs for Subscription {
+    async fn customer(&self, client: &Paddle) -> Result<Customer> {
+        client.customer_get(self.customer_id.clone()).send().await
+    }
+
+    async fn address(&self, client: &Paddle) -> Result<Address> {
+        client
+            .address_get(self.customer_id.clone(), self.address_id.clone())
+            .send()
+            .await
+    }
+
+    async fn business(&self, client: &Paddle) -> OptionalResult<Business> {
+        match &self.business_id {
+            Some(business_id) => client
+                .business_get(self.customer_id.clone(), business_id.clone())
+                .send()
+                .await
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Follow references from an [Adjustment] to related entities.
+pub trait AdjustmentRefs {
+    /// Fetch the transaction this adjustment is for.
+    fn transaction(
+        &self,
+        client: &Paddle,
+    ) -> impl Future<Output = Result<TransactionWithIncludes>> + Send;
+
+    /// Fetch the customer this adjustment is for.
+    fn customer(&self, client: &Paddle) -> impl Future<Output = Result<Customer>> + Send;
+
+    /// Fetch the subscription this adjustment is for.
+    fn subscription(
+        &self,
+        client: &Paddle,
+    ) -> impl Future<Output = OptionalResult<SubscriptionWithInclude>> + Send;
+}
+
+impl AdjustmentRefs for Adjustment {
+    async fn transaction(&self, client: &Paddle) -> Result<TransactionWithIncludes> {
+        client
+            .transaction_get(self.transaction_id.clone())
+            .send()
+            .await
+    }
+
+    async fn customer(&self, client: &Paddle) -> Result<Customer> {
+        client.customer_get(self.customer_id.clone()).send().await
+    }
+
+    async fn subscription(&self, client: &Paddle) -> OptionalResult<SubscriptionWithInclude> {
+        match &self.subscription_id {
+            Some(subscription_id) => client
+                .subscription_get(subscription_id.clone())
+                .send()
+                .await
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// List subscriptions with one of `statuses` and pair them with their customers.
+///
+/// Subscriptions are paged and their customers fetched in batches with [Paddle::get_customers], instead of a request per customer. Customers are returned in the order their first subscription was listed in, archived customers included. Subscriptions whose customer doesn't exist are left out.
+///
+/// ```rust,no_run
+/// use paddle_rust_sdk::enums::SubscriptionStatus;
+/// use paddle_rust_sdk::hydrate::customers_with_subscriptions;
+///
+/// let accounts = customers_with_subscriptions(&client, [SubscriptionStatus::Active]).await?;
+///
+/// for (customer, subscriptions) in accounts {
+///     println!("{}: {} subscriptions", customer.email, subscriptions.len());
+/// }
+/// ```
+pub async fn customers_with_subscriptions(
+    client: &Paddle,
+    statuses: impl IntoIterator<Item = SubscriptionStatus>,
+) -> std::result::Result<Vec<(Customer, Vec<Subscription>)>, Error> {
+    let subscriptions = client
+        .subscriptions_list()
+        .status(statuses)
+        .max_page_size()
+        .send()
+        .all()
+        .await?;
+
+    let mut customer_ids: Vec<CustomerID> = Vec::new();
+    let mut by_customer: HashMap<CustomerID, Vec<Subscription>> = HashMap::new();
+
+    for subscription in subscriptions {
+        let customer_subscriptions = by_customer
+            .entry(subscription.customer_id.clone())
+            .or_insert_with(|| {
+                customer_ids.push(subscription.customer_id.clone());
+                Vec::new()
+            });
+        customer_subscriptions.push(subscription);
+    }
+
+    let mut customers = client.get_customers(customer_ids.clone()).await?.found;
+
+    Ok(customer_ids
+        .into_iter()
+        .filter_map(|customer_id| {
+            let customer = customers.remove(&customer_id)?;
+            Some((customer, by_customer.remove(&customer_id)?))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    #[tokio::test]
+    async fn pairs_customers_with_their_subscriptions() {
+        let url = fixtures::serve_json(|target| async move {
+            let data = if target.starts_with("/customers") {
+                assert!(
+                    target.contains("ctm_01hv6y1jedq4p1n0yqn5ba3ky4"),
+                    "{target}"
+                );
+                json!([fixtures::customer()])
+            } else {
+                json!([fixtures::subscription(), fixtures::subscription()])
+            };
+
+            json!({
+                "data": data,
+                "meta": {
+                    "request_id": "req_1",
+                    "pagination": {
+                        "per_page": 200,
+                        "next": "http://localhost/next",
+                        "has_more": false,
+                        "estimated_total": 2
+                    }
+                }
+            })
+        })
+        .await;
+
+        let client = Paddle::new("key", url.as_str()).unwrap();
+        let accounts = customers_with_subscriptions(&client, [SubscriptionStatus::Active])
+            .await
+            .unwrap();
+
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].0.email, "sam@example.com");
+        assert_eq!(accounts[0].1.len(), 2);
+    }
+    #[tokio::test]
+    async fn archived_customers_are_paired() {
+        let url = fixtures::serve_json(|target| async move {
+            let data = if target.starts_with("/customers") {
+                // Only the archived lookup finds the customer
+                if target.contains("status=archived") {
+                    let mut customer = fixtures::customer();
+                    customer["status"] = "archived".into();
+                    json!([customer])
+                } else {
+                    json!([])
+                }
+            } else {
+                json!([fixtures::subscription()])
+            };
+
+            json!({
+                "data": data,
+                "meta": {
+                    "request_id": "req_1",
+                    "pagination": {
+                        "per_page": 200,
+                        "next": "http://localhost/next",
+                        "has_more": false,
+                        "estimated_total": 1
+                    }
+                }
+            })
+        })
+        .await;
+
+        let client = Paddle::new("key", url.as_str()).unwrap();
+        let accounts = customers_with_subscriptions(&client, [SubscriptionStatus::Active])
+            .await
+            .unwrap();
+
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].0.email, "sam@example.com");
+        assert_eq!(accounts[0].1.len(), 1);
+    }
+}
@@ -0,0 +1,188 @@
+//! Processing webhook events exactly once.
+//!
+//! Paddle delivers webhooks at least once. A delivery that times out or fails is retried, even when your handler already acted on it, so the same event can arrive several times. [process_once] records handled events in a [ProcessedEventStore] and skips events that were already handled, so side effects like emails or entitlement grants aren't repeated.
+//!
+//! [MemoryProcessedEventStore] keeps processed events in memory, implement the trait to store them in your database. A store backed by SQL could look like this:
+//!
+//! ```rust,no_run
+//! use chrono::{Duration, Utc};
+//! use paddle_rust_sdk::idempotency::ProcessedEventStore;
+//! use paddle_rust_sdk::ids::EventID;
+//!
+//! // CREATE TABLE processed_events (event_id TEXT PRIMARY KEY, expires_at TIMESTAMPTZ NOT NULL);
+//! struct SqlProcessedEventStore {
+//!     pool: sqlx::PgPool,
+//! }
+//!
+//! impl ProcessedEventStore for SqlProcessedEventStore {
+//!     type Error = sqlx::Error;
+//!
+//!     async fn is_processed(&self, event_id: &EventID) -> Result<bool, sqlx::Error> {
+//!         sqlx::query("SELECT 1 FROM processed_events WHERE event_id = $1 AND expires_at > now()")
+//!             .bind(event_id.as_ref())
+//!             .fetch_optional(&self.pool)
+//!             .await
+//!             .map(|row| row.is_some())
+//!     }
+//!
+//!     async fn mark_processed(&self, event_id: &EventID, ttl: Duration) -> Result<(), sqlx::Error> {
+//!         sqlx::query(
+//!             "INSERT INTO processed_events (event_id, expires_at) VALUES ($1, $2)
+//!              ON CONFLICT (event_id) DO UPDATE SET expires_at = EXCLUDED.expires_at",
+//!         )
+//!         .bind(event_id.as_ref())
+//!         .bind(Utc::now() + ttl)
+//!         .execute(&self.pool)
+//!         .await
+//!         .map(|_| ())
+//!     }
+//! }
+//! ```
+//!
+//! Delete rows past `expires_at` periodically to keep the table small.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::future::Future;
+use std::sync::RwLock;
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::entities::Event;
+use crate::ids::EventID;
+
+/// How long [process_once] remembers processed events. Longer than Paddle keeps retrying failed deliveries.
+pub const DEFAULT_TTL: Duration = Duration::days(7);
+
+/// Persists the IDs of processed webhook events.
+pub trait ProcessedEventStore {
+    /// Error returned by the store.
+    type Error;
+
+    /// Whether the event was processed and hasn't expired yet.
+    fn is_processed(
+        &self,
+        event_id: &EventID,
+    ) -> impl Future<Output = Result<bool, Self::Error>> + Send;
+
+    /// Record the event as processed. The record can be forgotten once `ttl` has passed.
+    fn mark_processed(
+        &self,
+        event_id: &EventID,
+        ttl: Duration,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+/// [ProcessedEventStore] keeping processed events in memory. Records are lost when the process exits.
+#[derive(Debug, Default)]
+pub struct MemoryProcessedEventStore {
+    expires_at: RwLock<HashMap<EventID, DateTime<Utc>>>,
+}
+
+impl ProcessedEventStore for MemoryProcessedEventStore {
+    type Error = Infallible;
+
+    async fn is_processed(&self, event_id: &EventID) -> Result<bool, Infallible> {
+        let expires_at = self
+            .expires_at
+            .read()
+            .unwrap_or_else(|err| err.into_inner());
+
+        Ok(expires_at
+            .get(event_id)
+            .is_some_and(|expires_at| *expires_at > Utc::now()))
+    }
+
+    async fn mark_processed(&self, event_id: &EventID, ttl: Duration) -> Result<(), Infallible> {
+        let now = Utc::now();
+        let mut expires_at = self
+            .expires_at
+            .write()
+            .unwrap_or_else(|err| err.into_inner());
+
+        expires_at.retain(|_, expires_at| *expires_at > now);
+        expires_at.insert(event_id.clone(), now + ttl);
+
+        Ok(())
+    }
+}
+
+/// Run `handler` for an event unless it was already processed.
+///
+/// The event is recorded as processed for `ttl` once `handler` succeeds. When it fails the event isn't recorded, so it's handled again when Paddle retries the delivery. Returns `None` if the event was skipped.
+///
+/// Two deliveries of the same event arriving at the same time can both be handled. Wrap the call in [EntityLocks::run](crate::webhooks::EntityLocks::run) to rule that out.
+///
+/// ```rust,no_run
+/// use paddle_rust_sdk::idempotency::{self, MemoryProcessedEventStore};
+///
+/// let store = MemoryProcessedEventStore::default();
+///
+/// idempotency::process_once(&store, &event, idempotency::DEFAULT_TTL, || async {
+///     send_welcome_email(&event).await
+/// })
+/// .await?;
+/// ```
+pub async fn process_once<S, F, Fut, T, E>(
+    store: &S,
+    event: &Event,
+    ttl: Duration,
+    handler: F,
+) -> Result<Option<T>, E>
+where
+    S: ProcessedEventStore,
+    E: From<S::Error>,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    if store.is_processed(&event.event_id).await? {
+        return Ok(None);
+    }
+
+    let output = handler().await?;
+    store.mark_processed(&event.event_id, ttl).await?;
+
+    Ok(Some(output))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[derive(Debug)]
+    struct HandlerError;
+
+    impl From<Infallible> for HandlerError {
+        fn from(err: Infallible) -> Self {
+            match err {}
+        }
+    }
+
+    #[tokio::test]
+    async fn events_are_processed_once() {
+        let store = MemoryProcessedEventStore::default();
+        let event = fixtures::event("subscription.updated", fixtures::subscription());
+
+        let failed = process_once(&store, &event, DEFAULT_TTL, || async {
+            Err::<(), _>(HandlerError)
+        })
+        .await;
+        assert!(failed.is_err());
+
+        for expected in [Some(1), None] {
+            let processed = process_once(&store, &event, DEFAULT_TTL, || async {
+                Ok::<_, HandlerError>(1)
+            })
+            .await
+            .unwrap();
+            assert_eq!(processed, expected);
+        }
+
+        store
+            .mark_processed(&event.event_id, Duration::zero())
+            .await
+            .unwrap();
+        assert!(!store.is_processed(&event.event_id).await.unwrap());
+    }
+}
@@ -0,0 +1,367 @@
+//! Glue for verifying webhooks in web frameworks.
+//!
+//! Paddle signs the raw request body. Signature verification fails if the body is parsed as JSON, re-encoded or trimmed before it's verified, so the adapters here always work on the unmodified bytes of the body.
+//!
+//! Each adapter is behind a feature named after the framework: `actix-web`, `axum`, `warp` and `rocket`.
+
+use crate::entities::Event;
+use crate::webhooks::WebhookVerifier;
+use crate::Error;
+
+/// Verify the signature of a webhook request and deserialize the event with [WebhookVerifier::unmarshal].
+///
+/// `signature` is the value of the `Paddle-Signature` header, `None` if the header is missing.
+fn unmarshal(
+    signature: Option<&str>,
+    request_body: &[u8],
+    verifier: &WebhookVerifier,
+) -> Result<Event, Error> {
+    // A missing header is reported like an empty one
+    verifier.unmarshal(request_body, signature.unwrap_or_default())
+}
+
+/// Webhook verification for [actix-web](https://actix.rs).
+///
+/// ```rust,no_run
+/// use actix_web::{post, web, HttpRequest, HttpResponse};
+/// use paddle_rust_sdk::integrations::actix;
+/// use paddle_rust_sdk::webhooks::MaximumVariance;
+///
+/// #[post("/paddle-callback")]
+/// async fn paddle_callback(req: HttpRequest, body: web::Bytes) -> HttpResponse {
+///     match actix::unmarshal(&req, &body, "your_webhook_key", MaximumVariance::default()) {
+///         Ok(event) => HttpResponse::Ok().finish(),
+///         Err(_) => HttpResponse::BadRequest().finish(),
+///     }
+/// }
+/// ```
+#[cfg(feature = "actix-web")]
+pub mod actix {
+    use actix_web::HttpRequest;
+
+    use crate::entities::Event;
+    use crate::webhooks::{MaximumVariance, WebhookVerifier, SIGNATURE_HEADER};
+    use crate::Error;
+
+    /// Verify a webhook request and deserialize the event.
+    ///
+    /// Extract the body as `web::Bytes`. Extracting it as `String` or `web::Json` works against an already processed body.
+    pub fn unmarshal(
+        req: &HttpRequest,
+        request_body: &[u8],
+        secret_key: impl AsRef<str>,
+        maximum_variance: MaximumVariance,
+    ) -> Result<Event, Error> {
+        let verifier = WebhookVerifier::new(secret_key.as_ref()).maximum_variance(maximum_variance);
+
+        unmarshal_with(req, request_body, &verifier)
+    }
+
+    /// Same as [unmarshal], with the options of a [WebhookVerifier].
+    pub fn unmarshal_with(
+        req: &HttpRequest,
+        request_body: &[u8],
+        verifier: &WebhookVerifier,
+    ) -> Result<Event, Error> {
+        let signature = req
+            .headers()
+            .get(SIGNATURE_HEADER)
+            .and_then(|value| value.to_str().ok());
+
+        super::unmarshal(signature, request_body, verifier)
+    }
+}
+
+/// Webhook verification for [axum](https://github.com/tokio-rs/axum).
+///
+/// ```rust,no_run
+/// use axum::body::Bytes;
+/// use axum::http::{HeaderMap, StatusCode};
+/// use paddle_rust_sdk::integrations::axum::unmarshal;
+/// use paddle_rust_sdk::webhooks::MaximumVariance;
+///
+/// async fn paddle_callback(headers: HeaderMap, body: Bytes) -> StatusCode {
+///     match unmarshal(&headers, &body, "your_webhook_key", MaximumVariance::default()) {
+///         Ok(event) => StatusCode::OK,
+///         Err(_) => StatusCode::BAD_REQUEST,
+///     }
+/// }
+/// ```
+#[cfg(feature = "axum")]
+pub mod axum {
+    use ::axum::http::HeaderMap;
+
+    use crate::entities::Event;
+    use crate::webhooks::{MaximumVariance, WebhookVerifier, SIGNATURE_HEADER};
+    use crate::Error;
+
+    /// Verify a webhook request and deserialize the event.
+    ///
+    /// Extract the body as `Bytes`. Extracting it as `String` or `Json` works against an already processed body.
+    pub fn unmarshal(
+        headers: &HeaderMap,
+        request_body: &[u8],
+        secret_key: impl AsRef<str>,
+        maximum_variance: MaximumVariance,
+    ) -> Result<Event, Error> {
+        let verifier = WebhookVerifier::new(secret_key.as_ref()).maximum_variance(maximum_variance);
+
+        unmarshal_with(headers, request_body, &verifier)
+    }
+
+    /// Same as [unmarshal], with the options of a [WebhookVerifier].
+    pub fn unmarshal_with(
+        headers: &HeaderMap,
+        request_body: &[u8],
+        verifier: &WebhookVerifier,
+    ) -> Result<Event, Error> {
+        let signature = headers
+            .get(SIGNATURE_HEADER)
+            .and_then(|value| value.to_str().ok());
+
+        super::unmarshal(signature, request_body, verifier)
+    }
+}
+
+/// Webhook verification for [warp](https://github.com/seanmonstar/warp).
+///
+/// ```rust,no_run
+/// use paddle_rust_sdk::integrations::warp::event;
+/// use paddle_rust_sdk::webhooks::MaximumVariance;
+/// use warp::Filter;
+///
+/// let route = warp::post()
+///     .and(warp::path("paddle-callback"))
+///     .and(event("your_webhook_key", MaximumVariance::default()))
+///     .map(|event| warp::reply());
+/// ```
+#[cfg(feature = "warp")]
+pub mod warp {
+    use std::sync::Arc;
+
+    use ::warp::hyper::body::Bytes;
+    use ::warp::{Filter, Rejection};
+
+    use crate::entities::Event;
+    use crate::webhooks::{MaximumVariance, WebhookVerifier, SIGNATURE_HEADER};
+    use crate::Error;
+
+    /// Rejection returned by [event] when a webhook request can't be verified.
+    #[derive(Debug)]
+    pub struct InvalidWebhook(pub Error);
+
+    impl ::warp::reject::Reject for InvalidWebhook {}
+
+    /// Filter that verifies a webhook request and extracts the event.
+    ///
+    /// Rejects with [InvalidWebhook] when the signature is missing or invalid.
+    pub fn event(
+        secret_key: impl Into<String>,
+        maximum_variance: MaximumVariance,
+    ) -> impl Filter<Extract = (Event,), Error = Rejection> + Clone {
+        event_with(WebhookVerifier::new(secret_key).maximum_variance(maximum_variance))
+    }
+
+    /// Same as [event], with the options of a [WebhookVerifier].
+    pub fn event_with(
+        verifier: WebhookVerifier,
+    ) -> impl Filter<Extract = (Event,), Error = Rejection> + Clone {
+        let verifier = Arc::new(verifier);
+
+        ::warp::header::optional::<String>(SIGNATURE_HEADER)
+            .and(::warp::body::bytes())
+            .and_then(move |signature: Option<String>, body: Bytes| {
+                let verifier = verifier.clone();
+
+                async move {
+                    super::unmarshal(signature.as_deref(), &body, &verifier)
+                        .map_err(|err| ::warp::reject::custom(InvalidWebhook(err)))
+                }
+            })
+    }
+}
+
+/// Webhook verification for [Rocket](https://rocket.rs).
+///
+/// Rocket limits `Vec<u8>` request bodies to the `bytes` data limit, 8KiB by default. Webhook events can be larger, so raise the limit in your Rocket config.
+///
+/// ```rust,no_run
+/// use paddle_rust_sdk::integrations::rocket::{unmarshal, PaddleSignature};
+/// use paddle_rust_sdk::webhooks::MaximumVariance;
+/// use rocket::http::Status;
+///
+/// #[rocket::post("/paddle-callback", data = "<body>")]
+/// fn paddle_callback(signature: PaddleSignature<'_>, body: Vec<u8>) -> Status {
+///     match unmarshal(signature, &body, "your_webhook_key", MaximumVariance::default()) {
+///         Ok(event) => Status::Ok,
+///         Err(_) => Status::BadRequest,
+///     }
+/// }
+/// ```
+#[cfg(feature = "rocket")]
+pub mod rocket {
+    use ::rocket::http::Status;
+    use ::rocket::request::{FromRequest, Outcome, Request};
+
+    use crate::entities::Event;
+    use crate::error::SignatureError;
+    use crate::webhooks::{MaximumVariance, WebhookVerifier, SIGNATURE_HEADER};
+    use crate::Error;
+
+    /// Request guard extracting the `Paddle-Signature` header. Fails with `400 Bad Request` when the header is missing.
+    #[derive(Clone, Copy, Debug)]
+    pub struct PaddleSignature<'r>(pub &'r str);
+
+    #[::rocket::async_trait]
+    impl<'r> FromRequest<'r> for PaddleSignature<'r> {
+        type Error = Error;
+
+        async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+            match request.headers().get_one(SIGNATURE_HEADER) {
+                Some(signature) => Outcome::Success(PaddleSignature(signature)),
+                None => Outcome::Error((
+                    Status::BadRequest,
+                    Error::PaddleSignature(SignatureError::Empty),
+                )),
+            }
+        }
+    }
+
+    /// Verify a webhook request and deserialize the event.
+    pub fn unmarshal(
+        signature: PaddleSignature<'_>,
+        request_body: &[u8],
+        secret_key: impl AsRef<str>,
+        maximum_variance: MaximumVariance,
+    ) -> Result<Event, Error> {
+        let verifier = WebhookVerifier::new(secret_key.as_ref()).maximum_variance(maximum_variance);
+
+        unmarshal_with(signature, request_body, &verifier)
+    }
+
+    /// Same as [unmarshal], with the options of a [WebhookVerifier].
+    pub fn unmarshal_with(
+        signature: PaddleSignature<'_>,
+        request_body: &[u8],
+        verifier: &WebhookVerifier,
+    ) -> Result<Event, Error> {
+        super::unmarshal(Some(signature.0), request_body, verifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::SignatureError;
+    use crate::fixtures;
+    use crate::webhooks::MaximumVariance;
+
+    const SECRET_KEY: &str = "pdl_ntfset_01h8bzakzx3hm2fmen703n5q45_secret";
+
+    fn body() -> Vec<u8> {
+        let event = fixtures::event("customer.created", fixtures::customer());
+        serde_json::to_vec(&event).unwrap()
+    }
+
+    #[test]
+    fn unmarshal_verifies_the_signature() {
+        let body = body();
+        let signature = fixtures::sign(&body, SECRET_KEY);
+        let verifier = WebhookVerifier::new(SECRET_KEY);
+
+        let event = unmarshal(Some(&signature), &body, &verifier).unwrap();
+        assert_eq!(event.event_id.as_ref(), "evt_01hv8x2acma3zqs7qwvbcwbm4r");
+
+        assert!(matches!(
+            unmarshal(None, &body, &verifier),
+            Err(Error::PaddleSignature(SignatureError::Empty))
+        ));
+        assert!(unmarshal(Some(&signature), &body[1..], &verifier).is_err());
+
+        // Verifier options apply to every adapter
+        let verifier = verifier.api_version(crate::webhooks::SUPPORTED_API_VERSION + 1);
+        assert!(matches!(
+            unmarshal(Some(&signature), &body, &verifier),
+            Err(Error::UnsupportedApiVersion(_))
+        ));
+    }
+
+    #[cfg(feature = "actix-web")]
+    #[test]
+    fn actix_reads_the_signature_header() {
+        let body = body();
+        let req = actix_web::test::TestRequest::default()
+            .insert_header((
+                crate::webhooks::SIGNATURE_HEADER,
+                fixtures::sign(&body, SECRET_KEY),
+            ))
+            .to_http_request();
+
+        assert!(actix::unmarshal(&req, &body, SECRET_KEY, MaximumVariance::default()).is_ok());
+
+        let req = actix_web::test::TestRequest::default().to_http_request();
+        assert!(actix::unmarshal(&req, &body, SECRET_KEY, MaximumVariance::default()).is_err());
+    }
+
+    #[cfg(feature = "axum")]
+    #[test]
+    fn axum_reads_the_signature_header() {
+        let body = body();
+        let mut headers = ::axum::http::HeaderMap::new();
+        assert!(axum::unmarshal(&headers, &body, SECRET_KEY, MaximumVariance::default()).is_err());
+
+        headers.insert(
+            crate::webhooks::SIGNATURE_HEADER,
+            fixtures::sign(&body, SECRET_KEY).parse().unwrap(),
+        );
+        assert!(axum::unmarshal(&headers, &body, SECRET_KEY, MaximumVariance::default()).is_ok());
+    }
+
+    #[cfg(feature = "warp")]
+    #[tokio::test]
+    async fn warp_filter_extracts_the_event() {
+        let body = body();
+        let filter = warp::event(SECRET_KEY, MaximumVariance::default());
+
+        let event = ::warp::test::request()
+            .method("POST")
+            .header(
+                crate::webhooks::SIGNATURE_HEADER,
+                fixtures::sign(&body, SECRET_KEY),
+            )
+            .body(body.clone())
+            .filter(&filter)
+            .await
+            .unwrap();
+        assert_eq!(event.event_id.as_ref(), "evt_01hv8x2acma3zqs7qwvbcwbm4r");
+
+        assert!(::warp::test::request()
+            .method("POST")
+            .body(body)
+            .filter(&filter)
+            .await
+            .is_err());
+    }
+
+    #[cfg(feature = "rocket")]
+    #[test]
+    fn rocket_verifies_the_guarded_signature() {
+        let body = body();
+        let signature = fixtures::sign(&body, SECRET_KEY);
+
+        assert!(rocket::unmarshal(
+            rocket::PaddleSignature(&signature),
+            &body,
+            SECRET_KEY,
+            MaximumVariance::default()
+        )
+        .is_ok());
+        assert!(rocket::unmarshal(
+            rocket::PaddleSignature("ts=1671552777;h1=00"),
+            &body,
+            SECRET_KEY,
+            MaximumVariance::default()
+        )
+        .is_err());
+    }
+}
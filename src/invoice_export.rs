@@ -0,0 +1,339 @@
+//! Invoices normalized for import into accounting software like QuickBooks or Xero.
+//!
+//! [InvoiceExport] flattens a transaction fetched with the `customer`, `address` and `business` includes into the fields accounting imports expect: an invoice number, a billing contact and address, line items and a tax breakdown. Amounts are decimal strings in the transaction currency, e.g. `10.50`.
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::enums::{Disposition, TransactionInclude};
+//! use paddle_rust_sdk::invoice_export::{to_csv, InvoiceExport};
+//! use paddle_rust_sdk::Paddle;
+//!
+//! let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+//!
+//! let transaction = client
+//!     .transaction_get("txn_01hv8wptq8987qeep44cyrewp9")
+//!     .include([TransactionInclude::Customer, TransactionInclude::Address, TransactionInclude::Business])
+//!     .send()
+//!     .await
+//!     .unwrap()
+//!     .data;
+//!
+//! let pdf = client.transaction_invoice(transaction.transaction.id.clone(), Disposition::Attachment).await.unwrap();
+//! let invoice = InvoiceExport::from_transaction(&transaction).unwrap().pdf_url(pdf.data.url);
+//!
+//! std::fs::write("invoices.csv", to_csv(&[invoice])).unwrap();
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::entities::{Address, Totals, TransactionWithIncludes};
+use crate::enums::CurrencyCode;
+use crate::ids::{CustomerID, TransactionID};
+use crate::Error;
+
+/// An invoice in the shape accounting imports expect.
+#[derive(Clone, Debug, Serialize)]
+pub struct InvoiceExport {
+    /// Invoice number generated by Paddle. `None` for transactions that were never billed.
+    pub invoice_number: Option<String>,
+    /// Paddle ID of the transaction the invoice is for.
+    pub transaction_id: TransactionID,
+    /// When the transaction was billed, falling back to when it was created.
+    pub issued_at: DateTime<Utc>,
+    /// Supported three-letter ISO 4217 currency code.
+    pub currency_code: CurrencyCode,
+    pub customer_id: Option<CustomerID>,
+    pub customer_name: Option<String>,
+    pub customer_email: Option<String>,
+    pub business_name: Option<String>,
+    /// Tax or VAT number of the business.
+    pub tax_identifier: Option<String>,
+    pub purchase_order_number: Option<String>,
+    pub billing_address: Option<BillingAddress>,
+    pub line_items: Vec<InvoiceLineItem>,
+    /// Tax collected, grouped by tax rate.
+    pub tax_breakdown: Vec<TaxBreakdown>,
+    /// Total before discount and tax.
+    pub subtotal: String,
+    pub discount: String,
+    pub tax: String,
+    /// Total after discount and tax.
+    pub total: String,
+    /// Link to the invoice PDF. Paddle links expire after an hour, so store the PDF itself if it needs to be accessed later.
+    pub pdf_url: Option<String>,
+}
+
+/// Address an invoice is billed to.
+#[derive(Clone, Debug, Serialize)]
+pub struct BillingAddress {
+    pub first_line: Option<String>,
+    pub second_line: Option<String>,
+    pub city: Option<String>,
+    pub postal_code: Option<String>,
+    pub region: Option<String>,
+    /// Two-letter ISO 3166-1 alpha-2 country code.
+    pub country_code: String,
+}
+
+/// A single line of an invoice.
+#[derive(Clone, Debug, Serialize)]
+pub struct InvoiceLineItem {
+    /// Name of the product.
+    pub description: String,
+    pub quantity: i64,
+    /// Price of a single unit before discount and tax.
+    pub unit_price: String,
+    /// Rate used to calculate tax for this line, e.g. `0.2` for 20%.
+    pub tax_rate: String,
+    pub subtotal: String,
+    pub discount: String,
+    pub tax: String,
+    pub total: String,
+}
+
+/// Tax collected at a single rate.
+#[derive(Clone, Debug, Serialize)]
+pub struct TaxBreakdown {
+    /// Rate used to calculate tax, e.g. `0.2` for 20%.
+    pub tax_rate: String,
+    /// Amount the tax was calculated on.
+    pub taxable_amount: String,
+    pub tax: String,
+}
+
+impl InvoiceExport {
+    /// Normalize a transaction. Customer, address and business details are only filled in when the transaction was fetched with the matching includes.
+    pub fn from_transaction(transaction: &TransactionWithIncludes) -> Result<Self, Error> {
+        let txn = &transaction.transaction;
+        let details = &txn.details;
+        let currency_code = &details.totals.currency_code;
+
+        let line_items = details
+            .line_items
+            .iter()
+            .map(|item| {
+                Ok(InvoiceLineItem {
+                    description: item.product.name.clone(),
+                    quantity: item.quantity,
+                    unit_price: decimal(&item.unit_totals.subtotal, currency_code)?,
+                    tax_rate: item.tax_rate.clone(),
+                    subtotal: decimal(&item.totals.subtotal, currency_code)?,
+                    discount: decimal(&item.totals.discount, currency_code)?,
+                    tax: decimal(&item.totals.tax, currency_code)?,
+                    total: decimal(&item.totals.total, currency_code)?,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let tax_breakdown = details
+            .tax_rates_used
+            .iter()
+            .map(|rate| {
+                Ok(TaxBreakdown {
+                    tax_rate: rate.tax_rate.clone(),
+                    taxable_amount: taxable_amount(&rate.totals, currency_code)?,
+                    tax: decimal(&rate.totals.tax, currency_code)?,
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let totals = &details.totals;
+
+        Ok(Self {
+            invoice_number: txn.invoice_number.clone(),
+            transaction_id: txn.id.clone(),
+            issued_at: txn.billed_at.unwrap_or(txn.created_at),
+            currency_code: currency_code.clone(),
+            customer_id: txn.customer_id.clone(),
+            customer_name: transaction
+                .customer
+                .as_ref()
+                .and_then(|customer| customer.name.clone()),
+            customer_email: transaction
+                .customer
+                .as_ref()
+                .map(|customer| customer.email.clone()),
+            business_name: transaction
+                .business
+                .as_ref()
+                .map(|business| business.name.clone()),
+            tax_identifier: transaction
+                .business
+                .as_ref()
+                .and_then(|business| business.tax_identifier.clone()),
+            purchase_order_number: txn
+                .billing_details
+                .as_ref()
+                .and_then(|details| details.purchase_order_number.clone())
+                .filter(|number| !number.is_empty()),
+            billing_address: transaction.address.as_ref().map(BillingAddress::from),
+            line_items,
+            tax_breakdown,
+            subtotal: decimal(&totals.subtotal, currency_code)?,
+            discount: decimal(&totals.discount, currency_code)?,
+            tax: decimal(&totals.tax, currency_code)?,
+            total: decimal(&totals.total, currency_code)?,
+            pdf_url: None,
+        })
+    }
+
+    /// Set the link to the invoice PDF, as returned by [crate::Paddle::transaction_invoice].
+    pub fn pdf_url(mut self, url: impl Into<String>) -> Self {
+        self.pdf_url = Some(url.into());
+        self
+    }
+
+    /// Serialize the invoice to JSON.
+    pub fn to_json(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+impl From<&Address> for BillingAddress {
+    fn from(address: &Address) -> Self {
+        Self {
+            first_line: address.first_line.clone(),
+            second_line: address.second_line.clone(),
+            city: address.city.clone(),
+            postal_code: address.postal_code.clone(),
+            region: address.region.clone(),
+            country_code: crate::enum_list(&[&address.country_code]),
+        }
+    }
+}
+
+/// Format invoices as CSV with a header row and one row per line item. Invoice fields are repeated on every row, which is the layout QuickBooks and Xero expect when importing invoices.
+pub fn to_csv(invoices: &[InvoiceExport]) -> String {
+    let mut csv = String::from(
+        "invoice_number,transaction_id,issued_at,currency_code,customer_name,customer_email,business_name,tax_identifier,purchase_order_number,address_first_line,address_second_line,address_city,address_postal_code,address_region,address_country_code,description,quantity,unit_price,tax_rate,line_subtotal,line_discount,line_tax,line_total,invoice_total,pdf_url\n",
+    );
+
+    for invoice in invoices {
+        let address = invoice.billing_address.as_ref();
+        let address_field = |field: fn(&BillingAddress) -> Option<&String>| {
+            address.and_then(field).cloned().unwrap_or_default()
+        };
+
+        let invoice_fields = [
+            invoice.invoice_number.clone().unwrap_or_default(),
+            invoice.transaction_id.to_string(),
+            invoice.issued_at.to_rfc3339(),
+            invoice.currency_code.as_iso().to_string(),
+            invoice.customer_name.clone().unwrap_or_default(),
+            invoice.customer_email.clone().unwrap_or_default(),
+            invoice.business_name.clone().unwrap_or_default(),
+            invoice.tax_identifier.clone().unwrap_or_default(),
+            invoice.purchase_order_number.clone().unwrap_or_default(),
+            address_field(|address| address.first_line.as_ref()),
+            address_field(|address| address.second_line.as_ref()),
+            address_field(|address| address.city.as_ref()),
+            address_field(|address| address.postal_code.as_ref()),
+            address_field(|address| address.region.as_ref()),
+            address
+                .map(|address| address.country_code.clone())
+                .unwrap_or_default(),
+        ];
+
+        for item in &invoice.line_items {
+            let item_fields = [
+                item.description.clone(),
+                item.quantity.to_string(),
+                item.unit_price.clone(),
+                item.tax_rate.clone(),
+                item.subtotal.clone(),
+                item.discount.clone(),
+                item.tax.clone(),
+                item.total.clone(),
+                invoice.total.clone(),
+                invoice.pdf_url.clone().unwrap_or_default(),
+            ];
+
+            let row = invoice_fields
+                .iter()
+                .chain(item_fields.iter())
+                .map(|field| csv_field(field))
+                .collect::<Vec<_>>();
+
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+    }
+
+    csv
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Amount tax was calculated on - the subtotal after discount.
+fn taxable_amount(totals: &Totals, currency_code: &CurrencyCode) -> Result<String, Error> {
+    let subtotal: i64 = totals.subtotal.parse()?;
+    let discount: i64 = totals.discount.parse()?;
+
+    Ok(currency_code.format_minor_decimal(subtotal - discount))
+}
+
+fn decimal(amount: &str, currency_code: &CurrencyCode) -> Result<String, Error> {
+    Ok(currency_code.format_minor_decimal(amount.parse()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    fn invoice() -> InvoiceExport {
+        let mut transaction = fixtures::transaction();
+        transaction["customer"] = fixtures::customer();
+        transaction["address"] = json!({
+            "id": "add_01hv8gq3318ktkfengj2r75gfx",
+            "customer_id": "ctm_01hv6y1jedq4p1n0yqn5ba3ky4",
+            "description": null,
+            "first_line": "1 Main St, Suite 2",
+            "second_line": null,
+            "city": "New York",
+            "postal_code": "10021",
+            "region": "NY",
+            "country_code": "US",
+            "custom_data": null,
+            "status": "active",
+            "created_at": fixtures::CREATED_AT,
+            "updated_at": fixtures::CREATED_AT,
+            "import_meta": null
+        });
+
+        InvoiceExport::from_transaction(&fixtures::from_value(transaction)).unwrap()
+    }
+
+    #[test]
+    fn normalizes_transaction() {
+        let invoice = invoice();
+
+        assert_eq!(invoice.invoice_number.as_deref(), Some("325-10566"));
+        assert_eq!(invoice.customer_name.as_deref(), Some("Sam Miller"));
+        assert_eq!(invoice.business_name, None);
+        assert_eq!(invoice.billing_address.unwrap().country_code, "US");
+        assert_eq!(invoice.line_items[0].unit_price, "30.00");
+        assert_eq!(invoice.line_items[0].tax, "6.00");
+        assert_eq!(invoice.tax_breakdown[0].taxable_amount, "30.00");
+        assert_eq!(invoice.total, "36.00");
+    }
+
+    #[test]
+    fn csv_quotes_fields_with_separators() {
+        let csv = to_csv(&[invoice().pdf_url("https://example.com/invoice.pdf")]);
+        let row = csv.lines().nth(1).unwrap();
+
+        assert!(row.starts_with("325-10566,txn_01hv8wptq8987qeep44cyrewp9,"));
+        assert!(row.contains(",\"1 Main St, Suite 2\",,New York,"));
+        assert!(row.ends_with(",36.00,https://example.com/invoice.pdf"));
+    }
+}
@@ -0,0 +1,268 @@
+//! Double-entry ledger lines for revenue recognition.
+//!
+//! [ledger_lines] turns `transaction.completed` and `adjustment.*` webhook events into balanced debit and credit lines that can be exported to an accounting system. Amounts are integers in the lowest denomination of the transaction currency, so totals never suffer from floating point rounding.
+//!
+//! | Event | Debit | Credit |
+//! |-------|-------|--------|
+//! | `transaction.completed` | [Account::PaddleBalance] (earnings), [Account::PaddleFees] (fee) | [Account::Revenue] (earnings plus fee) |
+//! | approved refund, credit or chargeback | [Account::Refunds], [Account::Credits] or [Account::Chargebacks] (subtotal) | [Account::PaddleFees] (returned fee), [Account::PaddleBalance] (rest) |
+//! | approved credit or chargeback reversal | as above, with debits and credits swapped |
+//!
+//! Paddle is the merchant of record, so it collects and remits tax itself. Tax never reaches your balance and isn't booked.
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::ledger::{ledger_lines, to_csv};
+//! use paddle_rust_sdk::Paddle;
+//!
+//! let event = Paddle::unmarshal(request_body, "your_webhook_key", signature, MaximumVariance::default()).unwrap();
+//! let lines = ledger_lines(&event).unwrap();
+//!
+//! std::fs::write("ledger.csv", to_csv(&lines)).unwrap();
+//! ```
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{Adjustment, Event, Transaction};
+use crate::enums::{AdjustmentAction, AdjustmentStatus, CurrencyCode, EventData};
+use crate::ids::{AdjustmentID, EventID, TransactionID};
+use crate::Error;
+
+/// Ledger account a line is booked to.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Account {
+    /// Sales, net of tax.
+    Revenue,
+    /// Fees taken by Paddle.
+    PaddleFees,
+    /// Amount Paddle owes you, paid out with the next payout.
+    PaddleBalance,
+    /// Refunds given to customers, a contra-revenue account.
+    Refunds,
+    /// Credits given to customers, a contra-revenue account.
+    Credits,
+    /// Disputed charges lost to chargebacks, a contra-revenue account.
+    Chargebacks,
+}
+
+/// Side of the ledger a line is booked on.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Side {
+    Debit,
+    Credit,
+}
+
+/// A single debit or credit.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LedgerLine {
+    /// Paddle ID of the event the line was created from.
+    pub event_id: EventID,
+    /// When the event occurred.
+    pub occurred_at: DateTime<Utc>,
+    /// Paddle ID of the transaction the line relates to.
+    pub transaction_id: TransactionID,
+    /// Paddle ID of the adjustment the line was created from. `None` for transactions.
+    pub adjustment_id: Option<AdjustmentID>,
+    pub account: Account,
+    pub side: Side,
+    /// Amount in the lowest denomination of the currency, e.g. cents for USD. Never negative.
+    pub amount: i64,
+    pub currency_code: CurrencyCode,
+}
+
+impl LedgerLine {
+    /// Amount as a decimal string without a currency symbol, e.g. `10.50` for `1050` USD and `1050` for `1050` JPY.
+    pub fn amount_decimal(&self) -> String {
+        self.currency_code.format_minor_decimal(self.amount)
+    }
+}
+
+/// Create ledger lines for a webhook event.
+///
+/// Returns no lines for events that don't move money: other event types, adjustments that aren't `approved` yet and chargeback warnings. Paddle sends `adjustment.updated` when a pending refund is approved, but can also send it for adjustments that were approved when created, so deduplicate adjustment lines by `adjustment_id`.
+///
+/// Amounts are taken from the totals in the transaction currency. Every set of lines returned is balanced - debits equal credits.
+pub fn ledger_lines(event: &Event) -> Result<Vec<LedgerLine>, Error> {
+    match &event.data {
+        EventData::TransactionCompleted(transaction) => transaction_lines(event, transaction),
+        EventData::AdjustmentCreated(adjustment) | EventData::AdjustmentUpdated(adjustment) => {
+            adjustment_lines(event, adjustment)
+        }
+        _ => Ok(Vec::new()),
+    }
+}
+
+/// Format ledger lines as CSV with a header row. Amounts are written as decimals, see [LedgerLine::amount_decimal].
+pub fn to_csv(lines: &[LedgerLine]) -> String {
+    let mut csv = String::from(
+        "event_id,occurred_at,transaction_id,adjustment_id,account,side,amount,currency_code\n",
+    );
+
+    for line in lines {
+        let fields = [
+            line.event_id.to_string(),
+            line.occurred_at.to_rfc3339(),
+            line.transaction_id.to_string(),
+            line.adjustment_id
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            crate::enum_list(&[line.account]),
+            crate::enum_list(&[line.side]),
+            line.amount_decimal(),
+            line.currency_code.as_iso().to_string(),
+        ];
+
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+fn transaction_lines(event: &Event, transaction: &Transaction) -> Result<Vec<LedgerLine>, Error> {
+    let totals = &transaction.details.totals;
+    let fee = totals
+        .fee
+        .as_deref()
+        .map(parse_amount)
+        .transpose()?
+        .unwrap_or(0);
+    let earnings = match totals.earnings.as_deref() {
+        Some(earnings) => parse_amount(earnings)?,
+        None => parse_amount(&totals.total)? - parse_amount(&totals.tax)? - fee,
+    };
+
+    let entries = [
+        (Account::PaddleBalance, Side::Debit, earnings),
+        (Account::PaddleFees, Side::Debit, fee),
+        (Account::Revenue, Side::Credit, earnings + fee),
+    ];
+
+    Ok(lines(
+        event,
+        &transaction.id,
+        None,
+        &totals.currency_code,
+        entries,
+    ))
+}
+
+fn adjustment_lines(event: &Event, adjustment: &Adjustment) -> Result<Vec<LedgerLine>, Error> {
+    if adjustment.status != AdjustmentStatus::Approved {
+        return Ok(Vec::new());
+    }
+
+    let (account, reversal) = match adjustment.action {
+        AdjustmentAction::Refund => (Account::Refunds, false),
+        AdjustmentAction::Credit => (Account::Credits, false),
+        AdjustmentAction::Chargeback => (Account::Chargebacks, false),
+        AdjustmentAction::CreditReverse => (Account::Credits, true),
+        AdjustmentAction::ChargebackReverse => (Account::Chargebacks, true),
+        AdjustmentAction::ChargebackWarning => return Ok(Vec::new()),
+    };
+
+    let totals = &adjustment.totals;
+    let subtotal = parse_amount(&totals.subtotal)?.abs();
+    let fee = parse_amount(&totals.fee)?.abs();
+
+    let (debit, credit) = if reversal {
+        (Side::Credit, Side::Debit)
+    } else {
+        (Side::Debit, Side::Credit)
+    };
+
+    let entries = [
+        (account, debit, subtotal),
+        (Account::PaddleFees, credit, fee),
+        (Account::PaddleBalance, credit, subtotal - fee),
+    ];
+
+    Ok(lines(
+        event,
+        &adjustment.transaction_id,
+        Some(&adjustment.id),
+        &totals.currency_code,
+        entries,
+    ))
+}
+
+/// Build lines from `(account, side, amount)` entries, skipping zero amounts. Negative amounts are booked on the opposite side.
+fn lines(
+    event: &Event,
+    transaction_id: &TransactionID,
+    adjustment_id: Option<&AdjustmentID>,
+    currency_code: &CurrencyCode,
+    entries: [(Account, Side, i64); 3],
+) -> Vec<LedgerLine> {
+    entries
+        .into_iter()
+        .filter(|(_, _, amount)| *amount != 0)
+        .map(|(account, side, amount)| {
+            let side = match (side, amount < 0) {
+                (side, false) => side,
+                (Side::Debit, true) => Side::Credit,
+                (Side::Credit, true) => Side::Debit,
+            };
+
+            LedgerLine {
+                event_id: event.event_id.clone(),
+                occurred_at: event.occurred_at,
+                transaction_id: transaction_id.clone(),
+                adjustment_id: adjustment_id.cloned(),
+                account,
+                side,
+                amount: amount.abs(),
+                currency_code: currency_code.clone(),
+            }
+        })
+        .collect()
+}
+
+fn parse_amount(amount: &str) -> Result<i64, Error> {
+    Ok(amount.parse()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn transaction_earnings_are_booked_to_paddle_balance() {
+        // Totals of a completed transaction, tax was added on top of the subtotal
+        let mut transaction = fixtures::transaction();
+        transaction["details"]["totals"] = json!({
+            "subtotal": "60000",
+            "discount": "0",
+            "tax": "4800",
+            "total": "64800",
+            "credit": "0",
+            "credit_to_balance": "0",
+            "balance": "0",
+            "grand_total": "64800",
+            "fee": "3290",
+            "earnings": "56710",
+            "currency_code": "USD"
+        });
+
+        let lines = ledger_lines(&fixtures::event("transaction.completed", transaction)).unwrap();
+        let booked = lines
+            .iter()
+            .map(|line| (line.account, line.side, line.amount))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            booked,
+            [
+                (Account::PaddleBalance, Side::Debit, 56710),
+                (Account::PaddleFees, Side::Debit, 3290),
+                (Account::Revenue, Side::Credit, 60000),
+            ]
+        );
+    }
+}
@@ -15,8 +15,8 @@
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
 //!     let client = Paddle::new(std::env::var("PADDLE_API_KEY")?, Paddle::SANDBOX)?;
 //!
-//!     let mut list = client.customers_list();
-//!     let mut paginated = list.per_page(2).send();
+//!     let list = client.customers_list().per_page(2);
+//!     let mut paginated = list.send();
 //!     let customers = paginated.all().await?;
 //!
 //!     dbg!(customers);
@@ -27,13 +27,29 @@
 //!
 //! The `examples/` dir has up to date working example code.
 //!
+//! Request builder setters take the builder by value and return it. Builders implement [Clone], so a partially configured builder can be kept as a template and cloned for every request:
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::enums::{CollectionMode, CurrencyCode};
+//!
+//! let template = client
+//!     .transaction_create()
+//!     .collection_mode(CollectionMode::Manual)
+//!     .currency_code(CurrencyCode::EUR);
+//!
+//! let transaction = template.clone().customer_id("ctm_01jqztc78e1xfdgwhcgjzdrvgd").send().await?;
+//! ```
+//!
 //! ## Webhook signature verification
 //!
 //! Use the [Paddle::unmarshal] method to verify that received events are genuinely sent from Paddle. Additionally, this method returns the deserialized event struct.
 //!
 
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
 use paddle_rust_sdk_types::reports::ReportType;
-pub use paddle_rust_sdk_types::{entities, enums, ids};
+pub use paddle_rust_sdk_types::{entities, enums, ids, permissions};
 use reqwest::{header::CONTENT_TYPE, IntoUrl, Method, StatusCode, Url};
 use serde::{de::DeserializeOwned, Serialize};
 
@@ -55,26 +71,66 @@ pub mod reports;
 pub mod subscriptions;
 pub mod transactions;
 
+pub mod audit;
+pub mod batch;
+pub mod budget;
+pub mod catalog_cache;
+pub mod checkout;
+pub mod cohorts;
+pub mod custom_data;
+pub mod data_export;
+pub mod defaults;
+pub mod documents;
+pub mod dunning;
+pub mod entitlements;
+#[cfg(test)]
+mod fixtures;
+pub mod fleet;
+pub mod hydrate;
+pub mod idempotency;
+#[cfg(any(
+    feature = "actix-web",
+    feature = "axum",
+    feature = "warp",
+    feature = "rocket"
+))]
+pub mod integrations;
+pub mod invoice_export;
+pub mod ledger;
+pub mod notifications;
 pub mod nullable;
+pub mod offboarding;
+pub mod payouts;
+pub mod prelude;
+pub mod report_scheduler;
+pub mod request;
 pub mod response;
+pub mod timeline;
 
 pub use nullable::Nullable;
 
+use budget::{Budget, BudgetPermit};
+use custom_data::CustomDataHook;
+use defaults::RequestDefaults;
 use paddle_rust_sdk_types::entities::{
-    CustomerAuthenticationToken, Event, EventType, PricePreviewItem, ReportBase, Subscription,
-    Transaction, TransactionInvoice,
+    Address, CustomerAuthenticationToken, Event, EventType, IpAddresses, NotificationReplay,
+    PricePreviewItem, ReportBase, SimulationRunSingleEvent, SimulationSingleEvent,
+    SimulationSingleEventCreate, Subscription, SubscriptionWithInclude, Transaction,
+    TransactionInvoice,
 };
 use paddle_rust_sdk_types::enums::{
-    AdjustmentAction, CountryCodeSupported, CurrencyCode, DiscountType, Disposition, TaxCategory,
+    AdjustmentAction, CountryCodeSupported, CurrencyCode, DiscountType, Disposition, EventTypeName,
+    NotificationStatus, SimulationEventStatus, SimulationRunStatus, Status, TaxCategory,
 };
 use paddle_rust_sdk_types::ids::{
-    AddressID, AdjustmentID, BusinessID, CustomerID, DiscountID, PaddleID, PaymentMethodID,
-    PriceID, ProductID, SubscriptionID, TransactionID,
+    AddressID, AdjustmentID, BusinessID, CustomerID, DiscountID, NotificationID,
+    NotificationSettingID, PaddleID, PaymentMethodID, PriceID, ProductID, SubscriptionID,
+    TransactionID,
 };
-use webhooks::{MaximumVariance, Signature};
+use webhooks::{MaximumVariance, WebhookTest};
 
 use error::PaddleApiError;
-use response::{ErrorResponse, Response, SuccessResponse};
+use response::{ErrorResponse, SuccessResponse};
 
 pub use error::Error;
 
@@ -83,16 +139,26 @@ type Result<T> = std::result::Result<SuccessResponse<T>, Error>;
 /// Paddle API client
 ///
 /// This struct is used to create a new Paddle client instance.
+///
+/// The client is `Clone + Send + Sync` and cheap to clone. Every clone shares the same configuration and connection pool, so create one client and clone it into each task or handler that needs it. There's no need to wrap it in an `Arc`.
 #[derive(Clone, Debug)]
 pub struct Paddle {
-    base_url: Url,
-    api_key: String,
+    http: reqwest::Client,
+    base_url: Arc<Url>,
+    api_key: Arc<str>,
+    custom_data_hook: Option<Arc<dyn CustomDataHook>>,
+    defaults: Arc<RequestDefaults>,
+    correlation_id: Option<Arc<str>>,
+    budget: Option<Budget>,
 }
 
 impl Paddle {
     pub const PRODUCTION: &'static str = "https://api.paddle.com";
     pub const SANDBOX: &'static str = "https://sandbox-api.paddle.com";
 
+    /// Name of the header [Paddle::with_correlation_id] sends the correlation ID in.
+    pub const CORRELATION_ID_HEADER: &'static str = "x-correlation-id";
+
     /// List of IP addresses Paddle uses to call webhook endpoints from the Live environment
     pub const ALLOWED_WEBHOOK_IPS_PRODUCTION: [&str; 6] = [
         "34.232.58.13",
@@ -126,33 +192,125 @@ impl Paddle {
         base_url: impl IntoUrl,
     ) -> std::result::Result<Self, Error> {
         Ok(Self {
-            base_url: base_url.into_url()?,
-            api_key: api_key.into(),
+            http: reqwest::Client::builder().build()?,
+            base_url: Arc::new(base_url.into_url()?),
+            api_key: Arc::from(api_key.into()),
+            custom_data_hook: None,
+            defaults: Arc::default(),
+            correlation_id: None,
+            budget: None,
         })
     }
 
+    /// Normalize `custom_data` of every request and response made by this client with the given hook.
+    ///
+    /// See the [custom_data] module for details.
+    pub fn with_custom_data_hook(mut self, hook: impl CustomDataHook + 'static) -> Self {
+        self.custom_data_hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Prefill request builders created by this client with the given defaults.
+    ///
+    /// See the [defaults] module for details.
+    pub fn with_defaults(mut self, defaults: RequestDefaults) -> Self {
+        self.defaults = Arc::new(defaults);
+        self
+    }
+
+    /// Send `correlation_id` in the [Paddle::CORRELATION_ID_HEADER] header of every request made by this client.
+    ///
+    /// Clients are cheap to clone, so a clone can be tagged with the ID of each incoming request to link outgoing Paddle requests to it in proxy logs.
+    /// With the `tracing` feature, the ID is also recorded on the span of every request along with the `request_id` Paddle returns, which Paddle support asks for.
+    ///
+    /// ```rust,no_run
+    /// let client = client.clone().with_correlation_id("7f3c9a1e");
+    /// let customer = client.customer_get("ctm_01jqztc78e1xfdgwhcgjzdrvgd").send().await?;
+    /// ```
+    pub fn with_correlation_id(mut self, correlation_id: impl Into<String>) -> Self {
+        self.correlation_id = Some(Arc::from(correlation_id.into()));
+        self
+    }
+
+    /// Limit concurrent requests made by this client and its clones with a [Budget] that can be shared with the rest of the app.
+    ///
+    /// See the [budget] module for details.
+    pub fn with_budget(mut self, budget: Budget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
     /// Validate the integrity of a Paddle webhook request.
     ///
-    /// - **request_body** - The raw body of the request. Don't transform or process the raw body of the request, including adding whitespace or applying other formatting. This results in a different signed payload, meaning signatures won't match when you compare.
+    /// - **request_body** - The raw bytes of the request body. It doesn't have to be decoded as UTF-8 first, so `&[u8]`, `Vec<u8>` and `Bytes` can be passed as-is. Don't transform or process the raw body of the request, including adding whitespace or applying other formatting. This results in a different signed payload, meaning signatures won't match when you compare.
     /// - **secret_key** - Secret key created in Paddle dashboard. Each notification destination has it's own secret key.
     /// - **signature** - "Paddle-Signature" HTTP request header from an incoming webhook sent by Paddle.
     /// - **maximum_variance** - Maximum allowed age for a generated signature. [MaximumVariance::default] is 5 seconds. Pass `MaximumVariance(None)` to disable timestamp checking.
     ///
     /// **Return** - the deserialized [Event] struct.
     ///
+    /// Shorthand for [WebhookVerifier::unmarshal](webhooks::WebhookVerifier::unmarshal). Use a [WebhookVerifier](webhooks::WebhookVerifier) to combine it with other options, e.g. a [VerificationHook](webhooks::VerificationHook) and batched delivery.
+    ///
     /// The `examples/` directory contains a demo webhook handler for Actix web.
     pub fn unmarshal(
-        request_body: impl AsRef<str>,
+        request_body: impl AsRef<[u8]>,
         secret_key: impl AsRef<str>,
         signature: impl AsRef<str>,
         maximum_variance: MaximumVariance,
     ) -> std::result::Result<Event, Error> {
-        let signature: Signature = signature.as_ref().parse()?;
-        signature.verify(request_body.as_ref(), secret_key, maximum_variance)?;
+        webhooks::WebhookVerifier::new(secret_key.as_ref())
+            .maximum_variance(maximum_variance)
+            .unmarshal(request_body, signature)
+    }
 
-        let event = serde_json::from_str(request_body.as_ref())?;
+    /// Same as [Paddle::unmarshal], reporting verification failures to a [VerificationHook](webhooks::VerificationHook) before returning them.
+    ///
+    /// Shorthand for a [WebhookVerifier](webhooks::WebhookVerifier) with a [hook](webhooks::WebhookVerifier::hook).
+    pub fn unmarshal_with_hook(
+        request_body: impl AsRef<[u8]>,
+        secret_key: impl AsRef<str>,
+        signature: impl AsRef<str>,
+        maximum_variance: MaximumVariance,
+        hook: &dyn webhooks::VerificationHook,
+    ) -> std::result::Result<Event, Error> {
+        Self::unmarshal(request_body, secret_key, signature, maximum_variance)
+            .inspect_err(|err| webhooks::report_verification_error(hook, err))
+    }
 
-        Ok(event)
+    /// Validate the integrity of a Paddle webhook request delivered in a specific API version.
+    ///
+    /// Same as [Paddle::unmarshal], but first checks that `api_version` - the API version configured for the notification destination - is one this crate can deserialize.
+    /// Returns [Error::UnsupportedApiVersion] otherwise, so that an account version upgrade is reported instead of surfacing as unrelated deserialization errors.
+    ///
+    /// Shorthand for a [WebhookVerifier](webhooks::WebhookVerifier) with an [api_version](webhooks::WebhookVerifier::api_version).
+    pub fn unmarshal_versioned(
+        request_body: impl AsRef<[u8]>,
+        secret_key: impl AsRef<str>,
+        signature: impl AsRef<str>,
+        maximum_variance: MaximumVariance,
+        api_version: i64,
+    ) -> std::result::Result<Event, Error> {
+        webhooks::WebhookVerifier::new(secret_key.as_ref())
+            .maximum_variance(maximum_variance)
+            .api_version(api_version)
+            .unmarshal(request_body, signature)
+    }
+
+    /// Validate the integrity of a Paddle webhook request that may contain several events.
+    ///
+    /// Same as [Paddle::unmarshal], but accepts both a single event and a JSON array of events, e.g. from a notification destination with batched delivery.
+    /// A single event is returned as a one element [Vec]. The signature is verified once for the whole body.
+    ///
+    /// Shorthand for [WebhookVerifier::unmarshal_any](webhooks::WebhookVerifier::unmarshal_any).
+    pub fn unmarshal_any(
+        request_body: impl AsRef<[u8]>,
+        secret_key: impl AsRef<str>,
+        signature: impl AsRef<str>,
+        maximum_variance: MaximumVariance,
+    ) -> std::result::Result<Vec<Event>, Error> {
+        webhooks::WebhookVerifier::new(secret_key.as_ref())
+            .maximum_variance(maximum_variance)
+            .unmarshal_any(request_body, signature)
     }
 
     /// Get a request builder for fetching products. Use the after method to page through results.
@@ -166,8 +324,8 @@ impl Paddle {
     /// use paddle_rust_sdk::Paddle;
     /// let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
     ///
-    /// let mut products_list = client.products_list();
-    /// let mut products = products_list.order_by_asc("id").per_page(20).send();
+    /// let products_list = client.products_list().order_by_asc("id").per_page(20);
+    /// let mut products = products_list.send();
     ///
     /// while let Some(res) = products.next().await.unwrap() {
     ///     dbg!(res.data);
@@ -231,8 +389,8 @@ impl Paddle {
     ///
     /// let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
     ///
-    /// let mut prices_list = client.prices_list();
-    /// let mut prices = prices_list.order_by_asc("id").per_page(20).send();
+    /// let prices_list = client.prices_list().order_by_asc("id").per_page(20);
+    /// let mut prices = prices_list.send();
     ///
     /// while let Some(res) = prices.next().await.unwrap() {
     ///     dbg!(res.data);
@@ -446,6 +604,27 @@ impl Paddle {
         customers::CustomerCreditBalances::new(self, customer_id)
     }
 
+    /// Collect the customer along with their addresses, businesses, saved payment methods, subscriptions, transactions and adjustments.
+    ///
+    /// Use this to answer data subject access requests. Every list is fetched in full, so this sends at least nine requests. See the [data_export] module for removing internal fields before sharing the export.
+    pub async fn export_customer_data(
+        &self,
+        customer_id: impl Into<CustomerID>,
+    ) -> std::result::Result<data_export::CustomerDataExport, Error> {
+        data_export::export_customer_data(self, customer_id.into()).await
+    }
+
+    /// Cancel the subscriptions of a customer, delete their saved payment methods, archive their addresses and businesses and clear `custom_data` of all of them.
+    ///
+    /// Use this when a customer deletes their account. Returns an error only if listing entities fails, see the [offboarding] module for details.
+    pub async fn offboard_customer(
+        &self,
+        customer_id: impl Into<CustomerID>,
+        options: offboarding::OffboardOptions,
+    ) -> std::result::Result<offboarding::OffboardReport, Error> {
+        offboarding::offboard_customer(self, customer_id.into(), options).await
+    }
+
     /// Generates an authentication token for a customer.
     ///
     /// You can pass a generated authentication token to Paddle.js when opening a checkout to let customers work with saved payment methods.
@@ -455,8 +634,6 @@ impl Paddle {
         &self,
         customer_id: impl Into<CustomerID>,
     ) -> Result<CustomerAuthenticationToken> {
-        let client = reqwest::Client::new();
-
         let customer_id = customer_id.into();
 
         let url = format!(
@@ -465,18 +642,10 @@ impl Paddle {
             customer_id.as_ref()
         );
 
-        let res: Response<_> = client
-            .post(url)
-            .bearer_auth(self.api_key.clone())
-            .send()
-            .await?
-            .json()
-            .await?;
+        let _permit = self.acquire_budget().await;
+        let res = self.http_request(Method::POST, url).send().await?;
 
-        match res {
-            Response::Success(success) => Ok(success),
-            Response::Error(error) => Err(Error::PaddleApi(error)),
-        }
+        response::decode(res).await
     }
 
     /// Get a request builder for fetching customers addresses.
@@ -548,6 +717,35 @@ impl Paddle {
         addresses::AddressUpdate::new(self, customer_id, address_id)
     }
 
+    /// Fetch the most recently created active address of a customer. `None` if the customer has no active addresses.
+    ///
+    /// Paddle doesn't have a concept of a default address. Customers typically add a new address when their old one changes, so the newest active address is a good default for checkout workflows. Archive addresses that shouldn't be used anymore.
+    ///
+    /// # Example:
+    ///
+    /// ```rust,no_run
+    /// use paddle_rust_sdk::Paddle;
+    /// let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+    /// let address = client.address_for_checkout("ctm_01jqztc78e1xfdgwhcgjzdrvgd").await.unwrap();
+    /// dbg!(address);
+    /// ```
+    pub async fn address_for_checkout(
+        &self,
+        customer_id: impl Into<CustomerID>,
+    ) -> std::result::Result<Option<Address>, Error> {
+        // Paddle IDs are time ordered, so ordering by ID returns the newest address first
+        let page = self
+            .addresses_list(customer_id)
+            .status(Status::Active)
+            .order_by_desc("id")
+            .per_page(1)
+            .send()
+            .next()
+            .await?;
+
+        Ok(page.and_then(|page| page.data.into_iter().next()))
+    }
+
     /// Get a request builder for fetching customers businesses.
     ///
     /// By default, Paddle returns addresses that are `active`. Use the status query parameter to return businesses that are archived.
@@ -668,8 +866,6 @@ impl Paddle {
         customer_id: impl Into<CustomerID>,
         payment_method_id: impl Into<PaymentMethodID>,
     ) -> std::result::Result<bool, Error> {
-        let client = reqwest::Client::new();
-
         let url = format!(
             "{}customers/{}/payment-methods/{}",
             self.base_url,
@@ -677,11 +873,8 @@ impl Paddle {
             payment_method_id.into().as_ref()
         );
 
-        let response = client
-            .delete(url)
-            .bearer_auth(self.api_key.clone())
-            .send()
-            .await?;
+        let _permit = self.acquire_budget().await;
+        let response = self.http_request(Method::DELETE, url).send().await?;
 
         Ok(response.status() == StatusCode::NO_CONTENT)
     }
@@ -813,6 +1006,37 @@ impl Paddle {
         self.send(params, Method::GET, &url).await
     }
 
+    /// Downloads the invoice PDF for a transaction.
+    ///
+    /// Same as [Paddle::transaction_invoice], but the returned link is followed and the file is returned along with a suggested file name, ready to be proxied to your customer.
+    ///
+    /// # Example:
+    ///
+    /// ```rust,no_run
+    /// use paddle_rust_sdk::{enums::Disposition, Paddle};
+    /// let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+    /// let invoice = client.fetch_invoice("txn_01hv8wptq8987qeep44cyrewp9", Disposition::Attachment).await.unwrap();
+    /// dbg!(invoice.filename, invoice.content_disposition(), invoice.bytes.len());
+    /// ```
+    pub async fn fetch_invoice(
+        &self,
+        transaction_id: impl Into<TransactionID>,
+        disposition: Disposition,
+    ) -> std::result::Result<documents::Document, Error> {
+        let transaction_id = transaction_id.into();
+        let invoice = self
+            .transaction_invoice(transaction_id.clone(), disposition)
+            .await?;
+
+        documents::download(
+            self,
+            invoice.data.url,
+            disposition,
+            format!("invoice_{}.pdf", transaction_id.as_ref()),
+        )
+        .await
+    }
+
     /// Get a request builder for generating a transaction preview without creating a transaction entity. Typically used for creating more advanced, dynamic pricing pages where users can build their own plans.
     ///
     /// You can provide location information when previewing a transaction. You must provide this if you want Paddle to calculate tax or automatically localize prices. You can provide one of:
@@ -884,6 +1108,73 @@ impl Paddle {
         subscriptions::SubscriptionGet::new(self, subscription_id)
     }
 
+    /// Create a transaction for a recurring price and return its checkout URL. Same as [Paddle::quick_checkout] for an existing customer, but the URL is required.
+    ///
+    /// Subscriptions can't be created directly. Paddle creates one once the customer pays a transaction for a recurring price. Send the customer to the returned URL, then get the subscription with [Paddle::await_subscription_for_transaction] or from the `subscription.created` webhook.
+    ///
+    /// Fails with [Error::MissingUrl] if Paddle didn't return a checkout URL, e.g. because no default payment link is set for the account.
+    ///
+    /// # Example:
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use paddle_rust_sdk::Paddle;
+    ///
+    /// let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+    /// let checkout = client
+    ///     .create_subscription_checkout("ctm_01hv6y1jedq4p1n0yqn5ba3ky4", "pri_01gsz8x8sawmvhz1pv30nge1ke", 1)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// // Send the customer to checkout.url
+    ///
+    /// let subscription = client
+    ///     .await_subscription_for_transaction(checkout.transaction_id, Duration::from_secs(15 * 60))
+    ///     .await
+    ///     .unwrap();
+    /// ```
+    pub async fn create_subscription_checkout(
+        &self,
+        customer_id: impl Into<CustomerID>,
+        price_id: impl Into<PriceID>,
+        quantity: u32,
+    ) -> std::result::Result<subscriptions::SubscriptionCheckout, Error> {
+        let checkout = self
+            .checkout_for(price_id, quantity, Some(customer_id.into()))
+            .await?;
+
+        Ok(subscriptions::SubscriptionCheckout {
+            url: checkout.url.ok_or(Error::MissingUrl)?,
+            transaction_id: checkout.transaction_id,
+        })
+    }
+
+    /// Wait until Paddle has created the subscription for a transaction and return it.
+    ///
+    /// The transaction is checked every 5 seconds. Fails with [Error::SubscriptionNotCreated] if no subscription was created within `timeout`, e.g. because the customer didn't complete checkout.
+    pub async fn await_subscription_for_transaction(
+        &self,
+        transaction_id: impl Into<TransactionID>,
+        timeout: std::time::Duration,
+    ) -> Result<SubscriptionWithInclude> {
+        let transaction_id = transaction_id.into();
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        loop {
+            let transaction = self.transaction_get(transaction_id.clone()).send().await?;
+
+            if let Some(subscription_id) = transaction.data.transaction.subscription_id {
+                return self.subscription_get(subscription_id).send().await;
+            }
+
+            if tokio::time::Instant::now() >= deadline {
+                return Err(Error::SubscriptionNotCreated(transaction_id));
+            }
+
+            tokio::time::sleep(subscriptions::SUBSCRIPTION_POLL_INTERVAL).await;
+        }
+    }
+
     /// Get a request builder for getting a preview of changes to a subscription without actually applying them.
     ///
     /// Typically used for previewing proration before making changes to a subscription.
@@ -1053,6 +1344,16 @@ impl Paddle {
         subscriptions::SubscriptionCancel::new(self, subscription_id)
     }
 
+    /// Collect the events, transactions and adjustments of a subscription into a single timeline, oldest first.
+    ///
+    /// Paddle can't list events by subscription, so every event Paddle still keeps is fetched, which covers the last 90 days. Transactions and adjustments are complete. See the [timeline] module for an example.
+    pub async fn subscription_timeline(
+        &self,
+        subscription_id: impl Into<SubscriptionID>,
+    ) -> std::result::Result<timeline::SubscriptionTimeline, Error> {
+        timeline::subscription_timeline(self, subscription_id.into()).await
+    }
+
     /// Get a request builder for retrieving adjustments from Paddle.
     ///
     /// Use the builder parameters to filter and page through results.
@@ -1133,6 +1434,53 @@ impl Paddle {
         self.send(params, Method::GET, &url).await
     }
 
+    /// Downloads the credit note PDF for an adjustment along with the adjustment itself.
+    ///
+    /// Same as [Paddle::adjustment_credit_note], but the returned link is followed. The adjustment is fetched as well, so its totals can be included in a refund confirmation with the credit note attached. Fails with [Error::NotFound] if the adjustment doesn't exist.
+    ///
+    /// # Example:
+    ///
+    /// ```rust,no_run
+    /// use paddle_rust_sdk::{enums::Disposition, Paddle};
+    /// let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+    /// let credit_note = client.adjustment_credit_note_pdf("adj_01hvgf2s84dr6reszzg29zbvcm", Disposition::Attachment).await.unwrap();
+    /// dbg!(credit_note.document.filename, credit_note.adjustment.totals.total);
+    /// ```
+    pub async fn adjustment_credit_note_pdf(
+        &self,
+        adjustment_id: impl Into<AdjustmentID>,
+        disposition: Disposition,
+    ) -> std::result::Result<documents::CreditNote, Error> {
+        let adjustment_id = adjustment_id.into();
+
+        // Paddle has no endpoint for a single adjustment, look it up before asking for its credit note
+        let adjustment = self
+            .adjustments_list()
+            .ids([adjustment_id.clone()])
+            .send()
+            .next()
+            .await?
+            .and_then(|page| page.data.into_iter().next())
+            .ok_or_else(|| Error::NotFound(adjustment_id.to_string()))?;
+
+        let credit_note = self
+            .adjustment_credit_note(adjustment_id.clone(), disposition)
+            .await?;
+
+        let document = documents::download(
+            self,
+            credit_note.data.url,
+            disposition,
+            format!("credit_note_{}.pdf", adjustment_id.as_ref()),
+        )
+        .await?;
+
+        Ok(documents::CreditNote {
+            document,
+            adjustment,
+        })
+    }
+
     /// Get a request builder for fetching pricing previews for one or more prices. Typically used for building pricing pages.
     ///
     /// You can provide location information when previewing prices. You must provide this if you want Paddle to calculate tax or automatically localize prices. You can provide one of:
@@ -1153,7 +1501,7 @@ impl Paddle {
     /// let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
     ///
     /// let res = client.pricing_preview()
-    ///     .send([PricePreviewItem { price_id: "pri_01jqxvdyjkp961jzv4me7ezg4d".into(), quantity: 1, }])
+    ///     .send([PricePreviewItem::new("pri_01jqxvdyjkp961jzv4me7ezg4d", 1)])
     ///     .await
     ///     .unwrap();
     ///
@@ -1210,6 +1558,22 @@ impl Paddle {
         reports::ReportCreate::new(self, report_type)
     }
 
+    /// Returns the IP addresses Paddle uses to send webhooks from, for the environment this client is for.
+    ///
+    /// Prefer a [WebhookIpAllowlist](webhooks::WebhookIpAllowlist) over calling this directly.
+    ///
+    /// # Example:
+    ///
+    /// ```rust,no_run
+    /// use paddle_rust_sdk::Paddle;
+    /// let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+    /// let res = client.ip_addresses().await.unwrap();
+    /// dbg!(res.data.ipv4_cidrs);
+    /// ```
+    pub async fn ip_addresses(&self) -> Result<IpAddresses> {
+        self.send((), Method::GET, "/ips").await
+    }
+
     /// Returns a list of event types.
     ///
     /// The response is not paginated.
@@ -1242,27 +1606,306 @@ impl Paddle {
         events::EventsList::new(self)
     }
 
+    /// Get a request builder for querying Paddle for notifications.
+    ///
+    /// # Example:
+    ///
+    /// ```rust,no_run
+    /// use paddle_rust_sdk::enums::NotificationStatus;
+    /// use paddle_rust_sdk::Paddle;
+    /// let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+    /// let res = client.notifications_list().status([NotificationStatus::Failed]).send().next().await.unwrap();
+    /// dbg!(res)
+    /// ```
+    pub fn notifications_list(&self) -> notifications::NotificationsList<'_> {
+        notifications::NotificationsList::new(self)
+    }
+
+    /// Attempts to resend a notification using its ID. Paddle creates a new notification with the origin `replay` for the same event and sends it to the same destination.
+    pub async fn notification_replay(
+        &self,
+        notification_id: impl Into<NotificationID>,
+    ) -> Result<NotificationReplay> {
+        let notification_id = notification_id.into();
+
+        let url = format!("/notifications/{}/replay", notification_id.as_ref());
+
+        self.send(serde_json::json!({}), Method::POST, &url).await
+    }
+
+    /// Replay every notification that occurred since `since` and failed to deliver. Use it to recover once your webhook endpoint is back up after an outage.
+    ///
+    /// Replays are sent one at a time, 250ms apart. Rate limited replays are retried up to 3 times after the delay Paddle asks for. Replays that still fail are returned in [ReplayedNotifications::failed](notifications::ReplayedNotifications::failed).
+    ///
+    /// # Example:
+    ///
+    /// ```rust,no_run
+    /// use chrono::{Duration, Utc};
+    /// use paddle_rust_sdk::Paddle;
+    ///
+    /// let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+    /// let summary = client
+    ///     .replay_failed_notifications(Utc::now() - Duration::days(1))
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// for (notification, err) in &summary.failed {
+    ///     eprintln!("Failed to replay {}: {err}", notification.id);
+    /// }
+    /// ```
+    pub async fn replay_failed_notifications(
+        &self,
+        since: DateTime<Utc>,
+    ) -> std::result::Result<notifications::ReplayedNotifications, Error> {
+        let failed = self
+            .notifications_list()
+            .status([NotificationStatus::Failed])
+            .from(since)
+            .max_page_size()
+            .send()
+            .all()
+            .await?;
+
+        let mut summary = notifications::ReplayedNotifications::default();
+
+        for (index, notification) in failed.into_iter().enumerate() {
+            if index > 0 {
+                tokio::time::sleep(notifications::REPLAY_DELAY).await;
+            }
+
+            let mut retries = 0;
+
+            loop {
+                match self.notification_replay(notification.id.clone()).await {
+                    Ok(response) => summary
+                        .replayed
+                        .push((notification, response.data.notification_id)),
+                    Err(err) => match err.retry_after() {
+                        Some(delay) if retries < notifications::MAX_RATE_LIMIT_RETRIES => {
+                            retries += 1;
+                            tokio::time::sleep(delay).await;
+                            continue;
+                        }
+                        _ => summary.failed.push((notification, err)),
+                    },
+                }
+
+                break;
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Send a simulated event to a notification destination and wait until Paddle has delivered it. Use it as a smoke test for your webhook endpoint, e.g. after a deploy.
+    ///
+    /// Creates a single event simulation with a demo payload, runs it and checks the run every 2 seconds. Fails with [Error::SimulationNotCompleted] if the event isn't delivered within a minute. The simulation is archived afterwards.
+    ///
+    /// The notification destination must have `traffic_source` set to `simulation` or `all` to receive simulated events.
+    ///
+    /// # Example:
+    ///
+    /// ```rust,no_run
+    /// use paddle_rust_sdk::enums::EventTypeName;
+    /// use paddle_rust_sdk::Paddle;
+    ///
+    /// let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+    /// let test = client
+    ///     .test_webhook("ntfset_01gkpjp8bkm3tm53kdgkx6sms7", EventTypeName::SubscriptionCreated)
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// if !test.delivered() {
+    ///     dbg!(test.response());
+    /// }
+    /// ```
+    pub async fn test_webhook(
+        &self,
+        notification_setting_id: impl Into<NotificationSettingID>,
+        event_type: EventTypeName,
+    ) -> std::result::Result<WebhookTest, Error> {
+        let simulation = SimulationSingleEventCreate::new(notification_setting_id, event_type)
+            .name("Webhook test");
+
+        let simulation: SimulationSingleEvent = self
+            .send(simulation, Method::POST, "/simulations")
+            .await?
+            .data;
+
+        let url = format!("/simulations/{}/runs", simulation.id.as_ref());
+        let run: SimulationRunSingleEvent = self
+            .send(serde_json::json!({}), Method::POST, &url)
+            .await?
+            .data;
+
+        let url = format!("{}/{}", url, run.id.as_ref());
+        let deadline = tokio::time::Instant::now() + webhooks::WEBHOOK_TEST_TIMEOUT;
+
+        let event = async {
+            loop {
+                let run: SimulationRunSingleEvent = self
+                    .send(
+                        serde_json::json!({ "include": "events" }),
+                        Method::GET,
+                        &url,
+                    )
+                    .await?
+                    .data;
+
+                let event = run.events.into_iter().flatten().next();
+
+                match event {
+                    Some(event)
+                        if run.status != SimulationRunStatus::Pending
+                            && event.status != SimulationEventStatus::Pending =>
+                    {
+                        break Ok(event)
+                    }
+                    _ if tokio::time::Instant::now() >= deadline => {
+                        break Err(Error::SimulationNotCompleted(run.id))
+                    }
+                    _ => tokio::time::sleep(webhooks::WEBHOOK_TEST_POLL_INTERVAL).await,
+                }
+            }
+        }
+        .await;
+
+        // Archived whether or not the test completed, a simulation that's left active is only clutter in the dashboard
+        let url = format!("/simulations/{}", simulation.id.as_ref());
+        let _ = self
+            .send::<SimulationSingleEvent>(
+                serde_json::json!({ "status": Status::Archived }),
+                Method::PATCH,
+                &url,
+            )
+            .await;
+
+        Ok(WebhookTest {
+            simulation_id: simulation.id,
+            event: event?,
+        })
+    }
+
+    /// Send a request described by any builder implementing [PaddleRequest](request::PaddleRequest).
+    ///
+    /// List requests only return the first page of results. Use the `send` method of list builders to paginate.
+    ///
+    /// # Example:
+    ///
+    /// ```rust,no_run
+    /// use paddle_rust_sdk::Paddle;
+    /// let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+    /// let request = client.customer_get("ctm_01jqztc78e1xfdgwhcgjzdrvgd");
+    /// let res = client.execute(&request).await.unwrap();
+    /// dbg!(res.data);
+    /// ```
+    pub async fn execute<R: request::PaddleRequest>(&self, request: &R) -> Result<R::Output> {
+        self.send(request.payload(), request.method(), &request.path())
+            .await
+    }
+
+    /// Make a request to any Paddle API endpoint, including ones this crate doesn't have a builder for yet.
+    ///
+    /// - **method** - HTTP method of the endpoint.
+    /// - **path** - Path of the endpoint relative to the API base URL, e.g. `/customers`.
+    /// - **payload** - Serialized as the query string for `GET` requests and as the JSON body for `POST`, `PUT` and `PATCH` requests. It's ignored for every other method, e.g. `DELETE` requests are sent without a body. Pass `()` when there's nothing to send, note that it's sent as a `null` body with `POST`, `PUT` and `PATCH`, use `serde_json::json!({})` if the endpoint expects an empty object.
+    ///
+    /// The query string is serialized with `serde_qs`, so `GET` payloads should be a struct or a map rather than a list of pairs.
+    ///
+    /// The response is handled the same way as for every other request - the `data` field is deserialized into `T` and API errors are returned as [Error::PaddleApi]. Use `serde_json::Value` as `T` to get the raw response data.
+    ///
+    /// # Example:
+    ///
+    /// ```rust,no_run
+    /// use paddle_rust_sdk::Paddle;
+    /// use reqwest::Method;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Query {
+    ///     per_page: usize,
+    /// }
+    ///
+    /// let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+    /// let res = client
+    ///     .request::<serde_json::Value>(Method::GET, "/customers", Query { per_page: 10 })
+    ///     .await
+    ///     .unwrap();
+    /// dbg!(res.data);
+    /// ```
+    pub async fn request<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        payload: impl Serialize,
+    ) -> Result<T> {
+        self.send(payload, method, path).await
+    }
+
     async fn send<T: DeserializeOwned>(
         &self,
         req: impl Serialize,
         method: Method,
         path: &str,
     ) -> Result<T> {
+        #[cfg(feature = "tracing")]
+        return traced(
+            self.send_request(req, method.clone(), path),
+            &method,
+            path,
+            self.correlation_id.as_deref(),
+        )
+        .await;
+
+        #[cfg(not(feature = "tracing"))]
+        self.send_request(req, method, path).await
+    }
+
+    /// Start an authenticated request to the Paddle API, tagged with the correlation ID if one is set.
+    fn http_request(&self, method: Method, url: impl IntoUrl) -> reqwest::RequestBuilder {
+        let builder = self.http.request(method, url).bearer_auth(&self.api_key);
+
+        match &self.correlation_id {
+            Some(correlation_id) => {
+                builder.header(Self::CORRELATION_ID_HEADER, correlation_id.as_ref())
+            }
+            None => builder,
+        }
+    }
+
+    /// Wait for the weight of a request if this client has a [Budget]. Held until the permit is dropped.
+    async fn acquire_budget(&self) -> Option<BudgetPermit> {
+        match &self.budget {
+            Some(budget) => budget.acquire_request().await,
+            None => None,
+        }
+    }
+
+    async fn send_request<T: DeserializeOwned>(
+        &self,
+        req: impl Serialize,
+        method: Method,
+        path: &str,
+    ) -> Result<T> {
+        let _permit = self.acquire_budget().await;
         let mut url = self.base_url.join(path)?;
-        let client = reqwest::Client::new();
 
         if method == reqwest::Method::GET {
             url.set_query(Some(&serde_qs::to_string(&req)?));
         }
 
-        let mut builder = client
-            .request(method.clone(), url)
-            .bearer_auth(self.api_key.clone())
+        let builder = self
+            .http_request(method.clone(), url)
             .header(CONTENT_TYPE, "application/json; charset=utf-8");
 
-        builder = match method {
+        let builder = match method {
             reqwest::Method::POST | reqwest::Method::PUT | reqwest::Method::PATCH => {
-                builder.json(&req)
+                match &self.custom_data_hook {
+                    Some(hook) => {
+                        builder.json(&custom_data::normalize_outgoing(&req, hook.as_ref())?)
+                    }
+                    None => builder.json(&req),
+                }
             }
             _ => builder,
         };
@@ -1281,15 +1924,60 @@ impl Paddle {
         // // println!("{}", serde_json::to_string(&res["data"]).unwrap());
         // todo!();
 
-        let res: Response<_> = builder.send().await?.json().await?;
+        let response = builder.send().await?;
+
+        match &self.custom_data_hook {
+            Some(hook) => {
+                let status = response.status();
+                let retry_after = response::retry_after(&response);
+                let body = response.bytes().await?;
 
-        match res {
-            Response::Success(success) => Ok(success),
-            Response::Error(error) => Err(Error::PaddleApi(error)),
+                custom_data::from_slice(status, &body, hook.as_ref())
+                    .map_err(|err| err.with_retry_after(retry_after))
+            }
+            None => response::decode(response).await,
         }
     }
 }
 
+/// Run a request inside a span carrying the correlation ID and record the `request_id` Paddle returned once it completes.
+#[cfg(feature = "tracing")]
+async fn traced<T>(
+    request: impl std::future::Future<Output = Result<T>>,
+    method: &Method,
+    path: &str,
+    correlation_id: Option<&str>,
+) -> Result<T> {
+    use tracing::Instrument;
+
+    let span = tracing::debug_span!(
+        "paddle_request",
+        %method,
+        path,
+        correlation_id,
+        request_id = tracing::field::Empty,
+    );
+
+    let result = request.instrument(span.clone()).await;
+
+    let request_id = match &result {
+        Ok(response) => Some(response.meta.request_id.as_str()),
+        Err(Error::PaddleApi(err)) => Some(err.meta.request_id.as_str()),
+        Err(_) => None,
+    };
+
+    if let Some(request_id) = request_id {
+        span.record("request_id", request_id);
+    }
+
+    span.in_scope(|| match &result {
+        Ok(_) => tracing::debug!("Paddle request succeeded"),
+        Err(err) => tracing::warn!(error = %err, "Paddle request failed"),
+    });
+
+    result
+}
+
 fn comma_separated<S, T>(
     values: &Option<Vec<T>>,
     serializer: S,
@@ -1312,6 +2000,16 @@ where
     }
 }
 
+/// Join enum values into a comma separated list, using their serialized names. Used for query parameters in request paths.
+fn enum_list<T: Serialize>(values: &[T]) -> String {
+    values
+        .iter()
+        .filter_map(|value| serde_json::to_value(value).ok())
+        .filter_map(|value| value.as_str().map(str::to_string))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 fn comma_separated_enum<S, T>(
     values: &Option<Vec<T>>,
     serializer: S,
@@ -1339,3 +2037,350 @@ where
         None => serializer.serialize_none(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn client_is_cheap_to_share() {
+        fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+        assert_shareable::<Paddle>();
+
+        let client = Paddle::new("key", Paddle::SANDBOX).unwrap();
+        let clone = client.clone();
+
+        assert!(Arc::ptr_eq(&client.api_key, &clone.api_key));
+        assert!(Arc::ptr_eq(&client.base_url, &clone.base_url));
+        assert!(Arc::ptr_eq(&client.defaults, &clone.defaults));
+    }
+
+    #[tokio::test]
+    async fn correlation_id_is_sent_with_requests() {
+        let url = fixtures::serve_json_raw(|request| async move {
+            let headers = request.to_lowercase();
+
+            if headers.contains("x-correlation-id: 7f3c9a1e\r\n") {
+                json!({"data": fixtures::customer(), "meta": {"request_id": "req_1"}})
+            } else {
+                json!({})
+            }
+        })
+        .await;
+
+        let client = Paddle::new("key", url).unwrap();
+        assert!(client
+            .customer_get("ctm_01hv6y1jedq4p1n0yqn5ba3ky4")
+            .send()
+            .await
+            .is_err());
+
+        let client = client.with_correlation_id("7f3c9a1e");
+        let customer = client
+            .customer_get("ctm_01hv6y1jedq4p1n0yqn5ba3ky4")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(customer.meta.request_id, "req_1");
+    }
+
+    #[tokio::test]
+    async fn request_sends_payload_by_method() {
+        #[derive(Serialize)]
+        struct Query {
+            per_page: usize,
+        }
+
+        let url = fixtures::serve_json_raw(|request| async move {
+            let (head, body) = request.split_once("\r\n\r\n").unwrap();
+
+            if head.starts_with("GET") {
+                assert!(head.starts_with("GET /customers?per_page=10 "), "{head}");
+            } else {
+                // DELETE requests are sent without a body
+                assert!(head.starts_with("DELETE /notification-settings/"), "{head}");
+                assert!(body.is_empty(), "{body}");
+            }
+
+            json!({ "data": {}, "meta": { "request_id": "req_1" } })
+        })
+        .await;
+        let client = Paddle::new("key", url).unwrap();
+
+        client
+            .request::<serde_json::Value>(Method::GET, "/customers", Query { per_page: 10 })
+            .await
+            .unwrap();
+        client
+            .request::<serde_json::Value>(
+                Method::DELETE,
+                "/notification-settings/ntfset_01gkpjp8bkm3tm53kdgkx6sms7",
+                (),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn credit_note_pdf_of_missing_adjustment_is_not_found() {
+        let url = fixtures::serve_json(|target| async move {
+            // The credit note of a missing adjustment can't be fetched
+            let data = if target.starts_with("/adjustments?") {
+                json!([])
+            } else {
+                json!({})
+            };
+
+            json!({
+                "data": data,
+                "meta": {
+                    "request_id": "req_1",
+                    "pagination": {
+                        "per_page": 50,
+                        "next": "http://localhost/adjustments",
+                        "has_more": false,
+                        "estimated_total": 0
+                    }
+                }
+            })
+        })
+        .await;
+
+        let client = Paddle::new("key", url).unwrap();
+        let err = client
+            .adjustment_credit_note_pdf("adj_01hvgf2s84dr6reszzg29zbvcm", Disposition::Inline)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, Error::NotFound(ref id) if id == "adj_01hvgf2s84dr6reszzg29zbvcm"));
+    }
+
+    #[tokio::test]
+    async fn credit_note_pdf_is_returned_with_its_adjustment() {
+        let url = fixtures::serve_responses(|request: String| async move {
+            let host = request
+                .lines()
+                .find_map(|line| line.strip_prefix("host: "))
+                .unwrap_or_default()
+                .to_string();
+
+            let data = if request.starts_with("GET /adjustments?") {
+                json!([{
+                    "id": "adj_01hvgf2s84dr6reszzg29zbvcm",
+                    "action": "refund",
+                    "type": "full",
+                    "transaction_id": "txn_01hv8wptq8987qeep44cyrewp9",
+                    "subscription_id": null,
+                    "customer_id": "ctm_01hv6y1jedq4p1n0yqn5ba3ky4",
+                    "reason": "Customer requested a refund",
+                    "credit_applied_to_balance": null,
+                    "currency_code": "USD",
+                    "status": "approved",
+                    "items": [],
+                    "totals": {
+                        "subtotal": "3000",
+                        "tax": "600",
+                        "total": "3600",
+                        "fee": "180",
+                        "earnings": "2820",
+                        "currency_code": "USD"
+                    },
+                    "payout_totals": null,
+                    "tax_rates_used": [],
+                    "created_at": fixtures::CREATED_AT,
+                    "updated_at": fixtures::CREATED_AT
+                }])
+            } else if request.starts_with("GET /adjustments/") {
+                json!({ "url": format!("http://{host}/files/credit_note.pdf") })
+            } else {
+                return fixtures::MockResponse::ok(json!("%PDF-1.7"));
+            };
+
+            fixtures::MockResponse::ok(json!({
+                "data": data,
+                "meta": {
+                    "request_id": "req_1",
+                    "pagination": {
+                        "per_page": 50,
+                        "next": "http://localhost/adjustments",
+                        "has_more": false,
+                        "estimated_total": 1
+                    }
+                }
+            }))
+        })
+        .await;
+
+        let client = Paddle::new("key", url).unwrap();
+        let credit_note = client
+            .adjustment_credit_note_pdf("adj_01hvgf2s84dr6reszzg29zbvcm", Disposition::Attachment)
+            .await
+            .unwrap();
+
+        assert_eq!(credit_note.adjustment.totals.total, "3600");
+        assert_eq!(credit_note.document.filename, "credit_note.pdf");
+        assert_eq!(credit_note.document.disposition, Disposition::Attachment);
+        assert_eq!(credit_note.document.bytes, b"\"%PDF-1.7\"");
+    }
+
+    #[tokio::test]
+    async fn test_webhook_returns_delivery() {
+        let url = fixtures::serve_json(|target| async move {
+            let mut simulation = json!({
+                "id": "ntfsim_01ghbkd0frb9k95cnhwd1bxpvk",
+                "status": "active",
+                "notification_setting_id": "ntfset_01gkpjp8bkm3tm53kdgkx6sms7",
+                "name": "Webhook test",
+                "type": "subscription.created",
+                "payload": null,
+                "last_run_at": null,
+                "created_at": fixtures::CREATED_AT,
+                "updated_at": fixtures::CREATED_AT
+            });
+
+            let data = if target.contains("/runs") {
+                let status = if target.contains("include=events") {
+                    "completed"
+                } else {
+                    "pending"
+                };
+
+                json!({
+                    "id": "ntfsimrun_01ghbkd0frb9k95cnhwd1bxpvk",
+                    "status": status,
+                    "type": "subscription.created",
+                    "created_at": fixtures::CREATED_AT,
+                    "updated_at": fixtures::CREATED_AT,
+                    "events": [{
+                        "id": "ntfsimevt_01ghbkd0frb9k95cnhwd1bxpvk",
+                        "status": "failed",
+                        "event_type": "subscription.created",
+                        "payload": {},
+                        "request": { "body": "{}" },
+                        "response": { "body": "Bad Gateway", "status_code": 502 },
+                        "created_at": fixtures::CREATED_AT,
+                        "updated_at": fixtures::CREATED_AT
+                    }]
+                })
+            } else {
+                simulation["status"] = json!("archived");
+                simulation
+            };
+
+            json!({ "data": data, "meta": { "request_id": "req_1" } })
+        })
+        .await;
+
+        let client = Paddle::new("key", url.as_str()).unwrap();
+        let test = client
+            .test_webhook(
+                "ntfset_01gkpjp8bkm3tm53kdgkx6sms7",
+                EventTypeName::SubscriptionCreated,
+            )
+            .await
+            .unwrap();
+
+        assert!(!test.delivered());
+        assert_eq!(test.response().unwrap().status_code, 502);
+        assert_eq!(test.response().unwrap().body, "Bad Gateway");
+    }
+
+    #[tokio::test]
+    async fn test_webhook_archives_simulation_on_error() {
+        let archived = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let seen = Arc::clone(&archived);
+
+        let url = fixtures::serve_json_raw(move |request| {
+            let seen = Arc::clone(&seen);
+
+            async move {
+                let simulation = json!({
+                    "id": "ntfsim_01ghbkd0frb9k95cnhwd1bxpvk",
+                    "status": "active",
+                    "notification_setting_id": "ntfset_01gkpjp8bkm3tm53kdgkx6sms7",
+                    "name": "Webhook test",
+                    "type": "subscription.created",
+                    "payload": null,
+                    "last_run_at": null,
+                    "created_at": fixtures::CREATED_AT,
+                    "updated_at": fixtures::CREATED_AT
+                });
+
+                let data = if request.starts_with("PATCH /simulations/") {
+                    seen.store(true, std::sync::atomic::Ordering::SeqCst);
+                    simulation
+                } else if request.contains("include=events") {
+                    // Checking the run fails
+                    return json!({});
+                } else if request.contains("/runs") {
+                    json!({
+                        "id": "ntfsimrun_01ghbkd0frb9k95cnhwd1bxpvk",
+                        "status": "pending",
+                        "type": "subscription.created",
+                        "created_at": fixtures::CREATED_AT,
+                        "updated_at": fixtures::CREATED_AT
+                    })
+                } else {
+                    simulation
+                };
+
+                json!({ "data": data, "meta": { "request_id": "req_1" } })
+            }
+        })
+        .await;
+
+        let client = Paddle::new("key", url.as_str()).unwrap();
+        let test = client
+            .test_webhook(
+                "ntfset_01gkpjp8bkm3tm53kdgkx6sms7",
+                EventTypeName::SubscriptionCreated,
+            )
+            .await;
+
+        assert!(test.is_err());
+        assert!(archived.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn subscription_checkout_returns_subscription_once_paid() {
+        let url = fixtures::serve_json(|target| async move {
+            let data = if target.starts_with("/subscriptions/") {
+                fixtures::subscription()
+            } else {
+                fixtures::transaction()
+            };
+
+            json!({ "data": data, "meta": { "request_id": "req_1" } })
+        })
+        .await;
+
+        let client = Paddle::new("key", url.as_str()).unwrap();
+
+        let checkout = client
+            .create_subscription_checkout(
+                "ctm_01hv6y1jedq4p1n0yqn5ba3ky4",
+                "pri_01gsz8x8sawmvhz1pv30nge0001",
+                1,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            checkout.url,
+            "https://aeroedit.com/pay?_ptxn=txn_01hv8wptq8987qeep44cyrewp9"
+        );
+
+        let subscription = client
+            .await_subscription_for_transaction(
+                checkout.transaction_id,
+                std::time::Duration::from_secs(1),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            subscription.data.subscription.id.as_ref(),
+            "sub_01hv8x29kz0t586xy6zn1a62ny"
+        );
+    }
+}
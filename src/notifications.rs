@@ -0,0 +1,247 @@
+//! Builders for making requests to the Paddle API for notifications.
+//!
+//! See the [Paddle API](https://developer.paddle.com/api-reference/notifications/overview) documentation for more information.
+
+use chrono::{DateTime, Utc};
+use reqwest::Method;
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use crate::entities::Notification;
+use crate::enums::NotificationStatus;
+use crate::ids::{NotificationID, NotificationSettingID};
+use crate::paginated::Paginated;
+use crate::permissions::Permission;
+use crate::request::PaddleRequest;
+use crate::{Error, Paddle};
+
+/// Delay between replays sent by [Paddle::replay_failed_notifications], to stay well under the rate limit.
+pub(crate) const REPLAY_DELAY: std::time::Duration = std::time::Duration::from_millis(250);
+
+/// Number of times [Paddle::replay_failed_notifications] retries a rate limited replay before giving up on a notification.
+pub(crate) const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+
+/// Request builder for querying Paddle for notifications.
+#[skip_serializing_none]
+#[derive(Clone, Serialize)]
+#[must_use]
+pub struct NotificationsList<'a> {
+    #[serde(skip)]
+    client: &'a Paddle,
+    after: Option<NotificationID>,
+    from: Option<DateTime<Utc>>,
+    #[serde(serialize_with = "crate::comma_separated")]
+    notification_setting_id: Option<Vec<NotificationSettingID>>,
+    order_by: Option<String>,
+    per_page: Option<usize>,
+    search: Option<String>,
+    #[serde(serialize_with = "crate::comma_separated_enum")]
+    status: Option<Vec<NotificationStatus>>,
+    to: Option<DateTime<Utc>>,
+}
+
+impl<'a> NotificationsList<'a> {
+    pub fn new(client: &'a Paddle) -> Self {
+        Self {
+            client,
+            after: None,
+            from: None,
+            notification_setting_id: None,
+            order_by: None,
+            per_page: None,
+            search: None,
+            status: None,
+            to: None,
+        }
+    }
+
+    /// Return entities after the specified Paddle ID when working with paginated endpoints. Used in the `meta.pagination.next` URL in responses for list operations.
+    pub fn after(mut self, notification_id: impl Into<NotificationID>) -> Self {
+        self.after = Some(notification_id.into());
+        self
+    }
+
+    /// Return entities that occurred at or after the specified time.
+    pub fn from(mut self, date: DateTime<Utc>) -> Self {
+        self.from = Some(date);
+        self
+    }
+
+    /// Return entities sent to the specified notification destinations.
+    pub fn notification_setting_ids(
+        mut self,
+        notification_setting_ids: impl IntoIterator<Item = impl Into<NotificationSettingID>>,
+    ) -> Self {
+        self.notification_setting_id = Some(
+            notification_setting_ids
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        );
+        self
+    }
+
+    /// Order returned entities by the specified field. Valid fields for ordering: `id`
+    pub fn order_by_asc(mut self, field: &str) -> Self {
+        self.order_by = Some(format!("{}[ASC]", field));
+        self
+    }
+
+    /// Order returned entities by the specified field. Valid fields for ordering: `id`
+    pub fn order_by_desc(mut self, field: &str) -> Self {
+        self.order_by = Some(format!("{}[DESC]", field));
+        self
+    }
+
+    /// Number of entities Paddle returns per page when [Self::per_page] isn't set.
+    pub const DEFAULT_PER_PAGE: usize = 50;
+    /// Largest page size Paddle accepts for this endpoint.
+    pub const MAX_PER_PAGE: usize = 200;
+
+    /// Set how many entities are returned per page. Paddle returns the maximum number of results if a number greater than the maximum is requested.
+    /// Check `meta.pagination.per_page` in the response to see how many were returned.
+    ///
+    /// Default: `50`; Maximum: `200`.
+    pub fn per_page(mut self, entities_per_page: usize) -> Self {
+        self.per_page = Some(entities_per_page);
+        self
+    }
+
+    /// Request the largest page Paddle allows for this endpoint. Use this to fetch everything in as few requests as possible.
+    pub fn max_page_size(self) -> Self {
+        self.per_page(Self::MAX_PER_PAGE)
+    }
+
+    /// Return entities that match a search query.
+    pub fn search(mut self, term: impl Into<String>) -> Self {
+        self.search = Some(term.into());
+        self
+    }
+
+    /// Return entities that match the specified status.
+    pub fn status(mut self, statuses: impl IntoIterator<Item = NotificationStatus>) -> Self {
+        self.status = Some(statuses.into_iter().collect());
+        self
+    }
+
+    /// Return entities that occurred before the specified time.
+    pub fn to(mut self, date: DateTime<Utc>) -> Self {
+        self.to = Some(date);
+        self
+    }
+
+    /// Returns a paginator for fetching pages of entities from Paddle
+    pub fn send(&self) -> Paginated<'_, Vec<Notification>> {
+        Paginated::new(self.client, &self.path(), self)
+    }
+}
+
+impl PaddleRequest for NotificationsList<'_> {
+    type Output = Vec<Notification>;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        "/notifications".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::NotificationRead]
+    }
+}
+
+/// Summary of [Paddle::replay_failed_notifications].
+#[derive(Debug, Default)]
+pub struct ReplayedNotifications {
+    /// Failed notifications that were replayed, along with the ID of the notification created by the replay.
+    pub replayed: Vec<(Notification, NotificationID)>,
+    /// Failed notifications that couldn't be replayed along with the error.
+    pub failed: Vec<(Notification, Error)>,
+}
+
+impl ReplayedNotifications {
+    /// Whether every failed notification was replayed.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    fn notification(id: &str) -> serde_json::Value {
+        json!({
+            "id": id,
+            "type": "customer.updated",
+            "status": "failed",
+            "payload": {
+                "notification_id": id,
+                "event_id": "evt_01hv8x2acma3zqs7qwvbcwbm4r",
+                "event_type": "customer.updated",
+                "occurred_at": fixtures::CREATED_AT,
+                "data": fixtures::customer()
+            },
+            "occurred_at": fixtures::CREATED_AT,
+            "delivered_at": null,
+            "replayed_at": null,
+            "origin": "event",
+            "last_attempt_at": fixtures::CREATED_AT,
+            "retry_at": null,
+            "times_attempted": 60,
+            "notification_setting_id": "ntfset_01gkpjp8bkm3tm53kdgkx6sms7"
+        })
+    }
+
+    #[tokio::test]
+    async fn replays_failed_notifications() {
+        let url = fixtures::serve_json(|target| async move {
+            if target.starts_with("/notifications?") {
+                assert!(target.contains("status=failed"), "{target}");
+
+                return json!({
+                    "data": [notification("ntf_01"), notification("ntf_02")],
+                    "meta": {
+                        "request_id": "req_1",
+                        "pagination": {
+                            "per_page": 200,
+                            "next": "http://localhost/notifications?after=ntf_02",
+                            "has_more": false,
+                            "estimated_total": 2
+                        }
+                    }
+                });
+            }
+
+            if target == "/notifications/ntf_01/replay" {
+                json!({ "data": { "notification_id": "ntf_03" }, "meta": { "request_id": "req_2" } })
+            } else {
+                json!({})
+            }
+        })
+        .await;
+
+        let client = Paddle::new("key", url.as_str()).unwrap();
+        let summary = client
+            .replay_failed_notifications(fixtures::CREATED_AT.parse().unwrap())
+            .await
+            .unwrap();
+
+        assert!(!summary.is_complete());
+        assert_eq!(summary.replayed.len(), 1);
+        assert_eq!(summary.replayed[0].0.id.as_ref(), "ntf_01");
+        assert_eq!(summary.replayed[0].1.as_ref(), "ntf_03");
+        assert_eq!(summary.failed.len(), 1);
+        assert_eq!(summary.failed[0].0.id.as_ref(), "ntf_02");
+    }
+}
@@ -8,8 +8,9 @@ use serde::Serialize;
 /// - `Unchanged` - Field will be omitted from serialization (no change)
 /// - `Null` - Field will be serialized as `null` (clear the value)
 /// - `Value(T)` - Field will be serialized as the contained value
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub enum Nullable<T> {
+    #[default]
     Unchanged,
     Null,
     Value(T),
@@ -21,12 +22,6 @@ impl<T> Nullable<T> {
     }
 }
 
-impl<T> Default for Nullable<T> {
-    fn default() -> Self {
-        Nullable::Unchanged
-    }
-}
-
 impl<T> From<T> for Nullable<T> {
     fn from(value: T) -> Self {
         Nullable::Value(value)
@@ -136,6 +131,17 @@ mod tests {
         assert!(n.is_unchanged());
     }
 
+    #[test]
+    fn default_is_omitted() {
+        let s = MultiField {
+            a: Nullable::default(),
+            b: Nullable::default(),
+            c: Nullable::default(),
+        };
+        let json = serde_json::to_value(&s).unwrap();
+        assert_eq!(json, serde_json::json!({}));
+    }
+
     #[test]
     fn from_value() {
         let n: Nullable<String> = "hello".to_string().into();
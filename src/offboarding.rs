@@ -0,0 +1,275 @@
+//! Remove a customer's billing setup when they delete their account.
+//!
+//! Paddle doesn't delete customers. [Paddle::offboard_customer] gets a customer as close to deleted as the API allows: subscriptions are canceled, saved payment methods are deleted, addresses and businesses are archived and `custom_data` is cleared everywhere. Use [Paddle::export_customer_data] first if the customer asked for a copy of their data.
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::enums::EffectiveFrom;
+//! use paddle_rust_sdk::offboarding::OffboardOptions;
+//! use paddle_rust_sdk::Paddle;
+//!
+//! let client = Paddle::new(std::env::var("PADDLE_API_KEY")?, Paddle::PRODUCTION)?;
+//!
+//! let options = OffboardOptions::default().effective_from(EffectiveFrom::Immediately);
+//! let report = client.offboard_customer("ctm_01hv6y1jedq4p1n0yqn5ba3ky4", options).await?;
+//!
+//! for (id, err) in &report.failed {
+//!     eprintln!("Failed to offboard {id}: {err}");
+//! }
+//! ```
+
+use crate::entities::{Address, Business};
+use crate::enums::{EffectiveFrom, Status, SubscriptionStatus};
+use crate::ids::{AddressID, BusinessID, CustomerID, PaymentMethodID, SubscriptionID};
+use crate::nullable::Nullable;
+use crate::{Error, Paddle};
+
+/// Subscriptions in these statuses are canceled.
+const CANCELABLE_STATUSES: [SubscriptionStatus; 4] = [
+    SubscriptionStatus::Active,
+    SubscriptionStatus::Trialing,
+    SubscriptionStatus::PastDue,
+    SubscriptionStatus::Paused,
+];
+
+/// Options for [Paddle::offboard_customer].
+#[derive(Clone, Copy, Debug)]
+pub struct OffboardOptions {
+    effective_from: EffectiveFrom,
+}
+
+impl OffboardOptions {
+    /// When subscriptions are canceled. Defaults to [EffectiveFrom::NextBillingPeriod], so customers keep access to what they already paid for.
+    pub fn effective_from(mut self, effective_from: EffectiveFrom) -> Self {
+        self.effective_from = effective_from;
+        self
+    }
+}
+
+impl Default for OffboardOptions {
+    fn default() -> Self {
+        Self {
+            effective_from: EffectiveFrom::NextBillingPeriod,
+        }
+    }
+}
+
+/// What [Paddle::offboard_customer] changed.
+///
+/// Every entity is handled even if some of them fail. Running the offboarding again retries only what's left, as canceled subscriptions, deleted payment methods and archived entities aren't listed again.
+#[derive(Debug, Default)]
+pub struct OffboardReport {
+    pub canceled_subscriptions: Vec<SubscriptionID>,
+    pub deleted_payment_methods: Vec<PaymentMethodID>,
+    /// Payment methods Paddle answered for without deleting them.
+    pub undeleted_payment_methods: Vec<PaymentMethodID>,
+    pub archived_addresses: Vec<AddressID>,
+    pub archived_businesses: Vec<BusinessID>,
+    /// Whether `custom_data` of the customer was cleared.
+    pub customer_scrubbed: bool,
+    /// IDs of the entities that couldn't be changed along with the error.
+    pub failed: Vec<(String, Error)>,
+}
+
+impl OffboardReport {
+    /// Whether every entity was offboarded.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty() && self.undeleted_payment_methods.is_empty()
+    }
+}
+
+pub(crate) async fn offboard_customer(
+    client: &Paddle,
+    customer_id: CustomerID,
+    options: OffboardOptions,
+) -> Result<OffboardReport, Error> {
+    let mut report = OffboardReport::default();
+
+    // Stop billing first, so a failure further down doesn't leave the customer paying
+    let subscriptions = client
+        .subscriptions_list()
+        .customer_id([customer_id.clone()])
+        .status(CANCELABLE_STATUSES)
+        .max_page_size()
+        .send()
+        .all()
+        .await?;
+
+    for subscription in subscriptions {
+        let result = client
+            .subscription_cancel(subscription.id.clone())
+            .effective_from(options.effective_from)
+            .send()
+            .await;
+
+        match result {
+            Ok(_) => report.canceled_subscriptions.push(subscription.id),
+            Err(err) => report.failed.push((subscription.id.to_string(), err)),
+        }
+    }
+
+    let payment_methods = client
+        .payment_methods_list(customer_id.clone())
+        .max_page_size()
+        .send()
+        .all()
+        .await?;
+
+    for payment_method in payment_methods {
+        match client
+            .payment_method_delete(customer_id.clone(), payment_method.id.clone())
+            .await
+        {
+            Ok(true) => report.deleted_payment_methods.push(payment_method.id),
+            Ok(false) => report.undeleted_payment_methods.push(payment_method.id),
+            Err(err) => report.failed.push((payment_method.id.to_string(), err)),
+        }
+    }
+
+    let addresses: Vec<Address> = client
+        .addresses_list(customer_id.clone())
+        .max_page_size()
+        .send()
+        .all()
+        .await?;
+
+    for address in addresses {
+        let result = client
+            .address_update(customer_id.clone(), address.id.clone())
+            .status(Status::Archived)
+            .custom_data(Nullable::Null)
+            .send()
+            .await;
+
+        match result {
+            Ok(_) => report.archived_addresses.push(address.id),
+            Err(err) => report.failed.push((address.id.to_string(), err)),
+        }
+    }
+
+    let businesses: Vec<Business> = client
+        .businesses_list(customer_id.clone())
+        .max_page_size()
+        .send()
+        .all()
+        .await?;
+
+    for business in businesses {
+        let result = client
+            .business_update(customer_id.clone(), business.id.clone())
+            .status(Status::Archived)
+            .custom_data(Nullable::Null)
+            .send()
+            .await;
+
+        match result {
+            Ok(_) => report.archived_businesses.push(business.id),
+            Err(err) => report.failed.push((business.id.to_string(), err)),
+        }
+    }
+
+    match client
+        .customer_update(customer_id.clone())
+        .custom_data(Nullable::Null)
+        .send()
+        .await
+    {
+        Ok(_) => report.customer_scrubbed = true,
+        Err(err) => report.failed.push((customer_id.to_string(), err)),
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    #[tokio::test]
+    async fn offboards_everything_and_reports_failures() {
+        let url = fixtures::serve_json(|target| async move {
+            let path = target.split('?').next().unwrap_or_default().to_string();
+            let list = |data| {
+                json!({
+                    "data": data,
+                    "meta": {
+                        "request_id": "req_1",
+                        "pagination": {
+                            "per_page": 200,
+                            "next": format!("http://localhost{path}"),
+                            "has_more": false,
+                            "estimated_total": 1
+                        }
+                    }
+                })
+            };
+
+            match path.as_str() {
+                "/subscriptions" => list(json!([fixtures::subscription()])),
+                "/customers/ctm_01hv6y1jedq4p1n0yqn5ba3ky4/addresses" => list(json!([{
+                    "id": "add_01hv8gq3318ktkfengj2r75gfx",
+                    "customer_id": "ctm_01hv6y1jedq4p1n0yqn5ba3ky4",
+                    "description": null,
+                    "first_line": null,
+                    "second_line": null,
+                    "city": null,
+                    "postal_code": "10021",
+                    "region": null,
+                    "country_code": "US",
+                    "custom_data": null,
+                    "status": "active",
+                    "created_at": fixtures::CREATED_AT,
+                    "updated_at": fixtures::CREATED_AT,
+                    "import_meta": null
+                }])),
+                "/customers/ctm_01hv6y1jedq4p1n0yqn5ba3ky4/payment-methods" => list(json!([{
+                    "id": "paymtd_01hs8zx6x377xfsfrt2bqsevbw",
+                    "customer_id": "ctm_01hv6y1jedq4p1n0yqn5ba3ky4",
+                    "address_id": "add_01hv8gq3318ktkfengj2r75gfx",
+                    "type": "alipay",
+                    "card": null,
+                    "paypal": null,
+                    "south_korea_local_card": null,
+                    "origin": "subscription",
+                    "saved_at": fixtures::CREATED_AT,
+                    "updated_at": fixtures::CREATED_AT
+                }])),
+                "/customers/ctm_01hv6y1jedq4p1n0yqn5ba3ky4/businesses" => list(json!([])),
+                "/subscriptions/sub_01hv8x29kz0t586xy6zn1a62ny/cancel" => json!({
+                    "data": fixtures::subscription(),
+                    "meta": {"request_id": "req_1"}
+                }),
+                "/customers/ctm_01hv6y1jedq4p1n0yqn5ba3ky4" => json!({
+                    "data": fixtures::customer(),
+                    "meta": {"request_id": "req_1"}
+                }),
+                // The address update fails, the payment method delete answers 200 instead of 204
+                _ => json!({}),
+            }
+        })
+        .await;
+
+        let client = Paddle::new("key", url).unwrap();
+        let report = client
+            .offboard_customer("ctm_01hv6y1jedq4p1n0yqn5ba3ky4", OffboardOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            report.canceled_subscriptions[0].as_ref(),
+            "sub_01hv8x29kz0t586xy6zn1a62ny"
+        );
+        assert!(report.customer_scrubbed);
+        assert!(report.archived_addresses.is_empty());
+        assert!(!report.is_complete());
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0, "add_01hv8gq3318ktkfengj2r75gfx");
+        assert!(report.deleted_payment_methods.is_empty());
+        assert_eq!(
+            report.undeleted_payment_methods[0].as_ref(),
+            "paymtd_01hs8zx6x377xfsfrt2bqsevbw"
+        );
+    }
+}
@@ -1,10 +1,206 @@
 //! # Helpers for working with paginated requests.
 
+use crate::budget::Budget;
+use crate::entities::Pagination;
+use crate::response::Meta;
 use crate::{Error, Paddle, SuccessResponse};
 use reqwest::{Method, Url};
-use serde::{de::DeserializeOwned, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::{Map, Value};
+use std::fmt;
 use std::marker::PhantomData;
+use tokio::task::JoinHandle;
+
+type PageResult<T> = Result<SuccessResponse<T>, Error>;
+
+/// Called with the index of the page, the number of entities fetched so far and the estimated total.
+type ProgressCallback<'a> = Box<dyn FnMut(usize, usize, Option<i64>) + Send + 'a>;
+
+/// Removes entities that shouldn't be returned from a fetched page.
+type PageFilter<'a, T> = Box<dyn FnMut(&mut T) + Send + 'a>;
+
+/// Spawns the request for a page on the tokio runtime. Set by [Paginated::prefetch], which requires the page to be `Send + 'static`.
+type PageSpawner<T> = fn(Paddle, String, Value) -> JoinHandle<PageResult<T>>;
+
+/// Number of times [Paginated::try_all] retries a rate limited page before giving up.
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+
+/// Position of the next page to fetch. Serializable, so that long exports can be resumed by another process.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Cursor {
+    path: String,
+    query: Value,
+}
+
+/// Sort order of a list request, parsed from its `order_by` parameter, e.g. `id[ASC]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderBy {
+    /// Field entities are ordered by.
+    pub field: String,
+    /// Whether entities are in descending order.
+    pub descending: bool,
+}
+
+/// The `next` URL of [Pagination] split into its components.
+///
+/// Paddle echoes the query of the original request in `next`. Compare it with a request using [NextPage::matches] before continuing a pagination manually, e.g. with state persisted by another process.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NextPage {
+    /// Path of the list endpoint, e.g. `/customers`.
+    pub path: String,
+    /// ID of the last entity on the current page.
+    pub after: Option<String>,
+    /// Number of entities per page that was requested.
+    pub per_page: Option<usize>,
+    /// Sort order that was requested.
+    pub order_by: Option<OrderBy>,
+    /// All other query parameters of the original request.
+    pub filters: Map<String, Value>,
+}
+
+impl NextPage {
+    /// Parse the `next` URL of a paginated response.
+    pub fn parse(pagination: &Pagination) -> Result<Self, Error> {
+        let url = Url::parse(&pagination.next)?;
+        let mut filters = parse_query(url.query().unwrap_or_default())?;
+
+        let after = filters.remove("after").and_then(into_string);
+        let per_page = filters
+            .remove("per_page")
+            .and_then(into_string)
+            .map(|per_page| per_page.parse())
+            .transpose()?;
+        let order_by = filters
+            .remove("order_by")
+            .and_then(into_string)
+            .map(|order_by| {
+                let (field, direction) = order_by
+                    .strip_suffix(']')
+                    .and_then(|order_by| order_by.split_once('['))
+                    .ok_or_else(|| Error::Validation(format!("invalid order_by: {order_by}")))?;
+
+                Ok::<_, Error>(OrderBy {
+                    field: field.to_string(),
+                    descending: direction.eq_ignore_ascii_case("desc"),
+                })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            path: url.path().to_string(),
+            after,
+            per_page,
+            order_by,
+            filters,
+        })
+    }
+
+    /// Whether `request` has the same filters as the request this page belongs to. `after`, `per_page` and `order_by` aren't compared.
+    ///
+    /// ```rust,no_run
+    /// let list = client.customers_list();
+    /// let page = list.send().next().await?.unwrap();
+    /// let next = NextPage::parse(&page.meta.pagination.unwrap())?;
+    ///
+    /// assert!(next.matches(&list)?);
+    /// ```
+    pub fn matches(&self, request: &impl Serialize) -> Result<bool, Error> {
+        let mut query = parse_query(&serde_qs::to_string(request)?)?;
+
+        for key in ["after", "per_page", "order_by"] {
+            query.remove(key);
+        }
+
+        Ok(query == self.filters)
+    }
+
+    /// Query to request the page with, `after` included.
+    fn query(&self) -> Map<String, Value> {
+        let mut query = self.filters.clone();
+
+        if let Some(after) = &self.after {
+            query.insert("after".to_string(), Value::String(after.clone()));
+        }
+
+        if let Some(per_page) = self.per_page {
+            query.insert("per_page".to_string(), Value::String(per_page.to_string()));
+        }
+
+        if let Some(order_by) = &self.order_by {
+            let direction = if order_by.descending { "DESC" } else { "ASC" };
+            query.insert(
+                "order_by".to_string(),
+                Value::String(format!("{}[{direction}]", order_by.field)),
+            );
+        }
+
+        query
+    }
+}
+
+impl From<NextPage> for Cursor {
+    fn from(next: NextPage) -> Self {
+        Self {
+            query: Value::Object(next.query()),
+            path: next.path,
+        }
+    }
+}
+
+fn parse_query(query: &str) -> Result<Map<String, Value>, Error> {
+    Ok(serde_qs::from_str(query)?)
+}
+
+fn into_string(value: Value) -> Option<String> {
+    match value {
+        Value::String(value) => Some(value),
+        _ => None,
+    }
+}
+
+/// Entities of every page fetched by [Paginated::all_with_meta], along with the `meta` of each page.
+#[derive(Debug)]
+pub struct PagesWithMeta<I> {
+    /// Entities from every page, in order.
+    pub data: Vec<I>,
+    /// Meta of every page, in the order the pages were fetched.
+    pub meta: Vec<Meta>,
+}
+
+impl<I> PagesWithMeta<I> {
+    /// Request IDs of every page, e.g. for an audit log.
+    pub fn request_ids(&self) -> impl Iterator<Item = &str> {
+        self.meta.iter().map(|meta| meta.request_id.as_str())
+    }
+}
+
+/// Returned by [Paginated::try_all] when fetching a page fails.
+#[derive(Debug)]
+pub struct PaginationInterrupted<I> {
+    /// Entities from the pages fetched before the failure.
+    pub collected: Vec<I>,
+    /// Where to continue from with [Paginated::resume]. `None` if the failure happened before the first page was requested.
+    pub resume_after: Option<Cursor>,
+    /// Error the failing page was fetched with.
+    pub error: Error,
+}
+
+impl<I> fmt::Display for PaginationInterrupted<I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Pagination interrupted after {} entities: {}",
+            self.collected.len(),
+            self.error
+        )
+    }
+}
+
+impl<I: fmt::Debug> std::error::Error for PaginationInterrupted<I> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
 
 pub struct Paginated<'a, T> {
     client: &'a Paddle,
@@ -12,6 +208,12 @@ pub struct Paginated<'a, T> {
     query: Option<Value>,
     _type: PhantomData<T>,
     error: Option<Error>,
+    prefetch: Option<PageSpawner<T>>,
+    prefetched: Option<JoinHandle<PageResult<T>>>,
+    prefetched_query: Option<Value>,
+    on_page: Option<ProgressCallback<'a>>,
+    filter: Option<PageFilter<'a, T>>,
+    pages_fetched: usize,
 }
 
 impl<'a, T> Paginated<'a, T> {
@@ -29,8 +231,89 @@ impl<'a, T> Paginated<'a, T> {
             query,
             _type: PhantomData,
             error,
+            prefetch: None,
+            prefetched: None,
+            prefetched_query: None,
+            on_page: None,
+            filter: None,
+            pages_fetched: 0,
         }
     }
+
+    /// Paginator whose first page fails with `error`, for requests that are known to be invalid before they're sent.
+    pub(crate) fn failed(client: &'a Paddle, error: Error) -> Self {
+        Self {
+            client,
+            path: String::new(),
+            query: None,
+            _type: PhantomData,
+            error: Some(error),
+            prefetch: None,
+            prefetched: None,
+            prefetched_query: None,
+            on_page: None,
+            filter: None,
+            pages_fetched: 0,
+        }
+    }
+
+    /// Continue paginating from a cursor returned by [Paginated::cursor] or [PaginationInterrupted::resume_after].
+    pub fn resume(client: &'a Paddle, cursor: Cursor) -> Self {
+        Self {
+            client,
+            path: cursor.path,
+            query: Some(cursor.query),
+            _type: PhantomData,
+            error: None,
+            prefetch: None,
+            prefetched: None,
+            prefetched_query: None,
+            on_page: None,
+            filter: None,
+            pages_fetched: 0,
+        }
+    }
+
+    /// Position of the next page to fetch. `None` once every page has been fetched.
+    pub fn cursor(&self) -> Option<Cursor> {
+        self.query
+            .as_ref()
+            .or(self.prefetched_query.as_ref())
+            .map(|query| Cursor {
+                path: self.path.clone(),
+                query: query.clone(),
+            })
+    }
+
+    /// Fetch the next page in the background while the current one is being processed.
+    ///
+    /// When enabled, each call to [next](Self::next) spawns a request for the following page on the tokio runtime, so the latency of consecutive requests overlaps with your own processing. Disabled by default.
+    pub fn prefetch(mut self, enabled: bool) -> Self
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        self.prefetch = enabled.then_some(spawn_page::<T> as PageSpawner<T>);
+        self
+    }
+
+    /// Report progress after every page fetched by [all](Paginated::all) and [try_all](Paginated::try_all), e.g. to drive a progress bar.
+    ///
+    /// The callback receives the zero based index of the page, the number of entities fetched so far and `estimated_total` as returned by Paddle. The estimate may change from page to page.
+    ///
+    /// ```rust,no_run
+    /// let transactions = client
+    ///     .transactions_list()
+    ///     .send()
+    ///     .on_page(|page, fetched, estimated_total| {
+    ///         eprintln!("page {page}: {fetched} of ~{}", estimated_total.unwrap_or_default());
+    ///     })
+    ///     .all()
+    ///     .await?;
+    /// ```
+    pub fn on_page(mut self, callback: impl FnMut(usize, usize, Option<i64>) + Send + 'a) -> Self {
+        self.on_page = Some(Box::new(callback));
+        self
+    }
 }
 
 impl<'a, T> Paginated<'a, T>
@@ -41,24 +324,55 @@ where
         if let Some(err) = self.error.take() {
             return Err(err);
         }
-        if let Some(query) = self.query.take() {
-            let response = self.client.send(query, Method::GET, &self.path).await?;
-            if let Some(pagination) = &response.meta.pagination {
-                if pagination.has_more {
-                    let url = Url::parse(&pagination.next)?;
-                    self.path = url.path().to_string();
-                    let query: Map<String, Value> = url
-                        .query()
-                        .map(serde_qs::from_str)
-                        .transpose()?
-                        .unwrap_or_default();
-                    self.query = Some(Value::Object(query));
+
+        // A failed page is kept as the next one, so that it can be retried
+        let mut response = if let Some(handle) = self.prefetched.take() {
+            let query = self.prefetched_query.take();
+            let response = handle
+                .await
+                .unwrap_or_else(|err| std::panic::resume_unwind(err.into_panic()));
+
+            if response.is_err() {
+                self.query = query;
+            }
+
+            response?
+        } else if let Some(query) = self.query.take() {
+            match self
+                .client
+                .send(query.clone(), Method::GET, &self.path)
+                .await
+            {
+                Ok(response) => response,
+                Err(err) => {
+                    self.query = Some(query);
+                    return Err(err);
                 }
             }
-            Ok(Some(response))
         } else {
-            Ok(None)
+            return Ok(None);
+        };
+
+        if let Some(pagination) = &response.meta.pagination {
+            if pagination.has_more {
+                let cursor = Cursor::from(NextPage::parse(pagination)?);
+                self.path = cursor.path;
+                self.query = Some(cursor.query);
+            }
         }
+
+        if let Some(filter) = &mut self.filter {
+            filter(&mut response.data);
+        }
+
+        if let Some(spawn) = self.prefetch {
+            if let Some(query) = self.query.take() {
+                self.prefetched_query = Some(query.clone());
+                self.prefetched = Some(spawn(self.client.clone(), self.path.clone(), query));
+            }
+        }
+
+        Ok(Some(response))
     }
 }
 
@@ -66,11 +380,416 @@ impl<'a, I> Paginated<'a, Vec<I>>
 where
     I: DeserializeOwned,
 {
+    /// Only return entities matching `predicate`. Every page is still fetched in full, so pages may be empty.
+    pub(crate) fn filter(mut self, predicate: impl Fn(&I) -> bool + Send + 'a) -> Self {
+        self.filter = Some(Box::new(move |data: &mut Vec<I>| {
+            data.retain(|item| predicate(item))
+        }));
+        self
+    }
+
     pub async fn all(&mut self) -> Result<Vec<I>, Error> {
         let mut collected = Vec::new();
         while let Some(response) = self.next().await? {
-            collected.extend(response.data.into_iter());
+            self.collect_page(response, &mut collected);
         }
         Ok(collected)
     }
+
+    /// Fetch all remaining pages like [all](Self::all), keeping the `meta` of every page.
+    ///
+    /// Use this when the request ID of every page needs to be recorded:
+    ///
+    /// ```rust,no_run
+    /// let transactions = client.transactions_list().send().all_with_meta().await?;
+    ///
+    /// for request_id in transactions.request_ids() {
+    ///     println!("Fetched transactions in request {request_id}");
+    /// }
+    /// ```
+    pub async fn all_with_meta(&mut self) -> Result<PagesWithMeta<I>, Error> {
+        let mut pages = PagesWithMeta {
+            data: Vec::new(),
+            meta: Vec::new(),
+        };
+        while let Some(response) = self.next().await? {
+            let meta = self.collect_page(response, &mut pages.data);
+            pages.meta.push(meta);
+        }
+        Ok(pages)
+    }
+
+    /// Add the entities of a page to `collected`, report progress and return the meta of the page.
+    fn collect_page(&mut self, response: SuccessResponse<Vec<I>>, collected: &mut Vec<I>) -> Meta {
+        let estimated_total = response
+            .meta
+            .pagination
+            .as_ref()
+            .map(|pagination| pagination.estimated_total);
+
+        collected.extend(response.data);
+
+        if let Some(on_page) = &mut self.on_page {
+            on_page(self.pages_fetched, collected.len(), estimated_total);
+        }
+
+        self.pages_fetched += 1;
+
+        response.meta
+    }
+
+    /// Fetch all remaining pages, keeping what was collected if a page fails.
+    ///
+    /// Rate limited pages are retried after the delay Paddle asks for, up to 3 times in a row. Any other failure returns [PaginationInterrupted] with the entities collected so far and a cursor to continue from:
+    ///
+    /// ```rust,no_run
+    /// use paddle_rust_sdk::paginated::Paginated;
+    ///
+    /// let list = client.transactions_list();
+    /// let mut paginated = list.send();
+    ///
+    /// let transactions = match paginated.try_all().await {
+    ///     Ok(transactions) => transactions,
+    ///     Err(interrupted) => {
+    ///         let mut transactions = interrupted.collected;
+    ///         if let Some(cursor) = interrupted.resume_after {
+    ///             transactions.extend(Paginated::resume(&client, cursor).all().await?);
+    ///         }
+    ///         transactions
+    ///     }
+    /// };
+    /// ```
+    pub async fn try_all(&mut self) -> Result<Vec<I>, PaginationInterrupted<I>> {
+        let mut collected = Vec::new();
+        let mut retries = 0;
+
+        loop {
+            match self.next().await {
+                Ok(Some(response)) => {
+                    retries = 0;
+                    self.collect_page(response, &mut collected);
+                }
+                Ok(None) => return Ok(collected),
+                Err(err) => match err.retry_after() {
+                    Some(delay) if retries < MAX_RATE_LIMIT_RETRIES => {
+                        retries += 1;
+                        tokio::time::sleep(delay).await;
+                    }
+                    _ => {
+                        return Err(PaginationInterrupted {
+                            collected,
+                            resume_after: self.cursor(),
+                            error: err,
+                        })
+                    }
+                },
+            }
+        }
+    }
+}
+
+fn spawn_page<T>(client: Paddle, path: String, query: Value) -> JoinHandle<PageResult<T>>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    tokio::spawn(Budget::inherit(async move {
+        client.send(query, Method::GET, &path).await
+    }))
+}
+
+impl<T> Drop for Paginated<'_, T> {
+    fn drop(&mut self) {
+        if let Some(handle) = self.prefetched.take() {
+            handle.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::entities::Customer;
+    use crate::fixtures;
+
+    #[tokio::test]
+    async fn try_all_keeps_collected_entities_and_resumes() {
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let url = fixtures::serve_json({
+            let requests = requests.clone();
+
+            move |target| {
+                let attempt = requests.fetch_add(1, Ordering::SeqCst);
+
+                async move {
+                    // Second page fails once
+                    if attempt == 1 {
+                        return json!({});
+                    }
+
+                    let (id, has_more) = if target.contains("after=") {
+                        ("ctm_02", false)
+                    } else {
+                        ("ctm_01", true)
+                    };
+
+                    let mut customer = fixtures::customer();
+                    customer["id"] = id.into();
+
+                    json!({
+                        "data": [customer],
+                        "meta": {
+                            "request_id": "req_1",
+                            "pagination": {
+                                "per_page": 1,
+                                "next": "http://localhost/customers?after=ctm_01",
+                                "has_more": has_more,
+                                "estimated_total": 2
+                            }
+                        }
+                    })
+                }
+            }
+        })
+        .await;
+
+        let client = Paddle::new("key", url.as_str()).unwrap();
+        let mut paginated = Paginated::<Vec<Customer>>::new(&client, "/customers", ());
+
+        let interrupted = paginated.try_all().await.unwrap_err();
+        assert_eq!(interrupted.collected.len(), 1);
+
+        let cursor = serde_json::to_value(interrupted.resume_after.unwrap()).unwrap();
+        let cursor = serde_json::from_value(cursor).unwrap();
+
+        let rest = Paginated::<Vec<Customer>>::resume(&client, cursor)
+            .try_all()
+            .await
+            .unwrap();
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].id.as_ref(), "ctm_02");
+    }
+
+    #[tokio::test]
+    async fn prefetched_page_is_returned_next() {
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let url = fixtures::serve_json({
+            let requests = requests.clone();
+
+            move |target| {
+                requests.fetch_add(1, Ordering::SeqCst);
+
+                async move {
+                    let (id, has_more) = if target.contains("after=") {
+                        ("ctm_02", false)
+                    } else {
+                        ("ctm_01", true)
+                    };
+
+                    let mut customer = fixtures::customer();
+                    customer["id"] = id.into();
+
+                    json!({
+                        "data": [customer],
+                        "meta": {
+                            "request_id": format!("req_{id}"),
+                            "pagination": {
+                                "per_page": 1,
+                                "next": "http://localhost/customers?after=ctm_01",
+                                "has_more": has_more,
+                                "estimated_total": 2
+                            }
+                        }
+                    })
+                }
+            }
+        })
+        .await;
+
+        let client = Paddle::new("key", url.as_str()).unwrap();
+        let mut paginated =
+            Paginated::<Vec<Customer>>::new(&client, "/customers", ()).prefetch(true);
+
+        let first = paginated.next().await.unwrap().unwrap();
+        assert_eq!(first.data[0].id.as_ref(), "ctm_01");
+
+        // The second page is requested in the background
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while requests.load(Ordering::SeqCst) < 2 {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .unwrap();
+
+        let second = paginated.next().await.unwrap().unwrap();
+        assert_eq!(second.data[0].id.as_ref(), "ctm_02");
+        assert_eq!(second.meta.request_id, "req_ctm_02");
+
+        assert!(paginated.next().await.unwrap().is_none());
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn try_all_retries_rate_limited_pages() {
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let url = fixtures::serve_responses({
+            let requests = requests.clone();
+
+            move |_| {
+                let attempt = requests.fetch_add(1, Ordering::SeqCst);
+
+                async move {
+                    if attempt == 0 {
+                        return fixtures::MockResponse::rate_limited(1);
+                    }
+
+                    fixtures::MockResponse::ok(json!({
+                        "data": [fixtures::customer()],
+                        "meta": {
+                            "request_id": "req_1",
+                            "pagination": {
+                                "per_page": 1,
+                                "next": "http://localhost/customers?after=ctm_01",
+                                "has_more": false,
+                                "estimated_total": 1
+                            }
+                        }
+                    }))
+                }
+            }
+        })
+        .await;
+
+        let client = Paddle::new("key", url.as_str()).unwrap();
+        let mut paginated = Paginated::<Vec<Customer>>::new(&client, "/customers", ());
+
+        let started = tokio::time::Instant::now();
+        let customers = paginated.try_all().await.unwrap();
+
+        assert_eq!(customers.len(), 1);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        assert!(started.elapsed() >= Duration::from_secs(1));
+    }
+
+    #[test]
+    fn max_page_size_uses_the_endpoint_limit() {
+        let client = Paddle::new("key", Paddle::SANDBOX).unwrap();
+
+        let transactions = client.transactions_list().max_page_size();
+        assert_eq!(serde_json::to_value(&transactions).unwrap()["per_page"], 30);
+
+        let adjustments = client.adjustments_list().max_page_size();
+        assert_eq!(serde_json::to_value(&adjustments).unwrap()["per_page"], 50);
+
+        let customers = client.customers_list().max_page_size();
+        assert_eq!(serde_json::to_value(&customers).unwrap()["per_page"], 200);
+    }
+
+    #[test]
+    fn next_page_is_parsed_and_matched() {
+        let pagination: Pagination = fixtures::from_value(json!({
+            "per_page": 50,
+            "next": "https://sandbox-api.paddle.com/customers?after=ctm_01&email=sam%40example.com&order_by=id%5BDESC%5D&per_page=50",
+            "has_more": true,
+            "estimated_total": 120
+        }));
+
+        let next = NextPage::parse(&pagination).unwrap();
+
+        assert_eq!(next.path, "/customers");
+        assert_eq!(next.after.as_deref(), Some("ctm_01"));
+        assert_eq!(next.per_page, Some(50));
+        assert_eq!(
+            next.order_by,
+            Some(OrderBy {
+                field: "id".to_string(),
+                descending: true
+            })
+        );
+
+        let client = Paddle::new("key", Paddle::SANDBOX).unwrap();
+        let list = client.customers_list().order_by_asc("id").per_page(10);
+        assert!(!next.matches(&list).unwrap());
+
+        let list = list.emails(["sam@example.com"]);
+        assert!(next.matches(&list).unwrap());
+    }
+
+    #[tokio::test]
+    async fn all_reports_progress_per_page() {
+        let url = fixtures::serve_json(|target| async move {
+            let has_more = !target.contains("after=");
+
+            json!({
+                "data": [fixtures::customer(), fixtures::customer()],
+                "meta": {
+                    "request_id": "req_1",
+                    "pagination": {
+                        "per_page": 2,
+                        "next": "http://localhost/customers?after=ctm_01",
+                        "has_more": has_more,
+                        "estimated_total": 4
+                    }
+                }
+            })
+        })
+        .await;
+
+        let client = Paddle::new("key", url.as_str()).unwrap();
+        let mut progress = Vec::new();
+
+        let customers = Paginated::<Vec<Customer>>::new(&client, "/customers", ())
+            .on_page(|page, fetched, estimated_total| {
+                progress.push((page, fetched, estimated_total))
+            })
+            .all()
+            .await
+            .unwrap();
+
+        assert_eq!(customers.len(), 4);
+        assert_eq!(progress, [(0, 2, Some(4)), (1, 4, Some(4))]);
+    }
+
+    #[tokio::test]
+    async fn all_with_meta_keeps_request_ids() {
+        let url = fixtures::serve_json(|target| async move {
+            let first_page = !target.contains("after=");
+
+            json!({
+                "data": [fixtures::customer()],
+                "meta": {
+                    "request_id": if first_page { "req_1" } else { "req_2" },
+                    "pagination": {
+                        "per_page": 1,
+                        "next": "http://localhost/customers?after=ctm_01",
+                        "has_more": first_page,
+                        "estimated_total": 2
+                    }
+                }
+            })
+        })
+        .await;
+
+        let client = Paddle::new("key", url.as_str()).unwrap();
+
+        let customers = Paginated::<Vec<Customer>>::new(&client, "/customers", ())
+            .all_with_meta()
+            .await
+            .unwrap();
+
+        assert_eq!(customers.data.len(), 2);
+        assert_eq!(
+            customers.request_ids().collect::<Vec<_>>(),
+            ["req_1", "req_2"]
+        );
+    }
 }
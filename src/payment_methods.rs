@@ -9,11 +9,14 @@ use serde_with::skip_serializing_none;
 use crate::entities::PaymentMethod;
 use crate::ids::{AddressID, CustomerID, PaymentMethodID};
 use crate::paginated::Paginated;
+use crate::permissions::Permission;
+use crate::request::PaddleRequest;
 use crate::{Paddle, Result};
 
 /// Request builder for fetching businesses from Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct PaymentMethodsList<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -42,57 +45,87 @@ impl<'a> PaymentMethodsList<'a> {
 
     /// Return entities related to the specified addresses.
     pub fn address_ids(
-        &mut self,
+        mut self,
         address_ids: impl IntoIterator<Item = impl Into<AddressID>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.address_id = Some(address_ids.into_iter().map(Into::into).collect());
         self
     }
 
     /// Return entities after the specified Paddle ID when working with paginated endpoints. Used in the `meta.pagination.next` URL in responses for list operations.
-    pub fn after(&mut self, id: impl Into<PaymentMethodID>) -> &mut Self {
+    pub fn after(mut self, id: impl Into<PaymentMethodID>) -> Self {
         self.after = Some(id.into());
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: id
-    pub fn order_by_asc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_asc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[ASC]", field));
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: id
-    pub fn order_by_desc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_desc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[DESC]", field));
         self
     }
 
+    /// Number of entities Paddle returns per page when [Self::per_page] isn't set.
+    pub const DEFAULT_PER_PAGE: usize = 50;
+    /// Largest page size Paddle accepts for this endpoint.
+    pub const MAX_PER_PAGE: usize = 200;
+
     /// Set how many entities are returned per page. Paddle returns the maximum number of results if a number greater than the maximum is requested.
     /// Check `meta.pagination.per_page` in the response to see how many were returned.
     ///
     /// Default: `50`; Maximum: `200`.
-    pub fn per_page(&mut self, entities_per_page: usize) -> &mut Self {
+    pub fn per_page(mut self, entities_per_page: usize) -> Self {
         self.per_page = Some(entities_per_page);
         self
     }
 
+    /// Request the largest page Paddle allows for this endpoint. Use this to fetch everything in as few requests as possible.
+    pub fn max_page_size(self) -> Self {
+        self.per_page(Self::MAX_PER_PAGE)
+    }
+
     /// Return entities that support being presented at checkout (`true`) or not (`false`).
-    pub fn supports_checkout(&mut self, flag: bool) -> &mut Self {
+    pub fn supports_checkout(mut self, flag: bool) -> Self {
         self.supports_checkout = Some(flag);
         self
     }
 
     /// Returns a paginator for fetching pages of entities from Paddle
     pub fn send(&self) -> Paginated<'_, Vec<PaymentMethod>> {
-        let url = format!("/customers/{}/payment-methods", self.customer_id.as_ref());
+        Paginated::new(self.client, &self.path(), self)
+    }
+}
+
+impl PaddleRequest for PaymentMethodsList<'_> {
+    type Output = Vec<PaymentMethod>;
+    type Payload = Self;
 
-        Paginated::new(self.client, &url, self)
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!("/customers/{}/payment-methods", self.customer_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::PaymentMethodRead]
     }
 }
 
 /// Request builder for fetching a single payment method from Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct PaymentMethodGet<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -117,16 +150,31 @@ impl<'a> PaymentMethodGet<'a> {
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<PaymentMethod> {
-        self.client
-            .send(
-                self,
-                Method::GET,
-                &format!(
-                    "/customers/{}/payment-methods/{}",
-                    self.customer_id.as_ref(),
-                    self.payment_method_id.as_ref()
-                ),
-            )
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for PaymentMethodGet<'_> {
+    type Output = PaymentMethod;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "/customers/{}/payment-methods/{}",
+            self.customer_id.as_ref(),
+            self.payment_method_id.as_ref()
+        )
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::PaymentMethodRead]
     }
 }
@@ -0,0 +1,214 @@
+//! Reconciling payouts with the balance report.
+//!
+//! The Paddle API doesn't list payouts, they're only delivered in `payout.*` webhooks. The balance report covers every movement of funds in and out of your balance, including payouts. [fetch_payout_rows] generates a balance report and returns its payout rows, [payout_rows] parses a balance report CSV you downloaded yourself.
+//!
+//! ```rust,no_run
+//! use chrono::{Duration, Utc};
+//! use paddle_rust_sdk::payouts;
+//!
+//! let payouts = payouts::fetch_payout_rows(&client, Utc::now() - Duration::days(30)).await?;
+//!
+//! for payout in payouts {
+//!     println!("{} {} {}", payout.created_at, payout.amount, payout.currency_code);
+//! }
+//! ```
+
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+use crate::entities::ReportFilterValue;
+use crate::enums::{BalanceReportFilterName, BalanceReportType, Disposition, FilterOperator};
+use crate::ids::PayoutID;
+use crate::{documents, report_scheduler, Error, Paddle};
+
+/// How often [fetch_payout_rows] checks whether the report is ready.
+const REPORT_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How long [fetch_payout_rows] waits for the report to be ready.
+const REPORT_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// A payout from the balance report.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PayoutRow {
+    /// ID of the payout. `None` if the report has no `payout_id` column or it's empty.
+    pub payout_id: Option<PayoutID>,
+    /// When the payout was made.
+    pub created_at: DateTime<Utc>,
+    /// Three-letter ISO 4217 currency code of the payout.
+    pub currency_code: String,
+    /// Amount paid out, as written in the report.
+    pub amount: String,
+    /// Description of the balance activity. `None` if the report has no `description` column.
+    pub description: Option<String>,
+}
+
+/// Generate a balance report of activity updated since `from`, wait until Paddle has generated it and return its payouts.
+///
+/// The report is checked every 10 seconds. Fails with [Error::ReportNotReady] if it isn't ready within 30 minutes.
+pub async fn fetch_payout_rows(
+    client: &Paddle,
+    from: DateTime<Utc>,
+) -> Result<Vec<PayoutRow>, Error> {
+    let report = client
+        .report_create(BalanceReportType::Balance)
+        .append_filter(
+            BalanceReportFilterName::UpdatedAt,
+            Some(FilterOperator::Gte),
+            ReportFilterValue::String(from.to_rfc3339()),
+        )
+        .send()
+        .await?
+        .data;
+
+    let report =
+        report_scheduler::wait_until_ready(client, report, REPORT_POLL_INTERVAL, REPORT_TIMEOUT)
+            .await?;
+    let url = client.report_download_url(report.id.clone()).await?;
+    let csv = documents::download(
+        client,
+        url.data.url,
+        Disposition::Attachment,
+        format!("{}.csv", report.id),
+    )
+    .await?;
+
+    payout_rows(&csv.bytes)
+}
+
+/// Parse a balance report CSV and return the rows of type `payout`.
+///
+/// Columns are found by their header, ignoring case, so reordered or added columns don't break parsing. Fails with [Error::InvalidReport] if the `type`, `created_at`, `currency_code` or `amount` column is missing or a payout has an invalid date.
+pub fn payout_rows(csv: &[u8]) -> Result<Vec<PayoutRow>, Error> {
+    let csv = std::str::from_utf8(csv)
+        .map_err(|err| Error::InvalidReport(format!("Report isn't UTF-8: {}", err)))?;
+
+    let mut records = parse_csv(csv).into_iter();
+    let header = records
+        .next()
+        .ok_or_else(|| Error::InvalidReport("Report is empty".to_string()))?;
+
+    let column = |names: &[&str]| {
+        header
+            .iter()
+            .position(|field| names.contains(&normalize_header(field).as_str()))
+    };
+    let required = |names: &[&str]| {
+        column(names)
+            .ok_or_else(|| Error::InvalidReport(format!("Report has no `{}` column", names[0])))
+    };
+
+    let r#type = required(&["type", "activity_type"])?;
+    let created_at = required(&["created_at", "date"])?;
+    let currency_code = required(&["currency_code", "currency"])?;
+    let amount = required(&["amount"])?;
+    let payout_id = column(&["payout_id"]);
+    let description = column(&["description"]);
+
+    let mut payouts = Vec::new();
+
+    for record in records {
+        let field = |index: usize| record.get(index).map(String::as_str).unwrap_or_default();
+
+        if !field(r#type).eq_ignore_ascii_case("payout") {
+            continue;
+        }
+
+        payouts.push(PayoutRow {
+            payout_id: payout_id
+                .map(field)
+                .filter(|id| !id.is_empty())
+                .map(PayoutID::from),
+            created_at: parse_date(field(created_at))?,
+            currency_code: field(currency_code).to_string(),
+            amount: field(amount).to_string(),
+            description: description.map(|index| field(index).to_string()),
+        });
+    }
+
+    Ok(payouts)
+}
+
+/// Header in snake case, e.g. `Currency Code` becomes `currency_code`.
+fn normalize_header(header: &str) -> String {
+    header.trim().to_ascii_lowercase().replace([' ', '-'], "_")
+}
+
+fn parse_date(date: &str) -> Result<DateTime<Utc>, Error> {
+    if let Ok(date) = DateTime::parse_from_rfc3339(date) {
+        return Ok(date.to_utc());
+    }
+
+    if let Ok(date) = NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M:%S") {
+        return Ok(date.and_utc());
+    }
+
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map(|date| date.and_time(Default::default()).and_utc())
+        .map_err(|_| Error::InvalidReport(format!("Invalid date `{}`", date)))
+}
+
+/// Split CSV into records of fields. Handles quoted fields containing separators, line breaks and doubled quotes.
+fn parse_csv(csv: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = csv.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => record.push(std::mem::take(&mut field)),
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            c => field.push(c),
+        }
+    }
+
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_payout_rows() {
+        let csv = "\u{feff}Created At,Type,Description,Currency Code,Amount,Payout ID\r\n\
+            2024-04-01T10:00:00Z,transaction,\"Sale, annual plan\",USD,100.00,\r\n\
+            2024-04-15 00:00:00,payout,\"Payout to \"\"Main\"\" account\",USD,-1500.00,payout_01\r\n";
+
+        let rows = payout_rows(csv.as_bytes()).unwrap();
+
+        assert_eq!(
+            rows,
+            [PayoutRow {
+                payout_id: Some(PayoutID::from("payout_01")),
+                created_at: "2024-04-15T00:00:00Z".parse().unwrap(),
+                currency_code: "USD".to_string(),
+                amount: "-1500.00".to_string(),
+                description: Some("Payout to \"Main\" account".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn missing_columns_are_reported() {
+        let err = payout_rows(b"type,amount\npayout,10.00\n").unwrap_err();
+
+        assert!(matches!(err, Error::InvalidReport(err) if err.contains("created_at")));
+    }
+}
@@ -0,0 +1,54 @@
+//! Commonly used types, re-exported for glob imports.
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::prelude::*;
+//!
+//! let client = Paddle::new(std::env::var("PADDLE_API_KEY")?, Paddle::SANDBOX)?;
+//!
+//! let subscriptions: Vec<Subscription> = client
+//!     .subscriptions_list()
+//!     .status([SubscriptionStatus::Active])
+//!     .send()
+//!     .all()
+//!     .await?;
+//! ```
+//!
+//! The prelude only exports names that are unlikely to clash with the standard library or other common crates. The billing
+//! [Duration](crate::entities::Duration) entity is exported as [PaddleDuration] so it can be used alongside
+//! [std::time::Duration] and [chrono::Duration]. Types that aren't listed here can still be imported from the
+//! [entities](crate::entities), [enums](crate::enums) and [ids](crate::ids) modules.
+
+pub use crate::entities::Duration as PaddleDuration;
+pub use crate::entities::{
+    Address, Adjustment, Business, Customer, Discount, Event, Money, Price, Product, Subscription,
+    TimePeriod, Transaction,
+};
+pub use crate::enums::{
+    CollectionMode, CountryCodeSupported, CurrencyCode, EventData, EventTypeName, Interval, Status,
+    SubscriptionStatus, TaxCategory, TransactionStatus,
+};
+pub use crate::hydrate::{AdjustmentRefs, SubscriptionRefs, TransactionRefs};
+pub use crate::ids::{
+    AddressID, AdjustmentID, BusinessID, CustomerID, DiscountID, EventID, PaddleID, PriceID,
+    ProductID, SubscriptionID, TransactionID,
+};
+pub use crate::paginated::Paginated;
+pub use crate::request::PaddleRequest;
+pub use crate::{Error, Nullable, Paddle};
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn does_not_shadow_std_duration() {
+        let price: Price = fixtures::from_value(fixtures::price("pri_1", "pro_1", 1000));
+        let billing_cycle: PaddleDuration = price.billing_cycle.unwrap();
+
+        assert_eq!(billing_cycle.interval, Interval::Month);
+        assert_eq!(Duration::from_secs(1).as_millis(), 1000);
+    }
+}
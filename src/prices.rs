@@ -10,23 +10,28 @@ use serde::Serialize;
 use serde_with::skip_serializing_none;
 
 use crate::entities::{Duration, Money, Price, PriceQuantity, UnitPriceOverride};
-use crate::enums::{CatalogType, CountryCodeSupported, CurrencyCode, Interval, Status, TaxMode};
+use crate::enums::{
+    CatalogType, CountryCodeSupported, CurrencyCode, PriceInclude, Status, TaxMode,
+};
 use crate::ids::{PriceID, ProductID};
 use crate::paginated::Paginated;
 use crate::nullable::Nullable;
-use crate::{Paddle, Result};
+use crate::permissions::Permission;
+use crate::request::PaddleRequest;
+use crate::{Error, Paddle, Result};
 
 /// Request builder for fetching prices from Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct PricesList<'a> {
     #[serde(skip)]
     client: &'a Paddle,
     after: Option<PriceID>,
     #[serde(serialize_with = "crate::comma_separated")]
     id: Option<Vec<PriceID>>,
-    #[serde(serialize_with = "crate::comma_separated")]
-    include: Option<Vec<String>>,
+    #[serde(serialize_with = "crate::comma_separated_enum")]
+    include: Option<Vec<PriceInclude>>,
     order_by: Option<String>,
     per_page: Option<usize>,
     #[serde(serialize_with = "crate::comma_separated")]
@@ -53,80 +58,112 @@ impl<'a> PricesList<'a> {
     }
 
     /// Return entities after the specified Paddle ID when working with paginated endpoints. Used in the `meta.pagination.next` URL in responses for list operations.
-    pub fn after(&mut self, price_id: impl Into<PriceID>) -> &mut Self {
+    pub fn after(mut self, price_id: impl Into<PriceID>) -> Self {
         self.after = Some(price_id.into());
         self
     }
 
     /// Return only the IDs specified.
-    pub fn ids(&mut self, price_ids: impl IntoIterator<Item = impl Into<PriceID>>) -> &mut Self {
+    pub fn ids(mut self, price_ids: impl IntoIterator<Item = impl Into<PriceID>>) -> Self {
         self.id = Some(price_ids.into_iter().map(Into::into).collect());
         self
     }
 
-    /// Include related entities in the response. Valid values are: "product".
-    pub fn include(&mut self, includes: impl IntoIterator<Item = impl Into<String>>) -> &mut Self {
-        self.include = Some(includes.into_iter().map(Into::into).collect());
+    /// Include related entities in the response.
+    pub fn include(mut self, entities: impl IntoIterator<Item = PriceInclude>) -> Self {
+        self.include = Some(entities.into_iter().collect());
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: billing_cycle.frequency, billing_cycle.interval, id, product_id, quantity.maximum, quantity.minimum, status, tax_mode, unit_price.amount, and unit_price.currency_code
-    pub fn order_by_asc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_asc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[ASC]", field));
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: billing_cycle.frequency, billing_cycle.interval, id, product_id, quantity.maximum, quantity.minimum, status, tax_mode, unit_price.amount, and unit_price.currency_code
-    pub fn order_by_desc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_desc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[DESC]", field));
         self
     }
 
+    /// Number of entities Paddle returns per page when [Self::per_page] isn't set.
+    pub const DEFAULT_PER_PAGE: usize = 50;
+    /// Largest page size Paddle accepts for this endpoint.
+    pub const MAX_PER_PAGE: usize = 200;
+
     /// Set how many entities are returned per page. Paddle returns the maximum number of results if a number greater than the maximum is requested.
     /// Check `meta.pagination.per_page` in the response to see how many were returned.
     ///
     /// Default: `50`; Maximum: `200`.
-    pub fn per_page(&mut self, entities_per_page: usize) -> &mut Self {
+    pub fn per_page(mut self, entities_per_page: usize) -> Self {
         self.per_page = Some(entities_per_page);
         self
     }
 
+    /// Request the largest page Paddle allows for this endpoint. Use this to fetch everything in as few requests as possible.
+    pub fn max_page_size(self) -> Self {
+        self.per_page(Self::MAX_PER_PAGE)
+    }
+
     /// Return only prices for the specified product IDs.
     pub fn product_ids(
-        &mut self,
+        mut self,
         product_ids: impl IntoIterator<Item = impl Into<ProductID>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.product_id = Some(product_ids.into_iter().map(Into::into).collect());
         self
     }
 
     /// Return only prices with the specified status.
-    pub fn status(&mut self, status: Status) -> &mut Self {
+    pub fn status(mut self, status: Status) -> Self {
         self.status = Some(status);
         self
     }
 
     /// Determine whether returned entities are for recurring prices (true) or one-time prices (false)
-    pub fn recurring(&mut self, value: bool) -> &mut Self {
+    pub fn recurring(mut self, value: bool) -> Self {
         self.recurring = Some(value);
         self
     }
 
     /// Return only prices with the specified type.
-    pub fn r#type(&mut self, catalog_type: CatalogType) -> &mut Self {
+    pub fn r#type(mut self, catalog_type: CatalogType) -> Self {
         self.r#type = Some(catalog_type);
         self
     }
 
     /// Returns a paginator for fetching pages of entities from Paddle
     pub fn send(&self) -> Paginated<'_, Vec<Price>> {
-        Paginated::new(self.client, "/prices", self)
+        Paginated::new(self.client, &self.path(), self)
+    }
+}
+
+impl PaddleRequest for PricesList<'_> {
+    type Output = Vec<Price>;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        "/prices".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::PriceRead]
     }
 }
 
 /// Request builder for creating a new price in Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct PricesCreate<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -155,10 +192,7 @@ impl<'a> PricesCreate<'a> {
             client,
             description: description.into(),
             product_id: product_id.into(),
-            unit_price: Money {
-                amount: amount.to_string(),
-                currency_code: currency,
-            },
+            unit_price: Money::new(amount, currency),
             r#type: None,
             name: None,
             billing_cycle: None,
@@ -171,40 +205,37 @@ impl<'a> PricesCreate<'a> {
     }
 
     /// Set the price type.
-    pub fn catalog_type(&mut self, catalog_type: CatalogType) -> &mut Self {
+    pub fn catalog_type(mut self, catalog_type: CatalogType) -> Self {
         self.r#type = Some(catalog_type);
         self
     }
 
+    /// Set the price type. Same as [PricesCreate::catalog_type].
+    pub fn r#type(self, catalog_type: CatalogType) -> Self {
+        self.catalog_type(catalog_type)
+    }
+
     /// Name of this price, shown to customers at checkout and on invoices. Typically describes how often the related product bills.
-    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+    pub fn name(mut self, name: impl Into<String>) -> Self {
         self.name = Some(name.into());
         self
     }
 
-    /// How often this price should be charged.
-    pub fn billing_cycle(&mut self, frequency: u64, interval: Interval) -> &mut Self {
-        self.billing_cycle = Some(Duration {
-            interval,
-            frequency,
-        });
-
+    /// How often this price should be charged, e.g. `Duration::months(1)`.
+    pub fn billing_cycle(mut self, billing_cycle: Duration) -> Self {
+        self.billing_cycle = Some(billing_cycle);
         self
     }
 
-    /// Trial period for the product related to this price. The billing cycle begins once the trial period is over. Requires billing_cycle.
-    pub fn trial_period(&mut self, frequency: u64, interval: Interval) -> &mut Self {
-        self.trial_period = Some(Duration {
-            interval,
-            frequency,
-        });
-
+    /// Trial period for the product related to this price, e.g. `Duration::days(14)`. The billing cycle begins once the trial period is over. Requires billing_cycle.
+    pub fn trial_period(mut self, trial_period: Duration) -> Self {
+        self.trial_period = Some(trial_period);
         self
     }
 
     /// How tax is calculated for this price. If omitted, defaults to TaxMode::AccountSetting.
     /// See [TaxMode] for more information.
-    pub fn tax_mode(&mut self, tax_mode: TaxMode) -> &mut Self {
+    pub fn tax_mode(mut self, tax_mode: TaxMode) -> Self {
         self.tax_mode = tax_mode;
         self
     }
@@ -215,11 +246,11 @@ impl<'a> PricesCreate<'a> {
     /// See [Money] for more information.
     /// See [CurrencyCode] for more information.
     pub fn add_unit_price_override(
-        &mut self,
+        mut self,
         country_codes: impl IntoIterator<Item = CountryCodeSupported>,
         amount: u64,
         currency: CurrencyCode,
-    ) -> &mut Self {
+    ) -> Self {
         if self.unit_price_overrides.is_none() {
             self.unit_price_overrides = Some(vec![]);
         }
@@ -227,13 +258,7 @@ impl<'a> PricesCreate<'a> {
         self.unit_price_overrides
             .as_mut()
             .unwrap()
-            .push(UnitPriceOverride {
-                country_codes: country_codes.into_iter().collect(),
-                unit_price: Money {
-                    amount: amount.to_string(),
-                    currency_code: currency,
-                },
-            });
+            .push(UnitPriceOverride::new(country_codes, amount, currency));
 
         self
     }
@@ -245,42 +270,73 @@ impl<'a> PricesCreate<'a> {
     /// See [CountryCodeSupported] for more information.
     /// See [Money] for more information.
     /// See [CurrencyCode] for more information.
-    pub fn set_unit_price_overrides(&mut self, overrides: Vec<UnitPriceOverride>) -> &mut Self {
+    pub fn set_unit_price_overrides(mut self, overrides: Vec<UnitPriceOverride>) -> Self {
         self.unit_price_overrides = Some(overrides);
         self
     }
 
     /// Limits on how many times the related product can be purchased at this price. Useful for discount campaigns. If omitted, defaults to 1..100.
-    pub fn quantity(&mut self, range: Range<u64>) -> &mut Self {
-        self.quantity = Some(PriceQuantity {
-            minimum: range.start,
-            maximum: range.end,
-        });
+    pub fn quantity(mut self, range: Range<u64>) -> Self {
+        self.quantity = Some(PriceQuantity::new(range.start, range.end));
+        self
+    }
+
+    /// Limits on how many times the related product can be purchased at this price, both inclusive. If omitted, Paddle allows 1 to 100.
+    ///
+    /// `minimum` must be at least `1` and not greater than `maximum`, otherwise [send](Self::send) fails with [Error::Validation] without sending a request.
+    pub fn quantity_range(mut self, minimum: u64, maximum: u64) -> Self {
+        self.quantity = Some(PriceQuantity::new(minimum, maximum));
         self
     }
 
     /// Set custom data for this price.
-    pub fn custom_data(&mut self, custom_data: HashMap<String, String>) -> &mut Self {
+    pub fn custom_data(mut self, custom_data: HashMap<String, String>) -> Self {
         self.custom_data = Some(custom_data);
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Price> {
-        self.client.send(self, Method::POST, "/prices").await
+        if let Some(quantity) = &self.quantity {
+            validate_quantity(quantity)?;
+        }
+
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for PricesCreate<'_> {
+    type Output = Price;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        "/prices".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::PriceWrite]
     }
 }
 
 /// Request builder for fetching a specific price from Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct PriceGet<'a> {
     #[serde(skip)]
     client: &'a Paddle,
     #[serde(skip)]
     price_id: PriceID,
-    #[serde(serialize_with = "crate::comma_separated")]
-    include: Option<Vec<String>>,
+    #[serde(serialize_with = "crate::comma_separated_enum")]
+    include: Option<Vec<PriceInclude>>,
 }
 
 impl<'a> PriceGet<'a> {
@@ -292,31 +348,42 @@ impl<'a> PriceGet<'a> {
         }
     }
 
-    /// Include related entities in the response. Allowed values: "product".
-    pub fn include(&mut self, entities: impl IntoIterator<Item = impl AsRef<str>>) -> &mut Self {
-        self.include = Some(
-            entities
-                .into_iter()
-                .map(|s| s.as_ref().to_string())
-                .collect(),
-        );
+    /// Include related entities in the response.
+    pub fn include(mut self, entities: impl IntoIterator<Item = PriceInclude>) -> Self {
+        self.include = Some(entities.into_iter().collect());
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Price> {
-        self.client
-            .send(
-                self,
-                Method::GET,
-                &format!("/prices/{}", self.price_id.as_ref()),
-            )
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for PriceGet<'_> {
+    type Output = Price;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!("/prices/{}", self.price_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::PriceRead]
     }
 }
 
 /// Request builder for updating a price in Paddle API.
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct PriceUpdate<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -366,72 +433,66 @@ impl<'a> PriceUpdate<'a> {
     }
 
     /// Update the price description.
-    pub fn description(&mut self, description: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn description(mut self, description: impl Into<Nullable<String>>) -> Self {
         self.description = description.into();
         self
     }
 
     /// Update the price type.
-    pub fn catalog_type(&mut self, catalog_type: impl Into<Nullable<CatalogType>>) -> &mut Self {
+    pub fn catalog_type(mut self, catalog_type: impl Into<Nullable<CatalogType>>) -> Self {
         self.r#type = catalog_type.into();
         self
     }
 
     /// Update the price name. Name is shown to customers at checkout and on invoices. Typically describes how often the related product bills.
-    pub fn name(&mut self, name: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn name(mut self, name: impl Into<Nullable<String>>) -> Self {
         self.name = name.into();
         self
     }
 
     /// Update how often this price should be charged.
     pub fn billing_cycle(
-        &mut self,
+        mut self,
         billing_cycle: impl Into<Nullable<Duration>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.billing_cycle = billing_cycle.into();
         self
     }
 
     /// Update the trial period for the product related to this price.
     pub fn trial_period(
-        &mut self,
+        mut self,
         trial_period: impl Into<Nullable<Duration>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.trial_period = trial_period.into();
         self
     }
 
     /// Update how tax is calculated for this price.
-    pub fn tax_mode(&mut self, tax_mode: impl Into<Nullable<TaxMode>>) -> &mut Self {
+    pub fn tax_mode(mut self, tax_mode: impl Into<Nullable<TaxMode>>) -> Self {
         self.tax_mode = tax_mode.into();
         self
     }
 
     /// Update the base price. This price applies to all customers, except for customers located in countries where you have unit_price_overrides.
-    pub fn unit_price(&mut self, unit_price: impl Into<Nullable<Money>>) -> &mut Self {
+    pub fn unit_price(mut self, unit_price: impl Into<Nullable<Money>>) -> Self {
         self.unit_price = unit_price.into();
         self
     }
 
     /// Use to override the base price with a custom price and currency for a country or group of countries.
     pub fn add_unit_price_override(
-        &mut self,
+        mut self,
         country_codes: impl IntoIterator<Item = CountryCodeSupported>,
         amount: u64,
         currency: CurrencyCode,
-    ) -> &mut Self {
+    ) -> Self {
         if !matches!(self.unit_price_overrides, Nullable::Value(_)) {
             self.unit_price_overrides = Nullable::Value(vec![]);
         }
 
         if let Nullable::Value(ref mut v) = self.unit_price_overrides {
-            v.push(UnitPriceOverride {
-                country_codes: country_codes.into_iter().collect(),
-                unit_price: Money {
-                    amount: amount.to_string(),
-                    currency_code: currency,
-                },
-            });
+            v.push(UnitPriceOverride::new(country_codes, amount, currency));
         }
 
         self
@@ -439,42 +500,147 @@ impl<'a> PriceUpdate<'a> {
 
     /// Use to override the base price with a custom price and currency for a country or group of countries.
     pub fn set_unit_price_overrides(
-        &mut self,
+        mut self,
         overrides: impl Into<Nullable<Vec<UnitPriceOverride>>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.unit_price_overrides = overrides.into();
         self
     }
 
     /// Update how many times the related product can be purchased at this price.
-    pub fn quantity(&mut self, quantity: impl Into<Nullable<PriceQuantity>>) -> &mut Self {
+    pub fn quantity(mut self, quantity: impl Into<Nullable<PriceQuantity>>) -> Self {
         self.quantity = quantity.into();
         self
     }
 
+    /// Update how many times the related product can be purchased at this price, both limits inclusive.
+    ///
+    /// `minimum` must be at least `1` and not greater than `maximum`, otherwise [send](Self::send) fails with [Error::Validation] without sending a request.
+    pub fn quantity_range(mut self, minimum: u64, maximum: u64) -> Self {
+        self.quantity = Nullable::Value(PriceQuantity::new(minimum, maximum));
+        self
+    }
+
     /// Update whether this entity can be used in Paddle.
-    pub fn status(&mut self, status: impl Into<Nullable<Status>>) -> &mut Self {
+    pub fn status(mut self, status: impl Into<Nullable<Status>>) -> Self {
         self.status = status.into();
         self
     }
 
     /// Set custom data for the price.
     pub fn custom_data(
-        &mut self,
+        mut self,
         custom_data: impl Into<Nullable<HashMap<String, String>>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.custom_data = custom_data.into();
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Price> {
-        self.client
-            .send(
-                self,
-                Method::PATCH,
-                &format!("/prices/{}", self.price_id.as_ref()),
+        if let Nullable::Value(quantity) = &self.quantity {
+            validate_quantity(quantity)?;
+        }
+
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for PriceUpdate<'_> {
+    type Output = Price;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn path(&self) -> String {
+        format!("/prices/{}", self.price_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::PriceWrite]
+    }
+}
+
+/// Check quantity limits before sending them, so mistakes don't cost a request.
+fn validate_quantity(quantity: &PriceQuantity) -> std::result::Result<(), Error> {
+    if quantity.minimum < 1 || quantity.minimum > quantity.maximum {
+        return Err(Error::Validation(format!(
+            "quantity minimum must be at least 1 and not greater than the maximum, got {}..={}",
+            quantity.minimum, quantity.maximum
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[tokio::test]
+    async fn invalid_quantity_ranges_fail_without_a_request() {
+        let client = Paddle::new("key", "http://127.0.0.1:9/").unwrap();
+
+        let create = client
+            .price_create(
+                "pro_01htz88xpr0mm7b3ta2pjkr7w2",
+                "Monthly",
+                1000,
+                CurrencyCode::USD,
+            )
+            .quantity_range(5, 1);
+        let err = create.send().await.unwrap_err();
+        assert!(matches!(err, Error::Validation(message) if message.contains("5..=1")));
+
+        let update = client
+            .price_update("pri_01gsz8x8sawmvhz1pv30nge1ke")
+            .quantity_range(0, 10);
+        let err = update.send().await.unwrap_err();
+        assert!(matches!(err, Error::Validation(_)));
+    }
+
+    #[test]
+    fn billing_cycle_and_trial_period_serialize_as_durations() {
+        let client = Paddle::new("key", "http://127.0.0.1:9/").unwrap();
+
+        let create = client
+            .price_create(
+                "pro_01htz88xpr0mm7b3ta2pjkr7w2",
+                "Quarterly",
+                3000,
+                CurrencyCode::USD,
             )
-            .await
+            .billing_cycle(Duration::months(3))
+            .trial_period(Duration::days(14));
+        let body = serde_json::to_value(&create).unwrap();
+
+        assert_eq!(
+            body["billing_cycle"],
+            serde_json::json!({ "interval": "month", "frequency": 3 })
+        );
+        assert_eq!(
+            body["trial_period"],
+            serde_json::json!({ "interval": "day", "frequency": 14 })
+        );
+    }
+
+    #[test]
+    fn missing_quantity_uses_paddle_defaults() {
+        let mut price = fixtures::price("pri_1", "pro_1", 1000);
+        price.as_object_mut().unwrap().remove("quantity");
+        let price: Price = fixtures::from_value(price);
+        assert_eq!((price.quantity.minimum, price.quantity.maximum), (1, 100));
+
+        let mut price = fixtures::price("pri_1", "pro_1", 1000);
+        price["quantity"] = serde_json::json!({ "maximum": 5 });
+        let price: Price = fixtures::from_value(price);
+        assert_eq!((price.quantity.minimum, price.quantity.maximum), (1, 5));
     }
 }
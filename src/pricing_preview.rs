@@ -9,11 +9,14 @@ use serde_with::skip_serializing_none;
 use crate::entities::{self, AddressPreview, PricePreviewItem};
 use crate::enums::CurrencyCode;
 use crate::ids::{AddressID, BusinessID, CustomerID, DiscountID};
+use crate::permissions::Permission;
+use crate::request::PaddleRequest;
 use crate::{Paddle, Result};
 
 /// Request builder for fetching transactions from Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct PricingPreview<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -43,7 +46,7 @@ impl<'a> PricingPreview<'a> {
     }
 
     /// Paddle ID of the customer that this preview is for.
-    pub fn customer_id(&mut self, customer_id: impl Into<CustomerID>) -> &mut Self {
+    pub fn customer_id(mut self, customer_id: impl Into<CustomerID>) -> Self {
         self.customer_id = Some(customer_id.into());
         self
     }
@@ -51,45 +54,64 @@ impl<'a> PricingPreview<'a> {
     /// Paddle ID of the address that this preview is for.
     ///
     /// Send one of `address_id`, `customer_ip_address`, or the `address` object when previewing.
-    pub fn address_id(&mut self, address_id: impl Into<AddressID>) -> &mut Self {
+    pub fn address_id(mut self, address_id: impl Into<AddressID>) -> Self {
         self.address_id = Some(address_id.into());
         self
     }
 
     /// Paddle ID of the business that this preview is for.
-    pub fn business_id(&mut self, business_id: impl Into<BusinessID>) -> &mut Self {
+    pub fn business_id(mut self, business_id: impl Into<BusinessID>) -> Self {
         self.business_id = Some(business_id.into());
         self
     }
 
     /// Supported three-letter ISO 4217 currency code.
-    pub fn currency_code(&mut self, currency_code: CurrencyCode) -> &mut Self {
+    pub fn currency_code(mut self, currency_code: CurrencyCode) -> Self {
         self.currency_code = Some(currency_code);
         self
     }
 
     /// Paddle ID of the discount applied to this preview
-    pub fn discount_id(&mut self, discount_id: impl Into<DiscountID>) -> &mut Self {
+    pub fn discount_id(mut self, discount_id: impl Into<DiscountID>) -> Self {
         self.discount_id = Some(discount_id.into());
         self
     }
 
     /// Address for this preview. Send one of `address_id`, `customer_ip_address`, or the `address` object when previewing.
-    pub fn address(&mut self, address: AddressPreview) -> &mut Self {
+    pub fn address(mut self, address: AddressPreview) -> Self {
         self.address = Some(address);
         self
     }
 
     /// IP address for this transaction preview. Send one of `address_id`, `customer_ip_address`, or the `address` object when previewing.
-    pub fn customer_ip_address(&mut self, ip: String) -> &mut Self {
+    pub fn customer_ip_address(mut self, ip: String) -> Self {
         self.customer_ip_address = Some(ip);
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<entities::PricingPreview> {
-        self.client
-            .send(self, Method::POST, "/pricing-preview")
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for PricingPreview<'_> {
+    type Output = entities::PricingPreview;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        "/pricing-preview".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::PriceRead]
     }
 }
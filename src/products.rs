@@ -9,23 +9,26 @@ use serde::Serialize;
 use serde_with::skip_serializing_none;
 
 use crate::entities::Product;
-use crate::enums::{CatalogType, Status, TaxCategory};
+use crate::enums::{CatalogType, ProductInclude, Status, TaxCategory};
 use crate::ids::ProductID;
 use crate::paginated::Paginated;
 use crate::nullable::Nullable;
+use crate::permissions::Permission;
+use crate::request::PaddleRequest;
 use crate::{Paddle, Result};
 
 /// Request builder for fetching products from Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct ProductsList<'a> {
     #[serde(skip)]
     client: &'a Paddle,
     after: Option<ProductID>,
     #[serde(serialize_with = "crate::comma_separated")]
     id: Option<Vec<ProductID>>,
-    #[serde(serialize_with = "crate::comma_separated")]
-    include: Option<Vec<String>>,
+    #[serde(serialize_with = "crate::comma_separated_enum")]
+    include: Option<Vec<ProductInclude>>,
     order_by: Option<String>,
     per_page: Option<usize>,
     status: Option<Status>,
@@ -50,82 +53,103 @@ impl<'a> ProductsList<'a> {
     }
 
     /// Return entities after the specified Paddle ID when working with paginated endpoints. Used in the `meta.pagination.next` URL in responses for list operations.
-    pub fn after(&mut self, product_id: impl Into<ProductID>) -> &mut Self {
+    pub fn after(mut self, product_id: impl Into<ProductID>) -> Self {
         self.after = Some(product_id.into());
         self
     }
 
     /// Return only the IDs specified.
-    pub fn ids(
-        &mut self,
-        product_ids: impl IntoIterator<Item = impl Into<ProductID>>,
-    ) -> &mut Self {
+    pub fn ids(mut self, product_ids: impl IntoIterator<Item = impl Into<ProductID>>) -> Self {
         self.id = Some(product_ids.into_iter().map(|i| i.into()).collect());
         self
     }
 
-    /// Include related entities in the response. Valid values are: "prices".
-    pub fn include(&mut self, entities: impl IntoIterator<Item = impl AsRef<str>>) -> &mut Self {
-        self.include = Some(
-            entities
-                .into_iter()
-                .map(|s| s.as_ref().to_string())
-                .collect(),
-        );
+    /// Include related entities in the response.
+    pub fn include(mut self, entities: impl IntoIterator<Item = ProductInclude>) -> Self {
+        self.include = Some(entities.into_iter().collect());
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: `created_at`, `custom_data`, `description`, `id`, `image_url`, `name`, `status`, `tax_category`, and `updated_at`.
-    pub fn order_by_asc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_asc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[ASC]", field));
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: `created_at`, `custom_data`, `description`, `id`, `image_url`, `name`, `status`, `tax_category`, and `updated_at`.
-    pub fn order_by_desc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_desc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[DESC]", field));
         self
     }
 
+    /// Number of entities Paddle returns per page when [Self::per_page] isn't set.
+    pub const DEFAULT_PER_PAGE: usize = 50;
+    /// Largest page size Paddle accepts for this endpoint.
+    pub const MAX_PER_PAGE: usize = 200;
+
     /// Set how many entities are returned per page. Paddle returns the maximum number of results if a number greater than the maximum is requested.
     /// Check `meta.pagination.per_page` in the response to see how many were returned.
     ///
     /// Default: `50`; Maximum: `200`.
-    pub fn per_page(&mut self, entities_per_page: usize) -> &mut Self {
+    pub fn per_page(mut self, entities_per_page: usize) -> Self {
         self.per_page = Some(entities_per_page);
         self
     }
 
+    /// Request the largest page Paddle allows for this endpoint. Use this to fetch everything in as few requests as possible.
+    pub fn max_page_size(self) -> Self {
+        self.per_page(Self::MAX_PER_PAGE)
+    }
+
     /// Return entities that match the specified status.
-    pub fn status(&mut self, status: Status) -> &mut Self {
+    pub fn status(mut self, status: Status) -> Self {
         self.status = Some(status);
         self
     }
 
     /// Return entities that match the specified tax categories.
-    pub fn tax_category(
-        &mut self,
-        tax_categories: impl IntoIterator<Item = TaxCategory>,
-    ) -> &mut Self {
+    pub fn tax_category(mut self, tax_categories: impl IntoIterator<Item = TaxCategory>) -> Self {
         self.tax_category = Some(tax_categories.into_iter().collect());
         self
     }
 
     /// Return entities that match the specified catalog type.
-    pub fn catalog_type(&mut self, catalog_type: CatalogType) -> &mut Self {
+    pub fn catalog_type(mut self, catalog_type: CatalogType) -> Self {
         self.r#type = Some(catalog_type);
         self
     }
 
     /// Returns a paginator for fetching pages of entities from Paddle
     pub fn send(&self) -> Paginated<'_, Vec<Product>> {
-        Paginated::new(self.client, "/products", self)
+        Paginated::new(self.client, &self.path(), self)
+    }
+}
+
+impl PaddleRequest for ProductsList<'_> {
+    type Output = Vec<Product>;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        "/products".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::ProductRead]
     }
 }
 
 /// Request builder for creating a new product in Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct ProductCreate<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -150,46 +174,73 @@ impl<'a> ProductCreate<'a> {
         }
     }
 
-    /// Set the product description.
-    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+    /// Short description for this product.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
         self.description = Some(description.into());
         self
     }
 
-    /// Set the product catalog type.
-    pub fn catalog_type(&mut self, catalog_type: CatalogType) -> &mut Self {
+    /// Type of item. Standard items are considered part of your catalog and are shown in the Paddle dashboard. If omitted, defaults to `standard`.
+    pub fn catalog_type(mut self, catalog_type: CatalogType) -> Self {
         self.r#type = Some(catalog_type);
         self
     }
 
-    /// Set the product image URL.
-    pub fn image_url(&mut self, image_url: impl Into<String>) -> &mut Self {
+    /// Type of item. Same as [ProductCreate::catalog_type].
+    pub fn r#type(self, catalog_type: CatalogType) -> Self {
+        self.catalog_type(catalog_type)
+    }
+
+    /// Image for this product. Included in the checkout and on some customer documents. Must be an HTTPS URL.
+    pub fn image_url(mut self, image_url: impl Into<String>) -> Self {
         self.image_url = Some(image_url.into());
         self
     }
 
-    /// Set custom data for the product.
-    pub fn custom_data(&mut self, custom_data: HashMap<String, String>) -> &mut Self {
+    /// Your own structured key-value data.
+    pub fn custom_data(mut self, custom_data: HashMap<String, String>) -> Self {
         self.custom_data = Some(custom_data);
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Product> {
-        self.client.send(self, Method::POST, "/products").await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for ProductCreate<'_> {
+    type Output = Product;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        "/products".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::ProductWrite]
     }
 }
 
 /// Request builder for fetching a specific product from Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct ProductGet<'a> {
     #[serde(skip)]
     client: &'a Paddle,
     #[serde(skip)]
     product_id: ProductID,
-    #[serde(serialize_with = "crate::comma_separated")]
-    include: Option<Vec<String>>,
+    #[serde(serialize_with = "crate::comma_separated_enum")]
+    include: Option<Vec<ProductInclude>>,
 }
 
 impl<'a> ProductGet<'a> {
@@ -202,30 +253,41 @@ impl<'a> ProductGet<'a> {
     }
 
     /// Include related entities in the response.
-    pub fn include(&mut self, entities: impl IntoIterator<Item = impl AsRef<str>>) -> &mut Self {
-        self.include = Some(
-            entities
-                .into_iter()
-                .map(|s| s.as_ref().to_string())
-                .collect(),
-        );
+    pub fn include(mut self, entities: impl IntoIterator<Item = ProductInclude>) -> Self {
+        self.include = Some(entities.into_iter().collect());
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Product> {
-        self.client
-            .send(
-                self,
-                Method::GET,
-                &format!("/products/{}", self.product_id.as_ref()),
-            )
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for ProductGet<'_> {
+    type Output = Product;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!("/products/{}", self.product_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::ProductRead]
     }
 }
 
 /// Request builder for updating a product in Paddle API.
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct ProductUpdate<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -262,59 +324,79 @@ impl<'a> ProductUpdate<'a> {
         }
     }
 
-    /// Set the product name.
-    pub fn name(&mut self, name: impl Into<Nullable<String>>) -> &mut Self {
+    /// Name of this product.
+    pub fn name(mut self, name: impl Into<Nullable<String>>) -> Self {
         self.name = name.into();
         self
     }
 
-    /// Set the product description.
-    pub fn description(&mut self, description: impl Into<Nullable<String>>) -> &mut Self {
+    /// Short description for this product. Pass `Nullable::Null` to remove it.
+    pub fn description(mut self, description: impl Into<Nullable<String>>) -> Self {
         self.description = description.into();
         self
     }
 
-    /// Set the product catalog type.
-    pub fn catalog_type(&mut self, catalog_type: impl Into<Nullable<CatalogType>>) -> &mut Self {
+    /// Type of item. Standard items are considered part of your catalog and are shown in the Paddle dashboard.
+    pub fn catalog_type(mut self, catalog_type: impl Into<Nullable<CatalogType>>) -> Self {
         self.r#type = catalog_type.into();
         self
     }
 
-    /// Set the product tax category.
-    pub fn tax_category(&mut self, tax_category: impl Into<Nullable<TaxCategory>>) -> &mut Self {
+    /// Type of item. Same as [ProductUpdate::catalog_type].
+    pub fn r#type(self, catalog_type: impl Into<Nullable<CatalogType>>) -> Self {
+        self.catalog_type(catalog_type)
+    }
+
+    /// Tax category for this product. Used for charging the correct rate of tax. Selected tax category must be enabled on your Paddle account.
+    pub fn tax_category(mut self, tax_category: impl Into<Nullable<TaxCategory>>) -> Self {
         self.tax_category = tax_category.into();
         self
     }
 
-    /// Set the product image URL.
-    pub fn image_url(&mut self, image_url: impl Into<Nullable<String>>) -> &mut Self {
+    /// Image for this product. Must be an HTTPS URL. Pass `Nullable::Null` to remove it.
+    pub fn image_url(mut self, image_url: impl Into<Nullable<String>>) -> Self {
         self.image_url = image_url.into();
         self
     }
 
-    /// Set custom data for the product.
+    /// Your own structured key-value data. Replaces any existing custom data. Pass `Nullable::Null` to remove it.
     pub fn custom_data(
-        &mut self,
+        mut self,
         custom_data: impl Into<Nullable<HashMap<String, String>>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.custom_data = custom_data.into();
         self
     }
 
-    /// Set the product status.
-    pub fn status(&mut self, status: impl Into<Nullable<Status>>) -> &mut Self {
+    /// Whether this entity can be used in Paddle. Set to `archived` to archive the product.
+    pub fn status(mut self, status: impl Into<Nullable<Status>>) -> Self {
         self.status = status.into();
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Product> {
-        self.client
-            .send(
-                self,
-                Method::PATCH,
-                &format!("/products/{}", self.product_id.as_ref()),
-            )
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for ProductUpdate<'_> {
+    type Output = Product;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn path(&self) -> String {
+        format!("/products/{}", self.product_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::ProductWrite]
     }
 }
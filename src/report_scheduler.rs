@@ -0,0 +1,378 @@
+//! Generate reports on a schedule.
+//!
+//! Paddle doesn't support scheduled reports, so [ReportScheduler] runs the schedule in your app. On every run it creates a report, waits until Paddle has generated it, downloads the CSV file and hands it to a [ReportSink].
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::documents::Document;
+//! use paddle_rust_sdk::entities::ReportBase;
+//! use paddle_rust_sdk::enums::TransactionsReportType;
+//! use paddle_rust_sdk::report_scheduler::{ReportScheduler, ReportSink, Schedule};
+//! use paddle_rust_sdk::{Error, Paddle};
+//!
+//! struct SaveToDisk;
+//!
+//! impl ReportSink for SaveToDisk {
+//!     async fn deliver(&self, report: ReportBase, csv: Document) {
+//!         tokio::fs::write(format!("reports/{}", csv.filename), csv.bytes).await.unwrap();
+//!     }
+//!
+//!     async fn failed(&self, error: Error) {
+//!         eprintln!("Monthly report failed: {error}");
+//!     }
+//! }
+//!
+//! let client = Paddle::new("your_api_key", Paddle::SANDBOX).unwrap();
+//! let schedule = Schedule::Monthly { day: 1, hour: 6, minute: 0 };
+//!
+//! tokio::spawn(ReportScheduler::new(client, TransactionsReportType::Transactions, schedule, SaveToDisk).run());
+//! ```
+
+use std::future::Future;
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveTime, Utc};
+use paddle_rust_sdk_types::reports::ReportType;
+use serde::de::DeserializeOwned;
+
+use crate::documents::{self, Document};
+use crate::entities::{ReportBase, ReportFilterValue};
+use crate::enums::{Disposition, FilterOperator, ReportStatus};
+use crate::{Error, Paddle};
+
+/// Filters of a scheduled report.
+pub type ReportFilters<T> = Vec<(
+    <T as ReportType>::FilterName,
+    Option<FilterOperator>,
+    ReportFilterValue,
+)>;
+
+/// When reports are generated. Times are in UTC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Schedule {
+    /// At a fixed interval, starting one interval after the scheduler is started. Runs are spaced from when the previous run was scheduled, not from when it finished. A zero interval never runs.
+    Every(Duration),
+    /// Every day at the given time.
+    Daily { hour: u32, minute: u32 },
+    /// Every month on the given day at the given time. Days past the end of a month run on the last day of that month.
+    Monthly { day: u32, hour: u32, minute: u32 },
+}
+
+impl Schedule {
+    /// First time the schedule runs after `after`. `None` if the schedule is invalid, e.g. `hour` is 24 or more.
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match *self {
+            Self::Every(interval) if interval.is_zero() => None,
+            Self::Every(interval) => Some(after + chrono::Duration::from_std(interval).ok()?),
+            Self::Daily { hour, minute } => {
+                let time = NaiveTime::from_hms_opt(hour, minute, 0)?;
+                let today = after.date_naive().and_time(time).and_utc();
+
+                if today > after {
+                    Some(today)
+                } else {
+                    Some(today.checked_add_days(Days::new(1))?)
+                }
+            }
+            Self::Monthly { day, hour, minute } => {
+                let time = NaiveTime::from_hms_opt(hour, minute, 0)?;
+                let first_of_month = NaiveDate::from_ymd_opt(after.year(), after.month(), 1)?;
+
+                for month in 0..2 {
+                    let first = first_of_month.checked_add_months(Months::new(month))?;
+                    let last = first.checked_add_months(Months::new(1))? - Days::new(1);
+                    let date = first.with_day(day.clamp(1, last.day()))?;
+                    let run_at = date.and_time(time).and_utc();
+
+                    if run_at > after {
+                        return Some(run_at);
+                    }
+                }
+
+                None
+            }
+        }
+    }
+
+    /// Run following the one scheduled at `previous`, skipping runs that were missed by `now`, e.g. because generating the previous report took longer than the interval.
+    fn next_run(&self, previous: DateTime<Utc>, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let mut next = self.next_after(previous)?;
+
+        while next <= now {
+            next = self.next_after(next)?;
+        }
+
+        Some(next)
+    }
+}
+
+/// Receives reports generated by a [ReportScheduler].
+pub trait ReportSink: Send + Sync {
+    /// Called with every generated report and its downloaded CSV file.
+    fn deliver(&self, report: ReportBase, csv: Document) -> impl Future<Output = ()> + Send;
+
+    /// Called when a scheduled run fails. The scheduler carries on with the next run afterwards.
+    fn failed(&self, error: Error) -> impl Future<Output = ()> + Send {
+        let _ = error;
+        async {}
+    }
+}
+
+/// Creates reports on a [Schedule] and delivers them to a [ReportSink].
+pub struct ReportScheduler<T: ReportType, S> {
+    client: Paddle,
+    report_type: T,
+    schedule: Schedule,
+    sink: S,
+    filters: Box<dyn Fn(DateTime<Utc>) -> ReportFilters<T> + Send + Sync>,
+    poll_interval: Duration,
+    timeout: Duration,
+}
+
+impl<T, S> ReportScheduler<T, S>
+where
+    T: ReportType + DeserializeOwned + Clone + Send + Sync,
+    T::FilterName: Send + Sync,
+    S: ReportSink,
+{
+    /// Create a scheduler that generates reports of `report_type` on `schedule` and delivers them to `sink`. Nothing runs until [ReportScheduler::run] is called.
+    ///
+    /// Reports are checked every 10 seconds and a run fails if its report isn't ready within 30 minutes. Change these with [ReportScheduler::with_poll_interval] and [ReportScheduler::with_timeout].
+    pub fn new(client: Paddle, report_type: T, schedule: Schedule, sink: S) -> Self {
+        Self {
+            client,
+            report_type,
+            schedule,
+            sink,
+            filters: Box::new(|_| Vec::new()),
+            poll_interval: Duration::from_secs(10),
+            timeout: Duration::from_secs(30 * 60),
+        }
+    }
+
+    /// Set the report filters. Called on every run with the time the run was scheduled for, e.g. to filter by the previous month.
+    ///
+    /// Without filters, Paddle includes data updated in the last 30 days.
+    pub fn with_filters(
+        mut self,
+        filters: impl Fn(DateTime<Utc>) -> ReportFilters<T> + Send + Sync + 'static,
+    ) -> Self {
+        self.filters = Box::new(filters);
+        self
+    }
+
+    /// How often to check whether a report is ready. Defaults to 10 seconds.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// How long to wait for a report to become ready before the run fails with [Error::ReportNotReady]. Defaults to 30 minutes.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Run the schedule. Only returns if the schedule has no next run, e.g. because it's invalid.
+    ///
+    /// Runs that are due while a report is still being generated are skipped.
+    ///
+    /// Spawn it as a background task with `tokio::spawn`.
+    pub async fn run(self) {
+        let mut next = self.schedule.next_after(Utc::now());
+
+        while let Some(run_at) = next {
+            let delay = (run_at - Utc::now()).to_std().unwrap_or_default();
+            tokio::time::sleep(delay).await;
+
+            match self.generate(run_at).await {
+                Ok((report, csv)) => self.sink.deliver(report, csv).await,
+                Err(err) => self.sink.failed(err).await,
+            }
+
+            next = self.schedule.next_run(run_at, Utc::now());
+        }
+    }
+
+    /// Create a report now, wait until it's ready and download it. `scheduled_at` is passed to the filters.
+    ///
+    /// The result isn't passed to the sink.
+    pub async fn generate(
+        &self,
+        scheduled_at: DateTime<Utc>,
+    ) -> Result<(ReportBase, Document), Error> {
+        let report = self
+            .client
+            .report_create(self.report_type.clone())
+            .set_filters((self.filters)(scheduled_at))
+            .send()
+            .await?
+            .data;
+
+        let report =
+            wait_until_ready(&self.client, report, self.poll_interval, self.timeout).await?;
+        let url = self.client.report_download_url(report.id.clone()).await?;
+        let csv = documents::download(
+            &self.client,
+            url.data.url,
+            Disposition::Attachment,
+            format!("{}.csv", report.id),
+        )
+        .await?;
+
+        Ok((report, csv))
+    }
+}
+
+/// Check a report every `poll_interval` until it's ready. Fails with [Error::ReportNotReady] once `timeout` has passed.
+pub(crate) async fn wait_until_ready(
+    client: &Paddle,
+    mut report: ReportBase,
+    poll_interval: Duration,
+    timeout: Duration,
+) -> Result<ReportBase, Error> {
+    let deadline = tokio::time::Instant::now() + timeout;
+
+    loop {
+        match report.status {
+            ReportStatus::Ready => return Ok(report),
+            ReportStatus::Pending if tokio::time::Instant::now() < deadline => {}
+            status => return Err(Error::ReportNotReady(report.id, status)),
+        }
+
+        tokio::time::sleep(poll_interval).await;
+        report = client.report_get(report.id).await?.data;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(time: &str) -> DateTime<Utc> {
+        time.parse().unwrap()
+    }
+
+    #[test]
+    fn every_adds_the_interval() {
+        let schedule = Schedule::Every(Duration::from_secs(90 * 60));
+
+        assert_eq!(
+            schedule.next_after(at("2024-04-12T23:00:00Z")),
+            Some(at("2024-04-13T00:30:00Z"))
+        );
+    }
+
+    #[test]
+    fn every_is_anchored_to_the_previous_run() {
+        let schedule = Schedule::Every(Duration::from_secs(60 * 60));
+        let previous = at("2024-04-12T10:00:00Z");
+
+        // Generating the report took 10 minutes
+        assert_eq!(
+            schedule.next_run(previous, at("2024-04-12T10:10:00Z")),
+            Some(at("2024-04-12T11:00:00Z"))
+        );
+        // Generating the report took longer than the interval
+        assert_eq!(
+            schedule.next_run(previous, at("2024-04-12T11:30:00Z")),
+            Some(at("2024-04-12T12:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn zero_interval_has_no_next_run() {
+        let schedule = Schedule::Every(Duration::ZERO);
+        let now = at("2024-04-12T10:00:00Z");
+
+        assert_eq!(schedule.next_after(now), None);
+        assert_eq!(schedule.next_run(now, now), None);
+    }
+
+    #[test]
+    fn daily_runs_today_or_tomorrow() {
+        let schedule = Schedule::Daily {
+            hour: 6,
+            minute: 30,
+        };
+
+        assert_eq!(
+            schedule.next_after(at("2024-04-12T05:00:00Z")),
+            Some(at("2024-04-12T06:30:00Z"))
+        );
+        assert_eq!(
+            schedule.next_after(at("2024-04-12T06:30:00Z")),
+            Some(at("2024-04-13T06:30:00Z"))
+        );
+        assert_eq!(
+            schedule.next_after(at("2024-12-31T22:00:00Z")),
+            Some(at("2025-01-01T06:30:00Z"))
+        );
+    }
+
+    #[test]
+    fn monthly_runs_this_month_or_next() {
+        let schedule = Schedule::Monthly {
+            day: 15,
+            hour: 6,
+            minute: 0,
+        };
+
+        assert_eq!(
+            schedule.next_after(at("2024-04-12T10:00:00Z")),
+            Some(at("2024-04-15T06:00:00Z"))
+        );
+        assert_eq!(
+            schedule.next_after(at("2024-04-15T06:00:00Z")),
+            Some(at("2024-05-15T06:00:00Z"))
+        );
+        assert_eq!(
+            schedule.next_after(at("2024-12-20T00:00:00Z")),
+            Some(at("2025-01-15T06:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn monthly_clamps_to_the_last_day() {
+        let schedule = Schedule::Monthly {
+            day: 31,
+            hour: 0,
+            minute: 0,
+        };
+
+        assert_eq!(
+            schedule.next_after(at("2024-02-10T00:00:00Z")),
+            Some(at("2024-02-29T00:00:00Z"))
+        );
+        assert_eq!(
+            schedule.next_after(at("2023-02-10T00:00:00Z")),
+            Some(at("2023-02-28T00:00:00Z"))
+        );
+        assert_eq!(
+            schedule.next_after(at("2024-04-30T00:00:00Z")),
+            Some(at("2024-05-31T00:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn invalid_times_have_no_next_run() {
+        let now = at("2024-04-12T10:00:00Z");
+
+        assert_eq!(
+            Schedule::Daily {
+                hour: 24,
+                minute: 0
+            }
+            .next_after(now),
+            None
+        );
+        assert_eq!(
+            Schedule::Monthly {
+                day: 1,
+                hour: 6,
+                minute: 60
+            }
+            .next_after(now),
+            None
+        );
+    }
+}
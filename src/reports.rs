@@ -12,11 +12,14 @@ use crate::entities::{ReportBase, ReportFilter, ReportFilterValue};
 use crate::enums::{FilterOperator, ReportStatus};
 use crate::ids::PaddleID;
 use crate::paginated::Paginated;
+use crate::permissions::Permission;
+use crate::request::PaddleRequest;
 use crate::{Paddle, Result};
 
 /// Request builder for querying Paddle for reports.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct ReportsList<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -39,47 +42,79 @@ impl<'a> ReportsList<'a> {
     }
 
     /// Return entities after the specified Paddle ID when working with paginated endpoints. Used in the `meta.pagination.next` URL in responses for list operations.
-    pub fn after(&mut self, report_id: impl Into<PaddleID>) -> &mut Self {
+    pub fn after(mut self, report_id: impl Into<PaddleID>) -> Self {
         self.after = Some(report_id.into());
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: `id`
-    pub fn order_by_asc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_asc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[ASC]", field));
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: `id`
-    pub fn order_by_desc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_desc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[DESC]", field));
         self
     }
 
+    /// Number of entities Paddle returns per page when [Self::per_page] isn't set.
+    pub const DEFAULT_PER_PAGE: usize = 50;
+    /// Largest page size Paddle accepts for this endpoint.
+    pub const MAX_PER_PAGE: usize = 200;
+
     /// Set how many entities are returned per page. Paddle returns the maximum number of results if a number greater than the maximum is requested.
     /// Check `meta.pagination.per_page` in the response to see how many were returned.
     ///
     /// Default: `50`; Maximum: `200`.
-    pub fn per_page(&mut self, entities_per_page: usize) -> &mut Self {
+    pub fn per_page(mut self, entities_per_page: usize) -> Self {
         self.per_page = Some(entities_per_page);
         self
     }
 
+    /// Request the largest page Paddle allows for this endpoint. Use this to fetch everything in as few requests as possible.
+    pub fn max_page_size(self) -> Self {
+        self.per_page(Self::MAX_PER_PAGE)
+    }
+
     /// Return entities that match the specified status.
-    pub fn status(&mut self, statuses: impl IntoIterator<Item = ReportStatus>) -> &mut Self {
+    pub fn status(mut self, statuses: impl IntoIterator<Item = ReportStatus>) -> Self {
         self.status = Some(statuses.into_iter().collect());
         self
     }
 
     /// Returns a paginator for fetching pages of entities from Paddle
     pub fn send(&self) -> Paginated<'_, Vec<ReportBase>> {
-        Paginated::new(self.client, "/reports", self)
+        Paginated::new(self.client, &self.path(), self)
+    }
+}
+
+impl PaddleRequest for ReportsList<'_> {
+    type Output = Vec<ReportBase>;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        "/reports".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::ReportRead]
     }
 }
 
 /// Request builder for creating reports in Paddle.
 #[skip_serializing_none]
 #[derive(Serialize)]
+#[must_use]
 pub struct ReportCreate<'a, T: ReportType> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -87,6 +122,20 @@ pub struct ReportCreate<'a, T: ReportType> {
     filters: Vec<ReportFilter<T::FilterName>>,
 }
 
+impl<T> Clone for ReportCreate<'_, T>
+where
+    T: ReportType + Clone,
+    T::FilterName: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client,
+            r#type: self.r#type.clone(),
+            filters: self.filters.clone(),
+        }
+    }
+}
+
 impl<'a, T: ReportType + DeserializeOwned> ReportCreate<'a, T> {
     pub fn new(client: &'a Paddle, r#type: T) -> Self {
         Self {
@@ -98,43 +147,57 @@ impl<'a, T: ReportType + DeserializeOwned> ReportCreate<'a, T> {
 
     /// Add filter criteria for this report. If omitted, reports are filtered to include data updated in the last 30 days. This means `updated_at` is greater than or equal to (`gte`) the date 30 days ago from the time the report was generated.
     pub fn append_filter(
-        &mut self,
+        mut self,
         name: T::FilterName,
         operator: Option<FilterOperator>,
         value: impl Into<ReportFilterValue>,
-    ) -> &mut Self {
-        self.filters.push(ReportFilter {
-            name,
-            operator,
-            value: value.into(),
-        });
+    ) -> Self {
+        self.filters.push(ReportFilter::new(name, operator, value));
 
         self
     }
 
     /// Clear all report filters
-    pub fn clear_filters(&mut self) {
+    pub fn clear_filters(mut self) -> Self {
         self.filters.clear();
+        self
     }
 
     /// Set all filter criteria for this report. This overrides any previously set filters.
     pub fn set_filters(
-        &mut self,
+        mut self,
         filters: impl IntoIterator<Item = (T::FilterName, Option<FilterOperator>, ReportFilterValue)>,
-    ) -> &mut Self {
+    ) -> Self {
         self.filters = filters
             .into_iter()
-            .map(|(name, operator, value)| ReportFilter {
-                name,
-                operator,
-                value,
-            })
+            .map(|(name, operator, value)| ReportFilter::new(name, operator, value))
             .collect();
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<ReportBase> {
-        self.client.send(self, Method::POST, "/reports").await
+        self.client.execute(self).await
+    }
+}
+
+impl<T: ReportType + DeserializeOwned> PaddleRequest for ReportCreate<'_, T> {
+    type Output = ReportBase;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        "/reports".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::ReportWrite]
     }
 }
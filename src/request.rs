@@ -0,0 +1,48 @@
+//! Common interface implemented by every request builder.
+//!
+//! [PaddleRequest] describes a request without sending it, which allows writing code that works with any builder - retries, logging, caching or mocking.
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::{request::PaddleRequest, Paddle};
+//!
+//! async fn send_logged<R: PaddleRequest>(client: &Paddle, request: &R) -> R::Output {
+//!     println!("{} {}", request.method(), request.path());
+//!     client.execute(request).await.unwrap().data
+//! }
+//! ```
+
+use reqwest::Method;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::permissions::Permission;
+
+/// A request to the Paddle API.
+pub trait PaddleRequest {
+    /// Type of the `data` field of a successful response.
+    type Output: DeserializeOwned;
+
+    /// Payload of the request. Serialized as the query string for `GET` requests and as the JSON body otherwise.
+    type Payload: Serialize + ?Sized;
+
+    /// HTTP method used for this request.
+    fn method(&self) -> Method;
+
+    /// Path of the endpoint relative to the API base URL.
+    fn path(&self) -> String;
+
+    /// Payload sent with this request.
+    fn payload(&self) -> &Self::Payload;
+
+    /// Permissions the API key needs to have for this request to succeed.
+    fn required_permissions(&self) -> &'static [Permission];
+
+    /// Payload sent as the query string. `None` unless this is a `GET` request.
+    fn query(&self) -> Option<&Self::Payload> {
+        (self.method() == Method::GET).then(|| self.payload())
+    }
+
+    /// Payload sent as the JSON body. `None` for `GET` requests.
+    fn body(&self) -> Option<&Self::Payload> {
+        (self.method() != Method::GET).then(|| self.payload())
+    }
+}
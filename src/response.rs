@@ -1,25 +1,23 @@
 //! This module defines the response structures for the Paddle API.
 
+use std::time::Duration;
+
+use reqwest::header::RETRY_AFTER;
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 
 use crate::entities::Pagination;
-use crate::PaddleApiError;
+use crate::{Error, PaddleApiError};
 
 /// Meta information about the API request.
 /// This includes the request ID, which can be used for debugging or tracking purposes.
-#[derive(Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize)]
 pub struct Meta {
     pub request_id: String,
     pub pagination: Option<Pagination>,
 }
 
-#[derive(Debug, Deserialize)]
-#[serde(untagged)]
-pub(crate) enum Response<T> {
-    Success(SuccessResponse<T>),
-    Error(ErrorResponse),
-}
-
 /// Success response structure for the Paddle API.
 #[derive(Debug, Deserialize)]
 pub struct SuccessResponse<T> {
@@ -32,4 +30,48 @@ pub struct SuccessResponse<T> {
 pub struct ErrorResponse {
     pub error: PaddleApiError,
     pub meta: Meta,
+    /// How long to wait before retrying, from the `Retry-After` header. Set when Paddle rate limits requests.
+    #[serde(skip)]
+    pub retry_after: Option<Duration>,
+}
+
+/// Parse a raw Paddle API response body.
+///
+/// The status code decides whether the body is parsed as a success or an error response, so the body is deserialized in a single pass without being buffered into an intermediate representation first.
+pub fn from_slice<T: DeserializeOwned>(
+    status: StatusCode,
+    body: &[u8],
+) -> Result<SuccessResponse<T>, Error> {
+    if status.is_success() {
+        Ok(serde_json::from_slice(body)?)
+    } else {
+        let mut err: ErrorResponse = serde_json::from_slice(body)?;
+        err.error.status_code = Some(status);
+
+        Err(Error::PaddleApi(err))
+    }
+}
+
+/// Read the body of a response returned by the Paddle API and parse it.
+pub(crate) async fn decode<T: DeserializeOwned>(
+    response: reqwest::Response,
+) -> Result<SuccessResponse<T>, Error> {
+    let status = response.status();
+    let retry_after = retry_after(&response);
+    let body = response.bytes().await?;
+
+    from_slice(status, &body).map_err(|err| err.with_retry_after(retry_after))
+}
+
+/// Parse the `Retry-After` header of a response. Only the delay in seconds form is supported, which is what Paddle sends.
+pub(crate) fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
 }
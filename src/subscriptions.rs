@@ -1,28 +1,37 @@
 //! Builders for making requests to the Paddle API for subscription entities.
 //!
 //! See the [Paddle API](https://developer.paddle.com/api-reference/subscriptions/overview) documentation for more information.
+//!
+//! The Paddle API has no endpoint for importing subscriptions. Subscriptions from another billing platform are imported by Paddle as part of a migration, and arrive as `subscription.imported` events with `import_meta` set. Use [Subscription::external_id] to match them to the records of the previous platform.
 
 use chrono::prelude::*;
+use chrono::{Days, Months};
 use reqwest::Method;
 use serde::Serialize;
 use serde_with::skip_serializing_none;
 
 use crate::entities::{
-    BillingDetails, Subscription, SubscriptionDiscountEffectiveFrom, SubscriptionPreview,
-    SubscriptionWithInclude,
+    BillingDetails, Duration, Subscription, SubscriptionDiscountEffectiveFrom, SubscriptionPreview,
+    SubscriptionWithInclude, TransactionItemNonCatalogPrice,
 };
 use crate::enums::{
-    CollectionMode, CurrencyCode, EffectiveFrom, ProrationBillingMode, ScheduledChangeAction,
-    SubscriptionInclude, SubscriptionOnPaymentFailure, SubscriptionOnResume, SubscriptionStatus,
+    CollectionMode, CurrencyCode, EffectiveFrom, Interval, ProrationBillingMode,
+    ScheduledChangeAction, SubscriptionInclude, SubscriptionOnPaymentFailure, SubscriptionOnResume,
+    SubscriptionStatus,
+};
+use crate::ids::{
+    AddressID, BusinessID, CustomerID, DiscountID, PriceID, SubscriptionID, TransactionID,
 };
-use crate::ids::{AddressID, BusinessID, CustomerID, PriceID, SubscriptionID};
 use crate::paginated::Paginated;
+use crate::permissions::Permission;
+use crate::request::PaddleRequest;
 use crate::transactions::TransactionItem;
-use crate::{Paddle, Result};
+use crate::{Error, Paddle, Result};
 
 /// Request builder for fetching subscriptions from Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct SubscriptionsList<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -63,94 +72,129 @@ impl<'a> SubscriptionsList<'a> {
 
     /// Return entities related to the specified addresses.
     pub fn address_ids(
-        &mut self,
+        mut self,
         address_ids: impl IntoIterator<Item = impl Into<AddressID>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.address_id = Some(address_ids.into_iter().map(Into::into).collect());
         self
     }
 
     /// Return entities after the specified Paddle ID when working with paginated endpoints. Used in the `meta.pagination.next` URL in responses for list operations.
-    pub fn after(&mut self, id: impl Into<SubscriptionID>) -> &mut Self {
+    pub fn after(mut self, id: impl Into<SubscriptionID>) -> Self {
         self.after = Some(id.into());
         self
     }
 
     /// Return entities that match the specified collection mode.
-    pub fn collection_mode(&mut self, mode: CollectionMode) -> &mut Self {
+    pub fn collection_mode(mut self, mode: CollectionMode) -> Self {
         self.collection_mode = Some(mode);
         self
     }
 
     /// Return entities related to the specified customers.
     pub fn customer_id(
-        &mut self,
+        mut self,
         customer_ids: impl IntoIterator<Item = impl Into<CustomerID>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.customer_id = Some(customer_ids.into_iter().map(Into::into).collect());
         self
     }
 
     /// Return only the IDs specified.
-    pub fn id(&mut self, ids: impl IntoIterator<Item = impl Into<SubscriptionID>>) -> &mut Self {
+    pub fn ids(mut self, ids: impl IntoIterator<Item = impl Into<SubscriptionID>>) -> Self {
         self.id = Some(ids.into_iter().map(Into::into).collect());
         self
     }
 
+    /// Return only the IDs specified.
+    #[deprecated(note = "use `ids` instead")]
+    pub fn id(self, ids: impl IntoIterator<Item = impl Into<SubscriptionID>>) -> Self {
+        self.ids(ids)
+    }
+
     /// Order returned entities by the specified field. Valid fields for ordering: `id`
-    pub fn order_by_asc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_asc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[ASC]", field));
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: `id`
-    pub fn order_by_desc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_desc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[DESC]", field));
         self
     }
 
+    /// Number of entities Paddle returns per page when [Self::per_page] isn't set.
+    pub const DEFAULT_PER_PAGE: usize = 50;
+    /// Largest page size Paddle accepts for this endpoint.
+    pub const MAX_PER_PAGE: usize = 200;
+
     /// Set how many entities are returned per page. Paddle returns the maximum number of results if a number greater than the maximum is requested.
     /// Check `meta.pagination.per_page` in the response to see how many were returned.
     ///
     /// Default: `50`; Maximum: `200`.
-    pub fn per_page(&mut self, entities_per_page: usize) -> &mut Self {
+    pub fn per_page(mut self, entities_per_page: usize) -> Self {
         self.per_page = Some(entities_per_page);
         self
     }
 
+    /// Request the largest page Paddle allows for this endpoint. Use this to fetch everything in as few requests as possible.
+    pub fn max_page_size(self) -> Self {
+        self.per_page(Self::MAX_PER_PAGE)
+    }
+
     /// Return entities related to the specified prices.
-    pub fn price_ids(
-        &mut self,
-        price_ids: impl IntoIterator<Item = impl Into<PriceID>>,
-    ) -> &mut Self {
+    pub fn price_ids(mut self, price_ids: impl IntoIterator<Item = impl Into<PriceID>>) -> Self {
         self.price_id = Some(price_ids.into_iter().map(Into::into).collect());
         self
     }
 
     /// Return subscriptions that have a scheduled changes.
     pub fn scheduled_change_action(
-        &mut self,
+        mut self,
         actions: impl IntoIterator<Item = ScheduledChangeAction>,
-    ) -> &mut Self {
+    ) -> Self {
         self.scheduled_change_action = Some(actions.into_iter().collect());
         self
     }
 
     /// Return entities that match the specified subscription statuses.
-    pub fn status(&mut self, statuses: impl IntoIterator<Item = SubscriptionStatus>) -> &mut Self {
+    pub fn status(mut self, statuses: impl IntoIterator<Item = SubscriptionStatus>) -> Self {
         self.status = Some(statuses.into_iter().collect());
         self
     }
 
     /// Returns a paginator for fetching pages of entities from Paddle
     pub fn send(&self) -> Paginated<'_, Vec<Subscription>> {
-        Paginated::new(self.client, "/subscriptions", self)
+        Paginated::new(self.client, &self.path(), self)
+    }
+}
+
+impl PaddleRequest for SubscriptionsList<'_> {
+    type Output = Vec<Subscription>;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        "/subscriptions".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::SubscriptionRead]
     }
 }
 
 /// Request builder for fetching a specific subscription.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct SubscriptionGet<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -165,28 +209,40 @@ impl<'a> SubscriptionGet<'a> {
         Self {
             client,
             subscription_id: subscription_id.into(),
-            include: None,
+            include: client.defaults.subscription_include.clone(),
         }
     }
 
     /// Include related entities in the response.
-    pub fn include(
-        &mut self,
-        entities: impl IntoIterator<Item = SubscriptionInclude>,
-    ) -> &mut Self {
+    pub fn include(mut self, entities: impl IntoIterator<Item = SubscriptionInclude>) -> Self {
         self.include = Some(entities.into_iter().collect());
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<SubscriptionWithInclude> {
-        self.client
-            .send(
-                self,
-                Method::GET,
-                &format!("/subscriptions/{}", self.subscription_id.as_ref()),
-            )
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for SubscriptionGet<'_> {
+    type Output = SubscriptionWithInclude;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!("/subscriptions/{}", self.subscription_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::SubscriptionRead]
     }
 }
 
@@ -196,6 +252,8 @@ impl<'a> SubscriptionGet<'a> {
 /// Request builder for getting a preview of changes to a subscription without actually applying them.
 ///
 /// Typically used for previewing proration before making changes to a subscription.
+#[derive(Clone)]
+#[must_use]
 pub struct SubscriptionPreviewUpdate<'a> {
     client: &'a Paddle,
     subscription_id: SubscriptionID,
@@ -212,19 +270,19 @@ impl<'a> SubscriptionPreviewUpdate<'a> {
     }
 
     /// The customer ID to use for the preview. Include to change the customer for a subscription.
-    pub fn customer_id(&mut self, customer_id: impl Into<CustomerID>) -> &mut Self {
+    pub fn customer_id(mut self, customer_id: impl Into<CustomerID>) -> Self {
         self.data["customer_id"] = serde_json::json!(customer_id.into());
         self
     }
 
     /// The address ID to use for the preview. Include to change the address for a subscription.
-    pub fn address_id(&mut self, address_id: impl Into<AddressID>) -> &mut Self {
+    pub fn address_id(mut self, address_id: impl Into<AddressID>) -> Self {
         self.data["address_id"] = serde_json::json!(address_id.into());
         self
     }
 
     /// The business ID to use for the preview. Include to change the business for a subscription.
-    pub fn business_id(&mut self, business_id: impl Into<BusinessID>) -> &mut Self {
+    pub fn business_id(mut self, business_id: impl Into<BusinessID>) -> Self {
         self.data["business_id"] = serde_json::json!(business_id.into());
         self
     }
@@ -232,34 +290,50 @@ impl<'a> SubscriptionPreviewUpdate<'a> {
     /// Supported currency code. Include to change the currency that a subscription bills in.
     ///
     /// When changing `collection_mode` to `manual`, you may need to change currency code to `USD`, `EUR`, or `GBP`.
-    pub fn currency_code(&mut self, currency_code: CurrencyCode) -> &mut Self {
+    pub fn currency_code(mut self, currency_code: CurrencyCode) -> Self {
         self.data["currency_code"] = serde_json::json!(currency_code);
         self
     }
 
     /// Datetime of when this subscription is next scheduled to be billed. Include to change the next billing date.
-    pub fn next_billed_at(&mut self, next_billed_at: DateTime<Utc>) -> &mut Self {
+    pub fn next_billed_at(mut self, next_billed_at: DateTime<Utc>) -> Self {
         self.data["next_billed_at"] = serde_json::json!(next_billed_at);
         self
     }
 
     /// Details of the discount applied to this subscription. Include to add a discount to a subscription. None to remove a discount.
-    pub fn set_discount(
-        &mut self,
-        discount: Option<SubscriptionDiscountEffectiveFrom>,
-    ) -> &mut Self {
+    pub fn set_discount(mut self, discount: Option<SubscriptionDiscountEffectiveFrom>) -> Self {
         self.data["discount"] = serde_json::json!(discount);
         self
     }
 
+    /// Apply a discount to this subscription, replacing any existing discount.
+    ///
+    /// Use `EffectiveFrom::Immediately` to apply a discount in the middle of a billing period, e.g. when offering a retention discount.
+    pub fn discount(
+        self,
+        discount_id: impl Into<DiscountID>,
+        effective_from: EffectiveFrom,
+    ) -> Self {
+        self.set_discount(Some(SubscriptionDiscountEffectiveFrom::new(
+            discount_id,
+            effective_from,
+        )))
+    }
+
+    /// Remove the discount applied to this subscription.
+    pub fn remove_discount(self) -> Self {
+        self.set_discount(None)
+    }
+
     /// How payment is collected for transactions created for this subscription. `automatic` for checkout, `manual` for invoices.
-    pub fn collection_mode(&mut self, mode: CollectionMode) -> &mut Self {
+    pub fn collection_mode(mut self, mode: CollectionMode) -> Self {
         self.data["collection_mode"] = serde_json::json!(mode);
         self
     }
 
     /// Details for invoicing. Required if `collection_mode` is `manual`. `None` if changing `collection_mode` to `automatic`.
-    pub fn billing_details(&mut self, billing_details: Option<BillingDetails>) -> &mut Self {
+    pub fn billing_details(mut self, billing_details: Option<BillingDetails>) -> Self {
         self.data["billing_details"] = serde_json::json!(billing_details);
         self
     }
@@ -269,19 +343,19 @@ impl<'a> SubscriptionPreviewUpdate<'a> {
     /// When updating, you may only set to `null` to remove a scheduled change.
     ///
     /// Use the pause subscription, cancel subscription, and resume subscription operations to create scheduled changes.
-    pub fn unset_scheduled_change(&mut self) -> &mut Self {
+    pub fn unset_scheduled_change(mut self) -> Self {
         self.data["scheduled_change"] = serde_json::json!(null);
         self
     }
 
     /// List of items on this subscription. Only recurring items may be added. Send the complete list of items that should be on this subscription, including existing items to retain.
-    pub fn items(&mut self, items: impl IntoIterator<Item = TransactionItem>) -> &mut Self {
+    pub fn items(mut self, items: impl IntoIterator<Item = TransactionItem>) -> Self {
         self.data["items"] = serde_json::json!(items.into_iter().collect::<Vec<_>>());
         self
     }
 
     /// Your own structured key-value data.
-    pub fn custom_data(&mut self, custom_data: serde_json::Value) -> &mut Self {
+    pub fn custom_data(mut self, custom_data: serde_json::Value) -> Self {
         self.data["custom_data"] = custom_data;
         self
     }
@@ -289,26 +363,43 @@ impl<'a> SubscriptionPreviewUpdate<'a> {
     /// How Paddle should handle proration calculation for changes made to a subscription or its items. Required when making changes that impact billing.
     ///
     /// For automatically-collected subscriptions, responses may take longer than usual if a proration billing mode that collects for payment immediately is used.
-    pub fn proration_billing_mode(&mut self, mode: ProrationBillingMode) -> &mut Self {
+    pub fn proration_billing_mode(mut self, mode: ProrationBillingMode) -> Self {
         self.data["proration_billing_mode"] = serde_json::json!(mode);
         self
     }
 
     /// How Paddle should handle changes made to a subscription or its items if the payment fails during update. If omitted, defaults to `prevent_change`.
-    pub fn on_payment_failure(&mut self, mode: SubscriptionOnPaymentFailure) -> &mut Self {
+    ///
+    /// With `prevent_change`, a failed payment is returned as an error where [Error::is_payment_declined](crate::Error::is_payment_declined) is `true`.
+    pub fn on_payment_failure(mut self, mode: SubscriptionOnPaymentFailure) -> Self {
         self.data["on_payment_failure"] = serde_json::json!(mode);
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<SubscriptionPreview> {
-        self.client
-            .send(
-                &self.data,
-                Method::PATCH,
-                &format!("/subscriptions/{}/preview", self.subscription_id.as_ref()),
-            )
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for SubscriptionPreviewUpdate<'_> {
+    type Output = SubscriptionPreview;
+    type Payload = serde_json::Value;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn path(&self) -> String {
+        format!("/subscriptions/{}/preview", self.subscription_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        &self.data
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::SubscriptionRead]
     }
 }
 
@@ -324,10 +415,14 @@ impl<'a> SubscriptionPreviewUpdate<'a> {
 /// For each item, send `price_id` and `quantity`. Paddle responds with the full price object for each price. If you're updating an existing item, you can omit the `quantity` if you don't want to update it.
 ///
 /// If successful, your response includes a copy of the updated subscription entity. When an update results in an immediate charge, responses may take longer than usual while a payment attempt is processed.
+#[derive(Clone)]
+#[must_use]
 pub struct SubscriptionUpdate<'a> {
     client: &'a Paddle,
     subscription_id: SubscriptionID,
     data: serde_json::Value,
+    requires_proration_billing_mode: bool,
+    include: Option<Vec<SubscriptionInclude>>,
 }
 
 impl<'a> SubscriptionUpdate<'a> {
@@ -336,23 +431,25 @@ impl<'a> SubscriptionUpdate<'a> {
             client,
             subscription_id: subscription_id.into(),
             data: serde_json::json!({}),
+            requires_proration_billing_mode: false,
+            include: None,
         }
     }
 
     /// The customer ID to use for the preview. Include to change the customer for a subscription.
-    pub fn customer_id(&mut self, customer_id: impl Into<CustomerID>) -> &mut Self {
+    pub fn customer_id(mut self, customer_id: impl Into<CustomerID>) -> Self {
         self.data["customer_id"] = serde_json::json!(customer_id.into());
         self
     }
 
     /// The address ID to use for the preview. Include to change the address for a subscription.
-    pub fn address_id(&mut self, address_id: impl Into<AddressID>) -> &mut Self {
+    pub fn address_id(mut self, address_id: impl Into<AddressID>) -> Self {
         self.data["address_id"] = serde_json::json!(address_id.into());
         self
     }
 
     /// The business ID to use for the preview. Include to change the business for a subscription.
-    pub fn business_id(&mut self, business_id: impl Into<BusinessID>) -> &mut Self {
+    pub fn business_id(mut self, business_id: impl Into<BusinessID>) -> Self {
         self.data["business_id"] = serde_json::json!(business_id.into());
         self
     }
@@ -360,56 +457,124 @@ impl<'a> SubscriptionUpdate<'a> {
     /// Supported currency code. Include to change the currency that a subscription bills in.
     ///
     /// When changing `collection_mode` to `manual`, you may need to change currency code to `USD`, `EUR`, or `GBP`.
-    pub fn currency_code(&mut self, currency_code: CurrencyCode) -> &mut Self {
+    pub fn currency_code(mut self, currency_code: CurrencyCode) -> Self {
         self.data["currency_code"] = serde_json::json!(currency_code);
         self
     }
 
     /// Datetime of when this subscription is next scheduled to be billed. Include to change the next billing date.
-    pub fn next_billed_at(&mut self, next_billed_at: DateTime<Utc>) -> &mut Self {
+    pub fn next_billed_at(mut self, next_billed_at: DateTime<Utc>) -> Self {
         self.data["next_billed_at"] = serde_json::json!(next_billed_at);
         self
     }
 
+    /// Same as [next_billed_at](Self::next_billed_at), but checks the date before the request is sent.
+    ///
+    /// - `next_billed_at` must be in the future.
+    /// - `next_billed_at` must be within one `billing_cycle` of the subscription from now, so that no billing period is skipped.
+    ///
+    /// Paddle requires a proration billing mode when changing the billing date. [send](Self::send) returns [Error::Validation] if [proration_billing_mode](Self::proration_billing_mode) isn't set.
+    pub fn next_billed_at_checked(
+        mut self,
+        next_billed_at: DateTime<Utc>,
+        billing_cycle: &Duration,
+    ) -> std::result::Result<Self, Error> {
+        let now = Utc::now();
+
+        if next_billed_at <= now {
+            return Err(Error::Validation(format!(
+                "next_billed_at {} is not in the future",
+                next_billed_at
+            )));
+        }
+
+        let latest = add_billing_cycle(now, billing_cycle).ok_or_else(|| {
+            Error::Validation("billing cycle of the subscription is out of range".to_string())
+        })?;
+
+        if next_billed_at > latest {
+            return Err(Error::Validation(format!(
+                "next_billed_at {} is more than one billing cycle ({} {:?}) from now",
+                next_billed_at, billing_cycle.frequency, billing_cycle.interval
+            )));
+        }
+
+        self.requires_proration_billing_mode = true;
+
+        Ok(self.next_billed_at(next_billed_at))
+    }
+
     /// Details of the discount applied to this subscription. Include to add a discount to a subscription. None to remove a discount.
-    pub fn set_discount(
-        &mut self,
-        discount: Option<SubscriptionDiscountEffectiveFrom>,
-    ) -> &mut Self {
+    pub fn set_discount(mut self, discount: Option<SubscriptionDiscountEffectiveFrom>) -> Self {
         self.data["discount"] = serde_json::json!(discount);
         self
     }
 
+    /// Apply a discount to this subscription, replacing any existing discount.
+    ///
+    /// Use `EffectiveFrom::Immediately` to apply a discount in the middle of a billing period, e.g. when offering a retention discount.
+    pub fn discount(
+        self,
+        discount_id: impl Into<DiscountID>,
+        effective_from: EffectiveFrom,
+    ) -> Self {
+        self.set_discount(Some(SubscriptionDiscountEffectiveFrom::new(
+            discount_id,
+            effective_from,
+        )))
+    }
+
+    /// Remove the discount applied to this subscription.
+    pub fn remove_discount(self) -> Self {
+        self.set_discount(None)
+    }
+
     /// How payment is collected for transactions created for this subscription. `automatic` for checkout, `manual` for invoices.
-    pub fn collection_mode(&mut self, mode: CollectionMode) -> &mut Self {
+    pub fn collection_mode(mut self, mode: CollectionMode) -> Self {
         self.data["collection_mode"] = serde_json::json!(mode);
         self
     }
 
     /// Details for invoicing. Required if `collection_mode` is `manual`. `None` if changing `collection_mode` to `automatic`.
-    pub fn billing_details(&mut self, billing_details: Option<BillingDetails>) -> &mut Self {
+    pub fn billing_details(mut self, billing_details: Option<BillingDetails>) -> Self {
         self.data["billing_details"] = serde_json::json!(billing_details);
         self
     }
 
+    /// Switch this subscription to manual collection, so transactions are sent as invoices. Sets `collection_mode` to `manual` and `billing_details`.
+    ///
+    /// ```rust,no_run
+    /// use paddle_rust_sdk::entities::BillingDetails;
+    ///
+    /// client
+    ///     .subscription_update("sub_01hv8x29kz0t586xy6zn1a62ny")
+    ///     .collect_manually(BillingDetails::payment_terms_days(30).purchase_order_number("PO-123"))
+    ///     .send()
+    ///     .await?;
+    /// ```
+    pub fn collect_manually(self, billing_details: BillingDetails) -> Self {
+        self.collection_mode(CollectionMode::Manual)
+            .billing_details(Some(billing_details))
+    }
+
     /// Change that's scheduled to be applied to a subscription.
     ///
     /// When updating, you may only set to `null` to remove a scheduled change.
     ///
     /// Use the pause subscription, cancel subscription, and resume subscription operations to create scheduled changes.
-    pub fn unset_scheduled_change(&mut self) -> &mut Self {
+    pub fn unset_scheduled_change(mut self) -> Self {
         self.data["scheduled_change"] = serde_json::json!(null);
         self
     }
 
     /// List of items on this subscription. Only recurring items may be added. Send the complete list of items that should be on this subscription, including existing items to retain.
-    pub fn items(&mut self, items: impl IntoIterator<Item = TransactionItem>) -> &mut Self {
+    pub fn items(mut self, items: impl IntoIterator<Item = TransactionItem>) -> Self {
         self.data["items"] = serde_json::json!(items.into_iter().collect::<Vec<_>>());
         self
     }
 
     /// Your own structured key-value data.
-    pub fn custom_data(&mut self, custom_data: serde_json::Value) -> &mut Self {
+    pub fn custom_data(mut self, custom_data: serde_json::Value) -> Self {
         self.data["custom_data"] = custom_data;
         self
     }
@@ -417,32 +582,83 @@ impl<'a> SubscriptionUpdate<'a> {
     /// How Paddle should handle proration calculation for changes made to a subscription or its items. Required when making changes that impact billing.
     ///
     /// For automatically-collected subscriptions, responses may take longer than usual if a proration billing mode that collects for payment immediately is used.
-    pub fn proration_billing_mode(&mut self, mode: ProrationBillingMode) -> &mut Self {
+    pub fn proration_billing_mode(mut self, mode: ProrationBillingMode) -> Self {
         self.data["proration_billing_mode"] = serde_json::json!(mode);
         self
     }
 
     /// How Paddle should handle changes made to a subscription or its items if the payment fails during update. If omitted, defaults to `prevent_change`.
-    pub fn on_payment_failure(&mut self, mode: SubscriptionOnPaymentFailure) -> &mut Self {
+    ///
+    /// With `prevent_change`, a failed payment is returned as an error where [Error::is_payment_declined](crate::Error::is_payment_declined) is `true`.
+    pub fn on_payment_failure(mut self, mode: SubscriptionOnPaymentFailure) -> Self {
         self.data["on_payment_failure"] = serde_json::json!(mode);
         self
     }
 
+    /// Include related entities in the response. Use [Self::send_with_include] to get them.
+    pub fn include(mut self, entities: impl IntoIterator<Item = SubscriptionInclude>) -> Self {
+        self.include = Some(entities.into_iter().collect());
+        self
+    }
+
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Subscription> {
+        self.validate()?;
+        self.client.execute(self).await
+    }
+
+    /// Send the request to Paddle and return the response, including the related entities requested with [Self::include].
+    ///
+    /// Saves fetching the subscription again after the change.
+    pub async fn send_with_include(&self) -> Result<SubscriptionWithInclude> {
+        self.validate()?;
+
         self.client
-            .send(
-                &self.data,
-                Method::PATCH,
-                &format!("/subscriptions/{}", self.subscription_id.as_ref()),
-            )
+            .request(self.method(), &self.path(), self.payload())
             .await
     }
+
+    fn validate(&self) -> std::result::Result<(), Error> {
+        if self.requires_proration_billing_mode && self.data["proration_billing_mode"].is_null() {
+            return Err(Error::Validation(
+                "proration_billing_mode is required when changing next_billed_at".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl PaddleRequest for SubscriptionUpdate<'_> {
+    type Output = Subscription;
+    type Payload = serde_json::Value;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn path(&self) -> String {
+        let path = format!("/subscriptions/{}", self.subscription_id.as_ref());
+
+        match &self.include {
+            Some(include) => format!("{}?include={}", path, crate::enum_list(include)),
+            None => path,
+        }
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        &self.data
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::SubscriptionWrite]
+    }
 }
 
 /// Request builder for creating a preview of one-time charge for a subscription without billing that charge.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct SubscriptionOneTimeChargePreview<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -465,7 +681,7 @@ impl<'a> SubscriptionOneTimeChargePreview<'a> {
     }
 
     /// When one-time charges should be billed.
-    pub fn effective_from(&mut self, effective_from: EffectiveFrom) -> &mut Self {
+    pub fn effective_from(mut self, effective_from: EffectiveFrom) -> Self {
         self.effective_from = Some(effective_from);
         self
     }
@@ -475,40 +691,87 @@ impl<'a> SubscriptionOneTimeChargePreview<'a> {
     /// You can charge for items that you've added to your catalog by passing the Paddle ID of an existing price entity, or you can charge for non-catalog items by passing a price object.
     ///
     /// Non-catalog items can be for existing products, or you can pass a product object as part of your price to charge for a non-catalog product.
-    pub fn items(&mut self, items: impl IntoIterator<Item = TransactionItem>) -> &mut Self {
+    pub fn items(mut self, items: impl IntoIterator<Item = TransactionItem>) -> Self {
         self.items = items.into_iter().collect();
         self
     }
 
+    /// Append to the list of one-time charges to bill for.
+    ///
+    /// You can charge for items that you've added to your catalog by passing the Paddle ID of an existing price entity.
+    ///
+    /// To charge for non-catalog items see append_non_catalog_item.
+    pub fn append_catalog_item(mut self, price_id: impl Into<PriceID>, quantity: u32) -> Self {
+        self.items.push(TransactionItem::CatalogItem {
+            price_id: price_id.into(),
+            quantity,
+        });
+
+        self
+    }
+
+    /// Append to the list of one-time charges to bill for.
+    ///
+    /// You can charge for non-catalog items by passing a `TransactionItemNonCatalogPrice` object.
+    pub fn append_non_catalog_item(
+        mut self,
+        price: TransactionItemNonCatalogPrice,
+        quantity: u32,
+    ) -> Self {
+        self.items
+            .push(TransactionItem::NonCatalogItem { price, quantity });
+        self
+    }
+
     /// How Paddle should handle changes made to a subscription or its items if the payment fails during update. If omitted, defaults to `prevent_change`.
-    pub fn on_payment_failure(&mut self, mode: SubscriptionOnPaymentFailure) -> &mut Self {
+    ///
+    /// With `prevent_change`, a failed payment is returned as an error where [Error::is_payment_declined](crate::Error::is_payment_declined) is `true`.
+    pub fn on_payment_failure(mut self, mode: SubscriptionOnPaymentFailure) -> Self {
         self.on_payment_failure = Some(mode);
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<SubscriptionPreview> {
-        self.client
-            .send(
-                self,
-                Method::POST,
-                &format!(
-                    "/subscriptions/{}/charge/preview",
-                    self.subscription_id.as_ref()
-                ),
-            )
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for SubscriptionOneTimeChargePreview<'_> {
+    type Output = SubscriptionPreview;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "/subscriptions/{}/charge/preview",
+            self.subscription_id.as_ref()
+        )
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::SubscriptionRead]
     }
 }
 
 /// Request builder for creating a new one-time charge for a subscription.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct SubscriptionOneTimeCharge<'a> {
     #[serde(skip)]
     client: &'a Paddle,
     #[serde(skip)]
     subscription_id: SubscriptionID,
+    #[serde(skip)]
+    include: Option<Vec<SubscriptionInclude>>,
     effective_from: Option<EffectiveFrom>,
     items: Vec<TransactionItem>,
     on_payment_failure: Option<SubscriptionOnPaymentFailure>,
@@ -519,6 +782,7 @@ impl<'a> SubscriptionOneTimeCharge<'a> {
         Self {
             client,
             subscription_id: subscription_id.into(),
+            include: None,
             effective_from: None,
             items: Vec::default(),
             on_payment_failure: None,
@@ -526,7 +790,7 @@ impl<'a> SubscriptionOneTimeCharge<'a> {
     }
 
     /// When one-time charges should be billed.
-    pub fn effective_from(&mut self, effective_from: EffectiveFrom) -> &mut Self {
+    pub fn effective_from(mut self, effective_from: EffectiveFrom) -> Self {
         self.effective_from = Some(effective_from);
         self
     }
@@ -536,37 +800,104 @@ impl<'a> SubscriptionOneTimeCharge<'a> {
     /// You can charge for items that you've added to your catalog by passing the Paddle ID of an existing price entity, or you can charge for non-catalog items by passing a price object.
     ///
     /// Non-catalog items can be for existing products, or you can pass a product object as part of your price to charge for a non-catalog product.
-    pub fn items(&mut self, items: impl IntoIterator<Item = TransactionItem>) -> &mut Self {
+    pub fn items(mut self, items: impl IntoIterator<Item = TransactionItem>) -> Self {
         self.items = items.into_iter().collect();
         self
     }
 
+    /// Append to the list of one-time charges to bill for.
+    ///
+    /// You can charge for items that you've added to your catalog by passing the Paddle ID of an existing price entity.
+    ///
+    /// To charge for non-catalog items see append_non_catalog_item.
+    pub fn append_catalog_item(mut self, price_id: impl Into<PriceID>, quantity: u32) -> Self {
+        self.items.push(TransactionItem::CatalogItem {
+            price_id: price_id.into(),
+            quantity,
+        });
+
+        self
+    }
+
+    /// Append to the list of one-time charges to bill for.
+    ///
+    /// You can charge for non-catalog items by passing a `TransactionItemNonCatalogPrice` object.
+    pub fn append_non_catalog_item(
+        mut self,
+        price: TransactionItemNonCatalogPrice,
+        quantity: u32,
+    ) -> Self {
+        self.items
+            .push(TransactionItem::NonCatalogItem { price, quantity });
+        self
+    }
+
     /// How Paddle should handle changes made to a subscription or its items if the payment fails during update. If omitted, defaults to `prevent_change`.
-    pub fn on_payment_failure(&mut self, mode: SubscriptionOnPaymentFailure) -> &mut Self {
+    ///
+    /// With `prevent_change`, a failed payment is returned as an error where [Error::is_payment_declined](crate::Error::is_payment_declined) is `true`.
+    pub fn on_payment_failure(mut self, mode: SubscriptionOnPaymentFailure) -> Self {
         self.on_payment_failure = Some(mode);
         self
     }
 
+    /// Include related entities in the response. Use [Self::send_with_include] to get them.
+    pub fn include(mut self, entities: impl IntoIterator<Item = SubscriptionInclude>) -> Self {
+        self.include = Some(entities.into_iter().collect());
+        self
+    }
+
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Subscription> {
+        self.client.execute(self).await
+    }
+
+    /// Send the request to Paddle and return the response, including the related entities requested with [Self::include].
+    ///
+    /// Saves fetching the subscription again after the change.
+    pub async fn send_with_include(&self) -> Result<SubscriptionWithInclude> {
         self.client
-            .send(
-                self,
-                Method::POST,
-                &format!("/subscriptions/{}/charge", self.subscription_id.as_ref()),
-            )
+            .request(self.method(), &self.path(), self.payload())
             .await
     }
 }
 
+impl PaddleRequest for SubscriptionOneTimeCharge<'_> {
+    type Output = Subscription;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        let path = format!("/subscriptions/{}/charge", self.subscription_id.as_ref());
+
+        match &self.include {
+            Some(include) => format!("{}?include={}", path, crate::enum_list(include)),
+            None => path,
+        }
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::SubscriptionWrite]
+    }
+}
+
 /// Request builder for pausing a subscription.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct SubscriptionPause<'a> {
     #[serde(skip)]
     client: &'a Paddle,
     #[serde(skip)]
     subscription_id: SubscriptionID,
+    #[serde(skip)]
+    include: Option<Vec<SubscriptionInclude>>,
     effective_from: Option<EffectiveFrom>,
     resume_at: Option<DateTime<Utc>>,
     on_resume: Option<SubscriptionOnResume>,
@@ -577,6 +908,7 @@ impl<'a> SubscriptionPause<'a> {
         Self {
             client,
             subscription_id: subscription_id.into(),
+            include: None,
             effective_from: None,
             resume_at: None,
             on_resume: None,
@@ -586,43 +918,81 @@ impl<'a> SubscriptionPause<'a> {
     /// When this subscription change should take effect from.
     ///
     /// Defaults to `next_billing_period` for active subscriptions, which creates a `scheduled_change` to apply the subscription change at the end of the billing period.
-    pub fn effective_from(&mut self, effective_from: EffectiveFrom) -> &mut Self {
+    pub fn effective_from(mut self, effective_from: EffectiveFrom) -> Self {
         self.effective_from = Some(effective_from);
         self
     }
 
     /// Datetime of when the paused subscription should resume. Omit to pause indefinitely until resumed.
-    pub fn resume_at(&mut self, datetime: DateTime<Utc>) -> &mut Self {
+    pub fn resume_at(mut self, datetime: DateTime<Utc>) -> Self {
         self.resume_at = Some(datetime);
         self
     }
 
     /// How Paddle should set the billing period for the subscription when resuming. If omitted, defaults to `start_new_billing_period`.
-    pub fn on_resume(&mut self, value: SubscriptionOnResume) -> &mut Self {
+    pub fn on_resume(mut self, value: SubscriptionOnResume) -> Self {
         self.on_resume = Some(value);
         self
     }
 
+    /// Include related entities in the response. Use [Self::send_with_include] to get them.
+    pub fn include(mut self, entities: impl IntoIterator<Item = SubscriptionInclude>) -> Self {
+        self.include = Some(entities.into_iter().collect());
+        self
+    }
+
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Subscription> {
+        self.client.execute(self).await
+    }
+
+    /// Send the request to Paddle and return the response, including the related entities requested with [Self::include].
+    ///
+    /// Saves fetching the subscription again after the change.
+    pub async fn send_with_include(&self) -> Result<SubscriptionWithInclude> {
         self.client
-            .send(
-                self,
-                Method::POST,
-                &format!("/subscriptions/{}/pause", self.subscription_id.as_ref()),
-            )
+            .request(self.method(), &self.path(), self.payload())
             .await
     }
 }
 
+impl PaddleRequest for SubscriptionPause<'_> {
+    type Output = Subscription;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        let path = format!("/subscriptions/{}/pause", self.subscription_id.as_ref());
+
+        match &self.include {
+            Some(include) => format!("{}?include={}", path, crate::enum_list(include)),
+            None => path,
+        }
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::SubscriptionWrite]
+    }
+}
+
 /// Request builder for resuming a subscription.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct SubscriptionResume<'a> {
     #[serde(skip)]
     client: &'a Paddle,
     #[serde(skip)]
     subscription_id: SubscriptionID,
+    #[serde(skip)]
+    include: Option<Vec<SubscriptionInclude>>,
     // Defaults to `immediately` if omitted.
     effective_from: Option<DateTime<Utc>>,
     on_resume: Option<SubscriptionOnResume>,
@@ -633,6 +1003,7 @@ impl<'a> SubscriptionResume<'a> {
         Self {
             client,
             subscription_id: subscription_id.into(),
+            include: None,
             effective_from: None,
             on_resume: None,
         }
@@ -641,37 +1012,91 @@ impl<'a> SubscriptionResume<'a> {
     /// When this subscription change should take effect from.
     ///
     /// Defaults to `next_billing_period` for active subscriptions, which creates a `scheduled_change` to apply the subscription change at the end of the billing period.
-    pub fn effective_from(&mut self, effective_from: DateTime<Utc>) -> &mut Self {
+    pub fn effective_from(mut self, effective_from: DateTime<Utc>) -> Self {
         self.effective_from = Some(effective_from);
         self
     }
 
     /// How Paddle should set the billing period for the subscription when resuming. If omitted, defaults to `start_new_billing_period`.
-    pub fn on_resume(&mut self, value: SubscriptionOnResume) -> &mut Self {
+    pub fn on_resume(mut self, value: SubscriptionOnResume) -> Self {
         self.on_resume = Some(value);
         self
     }
 
+    /// Include related entities in the response. Use [Self::send_with_include] to get them.
+    pub fn include(mut self, entities: impl IntoIterator<Item = SubscriptionInclude>) -> Self {
+        self.include = Some(entities.into_iter().collect());
+        self
+    }
+
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Subscription> {
+        self.client.execute(self).await
+    }
+
+    /// Send the request to Paddle and return the response, including the related entities requested with [Self::include].
+    ///
+    /// Saves fetching the subscription again after the change.
+    pub async fn send_with_include(&self) -> Result<SubscriptionWithInclude> {
         self.client
-            .send(
-                self,
-                Method::POST,
-                &format!("/subscriptions/{}/resume", self.subscription_id.as_ref()),
-            )
+            .request(self.method(), &self.path(), self.payload())
             .await
     }
 }
 
-/// Request builder for resuming a subscription.
+impl PaddleRequest for SubscriptionResume<'_> {
+    type Output = Subscription;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        let path = format!("/subscriptions/{}/resume", self.subscription_id.as_ref());
+
+        match &self.include {
+            Some(include) => format!("{}?include={}", path, crate::enum_list(include)),
+            None => path,
+        }
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::SubscriptionWrite]
+    }
+}
+
+/// How often [Paddle::await_subscription_for_transaction] checks whether the subscription was created.
+pub(crate) const SUBSCRIPTION_POLL_INTERVAL: std::time::Duration =
+    std::time::Duration::from_secs(5);
+
+/// Checkout for a new subscription, created by [Paddle::create_subscription_checkout].
+#[derive(Clone, Debug)]
+pub struct SubscriptionCheckout {
+    /// Transaction the customer pays at checkout. Pass it to [Paddle::await_subscription_for_transaction] to get the subscription once it's paid.
+    pub transaction_id: TransactionID,
+    /// Paddle Checkout URL to send the customer to.
+    pub url: String,
+}
+
+/// Key in `custom_data` under which [SubscriptionCancel::send_with_reason] stores the cancellation reason.
+pub const CANCELLATION_REASON_KEY: &str = "cancellation_reason";
+
+/// Request builder for canceling a subscription.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct SubscriptionCancel<'a> {
     #[serde(skip)]
     client: &'a Paddle,
     #[serde(skip)]
     subscription_id: SubscriptionID,
+    #[serde(skip)]
+    include: Option<Vec<SubscriptionInclude>>,
     effective_from: Option<EffectiveFrom>,
 }
 
@@ -680,6 +1105,7 @@ impl<'a> SubscriptionCancel<'a> {
         Self {
             client,
             subscription_id: subscription_id.into(),
+            include: None,
             effective_from: None,
         }
     }
@@ -687,19 +1113,240 @@ impl<'a> SubscriptionCancel<'a> {
     /// When this subscription change should take effect from.
     ///
     /// Defaults to `next_billing_period` for active subscriptions, which creates a `scheduled_change` to apply the subscription change at the end of the billing period.
-    pub fn effective_from(&mut self, effective_from: EffectiveFrom) -> &mut Self {
+    pub fn effective_from(mut self, effective_from: EffectiveFrom) -> Self {
         self.effective_from = Some(effective_from);
         self
     }
 
+    /// Include related entities in the response. Use [Self::send_with_include] to get them.
+    pub fn include(mut self, entities: impl IntoIterator<Item = SubscriptionInclude>) -> Self {
+        self.include = Some(entities.into_iter().collect());
+        self
+    }
+
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Subscription> {
+        self.client.execute(self).await
+    }
+
+    /// Send the request to Paddle and return the response, including the related entities requested with [Self::include].
+    ///
+    /// Saves fetching the subscription again after the change.
+    pub async fn send_with_include(&self) -> Result<SubscriptionWithInclude> {
         self.client
-            .send(
-                self,
-                Method::POST,
-                &format!("/subscriptions/{}/cancel", self.subscription_id.as_ref()),
-            )
+            .request(self.method(), &self.path(), self.payload())
             .await
     }
+
+    /// Record why the subscription is being canceled, then cancel it.
+    ///
+    /// The reason is stored in `custom_data` of the subscription under [CANCELLATION_REASON_KEY], keeping any other custom data. Paddle doesn't allow changes to canceled subscriptions, so the reason is stored before canceling. If storing the reason fails, the subscription isn't canceled.
+    ///
+    /// Returns the response of the cancel request, which includes the updated `custom_data`.
+    pub async fn send_with_reason(&self, reason: impl Into<String>) -> Result<Subscription> {
+        let subscription = self
+            .client
+            .subscription_get(self.subscription_id.clone())
+            .send()
+            .await?
+            .data
+            .subscription;
+
+        let mut custom_data = match subscription.custom_data {
+            Some(serde_json::Value::Object(custom_data)) => custom_data,
+            _ => serde_json::Map::new(),
+        };
+
+        custom_data.insert(
+            CANCELLATION_REASON_KEY.to_string(),
+            serde_json::Value::String(reason.into()),
+        );
+
+        self.client
+            .subscription_update(self.subscription_id.clone())
+            .custom_data(serde_json::Value::Object(custom_data))
+            .send()
+            .await?;
+
+        self.send().await
+    }
+}
+
+impl PaddleRequest for SubscriptionCancel<'_> {
+    type Output = Subscription;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        let path = format!("/subscriptions/{}/cancel", self.subscription_id.as_ref());
+
+        match &self.include {
+            Some(include) => format!("{}?include={}", path, crate::enum_list(include)),
+            None => path,
+        }
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::SubscriptionWrite]
+    }
+}
+
+/// Add one billing cycle to a date. `None` if the result is out of range.
+fn add_billing_cycle(date: DateTime<Utc>, billing_cycle: &Duration) -> Option<DateTime<Utc>> {
+    let frequency = u32::try_from(billing_cycle.frequency).ok()?;
+
+    match billing_cycle.interval {
+        Interval::Day => date.checked_add_days(Days::new(frequency.into())),
+        Interval::Week => date.checked_add_days(Days::new(u64::from(frequency) * 7)),
+        Interval::Month => date.checked_add_months(Months::new(frequency)),
+        Interval::Year => date.checked_add_months(Months::new(frequency.checked_mul(12)?)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    #[test]
+    fn collect_manually_sets_collection_mode_and_billing_details() {
+        let client = Paddle::new("key", Paddle::SANDBOX).unwrap();
+
+        let update = client
+            .subscription_update("sub_01hv8x29kz0t586xy6zn1a62ny")
+            .collect_manually(
+                BillingDetails::payment_terms_days(30)
+                    .purchase_order_number("PO-123")
+                    .enable_checkout(true),
+            );
+
+        assert_eq!(update.data["collection_mode"], "manual");
+        assert_eq!(
+            update.data["billing_details"],
+            json!({
+                "enable_checkout": true,
+                "purchase_order_number": "PO-123",
+                "additional_information": null,
+                "payment_terms": { "interval": "day", "frequency": 30 }
+            })
+        );
+    }
+
+    #[test]
+    fn subscription_accessors() {
+        let mut subscription = fixtures::subscription();
+        let mut addon = subscription["items"][0].clone();
+        addon["quantity"] = 3.into();
+        addon["price"]["unit_price"]["amount"] = "500".into();
+        let mut one_off = addon.clone();
+        one_off["recurring"] = false.into();
+        one_off["status"] = "inactive".into();
+        subscription["items"]
+            .as_array_mut()
+            .unwrap()
+            .extend([addon, one_off]);
+        subscription["scheduled_change"] = json!({
+            "action": "cancel",
+            "effective_at": "2024-05-12T10:18:47.635628Z",
+            "resume_at": null
+        });
+
+        let subscription: Subscription = fixtures::from_value(subscription);
+        assert!(!subscription.is_in_trial());
+        assert!(subscription.will_cancel());
+        assert!(!subscription.will_pause());
+        assert_eq!(subscription.active_items().count(), 2);
+
+        let total = subscription.total_recurring_amount().unwrap();
+        assert_eq!(total.amount, "4500");
+        assert_eq!(total.currency_code, CurrencyCode::USD);
+
+        let now = "2024-04-30T12:00:00Z".parse().unwrap();
+        assert_eq!(subscription.days_until_next_billing(now), Some(11));
+    }
+
+    #[test]
+    fn total_recurring_amount_rejects_invalid_amounts() {
+        let mut subscription = fixtures::subscription();
+        subscription["items"][0]["price"]["unit_price"]["amount"] = "10.50".into();
+        subscription["status"] = "trialing".into();
+        subscription["next_billed_at"] = serde_json::Value::Null;
+
+        let subscription: Subscription = fixtures::from_value(subscription);
+        assert!(subscription.is_in_trial());
+        assert!(subscription.total_recurring_amount().is_none());
+        assert_eq!(subscription.days_until_next_billing(Utc::now()), None);
+    }
+
+    #[test]
+    fn one_time_charge_items_are_appended() {
+        let client = Paddle::new("key", Paddle::SANDBOX).unwrap();
+        let expected = json!([
+            { "price_id": "pri_01gsz8x8sawmvhz1pv30nge1ke", "quantity": 2 },
+            {
+                "price": {
+                    "description": "Setup fee",
+                    "unit_price": { "amount": "5000", "currency_code": "USD" }
+                },
+                "quantity": 1
+            }
+        ]);
+
+        let charge = client
+            .subscription_one_time_charge("sub_01hv8x29kz0t586xy6zn1a62ny")
+            .append_catalog_item("pri_01gsz8x8sawmvhz1pv30nge1ke", 2)
+            .append_non_catalog_item(
+                TransactionItemNonCatalogPrice::new("Setup fee", 5000, CurrencyCode::USD),
+                1,
+            );
+        assert_eq!(serde_json::to_value(&charge).unwrap()["items"], expected);
+
+        let preview = client
+            .subscription_preview_one_time_charge("sub_01hv8x29kz0t586xy6zn1a62ny")
+            .append_catalog_item("pri_01gsz8x8sawmvhz1pv30nge1ke", 2)
+            .append_non_catalog_item(
+                TransactionItemNonCatalogPrice::new("Setup fee", 5000, CurrencyCode::USD),
+                1,
+            );
+        assert_eq!(serde_json::to_value(&preview).unwrap()["items"], expected);
+    }
+
+    #[test]
+    fn preview_totals() {
+        let transaction = |subtotal| {
+            json!({
+                "billing_period": fixtures::billing_period(),
+                "details": {
+                    "tax_rates_used": [],
+                    "totals": fixtures::transaction_totals(subtotal, None),
+                    "line_items": []
+                },
+                "adjustments": []
+            })
+        };
+
+        let mut preview = fixtures::subscription();
+        preview["next_transaction"] = transaction(3000);
+        preview["recurring_transaction_details"] = preview["next_transaction"]["details"].clone();
+
+        let without_immediate: SubscriptionPreview = fixtures::from_value(preview.clone());
+        assert!(without_immediate.immediate_total().is_none());
+
+        preview["immediate_transaction"] = transaction(500);
+        let preview: SubscriptionPreview = fixtures::from_value(preview);
+
+        let immediate = preview.immediate_total().unwrap();
+        assert_eq!(immediate.amount, "600");
+        assert_eq!(immediate.currency_code, CurrencyCode::USD);
+        assert_eq!(preview.next_billing_total().amount, "3600");
+    }
 }
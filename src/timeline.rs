@@ -0,0 +1,168 @@
+//! Billing activity of a subscription in chronological order, e.g. for an admin panel.
+//!
+//! ```rust,no_run
+//! use paddle_rust_sdk::timeline::TimelineEntry;
+//! use paddle_rust_sdk::Paddle;
+//!
+//! let client = Paddle::new(std::env::var("PADDLE_API_KEY")?, Paddle::PRODUCTION)?;
+//!
+//! let timeline = client.subscription_timeline("sub_01hv8x29kz0t586xy6zn1a62ny").await?;
+//!
+//! for entry in &timeline.entries {
+//!     match entry {
+//!         TimelineEntry::Event(event) => println!("{} {:?}", entry.occurred_at(), event.data.event_type()),
+//!         TimelineEntry::Transaction(transaction) => println!("{} {transaction}", entry.occurred_at()),
+//!         TimelineEntry::Adjustment(adjustment) => println!("{} {:?}", entry.occurred_at(), adjustment.action),
+//!     }
+//! }
+//! ```
+
+use chrono::{DateTime, Utc};
+
+use crate::entities::{Adjustment, Event, Transaction};
+use crate::ids::SubscriptionID;
+use crate::{Error, Paddle};
+
+/// Something that happened to a subscription.
+#[derive(Clone, Debug)]
+pub enum TimelineEntry {
+    /// Change to the subscription itself, e.g. `subscription.paused`.
+    Event(Event),
+    /// Transaction billed for the subscription.
+    Transaction(Transaction),
+    /// Refund, credit or chargeback against a transaction of the subscription.
+    Adjustment(Adjustment),
+}
+
+impl TimelineEntry {
+    /// When this entry happened. Transactions are placed when they were billed, or when they were created if they haven't been billed.
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        match self {
+            Self::Event(event) => event.occurred_at,
+            Self::Transaction(transaction) => {
+                transaction.billed_at.unwrap_or(transaction.created_at)
+            }
+            Self::Adjustment(adjustment) => adjustment.created_at,
+        }
+    }
+}
+
+/// Events, transactions and adjustments of a subscription, oldest first.
+#[derive(Clone, Debug)]
+pub struct SubscriptionTimeline {
+    pub subscription_id: SubscriptionID,
+    pub entries: Vec<TimelineEntry>,
+}
+
+impl SubscriptionTimeline {
+    /// Entries that happened between `from` and `to`, `from` inclusive.
+    pub fn between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> impl Iterator<Item = &TimelineEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| (from..to).contains(&entry.occurred_at()))
+    }
+}
+
+pub(crate) async fn subscription_timeline(
+    client: &Paddle,
+    subscription_id: SubscriptionID,
+) -> Result<SubscriptionTimeline, Error> {
+    // Transactions and adjustments are listed on their own, only keep the events of the subscription itself
+    let events = client
+        .events_list()
+        .for_entity(&subscription_id)
+        .max_page_size()
+        .send()
+        .all()
+        .await?
+        .into_iter()
+        .filter(|event| event.data.entity_id() == subscription_id.as_ref());
+
+    let transactions = client
+        .transactions_list()
+        .subscription_ids([subscription_id.clone()])
+        .max_page_size()
+        .send()
+        .all()
+        .await?;
+
+    let adjustments = client
+        .adjustments_list()
+        .subscription_ids([subscription_id.clone()])
+        .max_page_size()
+        .send()
+        .all()
+        .await?;
+
+    let mut entries = events
+        .map(TimelineEntry::Event)
+        .chain(transactions.into_iter().map(TimelineEntry::Transaction))
+        .chain(adjustments.into_iter().map(TimelineEntry::Adjustment))
+        .collect::<Vec<_>>();
+
+    entries.sort_by_key(TimelineEntry::occurred_at);
+
+    Ok(SubscriptionTimeline {
+        subscription_id,
+        entries,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    #[tokio::test]
+    async fn merges_activity_in_chronological_order() {
+        let url = fixtures::serve_json(|target| async move {
+            let path = target.split('?').next().unwrap_or_default().to_string();
+
+            let data = match path.as_str() {
+                "/events" => {
+                    let mut paused =
+                        fixtures::event("subscription.paused", fixtures::subscription());
+                    paused.occurred_at = "2024-05-01T00:00:00Z".parse().unwrap();
+
+                    json!([
+                        paused,
+                        fixtures::event("transaction.paid", fixtures::transaction()),
+                    ])
+                }
+                "/transactions" => json!([fixtures::transaction()]),
+                _ => json!([]),
+            };
+
+            json!({
+                "data": data,
+                "meta": {
+                    "request_id": "req_1",
+                    "pagination": {
+                        "per_page": 50,
+                        "next": format!("http://localhost{path}"),
+                        "has_more": false,
+                        "estimated_total": 1
+                    }
+                }
+            })
+        })
+        .await;
+
+        let client = Paddle::new("key", url).unwrap();
+        let timeline = client
+            .subscription_timeline("sub_01hv8x29kz0t586xy6zn1a62ny")
+            .await
+            .unwrap();
+
+        assert_eq!(timeline.entries.len(), 2);
+        assert!(matches!(timeline.entries[0], TimelineEntry::Transaction(_)));
+        assert!(matches!(timeline.entries[1], TimelineEntry::Event(_)));
+        assert!(timeline.entries[0].occurred_at() < timeline.entries[1].occurred_at());
+    }
+}
@@ -11,19 +11,23 @@ use serde_with::skip_serializing_none;
 
 use crate::entities::{
     AddressPreview, BillingDetails, TimePeriod, Transaction, TransactionCheckout,
-    TransactionItemNonCatalogPrice,
+    TransactionItemNonCatalogPrice, TransactionWithIncludes,
+};
+use crate::enums::{
+    CollectionMode, CurrencyCode, TransactionInclude, TransactionOrigin, TransactionStatus,
 };
-use crate::enums::{CollectionMode, CurrencyCode, TransactionOrigin, TransactionStatus};
 use crate::ids::{
     AddressID, BusinessID, CustomerID, DiscountID, PriceID, SubscriptionID, TransactionID,
 };
 use crate::nullable::Nullable;
 use crate::paginated::Paginated;
-use crate::{Paddle, Result};
+use crate::permissions::Permission;
+use crate::request::PaddleRequest;
+use crate::{Error, Paddle, Result};
 
 #[allow(non_snake_case)]
 #[skip_serializing_none]
-#[derive(Serialize, Default)]
+#[derive(Clone, Serialize, Default)]
 struct DateAtFilter {
     LT: Option<DateTime<Utc>>,
     LTE: Option<DateTime<Utc>>,
@@ -31,7 +35,7 @@ struct DateAtFilter {
     GTE: Option<DateTime<Utc>>,
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
 #[serde(untagged)]
 enum DateAt {
     Exact(DateTime<Utc>),
@@ -40,7 +44,8 @@ enum DateAt {
 
 /// Request builder for fetching transactions from Paddle API.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct TransactionsList<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -52,8 +57,8 @@ pub struct TransactionsList<'a> {
     customer_id: Option<Vec<CustomerID>>,
     #[serde(serialize_with = "crate::comma_separated")]
     id: Option<Vec<TransactionID>>,
-    #[serde(serialize_with = "crate::comma_separated")]
-    include: Option<Vec<String>>,
+    #[serde(serialize_with = "crate::comma_separated_enum")]
+    include: Option<Vec<TransactionInclude>>,
     #[serde(serialize_with = "crate::comma_separated")]
     invoice_number: Option<Vec<String>>,
     #[serde(serialize_with = "crate::comma_separated_enum")]
@@ -89,19 +94,19 @@ impl<'a> TransactionsList<'a> {
     }
 
     /// Return entities after the specified Paddle ID when working with paginated endpoints. Used in the `meta.pagination.next` URL in responses for list operations.
-    pub fn after(&mut self, transaction_id: impl Into<TransactionID>) -> &mut Self {
+    pub fn after(mut self, transaction_id: impl Into<TransactionID>) -> Self {
         self.after = Some(transaction_id.into());
         self
     }
 
     /// Return entities billed at a specific time.
-    pub fn billed_at(&mut self, date: DateTime<Utc>) -> &mut Self {
+    pub fn billed_at(mut self, date: DateTime<Utc>) -> Self {
         self.billed_at = Some(DateAt::Exact(date));
         self
     }
 
     /// Return entities billed before the specified time.
-    pub fn billed_at_lt(&mut self, date: DateTime<Utc>) -> &mut Self {
+    pub fn billed_at_lt(mut self, date: DateTime<Utc>) -> Self {
         self.billed_at = Some(DateAt::Filter(DateAtFilter {
             LT: Some(date),
             ..Default::default()
@@ -111,7 +116,7 @@ impl<'a> TransactionsList<'a> {
     }
 
     /// Return entities billed before or on the specified time.
-    pub fn billed_at_lte(&mut self, date: DateTime<Utc>) -> &mut Self {
+    pub fn billed_at_lte(mut self, date: DateTime<Utc>) -> Self {
         self.billed_at = Some(DateAt::Filter(DateAtFilter {
             LTE: Some(date),
             ..Default::default()
@@ -121,7 +126,7 @@ impl<'a> TransactionsList<'a> {
     }
 
     /// Return entities billed after the specified time.
-    pub fn billed_at_gt(&mut self, date: DateTime<Utc>) -> &mut Self {
+    pub fn billed_at_gt(mut self, date: DateTime<Utc>) -> Self {
         self.billed_at = Some(DateAt::Filter(DateAtFilter {
             GT: Some(date),
             ..Default::default()
@@ -131,7 +136,7 @@ impl<'a> TransactionsList<'a> {
     }
 
     /// Return entities billed after or on the specified time.
-    pub fn billed_at_gte(&mut self, date: DateTime<Utc>) -> &mut Self {
+    pub fn billed_at_gte(mut self, date: DateTime<Utc>) -> Self {
         self.billed_at = Some(DateAt::Filter(DateAtFilter {
             GTE: Some(date),
             ..Default::default()
@@ -141,19 +146,19 @@ impl<'a> TransactionsList<'a> {
     }
 
     /// Return entities that match the specified collection mode.
-    pub fn collection_mode(&mut self, mode: CollectionMode) -> &mut Self {
+    pub fn collection_mode(mut self, mode: CollectionMode) -> Self {
         self.collection_mode = Some(mode);
         self
     }
 
     /// Return entities created at a specific time.
-    pub fn created_at(&mut self, date: DateTime<Utc>) -> &mut Self {
+    pub fn created_at(mut self, date: DateTime<Utc>) -> Self {
         self.created_at = Some(DateAt::Exact(date));
         self
     }
 
     /// Return entities created before the specified time.
-    pub fn created_at_lt(&mut self, date: DateTime<Utc>) -> &mut Self {
+    pub fn created_at_lt(mut self, date: DateTime<Utc>) -> Self {
         self.created_at = Some(DateAt::Filter(DateAtFilter {
             LT: Some(date),
             ..Default::default()
@@ -163,7 +168,7 @@ impl<'a> TransactionsList<'a> {
     }
 
     /// Return entities created before or on the specified time.
-    pub fn created_at_lte(&mut self, date: DateTime<Utc>) -> &mut Self {
+    pub fn created_at_lte(mut self, date: DateTime<Utc>) -> Self {
         self.created_at = Some(DateAt::Filter(DateAtFilter {
             LTE: Some(date),
             ..Default::default()
@@ -173,7 +178,7 @@ impl<'a> TransactionsList<'a> {
     }
 
     /// Return entities created after the specified time.
-    pub fn created_at_gt(&mut self, date: DateTime<Utc>) -> &mut Self {
+    pub fn created_at_gt(mut self, date: DateTime<Utc>) -> Self {
         self.created_at = Some(DateAt::Filter(DateAtFilter {
             GT: Some(date),
             ..Default::default()
@@ -183,7 +188,7 @@ impl<'a> TransactionsList<'a> {
     }
 
     /// Return entities created after or on the specified time.
-    pub fn created_at_gte(&mut self, date: DateTime<Utc>) -> &mut Self {
+    pub fn created_at_gte(mut self, date: DateTime<Utc>) -> Self {
         self.created_at = Some(DateAt::Filter(DateAtFilter {
             GTE: Some(date),
             ..Default::default()
@@ -194,46 +199,33 @@ impl<'a> TransactionsList<'a> {
 
     /// Return entities related to the specified customers.
     pub fn customer_id(
-        &mut self,
+        mut self,
         customer_ids: impl IntoIterator<Item = impl Into<CustomerID>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.customer_id = Some(customer_ids.into_iter().map(Into::into).collect());
         self
     }
 
     /// Return only the IDs specified.
-    pub fn id(&mut self, ids: impl IntoIterator<Item = impl Into<TransactionID>>) -> &mut Self {
+    pub fn ids(mut self, ids: impl IntoIterator<Item = impl Into<TransactionID>>) -> Self {
         self.id = Some(ids.into_iter().map(Into::into).collect());
         self
     }
 
+    /// Return only the IDs specified.
+    #[deprecated(note = "use `ids` instead")]
+    pub fn id(self, ids: impl IntoIterator<Item = impl Into<TransactionID>>) -> Self {
+        self.ids(ids)
+    }
+
     /// Include related entities in the response.
-    ///
-    /// Valid values are:
-    ///
-    /// - `address`
-    /// - `adjustments`
-    /// - `adjustments_totals`
-    /// - `available_payment_methods`
-    /// - `business`
-    /// - `customer`
-    /// - `discount`
-    ///
-    pub fn include(&mut self, entities: impl IntoIterator<Item = impl AsRef<str>>) -> &mut Self {
-        self.include = Some(
-            entities
-                .into_iter()
-                .map(|s| s.as_ref().to_string())
-                .collect(),
-        );
+    pub fn include(mut self, entities: impl IntoIterator<Item = TransactionInclude>) -> Self {
+        self.include = Some(entities.into_iter().collect());
         self
     }
 
     /// Return entities that match the invoice number.
-    pub fn invoice_numbers(
-        &mut self,
-        numbers: impl IntoIterator<Item = impl AsRef<str>>,
-    ) -> &mut Self {
+    pub fn invoice_numbers(mut self, numbers: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
         self.invoice_number = Some(
             numbers
                 .into_iter()
@@ -244,55 +236,64 @@ impl<'a> TransactionsList<'a> {
     }
 
     /// Return entities related to the specified origin(s).
-    pub fn origin(&mut self, origins: impl IntoIterator<Item = TransactionOrigin>) -> &mut Self {
+    pub fn origin(mut self, origins: impl IntoIterator<Item = TransactionOrigin>) -> Self {
         self.origin = Some(origins.into_iter().collect());
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: `billed_at`, `created_at`, `id`, `updated_at`
-    pub fn order_by_asc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_asc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[ASC]", field));
         self
     }
 
     /// Order returned entities by the specified field. Valid fields for ordering: `billed_at`, `created_at`, `id`, `updated_at`
-    pub fn order_by_desc(&mut self, field: &str) -> &mut Self {
+    pub fn order_by_desc(mut self, field: &str) -> Self {
         self.order_by = Some(format!("{}[DESC]", field));
         self
     }
 
     /// Return entities that match the specified status.
-    pub fn status(&mut self, statuses: impl IntoIterator<Item = TransactionStatus>) -> &mut Self {
+    pub fn status(mut self, statuses: impl IntoIterator<Item = TransactionStatus>) -> Self {
         self.status = Some(statuses.into_iter().collect());
         self
     }
 
     /// Return entities related to the specified subscription.
     pub fn subscription_ids(
-        &mut self,
+        mut self,
         subscription_ids: impl IntoIterator<Item = impl Into<SubscriptionID>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.subscription_id = Some(subscription_ids.into_iter().map(Into::into).collect());
         self
     }
 
-    /// Set how many entities are returned per page. Paddle returns the maximum number of results if a number greater than the maximum is requested.
-    /// Check `meta.pagination.per_page` in the response to see how many were returned.
+    /// Number of entities Paddle returns per page when [Self::per_page] isn't set.
+    pub const DEFAULT_PER_PAGE: usize = 30;
+    /// Largest page size Paddle accepts for this endpoint.
+    pub const MAX_PER_PAGE: usize = 30;
+
+    /// Set how many entities are returned per page.
     ///
-    /// Default: `50`; Maximum: `200`.
-    pub fn per_page(&mut self, entities_per_page: usize) -> &mut Self {
+    /// Default: `30`; Maximum: `30`. Values outside of `1..=30` make the first page fail with [Error::Validation] without sending a request.
+    pub fn per_page(mut self, entities_per_page: usize) -> Self {
         self.per_page = Some(entities_per_page);
         self
     }
 
+    /// Request the largest page Paddle allows for this endpoint. Use this to fetch everything in as few requests as possible.
+    pub fn max_page_size(self) -> Self {
+        self.per_page(Self::MAX_PER_PAGE)
+    }
+
     /// Return entities updated at a specific time.
-    pub fn updated_at(&mut self, date: DateTime<Utc>) -> &mut Self {
+    pub fn updated_at(mut self, date: DateTime<Utc>) -> Self {
         self.updated_at = Some(DateAt::Exact(date));
         self
     }
 
     /// Return entities updated before the specified time.
-    pub fn updated_at_lt(&mut self, date: DateTime<Utc>) -> &mut Self {
+    pub fn updated_at_lt(mut self, date: DateTime<Utc>) -> Self {
         self.updated_at = Some(DateAt::Filter(DateAtFilter {
             LT: Some(date),
             ..Default::default()
@@ -302,7 +303,7 @@ impl<'a> TransactionsList<'a> {
     }
 
     /// Return entities updated before or on the specified time.
-    pub fn updated_at_lte(&mut self, date: DateTime<Utc>) -> &mut Self {
+    pub fn updated_at_lte(mut self, date: DateTime<Utc>) -> Self {
         self.updated_at = Some(DateAt::Filter(DateAtFilter {
             LTE: Some(date),
             ..Default::default()
@@ -312,7 +313,7 @@ impl<'a> TransactionsList<'a> {
     }
 
     /// Return entities updated after the specified time.
-    pub fn updated_at_gt(&mut self, date: DateTime<Utc>) -> &mut Self {
+    pub fn updated_at_gt(mut self, date: DateTime<Utc>) -> Self {
         self.updated_at = Some(DateAt::Filter(DateAtFilter {
             GT: Some(date),
             ..Default::default()
@@ -322,7 +323,7 @@ impl<'a> TransactionsList<'a> {
     }
 
     /// Return entities updated after or on the specified time.
-    pub fn updated_at_gte(&mut self, date: DateTime<Utc>) -> &mut Self {
+    pub fn updated_at_gte(mut self, date: DateTime<Utc>) -> Self {
         self.updated_at = Some(DateAt::Filter(DateAtFilter {
             GTE: Some(date),
             ..Default::default()
@@ -333,11 +334,45 @@ impl<'a> TransactionsList<'a> {
 
     /// Returns a paginator for fetching pages of entities from Paddle
     pub fn send(&self) -> Paginated<'_, Vec<Transaction>> {
-        Paginated::new(self.client, "/transactions", self)
+        if let Some(per_page) = self.per_page {
+            if !(1..=Self::MAX_PER_PAGE).contains(&per_page) {
+                return Paginated::failed(
+                    self.client,
+                    Error::Validation(format!(
+                        "per_page must be between 1 and {}, got {}",
+                        Self::MAX_PER_PAGE,
+                        per_page
+                    )),
+                );
+            }
+        }
+
+        Paginated::new(self.client, &self.path(), self)
+    }
+}
+
+impl PaddleRequest for TransactionsList<'_> {
+    type Output = Vec<Transaction>;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        "/transactions".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::TransactionRead]
     }
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
 #[serde(untagged)]
 #[allow(clippy::large_enum_variant)]
 pub enum TransactionItem {
@@ -353,12 +388,13 @@ pub enum TransactionItem {
 
 /// Request builder for creating a transaction in Paddle.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct TransactionCreate<'a> {
     #[serde(skip)]
     client: &'a Paddle,
     #[serde(skip)]
-    include: Option<Vec<String>>,
+    include: Option<Vec<TransactionInclude>>,
     items: Vec<TransactionItem>,
     status: Option<TransactionStatus>,
     customer_id: Option<CustomerID>,
@@ -377,15 +413,15 @@ impl<'a> TransactionCreate<'a> {
     pub fn new(client: &'a Paddle) -> Self {
         Self {
             client,
-            include: None,
+            include: client.defaults.transaction_include.clone(),
             items: Vec::default(),
             status: None,
             customer_id: None,
             address_id: None,
             business_id: None,
             custom_data: None,
-            currency_code: None,
-            collection_mode: None,
+            currency_code: client.defaults.currency_code.clone(),
+            collection_mode: client.defaults.collection_mode,
             discount_id: None,
             billing_details: None,
             billing_period: None,
@@ -394,18 +430,8 @@ impl<'a> TransactionCreate<'a> {
     }
 
     /// Include related entities in the response.
-    ///
-    /// ## Valid values are:
-    ///
-    /// - `address`
-    /// - `adjustments`
-    /// - `adjustments_totals`
-    /// - `available_payment_methods`
-    /// - `business`
-    /// - `customer`
-    /// - `discount`
-    pub fn include(&mut self, includes: impl IntoIterator<Item = impl Into<String>>) -> &mut Self {
-        self.include = Some(includes.into_iter().map(Into::into).collect());
+    pub fn include(mut self, entities: impl IntoIterator<Item = TransactionInclude>) -> Self {
+        self.include = Some(entities.into_iter().collect());
         self
     }
 
@@ -414,11 +440,7 @@ impl<'a> TransactionCreate<'a> {
     /// You can charge for items that you've added to your catalog by passing the Paddle ID of an existing price entity,
     ///
     /// To charge for non-catalog items see append_non_catalog_item.
-    pub fn append_catalog_item(
-        &mut self,
-        price_id: impl Into<PriceID>,
-        quantity: u32,
-    ) -> &mut Self {
+    pub fn append_catalog_item(mut self, price_id: impl Into<PriceID>, quantity: u32) -> Self {
         self.items.push(TransactionItem::CatalogItem {
             price_id: price_id.into(),
             quantity,
@@ -430,11 +452,14 @@ impl<'a> TransactionCreate<'a> {
     /// Append to the list of items to charge for.
     ///
     /// You can charge for non-catalog items by passing a `TransactionItemNonCatalogPrice` object.
+    ///
+    /// Paddle is the merchant of record and always calculates tax itself, so tax rates or amounts can't be supplied for line items.
+    /// Tax for a non-catalog item is controlled by its `tax_mode` and, for non-catalog products, the product `tax_category`.
     pub fn append_non_catalog_item(
-        &mut self,
+        mut self,
         price: TransactionItemNonCatalogPrice,
         quantity: u32,
-    ) -> &mut Self {
+    ) -> Self {
         self.items
             .push(TransactionItem::NonCatalogItem { price, quantity });
         self
@@ -445,7 +470,7 @@ impl<'a> TransactionCreate<'a> {
     /// Transactions are created as ready if they have an address_id, customer_id, and items, otherwise they are created as draft.
     ///
     /// Marking as billed when creating is typically used when working with manually-collected transactions as part of an invoicing workflow. Billed transactions cannot be updated, only canceled.
-    pub fn status(&mut self, status: TransactionStatus) -> &mut Self {
+    pub fn status(mut self, status: TransactionStatus) -> Self {
         self.status = Some(status);
         self
     }
@@ -453,7 +478,7 @@ impl<'a> TransactionCreate<'a> {
     /// Paddle ID of the customer that this transaction is for.
     ///
     /// If omitted, transaction status is `draft`.
-    pub fn customer_id(&mut self, customer_id: impl Into<CustomerID>) -> &mut Self {
+    pub fn customer_id(mut self, customer_id: impl Into<CustomerID>) -> Self {
         self.customer_id = Some(customer_id.into());
         self
     }
@@ -461,7 +486,7 @@ impl<'a> TransactionCreate<'a> {
     /// Paddle ID of the address that this transaction is for.
     ///
     /// Requires customer_id. If omitted, transaction status is draft.
-    pub fn address_id(&mut self, address_id: impl Into<AddressID>) -> &mut Self {
+    pub fn address_id(mut self, address_id: impl Into<AddressID>) -> Self {
         self.address_id = Some(address_id.into());
         self
     }
@@ -469,43 +494,43 @@ impl<'a> TransactionCreate<'a> {
     /// Paddle ID of the business that this transaction is for.
     ///
     /// Requires customer_id
-    pub fn business_id(&mut self, business_id: impl Into<BusinessID>) -> &mut Self {
+    pub fn business_id(mut self, business_id: impl Into<BusinessID>) -> Self {
         self.business_id = Some(business_id.into());
         self
     }
 
     /// Your own structured key-value data.
-    pub fn custom_data(&mut self, custom_data: HashMap<String, String>) -> &mut Self {
+    pub fn custom_data(mut self, custom_data: HashMap<String, String>) -> Self {
         self.custom_data = Some(custom_data);
         self
     }
 
     /// Supported three-letter ISO 4217 currency code. Must be `USD`, `EUR`, or `GBP` if `collection_mode` is `manual`.
-    pub fn currency_code(&mut self, currency_code: CurrencyCode) -> &mut Self {
+    pub fn currency_code(mut self, currency_code: CurrencyCode) -> Self {
         self.currency_code = Some(currency_code);
         self
     }
 
     /// How payment is collected for this transaction. `automatic` for checkout, `manual` for invoices. If omitted, defaults to `automatic`.
-    pub fn collection_mode(&mut self, mode: CollectionMode) -> &mut Self {
+    pub fn collection_mode(mut self, mode: CollectionMode) -> Self {
         self.collection_mode = Some(mode);
         self
     }
 
     /// Paddle ID of the discount applied to this transaction.
-    pub fn discount_id(&mut self, discount_id: impl Into<DiscountID>) -> &mut Self {
+    pub fn discount_id(mut self, discount_id: impl Into<DiscountID>) -> Self {
         self.discount_id = Some(discount_id.into());
         self
     }
 
     /// Details for invoicing. Required if `collection_mode` is `manual`.
-    pub fn billing_details(&mut self, billing_details: BillingDetails) -> &mut Self {
+    pub fn billing_details(mut self, billing_details: BillingDetails) -> Self {
         self.billing_details = Some(billing_details);
         self
     }
 
     /// Time period that this transaction is for. Set automatically by Paddle for subscription renewals to describe the period that charges are for.
-    pub fn billing_period(&mut self, billing_period: TimePeriod) -> &mut Self {
+    pub fn billing_period(mut self, billing_period: TimePeriod) -> Self {
         self.billing_period = Some(billing_period);
         self
     }
@@ -516,33 +541,101 @@ impl<'a> TransactionCreate<'a> {
     /// Pass the URL for an approved domain, or null to set to your default payment URL.
     ///
     /// Paddle returns a unique payment link composed of the URL passed or your default payment URL + ?_ptxn= and the Paddle ID for this transaction.
-    pub fn checkout_url(&mut self, url: String) -> &mut Self {
-        self.checkout = Some(TransactionCheckout { url: Some(url) });
+    pub fn checkout_url(mut self, url: String) -> Self {
+        self.checkout = Some(TransactionCheckout::new(url));
         self
     }
 
+    /// Create the transaction as an issued invoice, like the "Create and issue invoice" action in the Paddle dashboard.
+    ///
+    /// Sets `collection_mode` to `manual`, `billing_details` and `status` to `billed`. Billed transactions can't be changed, so [send](Self::send) checks that the customer, address and items are set before the request is made.
+    pub fn issue_as_invoice(mut self, billing_details: BillingDetails) -> Self {
+        self.collection_mode = Some(CollectionMode::Manual);
+        self.billing_details = Some(billing_details);
+        self.status = Some(TransactionStatus::Billed);
+        self
+    }
+
+    /// Check fields Paddle requires for manually-collected and billed transactions.
+    fn validate(&self) -> std::result::Result<(), Error> {
+        if self.collection_mode == Some(CollectionMode::Manual) {
+            if self.billing_details.is_none() {
+                return Err(Error::Validation(
+                    "billing_details is required when collection_mode is manual".to_string(),
+                ));
+            }
+
+            if let Some(currency_code) = &self.currency_code {
+                if !matches!(
+                    currency_code,
+                    CurrencyCode::USD | CurrencyCode::EUR | CurrencyCode::GBP
+                ) {
+                    return Err(Error::Validation(format!(
+                        "currency_code must be USD, EUR or GBP when collection_mode is manual, got {currency_code:?}"
+                    )));
+                }
+            }
+        }
+
+        if self.status == Some(TransactionStatus::Billed) {
+            let missing = [
+                ("customer_id", self.customer_id.is_none()),
+                ("address_id", self.address_id.is_none()),
+                ("items", self.items.is_empty()),
+            ];
+
+            if let Some((field, _)) = missing.iter().find(|(_, missing)| *missing) {
+                return Err(Error::Validation(format!(
+                    "{field} is required to create a billed transaction"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Transaction> {
-        let url = if let Some(include) = self.include.as_ref() {
-            &format!("/transactions?include={}", include.join(","))
-        } else {
-            "/transactions"
-        };
+        self.validate()?;
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for TransactionCreate<'_> {
+    type Output = Transaction;
+    type Payload = Self;
 
-        self.client.send(self, Method::POST, url).await
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        match self.include.as_ref() {
+            Some(include) => format!("/transactions?include={}", crate::enum_list(include)),
+            None => "/transactions".to_string(),
+        }
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::TransactionWrite]
     }
 }
 
 /// Request builder for fetching a specific transaction.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct TransactionGet<'a> {
     #[serde(skip)]
     client: &'a Paddle,
     #[serde(skip)]
     transaction_id: TransactionID,
-    #[serde(serialize_with = "crate::comma_separated")]
-    include: Option<Vec<String>>,
+    #[serde(serialize_with = "crate::comma_separated_enum")]
+    include: Option<Vec<TransactionInclude>>,
 }
 
 impl<'a> TransactionGet<'a> {
@@ -555,47 +648,48 @@ impl<'a> TransactionGet<'a> {
     }
 
     /// Include related entities in the response.
-    ///
-    /// ## Valid values are:
-    ///
-    /// - `address`
-    /// - `adjustments`
-    /// - `adjustments_totals`
-    /// - `available_payment_methods`
-    /// - `business`
-    /// - `customer`
-    /// - `discount`
-    pub fn include(&mut self, entities: impl IntoIterator<Item = impl AsRef<str>>) -> &mut Self {
-        self.include = Some(
-            entities
-                .into_iter()
-                .map(|s| s.as_ref().to_string())
-                .collect(),
-        );
+    pub fn include(mut self, entities: impl IntoIterator<Item = TransactionInclude>) -> Self {
+        self.include = Some(entities.into_iter().collect());
         self
     }
 
     /// Send the request to Paddle and return the response.
-    pub async fn send(&self) -> Result<Transaction> {
-        self.client
-            .send(
-                self,
-                Method::GET,
-                &format!("/transactions/{}", self.transaction_id.as_ref()),
-            )
-            .await
+    pub async fn send(&self) -> Result<TransactionWithIncludes> {
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for TransactionGet<'_> {
+    type Output = TransactionWithIncludes;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn path(&self) -> String {
+        format!("/transactions/{}", self.transaction_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::TransactionRead]
     }
 }
 
 /// Request builder for updating a transaction.
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct TransactionUpdate<'a> {
     #[serde(skip)]
     client: &'a Paddle,
     #[serde(skip)]
     transaction_id: TransactionID,
     #[serde(skip)]
-    include: Option<Vec<String>>,
+    include: Option<Vec<TransactionInclude>>,
     #[serde(skip_serializing_if = "Nullable::is_unchanged")]
     status: Nullable<TransactionStatus>,
     #[serde(skip_serializing_if = "Nullable::is_unchanged")]
@@ -644,110 +738,95 @@ impl<'a> TransactionUpdate<'a> {
     }
 
     /// Include related entities in the response.
-    ///
-    /// ## Valid values are:
-    ///
-    /// - `address`
-    /// - `adjustments`
-    /// - `adjustments_totals`
-    /// - `available_payment_methods`
-    /// - `business`
-    /// - `customer`
-    /// - `discount`
-    pub fn include(&mut self, entities: impl IntoIterator<Item = impl AsRef<str>>) -> &mut Self {
-        self.include = Some(
-            entities
-                .into_iter()
-                .map(|s| s.as_ref().to_string())
-                .collect(),
-        );
+    pub fn include(mut self, entities: impl IntoIterator<Item = TransactionInclude>) -> Self {
+        self.include = Some(entities.into_iter().collect());
         self
     }
 
     /// Status of this transaction. You may set a transaction to billed or canceled. Billed transactions cannot be changed.
     ///
     /// For manually-collected transactions, marking as billed is essentially issuing an invoice.
-    pub fn status(&mut self, status: impl Into<Nullable<TransactionStatus>>) -> &mut Self {
+    pub fn status(mut self, status: impl Into<Nullable<TransactionStatus>>) -> Self {
         self.status = status.into();
         self
     }
 
     /// Paddle ID of the customer that this transaction is for.
-    pub fn customer_id(&mut self, customer_id: impl Into<Nullable<CustomerID>>) -> &mut Self {
+    pub fn customer_id(mut self, customer_id: impl Into<Nullable<CustomerID>>) -> Self {
         self.customer_id = customer_id.into();
         self
     }
 
     /// Paddle ID of the address that this transaction is for.
-    pub fn address_id(&mut self, address_id: impl Into<Nullable<AddressID>>) -> &mut Self {
+    pub fn address_id(mut self, address_id: impl Into<Nullable<AddressID>>) -> Self {
         self.address_id = address_id.into();
         self
     }
 
     /// Paddle ID of the business that this transaction is for.
-    pub fn business_id(&mut self, business_id: impl Into<Nullable<BusinessID>>) -> &mut Self {
+    pub fn business_id(mut self, business_id: impl Into<Nullable<BusinessID>>) -> Self {
         self.business_id = business_id.into();
         self
     }
 
     /// Your own structured key-value data.
     pub fn custom_data(
-        &mut self,
+        mut self,
         custom_data: impl Into<Nullable<HashMap<String, String>>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.custom_data = custom_data.into();
         self
     }
 
     /// Supported three-letter currency code. Must be `USD`, `EUR`, or `GBP` if `collection_mode` is `manual`.
     pub fn currency_code(
-        &mut self,
+        mut self,
         currency_code: impl Into<Nullable<CurrencyCode>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.currency_code = currency_code.into();
         self
     }
 
     /// How payment is collected for this transaction. `automatic` for checkout, `manual` for invoices.
     pub fn collection_mode(
-        &mut self,
+        mut self,
         mode: impl Into<Nullable<CollectionMode>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.collection_mode = mode.into();
         self
     }
 
     /// Paddle ID of the discount applied to this transaction.
     pub fn discount_id(
-        &mut self,
+        mut self,
         discount_id: impl Into<Nullable<DiscountID>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.discount_id = discount_id.into();
         self
     }
 
     /// Details for invoicing. Required if `collection_mode` is `manual`.
     pub fn billing_details(
-        &mut self,
+        mut self,
         billing_details: impl Into<Nullable<BillingDetails>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.billing_details = billing_details.into();
         self
     }
 
     /// Time period that this transaction is for. Set automatically by Paddle for subscription renewals to describe the period that charges are for.
     pub fn billing_period(
-        &mut self,
+        mut self,
         billing_period: impl Into<Nullable<TimePeriod>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.billing_period = billing_period.into();
         self
     }
 
     pub fn items(
-        &mut self,
+        mut self,
         items: impl Into<Nullable<Vec<TransactionItem>>>,
-    ) -> &mut Self {
+    ) -> Self {
         self.items = items.into();
         self
     }
@@ -758,30 +837,52 @@ impl<'a> TransactionUpdate<'a> {
     /// Pass the URL for an approved domain, or null to set to your default payment URL.
     ///
     /// Paddle returns a unique payment link composed of the URL passed or your default payment URL + ?_ptxn= and the Paddle ID for this transaction.
-    pub fn checkout_url(&mut self, url: impl Into<Nullable<String>>) -> &mut Self {
+    pub fn checkout_url(mut self, url: impl Into<Nullable<String>>) -> Self {
         self.checkout = match url.into() {
             Nullable::Unchanged => Nullable::Unchanged,
             Nullable::Null => Nullable::Null,
-            Nullable::Value(url) => Nullable::Value(TransactionCheckout { url: Some(url) }),
+            Nullable::Value(url) => Nullable::Value(TransactionCheckout::new(url)),
         };
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Transaction> {
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for TransactionUpdate<'_> {
+    type Output = Transaction;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+
+    fn path(&self) -> String {
         let mut url = format!("/transactions/{}", self.transaction_id.as_ref());
 
         if let Some(include) = self.include.as_ref() {
-            url.push_str(&format!("?include={}", include.join(",")));
+            url.push_str(&format!("?include={}", crate::enum_list(include)));
         }
 
-        self.client.send(self, Method::PATCH, &url).await
+        url
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::TransactionWrite]
     }
 }
 
 /// Request builder for generating a transaction preview without creating a transaction entity.
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct TransactionPreview<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -817,11 +918,7 @@ impl<'a> TransactionPreview<'a> {
     /// You can charge for items that you've added to your catalog by passing the Paddle ID of an existing price entity,
     ///
     /// To charge for non-catalog items see append_non_catalog_item.
-    pub fn append_catalog_item(
-        &mut self,
-        price_id: impl Into<PriceID>,
-        quantity: u32,
-    ) -> &mut Self {
+    pub fn append_catalog_item(mut self, price_id: impl Into<PriceID>, quantity: u32) -> Self {
         self.items.push(TransactionItem::CatalogItem {
             price_id: price_id.into(),
             quantity,
@@ -834,53 +931,53 @@ impl<'a> TransactionPreview<'a> {
     ///
     /// You can charge for non-catalog items by passing a `TransactionItemNonCatalogPrice` object.
     pub fn append_non_catalog_item(
-        &mut self,
+        mut self,
         price: TransactionItemNonCatalogPrice,
         quantity: u32,
-    ) -> &mut Self {
+    ) -> Self {
         self.items
             .push(TransactionItem::NonCatalogItem { price, quantity });
         self
     }
 
     /// Address to charge tax for.
-    pub fn address(&mut self, address: AddressPreview) -> &mut Self {
+    pub fn address(mut self, address: AddressPreview) -> Self {
         self.address = Some(address);
         self
     }
 
     /// IP address of the customer. Paddle fetches location using this IP address to calculate totals.
-    pub fn customer_ip_address(&mut self, ip: String) -> &mut Self {
+    pub fn customer_ip_address(mut self, ip: String) -> Self {
         self.customer_ip_address = Some(ip);
         self
     }
 
     /// Paddle ID of the address that this transaction preview is for.
-    pub fn address_id(&mut self, address_id: impl Into<AddressID>) -> &mut Self {
+    pub fn address_id(mut self, address_id: impl Into<AddressID>) -> Self {
         self.address_id = Some(address_id.into());
         self
     }
 
     /// Paddle ID of the business that this transaction is for.
-    pub fn business_id(&mut self, business_id: impl Into<BusinessID>) -> &mut Self {
+    pub fn business_id(mut self, business_id: impl Into<BusinessID>) -> Self {
         self.business_id = Some(business_id.into());
         self
     }
 
     /// Paddle ID of the customer that this transaction is for.
-    pub fn customer_id(&mut self, customer_id: impl Into<CustomerID>) -> &mut Self {
+    pub fn customer_id(mut self, customer_id: impl Into<CustomerID>) -> Self {
         self.customer_id = Some(customer_id.into());
         self
     }
 
     /// Supported three-letter currency code.
-    pub fn currency_code(&mut self, currency_code: CurrencyCode) -> &mut Self {
+    pub fn currency_code(mut self, currency_code: CurrencyCode) -> Self {
         self.currency_code = Some(currency_code);
         self
     }
 
     /// Paddle ID of the discount applied to this transaction.
-    pub fn discount_id(&mut self, discount_id: impl Into<DiscountID>) -> &mut Self {
+    pub fn discount_id(mut self, discount_id: impl Into<DiscountID>) -> Self {
         self.discount_id = Some(discount_id.into());
         self
     }
@@ -888,32 +985,51 @@ impl<'a> TransactionPreview<'a> {
     /// Whether trials should be ignored for transaction preview calculations.
     ///
     /// By default, recurring items with trials are considered to have a zero charge when previewing. Set to `true` to disable this.
-    pub fn ignore_trials(&mut self, ignore_trials: bool) -> &mut Self {
+    pub fn ignore_trials(mut self, ignore_trials: bool) -> Self {
         self.ignore_trials = ignore_trials;
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<crate::entities::TransactionPreview> {
-        self.client
-            .send(self, Method::POST, "/transactions/preview")
-            .await
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for TransactionPreview<'_> {
+    type Output = crate::entities::TransactionPreview;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        "/transactions/preview".to_string()
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::TransactionRead]
     }
 }
 
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
 struct RevisedCustomer {
     name: String,
 }
 
-#[derive(Serialize, Default)]
+#[derive(Clone, Serialize, Default)]
 #[skip_serializing_none]
 struct RevisedBusiness {
     name: Option<String>,
     tax_identifier: Option<String>,
 }
 
-#[derive(Serialize, Default)]
+#[derive(Clone, Serialize, Default)]
 #[skip_serializing_none]
 struct RevisedAddress {
     first_line: Option<String>,
@@ -923,7 +1039,8 @@ struct RevisedAddress {
 }
 
 #[skip_serializing_none]
-#[derive(Serialize)]
+#[derive(Clone, Serialize)]
+#[must_use]
 pub struct TransactionRevise<'a> {
     #[serde(skip)]
     client: &'a Paddle,
@@ -946,13 +1063,13 @@ impl<'a> TransactionRevise<'a> {
     }
 
     /// Revised name of the customer for this transaction.
-    pub fn customer_name(&mut self, name: impl Into<String>) -> &mut Self {
+    pub fn customer_name(mut self, name: impl Into<String>) -> Self {
         self.customer = Some(RevisedCustomer { name: name.into() });
         self
     }
 
     /// Revised name of the business for this transaction.
-    pub fn business_name(&mut self, name: impl Into<String>) -> &mut Self {
+    pub fn business_name(mut self, name: impl Into<String>) -> Self {
         self.business.get_or_insert_default().name = Some(name.into());
         self
     }
@@ -962,39 +1079,144 @@ impl<'a> TransactionRevise<'a> {
     /// You can't remove a valid tax or VAT number, only replace it with another valid one.
     ///
     /// Paddle automatically creates an adjustment to refund any tax where applicable.
-    pub fn business_tax_identifier(&mut self, tax_identifier: impl Into<String>) -> &mut Self {
+    pub fn business_tax_identifier(mut self, tax_identifier: impl Into<String>) -> Self {
         self.business.get_or_insert_default().tax_identifier = Some(tax_identifier.into());
         self
     }
 
     /// Revised first line of the address for this transaction.
-    pub fn address_first_line(&mut self, first_line: impl Into<String>) -> &mut Self {
+    pub fn address_first_line(mut self, first_line: impl Into<String>) -> Self {
         self.address.get_or_insert_default().first_line = Some(first_line.into());
         self
     }
 
     /// Revised second line of the address for this transaction.
-    pub fn address_second_line(&mut self, second_line: impl Into<String>) -> &mut Self {
+    pub fn address_second_line(mut self, second_line: impl Into<String>) -> Self {
         self.address.get_or_insert_default().second_line = Some(second_line.into());
         self
     }
 
     /// Revised city of the address for this transaction.
-    pub fn address_city(&mut self, city: impl Into<String>) -> &mut Self {
+    pub fn address_city(mut self, city: impl Into<String>) -> Self {
         self.address.get_or_insert_default().city = Some(city.into());
         self
     }
 
     /// Revised region of the address for this transaction.
-    pub fn address_region(&mut self, region: impl Into<String>) -> &mut Self {
+    pub fn address_region(mut self, region: impl Into<String>) -> Self {
         self.address.get_or_insert_default().region = Some(region.into());
         self
     }
 
     /// Send the request to Paddle and return the response.
     pub async fn send(&self) -> Result<Transaction> {
-        let url = format!("/transactions/{}/revise", self.transaction_id.as_ref());
+        self.client.execute(self).await
+    }
+}
+
+impl PaddleRequest for TransactionRevise<'_> {
+    type Output = Transaction;
+    type Payload = Self;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
 
-        self.client.send(self, Method::POST, &url).await
+    fn path(&self) -> String {
+        format!("/transactions/{}/revise", self.transaction_id.as_ref())
+    }
+
+    fn payload(&self) -> &Self::Payload {
+        self
+    }
+
+    fn required_permissions(&self) -> &'static [Permission] {
+        &[Permission::TransactionWrite]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    #[tokio::test]
+    async fn per_page_out_of_bounds_fails_without_a_request() {
+        let client = Paddle::new("key", "http://127.0.0.1:9/").unwrap();
+        let list = client.transactions_list().per_page(201);
+        let err = list.send().next().await.unwrap_err();
+        assert!(matches!(err, Error::Validation(message) if message.contains("201")));
+
+        let list = list.per_page(0);
+        assert!(list.send().next().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn invoices_are_validated_before_sending() {
+        let client = Paddle::new("key", Paddle::SANDBOX).unwrap();
+        let billing_details =
+            BillingDetails::payment_terms_days(14).purchase_order_number("PO-123");
+
+        let transaction = client
+            .transaction_create()
+            .customer_id("ctm_01hv6y1jedq4p1n0yqn5ba3ky4")
+            .append_catalog_item("pri_01gsz8x8sawmvhz1pv30nge0001", 1)
+            .issue_as_invoice(billing_details);
+
+        let body = serde_json::to_value(&transaction).unwrap();
+        assert_eq!(body["collection_mode"], "manual");
+        assert_eq!(body["status"], "billed");
+        assert_eq!(
+            body["billing_details"]["payment_terms"],
+            json!({ "interval": "day", "frequency": 14 })
+        );
+
+        let err = transaction.send().await.unwrap_err();
+        assert!(matches!(err, Error::Validation(message) if message.starts_with("address_id")));
+    }
+
+    #[tokio::test]
+    async fn get_returns_the_transaction_with_included_entities() {
+        let url = fixtures::serve_json(|target| async move {
+            let mut transaction = fixtures::transaction();
+
+            if target.contains("include=") {
+                assert!(target.contains("customer"), "{target}");
+                transaction["customer"] = fixtures::customer();
+            }
+
+            json!({ "data": transaction, "meta": { "request_id": "req_1" } })
+        })
+        .await;
+        let client = Paddle::new("key", url.as_str()).unwrap();
+
+        let plain = client
+            .transaction_get("txn_01hv8wptq8987qeep44cyrewp9")
+            .send()
+            .await
+            .unwrap()
+            .data;
+        assert_eq!(
+            plain.transaction.id.as_ref(),
+            "txn_01hv8wptq8987qeep44cyrewp9"
+        );
+        assert!(plain.customer.is_none());
+        assert!(plain.adjustments.is_none());
+
+        let included = client
+            .transaction_get("txn_01hv8wptq8987qeep44cyrewp9")
+            .include([TransactionInclude::Customer])
+            .send()
+            .await
+            .unwrap()
+            .data;
+        assert_eq!(included.transaction.id, plain.transaction.id);
+        assert_eq!(
+            included.transaction.details.totals.total,
+            plain.transaction.details.totals.total
+        );
+        assert_eq!(included.customer.unwrap().email, "sam@example.com");
     }
 }
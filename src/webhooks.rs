@@ -1,21 +1,408 @@
 //! # Helpers for validating webhook requests.
 
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::io::Write;
+use std::net::{IpAddr, Ipv4Addr};
 use std::num::ParseIntError;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
 
 use chrono::{prelude::*, Duration};
 use hmac::{Hmac, KeyInit, Mac};
+use serde::de::DeserializeOwned;
 use sha2::Sha256;
 
-use crate::error::{Error, SignatureError};
+use crate::custom_data::{self, CustomDataHook};
+use crate::entities::{Event, SimulationEvent, SimulationEventResponse};
+use crate::enums::SimulationEventStatus;
+use crate::error::{Error, SignatureError, WebhookRejection};
+use crate::ids::SimulationID;
+use crate::Paddle;
 
 type HmacSha256 = Hmac<Sha256>;
 
+/// Paddle API version that the entities in this crate conform to.
+///
+/// Events are delivered in the API version configured on the notification destination (`api_version` of [NotificationSetting](crate::entities::NotificationSetting)).
+/// When that version changes, the shape of the delivered entities may no longer match the structs in this crate.
+pub const SUPPORTED_API_VERSION: i64 = 1;
+
+/// Check that events delivered in `api_version` can be deserialized by this crate.
+///
+/// Call this with the `api_version` of your notification destination, e.g. at startup, to detect an account version upgrade before handlers start failing.
+pub fn check_api_version(api_version: i64) -> Result<(), Error> {
+    if api_version != SUPPORTED_API_VERSION {
+        return Err(Error::UnsupportedApiVersion(api_version));
+    }
+
+    Ok(())
+}
+
+/// Name of the HTTP header Paddle sends the webhook signature in.
+pub const SIGNATURE_HEADER: &str = "paddle-signature";
+
+/// Cheap checks to run on an incoming webhook request before computing its signature.
+///
+/// Rejects oversized bodies, requests that aren't JSON and requests without a signature header, so that junk traffic is turned away without reading or hashing it. A rejection carries the HTTP status code to respond with, see [WebhookRejection::status_code].
+///
+/// ```rust,no_run
+/// use paddle_rust_sdk::webhooks::WebhookPreflight;
+///
+/// let preflight = WebhookPreflight::default().max_body_size(256 * 1024);
+///
+/// if let Err(rejection) = preflight.check(content_type, signature, body.len()) {
+///     return respond(rejection.status_code(), rejection.to_string());
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct WebhookPreflight {
+    max_body_size: usize,
+}
+
+impl WebhookPreflight {
+    /// Default maximum body size, 1 MiB. Events sent by Paddle are much smaller.
+    pub const DEFAULT_MAX_BODY_SIZE: usize = 1024 * 1024;
+
+    /// Maximum allowed size of the request body in bytes.
+    pub fn max_body_size(mut self, max_body_size: usize) -> Self {
+        self.max_body_size = max_body_size;
+        self
+    }
+
+    /// Check the request headers and body length.
+    ///
+    /// - **content_type** - value of the `Content-Type` header, `None` if missing. Parameters such as `charset` are allowed.
+    /// - **signature** - value of the `Paddle-Signature` header, `None` if missing.
+    /// - **body_len** - length of the request body in bytes. Pass the `Content-Length` header to reject requests before reading the body.
+    pub fn check(
+        &self,
+        content_type: Option<&str>,
+        signature: Option<&str>,
+        body_len: usize,
+    ) -> Result<(), WebhookRejection> {
+        if body_len > self.max_body_size {
+            return Err(WebhookRejection::BodyTooLarge {
+                size: body_len,
+                limit: self.max_body_size,
+            });
+        }
+
+        if body_len == 0 {
+            return Err(WebhookRejection::EmptyBody);
+        }
+
+        let content_type = content_type.ok_or(WebhookRejection::MissingContentType)?;
+        let mime_type = content_type.split(';').next().unwrap_or_default().trim();
+
+        if !mime_type.eq_ignore_ascii_case("application/json") {
+            return Err(WebhookRejection::UnsupportedContentType(
+                content_type.to_string(),
+            ));
+        }
+
+        match signature {
+            Some(signature) if !signature.trim().is_empty() => Ok(()),
+            _ => Err(WebhookRejection::MissingSignature),
+        }
+    }
+}
+
+impl Default for WebhookPreflight {
+    /// Limit bodies to [WebhookPreflight::DEFAULT_MAX_BODY_SIZE].
+    fn default() -> Self {
+        Self {
+            max_body_size: Self::DEFAULT_MAX_BODY_SIZE,
+        }
+    }
+}
+
+/// IP addresses webhooks are accepted from.
+///
+/// Starts with the addresses hard-coded in this crate, see [Paddle::ALLOWED_WEBHOOK_IPS_PRODUCTION] and [Paddle::ALLOWED_WEBHOOK_IPS_SANDBOX]. Call [refresh](Self::refresh) periodically to pick up changes Paddle publishes without upgrading the crate. Clones share the same list, so a clone can be refreshed in the background while others are used to check requests.
+///
+/// ```rust,no_run
+/// use paddle_rust_sdk::webhooks::WebhookIpAllowlist;
+/// use paddle_rust_sdk::Paddle;
+///
+/// let client = Paddle::new("your_api_key", Paddle::PRODUCTION).unwrap();
+/// let allowlist = WebhookIpAllowlist::production();
+///
+/// tokio::spawn({
+///     let allowlist = allowlist.clone();
+///     async move {
+///         loop {
+///             // On failure the previous list is kept
+///             let _ = allowlist.refresh(&client).await;
+///             tokio::time::sleep(std::time::Duration::from_secs(3600)).await;
+///         }
+///     }
+/// });
+///
+/// if !allowlist.contains(peer_addr.ip()) {
+///     return respond(403);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct WebhookIpAllowlist {
+    networks: Arc<RwLock<Vec<Ipv4Network>>>,
+}
+
+/// An IPv4 CIDR block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Ipv4Network {
+    address: u32,
+    prefix_len: u32,
+}
+
+impl Ipv4Network {
+    fn contains(&self, ip: Ipv4Addr) -> bool {
+        let mask = u32::MAX.checked_shl(32 - self.prefix_len).unwrap_or(0);
+        u32::from(ip) & mask == self.address & mask
+    }
+}
+
+impl FromStr for Ipv4Network {
+    type Err = Error;
+
+    fn from_str(cidr: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidCidr(cidr.to_string());
+
+        let (address, prefix_len) = cidr.split_once('/').unwrap_or((cidr, "32"));
+        let address: Ipv4Addr = address.parse().map_err(|_| invalid())?;
+        let prefix_len: u32 = prefix_len.parse().map_err(|_| invalid())?;
+
+        if prefix_len > 32 {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            address: address.into(),
+            prefix_len,
+        })
+    }
+}
+
+impl WebhookIpAllowlist {
+    /// Allow the addresses Paddle sends Live environment webhooks from.
+    pub fn production() -> Self {
+        Self::from_cidrs(Paddle::ALLOWED_WEBHOOK_IPS_PRODUCTION).expect("valid IP addresses")
+    }
+
+    /// Allow the addresses Paddle sends Sandbox environment webhooks from.
+    pub fn sandbox() -> Self {
+        Self::from_cidrs(Paddle::ALLOWED_WEBHOOK_IPS_SANDBOX).expect("valid IP addresses")
+    }
+
+    /// Allow a list of IPv4 addresses or CIDR blocks, e.g. `34.194.127.46` or `34.194.127.46/32`.
+    pub fn from_cidrs(cidrs: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Self, Error> {
+        Ok(Self {
+            networks: Arc::new(RwLock::new(parse_cidrs(cidrs)?)),
+        })
+    }
+
+    /// Whether a webhook request from `ip` should be accepted. IPv4-mapped IPv6 addresses are checked as IPv4.
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        let ip = match ip {
+            IpAddr::V4(ip) => ip,
+            IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+                Some(ip) => ip,
+                None => return false,
+            },
+        };
+
+        self.networks
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .any(|network| network.contains(ip))
+    }
+
+    /// Replace the list with the addresses Paddle currently publishes for the environment of `client`.
+    ///
+    /// The list is left unchanged if the request fails or Paddle returns no addresses.
+    pub async fn refresh(&self, client: &Paddle) -> Result<(), Error> {
+        let ips = client.ip_addresses().await?.data;
+        let networks = parse_cidrs(&ips.ipv4_cidrs)?;
+
+        if networks.is_empty() {
+            return Err(Error::UnexpectedResponse(
+                "Paddle returned no webhook IP addresses".to_string(),
+            ));
+        }
+
+        *self
+            .networks
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = networks;
+
+        Ok(())
+    }
+}
+
+fn parse_cidrs(
+    cidrs: impl IntoIterator<Item = impl AsRef<str>>,
+) -> Result<Vec<Ipv4Network>, Error> {
+    cidrs
+        .into_iter()
+        .map(|cidr| cidr.as_ref().trim().parse())
+        .collect()
+}
+
+/// Callbacks for webhook verification failures, e.g. for metrics and alerts.
+///
+/// A spike in invalid signatures usually means the secret key doesn't match the notification destination, for example after the key was rotated. Pass a hook to [WebhookVerifier::hook]. Every method does nothing by default.
+///
+/// ```rust,no_run
+/// use paddle_rust_sdk::webhooks::VerificationHook;
+///
+/// #[derive(Debug)]
+/// struct Metrics;
+///
+/// impl VerificationHook for Metrics {
+///     fn on_invalid_signature(&self) {
+///         metrics::counter!("paddle_webhook_invalid_signature").increment(1);
+///     }
+/// }
+/// ```
+pub trait VerificationHook: fmt::Debug + Send + Sync {
+    /// None of the signatures match the request body and secret key.
+    fn on_invalid_signature(&self) {}
+
+    /// The signature is older than the allowed `maximum_variance`, which is passed to the hook.
+    fn on_expired_timestamp(&self, maximum_variance: Duration) {
+        let _ = maximum_variance;
+    }
+
+    /// The signature header is malformed or the request body isn't a valid event.
+    fn on_parse_error(&self, err: &Error) {
+        let _ = err;
+    }
+}
+
+/// Call the [VerificationHook] method matching a verification error.
+pub(crate) fn report_verification_error(hook: &dyn VerificationHook, err: &Error) {
+    match err {
+        Error::MacError(_) => hook.on_invalid_signature(),
+        Error::PaddleSignature(SignatureError::MaxVarianceExceeded(maximum_variance)) => {
+            hook.on_expired_timestamp(*maximum_variance)
+        }
+        Error::PaddleSignature(_) | Error::ParseIntError(_) | Error::JsonError(_) => {
+            hook.on_parse_error(err)
+        }
+        _ => {}
+    }
+}
+
+/// Processes webhook events for the same entity one at a time.
+///
+/// Web servers handle requests concurrently, so two events for the same subscription can be processed at the same time and the older one may be written last. Wrap event handling in [EntityLocks::run] to process events for the same entity in the order their requests arrived, while events for different entities still run concurrently.
+///
+/// Paddle doesn't guarantee delivery order. Compare `occurred_at` with the last processed event of the entity to skip events that arrive late.
+///
+/// ```rust,no_run
+/// use paddle_rust_sdk::webhooks::EntityLocks;
+///
+/// // Shared between all requests, e.g. as application state
+/// let locks = EntityLocks::new();
+///
+/// locks.run(&event, async {
+///     // Sync the entity to your database
+/// }).await;
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct EntityLocks {
+    locks: Arc<Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>>,
+}
+
+impl EntityLocks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `handler` once no other handler for the entity of `event` is running.
+    pub async fn run<T>(&self, event: &Event, handler: impl Future<Output = T>) -> T {
+        self.run_keyed(event.data.entity_id(), handler).await
+    }
+
+    /// Run `handler` once no other handler with the same `key` is running. Use this to serialize events by something other than their own entity, e.g. the subscription ID of `transaction.*` events.
+    pub async fn run_keyed<T>(&self, key: &str, handler: impl Future<Output = T>) -> T {
+        let lock = self
+            .locks
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(key.to_string())
+            .or_default()
+            .clone();
+
+        // Forgets the lock when the handler completes or is cancelled, also while waiting for the lock
+        let entry = LockEntry {
+            locks: &self.locks,
+            key,
+            lock: Some(lock),
+        };
+
+        let _guard = entry.lock.as_ref().unwrap().lock().await;
+        handler.await
+    }
+}
+
+/// Lock of a key in [EntityLocks], removed from the map on drop once no other handler is waiting for it.
+struct LockEntry<'a> {
+    locks: &'a Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+    key: &'a str,
+    lock: Option<Arc<tokio::sync::Mutex<()>>>,
+}
+
+impl Drop for LockEntry<'_> {
+    fn drop(&mut self) {
+        let mut locks = self.locks.lock().unwrap_or_else(PoisonError::into_inner);
+        drop(self.lock.take());
+
+        if locks
+            .get(self.key)
+            .is_some_and(|lock| Arc::strong_count(lock) == 1)
+        {
+            locks.remove(self.key);
+        }
+    }
+}
+
+/// How often [Paddle::test_webhook](crate::Paddle::test_webhook) checks whether the simulated event was delivered.
+pub(crate) const WEBHOOK_TEST_POLL_INTERVAL: std::time::Duration =
+    std::time::Duration::from_secs(2);
+
+/// How long [Paddle::test_webhook](crate::Paddle::test_webhook) waits for the simulated event to be delivered.
+pub(crate) const WEBHOOK_TEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Result of delivering a simulated event with [Paddle::test_webhook](crate::Paddle::test_webhook).
+#[derive(Clone, Debug)]
+pub struct WebhookTest {
+    /// Simulation created for the test. It's archived once the test completes.
+    pub simulation_id: SimulationID,
+    /// The simulated event along with the request Paddle sent and the response from your endpoint.
+    pub event: SimulationEvent,
+}
+
+impl WebhookTest {
+    /// Whether your endpoint accepted the event.
+    pub fn delivered(&self) -> bool {
+        self.event.status == SimulationEventStatus::Success
+    }
+
+    /// Status code and body your endpoint responded with. `None` if it didn't respond.
+    pub fn response(&self) -> Option<&SimulationEventResponse> {
+        self.event.response.as_ref()
+    }
+}
+
 /// Maximum allowed age for a signature.
 ///
 /// Signatures sent by Paddle contain the timestamp when they were generated. Pass this struct to [Paddle::unmarshal](crate::Paddle::unmarshal) to set the maximum allowed age for signatures.
 ///
 /// [MaximumVariance::default] - signatures cannot be older than 5 seconds.
+#[derive(Clone, Copy, Debug)]
 pub struct MaximumVariance(pub Option<Duration>);
 
 impl MaximumVariance {
@@ -31,15 +418,155 @@ impl Default for MaximumVariance {
     }
 }
 
+/// Verifies webhook requests and deserializes their events.
+///
+/// Collects every verification option in one place, so they can be combined - a [VerificationHook], an API version check, a [CustomDataHook] and batched deliveries. [Paddle::unmarshal] and its variants are shorthands for a verifier with a single option set.
+///
+/// ```rust,no_run
+/// use paddle_rust_sdk::webhooks::{MaximumVariance, WebhookVerifier};
+///
+/// // Created once, e.g. as application state
+/// let verifier = WebhookVerifier::new("your_webhook_key")
+///     .maximum_variance(MaximumVariance::seconds(10))
+///     .api_version(1)
+///     .hook(metrics);
+///
+/// for event in verifier.unmarshal_any(request_body, signature)? {
+///     println!("{}", event.event_id);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct WebhookVerifier {
+    secret_key: String,
+    maximum_variance: MaximumVariance,
+    api_version: Option<i64>,
+    hook: Option<Arc<dyn VerificationHook>>,
+    custom_data_hook: Option<Arc<dyn CustomDataHook>>,
+}
+
+impl WebhookVerifier {
+    /// Verify requests signed with `secret_key`, the secret key of the notification destination.
+    pub fn new(secret_key: impl Into<String>) -> Self {
+        Self {
+            secret_key: secret_key.into(),
+            maximum_variance: MaximumVariance::default(),
+            api_version: None,
+            hook: None,
+            custom_data_hook: None,
+        }
+    }
+
+    /// Maximum allowed age for a signature. Defaults to [MaximumVariance::default].
+    pub fn maximum_variance(mut self, maximum_variance: MaximumVariance) -> Self {
+        self.maximum_variance = maximum_variance;
+        self
+    }
+
+    /// API version configured for the notification destination. Requests are rejected with [Error::UnsupportedApiVersion] if this crate can't deserialize it, see [check_api_version].
+    pub fn api_version(mut self, api_version: i64) -> Self {
+        self.api_version = Some(api_version);
+        self
+    }
+
+    /// Report verification failures to `hook` before returning them.
+    pub fn hook(mut self, hook: impl VerificationHook + 'static) -> Self {
+        self.hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Normalize `custom_data` of delivered events with `hook` before deserializing them.
+    pub fn custom_data_hook(mut self, hook: impl CustomDataHook + 'static) -> Self {
+        self.custom_data_hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Verify a webhook request and deserialize its event.
+    ///
+    /// - **request_body** - The raw bytes of the request body.
+    /// - **signature** - "Paddle-Signature" HTTP request header from the request.
+    pub fn unmarshal(
+        &self,
+        request_body: impl AsRef<[u8]>,
+        signature: impl AsRef<str>,
+    ) -> Result<Event, Error> {
+        self.verified(request_body.as_ref(), signature.as_ref(), |body| {
+            self.parse(body)
+        })
+    }
+
+    /// Same as [unmarshal](Self::unmarshal), but accepts both a single event and a JSON array of events, e.g. from a notification destination with batched delivery.
+    ///
+    /// A single event is returned as a one element [Vec]. The signature is verified once for the whole body.
+    pub fn unmarshal_any(
+        &self,
+        request_body: impl AsRef<[u8]>,
+        signature: impl AsRef<str>,
+    ) -> Result<Vec<Event>, Error> {
+        self.verified(request_body.as_ref(), signature.as_ref(), |body| {
+            let is_batch = body
+                .iter()
+                .find(|byte| !byte.is_ascii_whitespace())
+                .is_some_and(|byte| *byte == b'[');
+
+            if is_batch {
+                self.parse(body)
+            } else {
+                Ok(vec![self.parse(body)?])
+            }
+        })
+    }
+
+    /// Check the API version and signature, then parse the body. Failures are reported to the hook.
+    fn verified<T>(
+        &self,
+        request_body: &[u8],
+        signature: &str,
+        parse: impl FnOnce(&[u8]) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        if let Some(api_version) = self.api_version {
+            check_api_version(api_version)?;
+        }
+
+        let result = signature
+            .parse::<Signature>()
+            .and_then(|signature| {
+                signature.verify(request_body, &self.secret_key, self.maximum_variance)
+            })
+            .and_then(|()| parse(request_body));
+
+        if let (Err(err), Some(hook)) = (&result, &self.hook) {
+            report_verification_error(hook.as_ref(), err);
+        }
+
+        result
+    }
+
+    fn parse<T: DeserializeOwned>(&self, request_body: &[u8]) -> Result<T, Error> {
+        match &self.custom_data_hook {
+            Some(hook) => Ok(serde_json::from_value(custom_data::normalize_incoming(
+                request_body,
+                hook.as_ref(),
+            )?)?),
+            None => Ok(serde_json::from_slice(request_body)?),
+        }
+    }
+}
+
+/// Parsed `Paddle-Signature` header.
+///
+/// The header may contain several `h1` signatures, e.g. while a webhook secret is being rotated. Verification succeeds if any of them matches. Keys other than `ts` and `h1` are ignored, so that new signature schemes don't break verification.
 pub struct Signature {
     timestamp: DateTime<Utc>,
-    signature: Vec<u8>,
+    signatures: Vec<Vec<u8>>,
 }
 
 impl Signature {
+    /// Verify that `request_body` was signed by Paddle with `key`.
+    ///
+    /// The body is taken as raw bytes, so framework body types like `Bytes` or `Vec<u8>` can be passed without decoding them as UTF-8 first. Verification doesn't allocate.
     pub fn verify(
         &self,
-        request_body: impl AsRef<str>,
+        request_body: impl AsRef<[u8]>,
         key: impl AsRef<str>,
         maximum_variance: MaximumVariance,
     ) -> Result<(), Error> {
@@ -51,13 +578,30 @@ impl Signature {
             }
         }
 
-        let signed_payload = format!("{}:{}", self.timestamp.format("%s"), request_body.as_ref());
-
         let mut mac = HmacSha256::new_from_slice(key.as_ref().as_bytes())
             .expect("HMAC can take key of any size");
 
-        mac.update(signed_payload.as_bytes());
-        mac.verify_slice(&self.signature)?;
+        // Signed payload is `{timestamp}:{request_body}`. The longest prefix is `-9223372036854775808:`
+        let mut prefix = [0; 21];
+        let remaining = {
+            let mut cursor = &mut prefix[..];
+            write!(cursor, "{}:", self.timestamp.timestamp())
+                .expect("timestamp fits in the buffer");
+            cursor.len()
+        };
+        let prefix_len = prefix.len() - remaining;
+
+        mac.update(&prefix[..prefix_len]);
+        mac.update(request_body.as_ref());
+
+        let verified = self
+            .signatures
+            .iter()
+            .any(|signature| mac.clone().verify_slice(signature).is_ok());
+
+        if !verified {
+            return Err(Error::MacError(hmac::digest::MacError));
+        }
 
         Ok(())
     }
@@ -73,52 +617,55 @@ impl FromStr for Signature {
             return Err(Error::PaddleSignature(SignatureError::Empty));
         }
 
-        let signature_parts = signature.split(';').collect::<Vec<_>>();
-
-        if signature_parts.len() != 2 {
-            return Err(Error::PaddleSignature(SignatureError::InvalidFormat));
-        }
-
         let mut timestamp = None;
-        let mut signature = None;
-
-        for part in signature_parts {
-            let key_value = part.split('=').collect::<Vec<_>>();
+        let mut signatures = Vec::new();
 
-            if key_value.len() != 2 {
+        for part in signature.split(';').filter(|part| !part.is_empty()) {
+            let Some((key, value)) = part.split_once('=') else {
                 return Err(Error::PaddleSignature(SignatureError::InvalidPartFormat));
-            }
-
-            if key_value[0] == "ts" {
-                timestamp = DateTime::from_timestamp(key_value[1].parse()?, 0);
-            }
+            };
 
-            if key_value[0] == "h1" {
-                signature = Some(key_value[1].to_string());
+            match key {
+                "ts" => timestamp = DateTime::from_timestamp(value.parse()?, 0),
+                "h1" => signatures.push(decode_hex(value)?),
+                // Unknown signature schemes are skipped
+                _ => {}
             }
         }
 
-        let Some((timestamp, signature)) = timestamp.zip(signature) else {
+        let Some(timestamp) = timestamp else {
             return Err(Error::PaddleSignature(SignatureError::InvalidFormat));
         };
 
+        if signatures.is_empty() {
+            return Err(Error::PaddleSignature(SignatureError::InvalidFormat));
+        }
+
         Ok(Self {
             timestamp,
-            signature: decode_hex(&signature)?,
+            signatures,
         })
     }
 }
 
-fn decode_hex(s: &str) -> Result<Vec<u8>, ParseIntError> {
+fn decode_hex(s: &str) -> Result<Vec<u8>, Error> {
+    if s.is_empty() || !s.len().is_multiple_of(2) || !s.is_ascii() {
+        return Err(Error::PaddleSignature(SignatureError::InvalidPartFormat));
+    }
+
     (0..s.len())
         .step_by(2)
         .map(|i| u8::from_str_radix(&s[i..i + 2], 16))
-        .collect()
+        .collect::<Result<_, ParseIntError>>()
+        .map_err(Error::from)
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
     use super::*;
+    use crate::fixtures;
 
     #[test]
     fn parsing_correctly() {
@@ -138,4 +685,412 @@ mod tests {
             "ts=1671552a777;h1=eb4d0dc8853be92b7f063b9f3ba5233eb920a09459b6e6b2c26705b4364db151";
         assert!(signature_str.parse::<Signature>().is_err());
     }
+
+    #[test]
+    fn unknown_keys_are_skipped() {
+        let signature_str = "ts=1671552777;p1=abc;h1=eb4d0dc8853be92b7f063b9f3ba5233eb920a09459b6e6b2c26705b4364db151;h2=0f";
+        let sig: Signature = signature_str.parse().expect("To parse correctly");
+        assert_eq!(sig.signatures.len(), 1);
+
+        let signature_str = "ts=1671552777;p1=abc";
+        assert!(signature_str.parse::<Signature>().is_err());
+    }
+
+    #[test]
+    fn verifies_any_h1_signature() {
+        let body = r#"{"event_id":"evt_01h8bzakzx3hm2fmen703n5q45"}"#;
+        let key = "pdl_ntfset_01h8bzakzx3hm2fmen703n5q45_secret";
+        let timestamp = Utc::now().timestamp();
+
+        let mut mac = HmacSha256::new_from_slice(key.as_bytes()).unwrap();
+        mac.update(format!("{}:{}", timestamp, body).as_bytes());
+        let valid = mac
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+        let invalid = "00".repeat(32);
+
+        let signature_str = format!("ts={timestamp};h1={invalid};x1=unknown;h1={valid}");
+        let sig: Signature = signature_str.parse().expect("To parse correctly");
+        assert!(sig.verify(body, key, MaximumVariance::default()).is_ok());
+
+        let signature_str = format!("ts={timestamp};h1={invalid}");
+        let sig: Signature = signature_str.parse().expect("To parse correctly");
+        assert!(sig.verify(body, key, MaximumVariance::default()).is_err());
+    }
+
+    #[test]
+    fn verifies_bodies_that_are_not_utf8() {
+        let body: &[u8] = b"{\"event_id\":\"\xff\xfe\"}";
+        let key = "pdl_ntfset_01h8bzakzx3hm2fmen703n5q45_secret";
+        let timestamp = Utc::now().timestamp();
+
+        let mut mac = HmacSha256::new_from_slice(key.as_bytes()).unwrap();
+        mac.update(format!("{timestamp}:").as_bytes());
+        mac.update(body);
+        let valid = mac
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+
+        let sig: Signature = format!("ts={timestamp};h1={valid}").parse().unwrap();
+        assert!(sig.verify(body, key, MaximumVariance::default()).is_ok());
+        assert!(sig
+            .verify(&body[1..], key, MaximumVariance::default())
+            .is_err());
+    }
+
+    #[test]
+    fn odd_length_signature() {
+        assert!("ts=1671552777;h1=abc".parse::<Signature>().is_err());
+        assert!("ts=1671552777;h1=éé".parse::<Signature>().is_err());
+    }
+
+    #[test]
+    fn preflight_rejections() {
+        let preflight = WebhookPreflight::default().max_body_size(10);
+        let signature = Some("ts=1671552777;h1=00");
+
+        assert!(preflight
+            .check(Some("application/json"), signature, 10)
+            .is_ok());
+        assert!(preflight
+            .check(Some("Application/JSON; charset=utf-8"), signature, 1)
+            .is_ok());
+
+        assert_eq!(
+            preflight.check(Some("application/json"), signature, 11),
+            Err(WebhookRejection::BodyTooLarge {
+                size: 11,
+                limit: 10
+            })
+        );
+        assert_eq!(
+            preflight.check(Some("application/json"), signature, 0),
+            Err(WebhookRejection::EmptyBody)
+        );
+        assert_eq!(
+            preflight.check(None, signature, 1),
+            Err(WebhookRejection::MissingContentType)
+        );
+        assert_eq!(
+            preflight.check(Some("text/plain"), signature, 1),
+            Err(WebhookRejection::UnsupportedContentType(
+                "text/plain".to_string()
+            ))
+        );
+        assert_eq!(
+            preflight.check(Some("application/json"), Some(" "), 1),
+            Err(WebhookRejection::MissingSignature)
+        );
+    }
+
+    #[test]
+    fn ip_allowlist() {
+        let allowlist = WebhookIpAllowlist::sandbox();
+        assert!(allowlist.contains("34.194.127.46".parse().unwrap()));
+        assert!(allowlist.contains("::ffff:34.194.127.46".parse().unwrap()));
+        assert!(!allowlist.contains("34.194.127.47".parse().unwrap()));
+
+        let allowlist = WebhookIpAllowlist::from_cidrs(["10.0.0.0/8", "0.0.0.0/0"]).unwrap();
+        assert!(allowlist.contains("192.168.1.1".parse().unwrap()));
+
+        assert!(matches!(
+            WebhookIpAllowlist::from_cidrs(["10.0.0.0/33"]),
+            Err(Error::InvalidCidr(cidr)) if cidr == "10.0.0.0/33"
+        ));
+        assert!(WebhookIpAllowlist::from_cidrs(["10.0.0/8"]).is_err());
+    }
+
+    #[tokio::test]
+    async fn ip_allowlist_refresh() {
+        let url = crate::fixtures::serve_json(|_| async {
+            serde_json::json!({
+                "data": { "ipv4_cidrs": ["10.0.0.0/24"] },
+                "meta": { "request_id": "req_1" }
+            })
+        })
+        .await;
+
+        let allowlist = WebhookIpAllowlist::production();
+        let shared = allowlist.clone();
+        allowlist
+            .refresh(&Paddle::new("key", url.as_str()).unwrap())
+            .await
+            .unwrap();
+
+        assert!(shared.contains("10.0.0.255".parse().unwrap()));
+        assert!(!shared.contains("34.232.58.13".parse().unwrap()));
+    }
+
+    #[derive(Debug, Default)]
+    struct CountingHook {
+        invalid: AtomicUsize,
+        expired: AtomicUsize,
+        parse: AtomicUsize,
+    }
+
+    impl VerificationHook for CountingHook {
+        fn on_invalid_signature(&self) {
+            self.invalid.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn on_expired_timestamp(&self, _: Duration) {
+            self.expired.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn on_parse_error(&self, _: &Error) {
+            self.parse.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn verification_hook_is_told_why_verification_failed() {
+        let hook = CountingHook::default();
+        let invalid = "00".repeat(32);
+        let now = Utc::now().timestamp();
+        let unmarshal = |signature: &str| {
+            crate::Paddle::unmarshal_with_hook(
+                "{}",
+                "secret",
+                signature,
+                MaximumVariance::default(),
+                &hook,
+            )
+        };
+
+        assert!(unmarshal(&format!("ts={now};h1={invalid}")).is_err());
+        assert!(unmarshal(&format!("ts=1671552777;h1={invalid}")).is_err());
+        assert!(unmarshal("ts=abc").is_err());
+
+        assert_eq!(hook.invalid.load(Ordering::SeqCst), 1);
+        assert_eq!(hook.expired.load(Ordering::SeqCst), 1);
+        assert_eq!(hook.parse.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn unmarshal_any_accepts_single_and_batched_events() {
+        let key = "pdl_ntfset_01h8bzakzx3hm2fmen703n5q45_secret";
+        let event = fixtures::event("customer.created", fixtures::customer());
+        let sign = |body: &str| {
+            let timestamp = Utc::now().timestamp();
+            let mut mac = HmacSha256::new_from_slice(key.as_bytes()).unwrap();
+            mac.update(format!("{timestamp}:{body}").as_bytes());
+            let h1 = mac
+                .finalize()
+                .into_bytes()
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<String>();
+
+            format!("ts={timestamp};h1={h1}")
+        };
+
+        let single = serde_json::to_string(&event).unwrap();
+        let events =
+            crate::Paddle::unmarshal_any(&single, key, sign(&single), MaximumVariance::default())
+                .unwrap();
+        assert_eq!(events.len(), 1);
+
+        let batch = format!("\n [{single},{single}]");
+        let events =
+            crate::Paddle::unmarshal_any(&batch, key, sign(&batch), MaximumVariance::default())
+                .unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[1].event_id, event.event_id);
+
+        assert!(crate::Paddle::unmarshal_any(
+            &batch,
+            key,
+            sign(&single),
+            MaximumVariance::default()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn verifier_options_compose() {
+        let key = "pdl_ntfset_01h8bzakzx3hm2fmen703n5q45_secret";
+        let hook = Arc::new(CountingHook::default());
+
+        let mut customer = fixtures::customer();
+        customer["custom_data"] = serde_json::json!({ "plan": "pro", "coupon": null });
+        let event = serde_json::to_string(&fixtures::event("customer.created", customer)).unwrap();
+        let batch = format!("[{event},{event}]");
+
+        let verifier = WebhookVerifier::new(key)
+            .api_version(SUPPORTED_API_VERSION)
+            .hook(SharedHook(hook.clone()))
+            .custom_data_hook(crate::custom_data::CustomDataLimits {
+                strip_nulls: true,
+                max_keys: None,
+                max_bytes: None,
+            });
+
+        let events = verifier
+            .unmarshal_any(&batch, fixtures::sign(batch.as_bytes(), key))
+            .unwrap();
+        assert_eq!(events.len(), 2);
+
+        for event in events {
+            let crate::enums::EventData::CustomerCreated(customer) = event.data else {
+                panic!("unexpected event {:?}", event.data);
+            };
+            assert_eq!(
+                customer.custom_data,
+                Some(serde_json::json!({ "plan": "pro" }))
+            );
+        }
+
+        assert!(verifier
+            .unmarshal(&event, fixtures::sign(batch.as_bytes(), key))
+            .is_err());
+        assert!(verifier
+            .clone()
+            .maximum_variance(MaximumVariance::seconds(0))
+            .unmarshal(&event, format!("ts=1671552777;h1={}", "00".repeat(32)))
+            .is_err());
+
+        assert_eq!(hook.invalid.load(Ordering::SeqCst), 1);
+        assert_eq!(hook.expired.load(Ordering::SeqCst), 1);
+    }
+
+    /// [CountingHook] shared with the test, so its counters can be read after it's given to a verifier.
+    #[derive(Debug)]
+    struct SharedHook(Arc<CountingHook>);
+
+    impl VerificationHook for SharedHook {
+        fn on_invalid_signature(&self) {
+            self.0.on_invalid_signature();
+        }
+
+        fn on_expired_timestamp(&self, maximum_variance: Duration) {
+            self.0.on_expired_timestamp(maximum_variance);
+        }
+
+        fn on_parse_error(&self, err: &Error) {
+            self.0.on_parse_error(err);
+        }
+    }
+
+    #[test]
+    fn api_version_mismatch() {
+        assert!(check_api_version(SUPPORTED_API_VERSION).is_ok());
+        assert!(matches!(
+            check_api_version(SUPPORTED_API_VERSION + 1),
+            Err(Error::UnsupportedApiVersion(_))
+        ));
+    }
+
+    #[test]
+    fn events_convert_into_their_entities() {
+        use crate::entities::{Customer, Subscription, Transaction};
+
+        let mut created = fixtures::subscription();
+        created["transaction_id"] = "txn_01hv8wptq8987qeep44cyrewp9".into();
+        let subscription =
+            Subscription::try_from(fixtures::event("subscription.created", created)).unwrap();
+        assert_eq!(subscription.id.as_ref(), "sub_01hv8x29kz0t586xy6zn1a62ny");
+
+        let event = fixtures::event("subscription.canceled", fixtures::subscription());
+        assert!(Subscription::try_from(event).is_ok());
+
+        let event = fixtures::event("transaction.completed", fixtures::transaction());
+        let transaction = Transaction::try_from(event).unwrap();
+        assert_eq!(transaction.id.as_ref(), "txn_01hv8wptq8987qeep44cyrewp9");
+
+        // The event is handed back untouched so it can be tried as another entity
+        let event = fixtures::event("customer.updated", fixtures::customer());
+        let event = Subscription::try_from(event).unwrap_err();
+        let customer: Customer = event.try_into().unwrap();
+        assert_eq!(customer.email, "sam@example.com");
+    }
+
+    #[tokio::test]
+    async fn entity_locks_serialize_handlers_per_key() {
+        let locks = EntityLocks::new();
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let first = tokio::spawn({
+            let (locks, order) = (locks.clone(), order.clone());
+            async move {
+                locks
+                    .run_keyed("sub_01", async {
+                        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                        order.lock().unwrap().push("sub_01 first");
+                    })
+                    .await
+            }
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+
+        let second = tokio::spawn({
+            let (locks, order) = (locks.clone(), order.clone());
+            async move {
+                locks
+                    .run_keyed("sub_01", async {
+                        order.lock().unwrap().push("sub_01 second");
+                    })
+                    .await
+            }
+        });
+        locks
+            .run_keyed("sub_02", async {
+                order.lock().unwrap().push("sub_02");
+            })
+            .await;
+
+        first.await.unwrap();
+        second.await.unwrap();
+
+        assert_eq!(
+            *order.lock().unwrap(),
+            ["sub_02", "sub_01 first", "sub_01 second"]
+        );
+        assert!(locks.locks.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn entity_locks_forget_cancelled_handlers() {
+        let locks = EntityLocks::new();
+
+        // Cancelled while running the handler
+        let running = locks.run_keyed("sub_01", std::future::pending::<()>());
+        assert!(
+            tokio::time::timeout(std::time::Duration::from_millis(10), running)
+                .await
+                .is_err()
+        );
+        assert!(locks.locks.lock().unwrap().is_empty());
+
+        // Cancelled while waiting for another handler
+        let (release, released) = tokio::sync::oneshot::channel::<()>();
+        let holder = tokio::spawn({
+            let locks = locks.clone();
+            async move {
+                locks
+                    .run_keyed("sub_01", async {
+                        let _ = released.await;
+                    })
+                    .await
+            }
+        });
+        tokio::task::yield_now().await;
+
+        let waiting = locks.run_keyed("sub_01", async {});
+        assert!(
+            tokio::time::timeout(std::time::Duration::from_millis(10), waiting)
+                .await
+                .is_err()
+        );
+        assert_eq!(locks.locks.lock().unwrap().len(), 1);
+
+        release.send(()).unwrap();
+        holder.await.unwrap();
+        assert!(locks.locks.lock().unwrap().is_empty());
+    }
 }
@@ -0,0 +1,360 @@
+//! Integration tests against the Paddle sandbox.
+//!
+//! Every test creates the entities it needs, so they can be run against any sandbox account:
+//!
+//! ```sh
+//! PADDLE_SANDBOX_KEY=your_sandbox_api_key cargo test --features sandbox-tests --test sandbox
+//! ```
+//!
+//! Tests are skipped when `PADDLE_SANDBOX_KEY` isn't set.
+
+#![cfg(feature = "sandbox-tests")]
+
+use std::collections::HashMap;
+
+use chrono::{Months, Utc};
+use paddle_rust_sdk::entities::{
+    Contact, Duration, PricePreviewItem, TransactionItemNonCatalogPrice,
+};
+use paddle_rust_sdk::enums::{
+    CountryCodeSupported, CurrencyCode, DiscountType, TaxCategory, TransactionInclude,
+};
+use paddle_rust_sdk::Paddle;
+
+/// Client for the sandbox, or `None` when `PADDLE_SANDBOX_KEY` isn't set.
+fn sandbox() -> Option<Paddle> {
+    let Ok(api_key) = std::env::var("PADDLE_SANDBOX_KEY") else {
+        eprintln!("PADDLE_SANDBOX_KEY is not set, skipping");
+        return None;
+    };
+
+    Some(Paddle::new(api_key, Paddle::SANDBOX).unwrap())
+}
+
+/// Unique suffix for names and emails, so reruns don't collide with entities left from earlier runs.
+fn unique() -> String {
+    Utc::now().timestamp_micros().to_string()
+}
+
+#[tokio::test]
+async fn products_and_prices() {
+    let Some(client) = sandbox() else { return };
+
+    let product = client
+        .product_create(
+            format!("SDK test product {}", unique()),
+            TaxCategory::Standard,
+        )
+        .description("Created by the sandbox tests")
+        .custom_data([("source".to_string(), "sandbox-tests".to_string())].into())
+        .send()
+        .await
+        .unwrap()
+        .data;
+
+    let fetched = client
+        .product_get(product.id.clone())
+        .send()
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(fetched.id, product.id);
+
+    let updated = client
+        .product_update(product.id.clone())
+        .description("Updated by the sandbox tests")
+        .send()
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(
+        updated.description.as_deref(),
+        Some("Updated by the sandbox tests")
+    );
+
+    let price = client
+        .price_create(product.id.clone(), "SDK test price", 999, CurrencyCode::USD)
+        .name("Monthly")
+        .billing_cycle(Duration::months(1))
+        .trial_period(Duration::days(14))
+        .add_unit_price_override([CountryCodeSupported::BG], 555, CurrencyCode::USD)
+        .quantity(1..200)
+        .send()
+        .await
+        .unwrap()
+        .data;
+
+    let fetched = client
+        .price_get(price.id.clone())
+        .send()
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(fetched.id, price.id);
+
+    let updated = client
+        .price_update(price.id.clone())
+        .name("Updated name")
+        .send()
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(updated.name.as_deref(), Some("Updated name"));
+
+    let preview = client
+        .pricing_preview([PricePreviewItem::new(price.id.clone(), 1)])
+        .send()
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(preview.details.line_items.len(), 1);
+
+    client
+        .products_list()
+        .per_page(10)
+        .send()
+        .next()
+        .await
+        .unwrap();
+    client
+        .prices_list()
+        .per_page(10)
+        .send()
+        .next()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn customers_addresses_and_businesses() {
+    let Some(client) = sandbox() else { return };
+
+    let customer = client
+        .customer_create(format!("sdk-{}@example.com", unique()))
+        .name("John Doe")
+        .send()
+        .await
+        .unwrap()
+        .data;
+
+    let fetched = client
+        .customer_get(customer.id.clone())
+        .send()
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(fetched.id, customer.id);
+
+    let updated = client
+        .customer_update(customer.id.clone())
+        .name("Jane Doe")
+        .send()
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(updated.name.as_deref(), Some("Jane Doe"));
+
+    let address = client
+        .address_create(customer.id.clone(), CountryCodeSupported::US)
+        .first_line("Address first line")
+        .city("Atlanta")
+        .postal_code("30033")
+        .region("GA")
+        .send()
+        .await
+        .unwrap()
+        .data;
+
+    client
+        .address_get(customer.id.clone(), address.id.clone())
+        .send()
+        .await
+        .unwrap();
+
+    let updated = client
+        .address_update(customer.id.clone(), address.id.clone())
+        .first_line("Updated first line")
+        .send()
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(updated.first_line.as_deref(), Some("Updated first line"));
+
+    let business = client
+        .business_create(customer.id.clone(), "Company Inc.")
+        .company_number("202835086")
+        .contacts([Contact::new("Primary Contact", "contact@example.com")])
+        .send()
+        .await
+        .unwrap()
+        .data;
+
+    client
+        .business_get(customer.id.clone(), business.id.clone())
+        .send()
+        .await
+        .unwrap();
+
+    let updated = client
+        .business_update(customer.id.clone(), business.id.clone())
+        .name("Updated Company Inc.")
+        .send()
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(updated.name, "Updated Company Inc.");
+
+    client
+        .customers_list()
+        .per_page(10)
+        .send()
+        .next()
+        .await
+        .unwrap();
+    client
+        .addresses_list(customer.id.clone())
+        .send()
+        .next()
+        .await
+        .unwrap();
+    client
+        .businesses_list(customer.id.clone())
+        .send()
+        .next()
+        .await
+        .unwrap();
+    client
+        .payment_methods_list(customer.id.clone())
+        .send()
+        .next()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn discounts() {
+    let Some(client) = sandbox() else { return };
+
+    let discount = client
+        .discount_create("15", "SDK test discount", DiscountType::Percentage)
+        .enabled_for_checkout(true)
+        .code(format!("SDK{}", unique()))
+        .usage_limit(10)
+        .expires_at(Utc::now() + Months::new(1))
+        .send()
+        .await
+        .unwrap()
+        .data;
+
+    let fetched = client
+        .discount_get(discount.id.clone())
+        .send()
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(fetched.id, discount.id);
+
+    let updated = client
+        .discount_update(discount.id.clone())
+        .amount("18")
+        .send()
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(updated.amount, "18");
+
+    client
+        .discounts_list()
+        .per_page(10)
+        .send()
+        .next()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn transactions() {
+    let Some(client) = sandbox() else { return };
+
+    let item = TransactionItemNonCatalogPrice::new("SDK test item", 1000, CurrencyCode::USD)
+        .name("SDK test item");
+
+    let preview = client
+        .transaction_preview()
+        .append_non_catalog_item(item.clone(), 1)
+        .send()
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(preview.items.len(), 1);
+
+    let customer = client
+        .customer_create(format!("sdk-{}@example.com", unique()))
+        .send()
+        .await
+        .unwrap()
+        .data;
+
+    let transaction = client
+        .transaction_create()
+        .customer_id(customer.id.clone())
+        .include([TransactionInclude::Customer])
+        .append_non_catalog_item(item, 2)
+        .send()
+        .await
+        .unwrap()
+        .data;
+
+    let fetched = client
+        .transaction_get(transaction.id.clone())
+        .send()
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(fetched.transaction.id, transaction.id);
+
+    client
+        .transaction_update(transaction.id.clone())
+        .custom_data(HashMap::from([(
+            "source".to_string(),
+            "sandbox-tests".to_string(),
+        )]))
+        .send()
+        .await
+        .unwrap();
+
+    client
+        .transactions_list()
+        .per_page(10)
+        .send()
+        .next()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn subscriptions_events_and_reports() {
+    let Some(client) = sandbox() else { return };
+
+    // Subscriptions can only be created through checkout, so only the read paths are covered.
+    let subscriptions = client
+        .subscriptions_list()
+        .per_page(1)
+        .send()
+        .next()
+        .await
+        .unwrap();
+
+    if let Some(subscription) = subscriptions.and_then(|page| page.data.into_iter().next()) {
+        client
+            .subscription_get(subscription.id)
+            .send()
+            .await
+            .unwrap();
+    }
+
+    client.adjustments_list().send().next().await.unwrap();
+    client.events_list().send().next().await.unwrap();
+    client.event_types_list().await.unwrap();
+    client.reports_list().send().next().await.unwrap();
+}